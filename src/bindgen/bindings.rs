@@ -4,18 +4,27 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path;
 use std::rc::Rc;
+use std::sync::Arc;
 
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::Config;
+use crate::bindgen::diff::{self, AbiDiff};
+use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
-    Constant, Function, ItemContainer, ItemMap, Path as BindgenPath, Static, Struct, Typedef,
+    Constant, Function, IntKind, ItemContainer, ItemMap, Path as BindgenPath, PrimitiveType,
+    Static, Struct, Type, Typedef, VariantBody,
 };
-use crate::bindgen::writer::{Source, SourceWriter};
+use crate::bindgen::json;
+use crate::bindgen::language_backend::{self, LanguageBackend};
+use crate::bindgen::layout::{self, ItemLayout};
+use crate::bindgen::split::{self, HeaderSplit};
+use crate::bindgen::symbols;
+use crate::bindgen::writer::SourceWriter;
 
 /// A bindings header that can be written.
 pub struct Bindings {
@@ -25,20 +34,28 @@ pub struct Bindings {
     struct_map: ItemMap<Struct>,
     typedef_map: ItemMap<Typedef>,
     struct_fileds_memo: RefCell<HashMap<BindgenPath, Rc<Vec<String>>>>,
+    /// The integer types of enums without data, and of the tags of enums with
+    /// data, by export name. Python needs these to refer to an enum as a type.
+    enum_reprs: HashMap<String, PrimitiveType>,
+    /// The layouts of the structs, unions, enums and typedefs, by path.
+    layouts: HashMap<BindgenPath, ItemLayout>,
     globals: Vec<Static>,
     constants: Vec<Constant>,
     items: Vec<ItemContainer>,
     functions: Vec<Function>,
+    /// The functions of all the headers of `[split]`, for the table of
+    /// `[loader]` in the umbrella header.
+    loader_functions: Option<Vec<Function>>,
+    /// The paths of the instantiations of generic items, which have no name
+    /// in Rust.
+    monomorph_paths: HashSet<BindgenPath>,
+    /// How to split the items into the headers of `[split]`, if enabled.
+    split: Option<HeaderSplit>,
     source_files: Vec<path::PathBuf>,
     /// Bindings are generated by a recursive call to cbindgen
     /// and shouldn't do anything when written anywhere.
     noop: bool,
-}
-
-#[derive(PartialEq, Eq)]
-enum NamespaceOperation {
-    Open,
-    Close,
+    language_backend: Arc<dyn LanguageBackend>,
 }
 
 impl Bindings {
@@ -51,21 +68,177 @@ impl Bindings {
         globals: Vec<Static>,
         items: Vec<ItemContainer>,
         functions: Vec<Function>,
+        monomorph_paths: HashSet<BindgenPath>,
+        split: Option<HeaderSplit>,
         source_files: Vec<path::PathBuf>,
         noop: bool,
+        language_backend: Arc<dyn LanguageBackend>,
     ) -> Bindings {
+        let mut enum_reprs = HashMap::new();
+        for item in &items {
+            if let ItemContainer::Enum(ref x) = *item {
+                // C leaves the size of an enum without a `#[repr(prim)]` up to the
+                // implementation, but in practice it's always an `int`.
+                let repr = x.repr.ty.map_or(
+                    PrimitiveType::Integer {
+                        zeroable: true,
+                        signed: true,
+                        kind: IntKind::Int,
+                    },
+                    |ty| ty.to_primitive(),
+                );
+                enum_reprs.insert(x.tag_name().to_owned(), repr);
+            }
+        }
+
+        let layouts = layout::compute_layouts(&config.layout, &items);
+
         Bindings {
             config,
             struct_map,
             typedef_map,
             struct_fileds_memo: Default::default(),
+            enum_reprs,
+            layouts,
             globals,
             constants,
             items,
             functions,
+            loader_functions: None,
+            monomorph_paths,
+            split,
             source_files,
             noop,
+            language_backend,
+        }
+    }
+
+    /// Reads back bindings written by `write_json`, to `diff` them. They are
+    /// written in the language of `config`, and laid out for its `[layout]`.
+    pub fn from_json<R: Read>(config: Config, reader: R) -> Result<Bindings, Error> {
+        let value =
+            serde_json::from_reader(reader).map_err(|e| Error::InvalidJson(e.to_string()))?;
+        let loaded = json::load(&value, &config).map_err(Error::InvalidJson)?;
+
+        let mut struct_map = ItemMap::default();
+        let mut typedef_map = ItemMap::default();
+        for item in &loaded.items {
+            match *item {
+                ItemContainer::Struct(ref x) => {
+                    struct_map.try_insert(x.clone());
+                }
+                ItemContainer::Typedef(ref x) => {
+                    typedef_map.try_insert(x.clone());
+                }
+                _ => {}
+            }
+        }
+
+        let language_backend = language_backend::for_language(config.language);
+        Ok(Bindings::new(
+            config,
+            struct_map,
+            typedef_map,
+            loaded.constants,
+            loaded.globals,
+            loaded.items,
+            loaded.functions,
+            HashSet::new(),
+            None,
+            vec![],
+            false,
+            language_backend,
+        ))
+    }
+
+    /// The backend these are written with.
+    pub fn backend(&self) -> &dyn LanguageBackend {
+        &*self.language_backend
+    }
+
+    /// The items to write, other than constants, globals and functions.
+    pub fn items(&self) -> &[ItemContainer] {
+        &self.items
+    }
+
+    pub fn constants(&self) -> &[Constant] {
+        &self.constants
+    }
+
+    pub fn globals(&self) -> &[Static] {
+        &self.globals
+    }
+
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
+    /// The functions the table of `[loader]` has pointers to.
+    pub fn loader_functions(&self) -> &[Function] {
+        self.loader_functions.as_deref().unwrap_or(&self.functions)
+    }
+
+    /// The layout of the struct, union, enum, typedef or enum variant body at
+    /// `path`, if it's known.
+    pub fn layout(&self, path: &BindgenPath) -> Option<&ItemLayout> {
+        self.layouts.get(path)
+    }
+
+    /// Compares the ABI of these bindings to that of `new`, a later version
+    /// of them, for changes that would break code built against these.
+    pub fn diff(&self, new: &Bindings) -> AbiDiff {
+        diff::diff(self, new)
+    }
+
+    /// Whether any of the types written is atomic, for which C++ needs
+    /// `<atomic>`.
+    pub(crate) fn uses_atomics(&self) -> bool {
+        let struct_uses_atomics = |s: &Struct| s.fields.iter().any(|f| f.ty.has_atomic());
+        self.items.iter().any(|item| match *item {
+            ItemContainer::Struct(ref s) => struct_uses_atomics(s),
+            ItemContainer::Union(ref u) => u.fields.iter().any(|f| f.ty.has_atomic()),
+            ItemContainer::Enum(ref e) => e.variants.iter().any(|v| match v.body {
+                VariantBody::Body { ref body, .. } => struct_uses_atomics(body),
+                VariantBody::Empty(..) => false,
+            }),
+            ItemContainer::Typedef(ref t) => t.aliased.has_atomic(),
+            ItemContainer::Static(ref s) => s.ty.has_atomic(),
+            ItemContainer::Constant(ref c) => c.ty.has_atomic(),
+            ItemContainer::OpaqueItem(..) => false,
+        }) || self.globals.iter().any(|g| g.ty.has_atomic())
+            || self.constants.iter().any(|c| c.ty.has_atomic())
+            || self
+                .functions
+                .iter()
+                .any(|f| f.ret.has_atomic() || f.args.iter().any(|arg| arg.ty.has_atomic()))
+    }
+
+    /// The primitives the written types are made of.
+    pub(crate) fn primitives(&self) -> BTreeSet<PrimitiveType> {
+        let mut primitives = BTreeSet::new();
+        for item in &self.items {
+            for ty in item.types() {
+                ty.add_primitives(&mut primitives);
+            }
+        }
+        for global in &self.globals {
+            global.ty.add_primitives(&mut primitives);
         }
+        for constant in &self.constants {
+            constant.ty.add_primitives(&mut primitives);
+        }
+        for function in &self.functions {
+            function.ret.add_primitives(&mut primitives);
+            for arg in &function.args {
+                arg.ty.add_primitives(&mut primitives);
+            }
+        }
+        primitives
+    }
+
+    /// Whether the item at `path` is an instantiation of a generic item.
+    pub(crate) fn is_monomorph(&self, path: &BindgenPath) -> bool {
+        self.monomorph_paths.contains(path)
     }
 
     // FIXME(emilio): What to do when the configuration doesn't match?
@@ -95,6 +268,28 @@ impl Bindings {
         resolved_path
     }
 
+    pub fn enum_repr(&self, export_name: &str) -> Option<&PrimitiveType> {
+        self.enum_reprs.get(export_name)
+    }
+
+    /// The export names of the enum without data `ty` stands for, and of its
+    /// variant `name`, for languages where a variant can't be used without
+    /// its enum.
+    pub fn enum_variant(&self, ty: &Type, name: &str) -> Option<(&str, &str)> {
+        let path = match *ty {
+            Type::Path(ref generic) => generic.export_name(),
+            _ => return None,
+        };
+        self.items.iter().find_map(|item| match *item {
+            ItemContainer::Enum(ref e) if e.tag.is_none() && e.export_name == path => e
+                .variants
+                .iter()
+                .find(|v| v.name == name || v.export_name == name)
+                .map(|v| (&*e.export_name, &*v.export_name)),
+            _ => None,
+        })
+    }
+
     pub fn struct_exists(&self, path: &BindgenPath) -> bool {
         let mut any = false;
         self.struct_map
@@ -102,6 +297,20 @@ impl Bindings {
         any
     }
 
+    /// The type `path` stands for if it's a typedef or a transparent struct.
+    pub fn aliased_type(&self, path: &BindgenPath) -> Option<Type> {
+        let mut aliased = None;
+        self.typedef_map.for_items(path, |item| {
+            aliased.get_or_insert_with(|| item.aliased.clone());
+        });
+        self.struct_map.for_items(path, |item| {
+            if item.is_transparent {
+                aliased.get_or_insert_with(|| item.fields[0].ty.clone());
+            }
+        });
+        aliased
+    }
+
     pub fn struct_field_names(&self, path: &BindgenPath) -> Rc<Vec<String>> {
         let mut memos = self.struct_fileds_memo.borrow_mut();
         if let Some(memo) = memos.get(path) {
@@ -141,7 +350,7 @@ impl Bindings {
         let mut canon_source_files: Vec<_> = self
             .source_files
             .iter()
-            .chain(self.config.config_path.as_ref().into_iter())
+            .chain(&self.config.config_paths)
             .map(|p| p.canonicalize().unwrap())
             .collect();
         // Sorting makes testing easier by ensuring the output is ordered.
@@ -172,7 +381,172 @@ impl Bindings {
         depfile.flush().unwrap();
     }
 
+    /// Writes the bindings to `path`, along with the other headers of
+    /// `[split]` next to it if enabled.
     pub fn write_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        let path = path.as_ref();
+        let mut changed = self.write_to_file_with(path, |bindings, file| bindings.write(file));
+
+        if let Some(ref split) = self.split {
+            let names = self.header_names(path);
+            let dir = path.parent().unwrap_or_else(|| path::Path::new(""));
+            for header in split::MAIN + 1..split.len() {
+                let bindings = self.split_header(split, header, &names);
+                changed |=
+                    bindings.write_to_file_with(dir.join(&names[header]), |b, file| b.write(file));
+            }
+            if let Some(ref umbrella) = self.config.split.umbrella {
+                let bindings = self.umbrella_header(umbrella, &names);
+                changed |= bindings.write_to_file_with(dir.join(umbrella), |b, file| b.write(file));
+            }
+        }
+
+        changed
+    }
+
+    /// The file names of the headers of `[split]`, with the main one at
+    /// `path`.
+    fn header_names(&self, path: &path::Path) -> Vec<String> {
+        let main = path
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        std::iter::once(main)
+            .chain(self.config.split.headers.iter().map(|h| h.name.clone()))
+            .collect()
+    }
+
+    /// The bindings of one of the headers of `[split]`, including the other
+    /// headers it depends on by their `names`.
+    fn split_header(&self, split: &HeaderSplit, header: usize, names: &[String]) -> Bindings {
+        let mut config = self.config.clone();
+        config
+            .includes
+            .extend(split.includes(header).map(|h| names[h].clone()));
+        if header != split::MAIN && config.include_guard.is_some() {
+            config.include_guard = Some(include_guard(&names[header]));
+        }
+        // The table of the loader goes to the umbrella header.
+        config.loader.table = None;
+
+        let in_header = |path: &BindgenPath| split.is_in(path, header);
+        let mut items = split.forward_declarations(header, &self.items);
+        items.extend(
+            self.items
+                .iter()
+                .filter(|x| in_header(x.deref().path()))
+                .cloned(),
+        );
+        self.with_config_and_items(
+            config,
+            self.constants
+                .iter()
+                .filter(|x| in_header(&x.path))
+                .cloned()
+                .collect(),
+            self.globals
+                .iter()
+                .filter(|x| in_header(&x.path))
+                .cloned()
+                .collect(),
+            items,
+            self.functions
+                .iter()
+                .filter(|x| in_header(&x.path))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// The bindings of the umbrella header of `[split]`, which only includes
+    /// the others by their `names`.
+    fn umbrella_header(&self, umbrella: &str, names: &[String]) -> Bindings {
+        let mut config = self.config.clone();
+        config.includes.extend(names.iter().cloned());
+        config.after_includes = None;
+        if config.include_guard.is_some() {
+            config.include_guard = Some(include_guard(umbrella));
+        }
+        let mut bindings = self.with_config_and_items(config, vec![], vec![], vec![], vec![]);
+        bindings.loader_functions = Some(self.functions.clone());
+        bindings
+    }
+
+    fn with_config_and_items(
+        &self,
+        mut config: Config,
+        constants: Vec<Constant>,
+        globals: Vec<Static>,
+        items: Vec<ItemContainer>,
+        functions: Vec<Function>,
+    ) -> Bindings {
+        config.split = Default::default();
+        Bindings {
+            config,
+            struct_map: self.struct_map.clone(),
+            typedef_map: self.typedef_map.clone(),
+            struct_fileds_memo: Default::default(),
+            enum_reprs: self.enum_reprs.clone(),
+            layouts: self.layouts.clone(),
+            globals,
+            constants,
+            items,
+            functions,
+            loader_functions: None,
+            monomorph_paths: self.monomorph_paths.clone(),
+            split: None,
+            source_files: self.source_files.clone(),
+            noop: self.noop,
+            language_backend: self.language_backend.clone(),
+        }
+    }
+
+    /// Like `write_to_file`, but writes the JSON dump of `write_json`.
+    pub fn write_json_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        self.write_to_file_with(path, |bindings, file| bindings.write_json(file))
+    }
+
+    /// Like `write_to_file`, but writes the Rust layout checks of
+    /// `write_rust_layout_checks`.
+    pub fn write_rust_layout_checks_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        self.write_to_file_with(path, |bindings, file| {
+            bindings.write_rust_layout_checks(file)
+        })
+    }
+
+    /// Like `write_to_file`, but writes the C layout checks of
+    /// `write_c_layout_checks`.
+    pub fn write_c_layout_checks_to_file<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        header: &str,
+    ) -> bool {
+        self.write_to_file_with(path, |bindings, file| {
+            bindings.write_c_layout_checks(file, header)
+        })
+    }
+
+    /// Like `write_to_file`, but writes the version script of
+    /// `write_version_script`.
+    pub fn write_version_script_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        self.write_to_file_with(path, |bindings, file| bindings.write_version_script(file))
+    }
+
+    /// Like `write_to_file`, but writes the `.def` file of `write_def_file`.
+    pub fn write_def_file_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        self.write_to_file_with(path, |bindings, file| bindings.write_def_file(file))
+    }
+
+    /// Like `write_to_file`, but writes the symbol list of
+    /// `write_symbol_list`.
+    pub fn write_symbol_list_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        self.write_to_file_with(path, |bindings, file| bindings.write_symbol_list(file))
+    }
+
+    fn write_to_file_with<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+        write: impl Fn(&Self, &mut dyn Write),
+    ) -> bool {
         if self.noop {
             return false;
         }
@@ -182,12 +556,12 @@ impl Bindings {
             if let Some(parent) = path::Path::new(path.as_ref()).parent() {
                 fs::create_dir_all(parent).unwrap();
             }
-            self.write(File::create(path).unwrap());
+            write(self, &mut File::create(path).unwrap());
             return true;
         }
 
         let mut new_file_contents = Vec::new();
-        self.write(&mut new_file_contents);
+        write(self, &mut new_file_contents);
 
         let mut old_file_contents = Vec::new();
         {
@@ -204,334 +578,97 @@ impl Bindings {
         }
     }
 
-    pub fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+    /// Writes the items to be generated as JSON instead of source code, see
+    /// `json::SCHEMA_VERSION` for the compatibility guarantees of the format.
+    pub fn write_json<F: Write>(&self, mut file: F) {
         if self.noop {
             return;
         }
 
-        if let Some(ref f) = self.config.header {
-            out.new_line_if_not_start();
-            write!(out, "{}", f);
-            out.new_line();
-        }
-        if let Some(f) = self.config.include_guard() {
-            out.new_line_if_not_start();
-            write!(out, "#ifndef {}", f);
-            out.new_line();
-            write!(out, "#define {}", f);
-            out.new_line();
-        }
-        if self.config.pragma_once && self.config.language != Language::Cython {
-            out.new_line_if_not_start();
-            write!(out, "#pragma once");
-            out.new_line();
-        }
-        if self.config.include_version {
-            out.new_line_if_not_start();
-            write!(
-                out,
-                "/* Generated with cbindgen:{} */",
-                crate::bindgen::config::VERSION
-            );
-            out.new_line();
-        }
-        if let Some(ref f) = self.config.autogen_warning {
-            out.new_line_if_not_start();
-            write!(out, "{}", f);
-            out.new_line();
-        }
+        let value = json::bindings(&self.constants, &self.globals, &self.items, &self.functions);
+        serde_json::to_writer_pretty(&mut file, &value).unwrap();
+        writeln!(file).unwrap();
+    }
 
-        if self.config.no_includes
-            && self.config.sys_includes().is_empty()
-            && self.config.includes().is_empty()
-            && (self.config.cython.cimports.is_empty() || self.config.language != Language::Cython)
-            && self.config.after_includes.is_none()
-        {
+    /// Writes Rust code asserting at compile time that rustc lays out the
+    /// types of the bindings as computed for `[layout]`, along with a
+    /// `print_layouts` function to compare with the output of the program
+    /// written by `write_c_layout_checks`.
+    pub fn write_rust_layout_checks<F: Write>(&self, mut file: F) {
+        if self.noop {
             return;
         }
 
-        out.new_line_if_not_start();
-
-        if !self.config.no_includes {
-            match self.config.language {
-                Language::C => {
-                    out.write("#include <stdarg.h>");
-                    out.new_line();
-                    out.write("#include <stdbool.h>");
-                    out.new_line();
-                    if self.config.usize_is_size_t {
-                        out.write("#include <stddef.h>");
-                        out.new_line();
-                    }
-                    out.write("#include <stdint.h>");
-                    out.new_line();
-                    out.write("#include <stdlib.h>");
-                    out.new_line();
-                }
-                Language::Cxx => {
-                    out.write("#include <cstdarg>");
-                    out.new_line();
-                    if self.config.usize_is_size_t {
-                        out.write("#include <cstddef>");
-                        out.new_line();
-                    }
-                    out.write("#include <cstdint>");
-                    out.new_line();
-                    out.write("#include <cstdlib>");
-                    out.new_line();
-                    out.write("#include <ostream>");
-                    out.new_line();
-                    out.write("#include <new>");
-                    out.new_line();
-                    if self.config.enumeration.cast_assert_name.is_none()
-                        && (self.config.enumeration.derive_mut_casts
-                            || self.config.enumeration.derive_const_casts)
-                    {
-                        out.write("#include <cassert>");
-                        out.new_line();
-                    }
-                }
-                Language::Cython => {
-                    out.write(
-                        "from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t",
-                    );
-                    out.new_line();
-                    out.write(
-                        "from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t",
-                    );
-                    out.new_line();
-                    out.write("cdef extern from *");
-                    out.open_brace();
-                    out.write("ctypedef bint bool");
-                    out.new_line();
-                    out.write("ctypedef struct va_list");
-                    out.new_line();
-                    out.close_brace(false);
-                }
-            }
-        }
-
-        for include in self.config.sys_includes() {
-            write!(out, "#include <{}>", include);
-            out.new_line();
-        }
-
-        for include in self.config.includes() {
-            write!(out, "#include \"{}\"", include);
-            out.new_line();
-        }
-
-        if self.config.language == Language::Cython {
-            for (module, names) in &self.config.cython.cimports {
-                write!(out, "from {} cimport {}", module, names.join(", "));
-                out.new_line();
-            }
-        }
-
-        if let Some(ref line) = self.config.after_includes {
-            write!(out, "{}", line);
-            out.new_line();
-        }
+        layout::write_rust_checks(self, &mut file);
     }
 
-    pub fn write<F: Write>(&self, file: F) {
+    /// Writes a C (or C++) program including `header`, the path to these
+    /// bindings, and printing the layouts of their types.
+    pub fn write_c_layout_checks<F: Write>(&self, mut file: F, header: &str) {
         if self.noop {
             return;
         }
 
-        let mut out = SourceWriter::new(file, self);
-
-        self.write_headers(&mut out);
-
-        self.open_namespaces(&mut out);
-
-        for constant in &self.constants {
-            if constant.uses_only_primitive_types() {
-                out.new_line_if_not_start();
-                constant.write(&self.config, &mut out, None);
-                out.new_line();
-            }
-        }
-
-        for item in &self.items {
-            if item
-                .deref()
-                .annotations()
-                .bool("no-export")
-                .unwrap_or(false)
-            {
-                continue;
-            }
-
-            out.new_line_if_not_start();
-            match *item {
-                ItemContainer::Constant(..) => unreachable!(),
-                ItemContainer::Static(..) => unreachable!(),
-                ItemContainer::Enum(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Struct(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Union(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::OpaqueItem(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Typedef(ref x) => x.write(&self.config, &mut out),
-            }
-            out.new_line();
-        }
-
-        for constant in &self.constants {
-            if !constant.uses_only_primitive_types() {
-                out.new_line_if_not_start();
-                constant.write(&self.config, &mut out, None);
-                out.new_line();
-            }
-        }
-
-        if !self.functions.is_empty() || !self.globals.is_empty() {
-            if self.config.cpp_compatible_c() {
-                out.new_line_if_not_start();
-                out.write("#ifdef __cplusplus");
-            }
-
-            if self.config.language == Language::Cxx {
-                if let Some(ref using_namespaces) = self.config.using_namespaces {
-                    for namespace in using_namespaces {
-                        out.new_line();
-                        write!(out, "using namespace {};", namespace);
-                    }
-                    out.new_line();
-                }
-            }
-
-            if self.config.language == Language::Cxx || self.config.cpp_compatible_c() {
-                out.new_line();
-                out.write("extern \"C\" {");
-                out.new_line();
-            }
-
-            if self.config.cpp_compatible_c() {
-                out.write("#endif // __cplusplus");
-                out.new_line();
-            }
-
-            for global in &self.globals {
-                out.new_line_if_not_start();
-                global.write(&self.config, &mut out);
-                out.new_line();
-            }
-
-            for function in &self.functions {
-                out.new_line_if_not_start();
-                function.write(&self.config, &mut out);
-                out.new_line();
-            }
-
-            if self.config.cpp_compatible_c() {
-                out.new_line();
-                out.write("#ifdef __cplusplus");
-            }
-
-            if self.config.language == Language::Cxx || self.config.cpp_compatible_c() {
-                out.new_line();
-                out.write("} // extern \"C\"");
-                out.new_line();
-            }
-
-            if self.config.cpp_compatible_c() {
-                out.write("#endif // __cplusplus");
-                out.new_line();
-            }
-        }
-
-        if self.config.language == Language::Cython
-            && self.globals.is_empty()
-            && self.constants.is_empty()
-            && self.items.is_empty()
-            && self.functions.is_empty()
-        {
-            out.write("pass");
-        }
-
-        self.close_namespaces(&mut out);
-
-        if let Some(f) = self.config.include_guard() {
-            out.new_line_if_not_start();
-            if self.config.language == Language::C {
-                write!(out, "#endif /* {} */", f);
-            } else {
-                write!(out, "#endif // {}", f);
-            }
-            out.new_line();
-        }
-        if let Some(ref f) = self.config.trailer {
-            out.new_line_if_not_start();
-            write!(out, "{}", f);
-            if !f.ends_with('\n') {
-                out.new_line();
-            }
-        }
+        layout::write_c_checks(self, header, &mut file);
     }
 
-    fn all_namespaces(&self) -> Vec<&str> {
-        if self.config.language != Language::Cxx && !self.config.cpp_compatible_c() {
-            return vec![];
-        }
-        let mut ret = vec![];
-        if let Some(ref namespace) = self.config.namespace {
-            ret.push(&**namespace);
-        }
-        if let Some(ref namespaces) = self.config.namespaces {
-            for namespace in namespaces {
-                ret.push(&**namespace);
-            }
+    /// Writes a GNU ld version script exporting exactly the functions and
+    /// globals of the bindings, for `-Wl,--version-script`. Symbols under a
+    /// cfg are written with a comment, as it can't be evaluated here.
+    pub fn write_version_script<F: Write>(&self, mut file: F) {
+        if self.noop {
+            return;
         }
-        ret
+
+        symbols::write_version_script(self, &mut file);
     }
 
-    fn open_close_namespaces<F: Write>(&self, op: NamespaceOperation, out: &mut SourceWriter<F>) {
-        if self.config.language == Language::Cython {
-            if op == NamespaceOperation::Open {
-                out.new_line();
-                let header = self.config.cython.header.as_deref().unwrap_or("*");
-                write!(out, "cdef extern from {}", header);
-                out.open_brace();
-            } else {
-                out.close_brace(false);
-            }
+    /// Writes a Windows module-definition (`.def`) file exporting the
+    /// functions and globals of the bindings.
+    pub fn write_def_file<F: Write>(&self, mut file: F) {
+        if self.noop {
             return;
         }
 
-        let mut namespaces = self.all_namespaces();
-        if namespaces.is_empty() {
-            return;
-        }
+        symbols::write_def_file(self, &mut file);
+    }
 
-        if op == NamespaceOperation::Close {
-            namespaces.reverse();
+    /// Writes the symbols of the functions and globals of the bindings, one
+    /// per line.
+    pub fn write_symbol_list<F: Write>(&self, mut file: F) {
+        if self.noop {
+            return;
         }
 
-        if self.config.cpp_compatible_c() {
-            out.new_line_if_not_start();
-            out.write("#ifdef __cplusplus");
-        }
+        symbols::write_symbol_list(self, &mut file);
+    }
 
-        for namespace in namespaces {
-            out.new_line();
-            match op {
-                NamespaceOperation::Open => write!(out, "namespace {} {{", namespace),
-                NamespaceOperation::Close => write!(out, "}} // namespace {}", namespace),
-            }
+    /// Writes the bindings, or only the main header of `[split]` if enabled.
+    pub fn write<F: Write>(&self, mut file: F) {
+        if self.noop {
+            return;
         }
 
-        out.new_line();
-        if self.config.cpp_compatible_c() {
-            out.write("#endif // __cplusplus");
-            out.new_line();
+        if let Some(ref split) = self.split {
+            // The main header never includes itself, so its name is unneeded.
+            let names = self.header_names(path::Path::new(""));
+            return self.split_header(split, split::MAIN, &names).write(file);
         }
-    }
 
-    pub(crate) fn open_namespaces<F: Write>(&self, out: &mut SourceWriter<F>) {
-        self.open_close_namespaces(NamespaceOperation::Open, out);
+        let mut out = SourceWriter::new(&mut file, self);
+        self.backend().write_bindings(self, &mut out);
     }
+}
 
-    pub(crate) fn close_namespaces<F: Write>(&self, out: &mut SourceWriter<F>) {
-        self.open_close_namespaces(NamespaceOperation::Close, out);
-    }
+/// An include guard for the header `name`, like `FOO_BAR_H` for `foo/bar.h`.
+fn include_guard(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
 }
@@ -3,11 +3,13 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::path;
+use std::sync::Arc;
 
 use crate::bindgen::bindings::Bindings;
 use crate::bindgen::cargo::Cargo;
 use crate::bindgen::config::{Braces, Config, Language, Profile, Style};
 use crate::bindgen::error::Error;
+use crate::bindgen::language_backend::{self, LanguageBackend};
 use crate::bindgen::library::Library;
 use crate::bindgen::parser::{self, Parse};
 
@@ -20,6 +22,7 @@ pub struct Builder {
     lib_cargo: Option<Cargo>,
     std_types: bool,
     lockfile: Option<path::PathBuf>,
+    language_backend: Option<Arc<dyn LanguageBackend>>,
 }
 
 impl Builder {
@@ -32,6 +35,7 @@ impl Builder {
             lib_cargo: None,
             std_types: true,
             lockfile: None,
+            language_backend: None,
         }
     }
 
@@ -344,7 +348,28 @@ impl Builder {
         self
     }
 
-    pub fn generate(self) -> Result<Bindings, Error> {
+    /// Writes the bindings with `backend` instead of the one for the configured
+    /// language.
+    #[allow(unused)]
+    pub fn with_language_backend<B: LanguageBackend + 'static>(mut self, backend: B) -> Builder {
+        self.language_backend = Some(Arc::new(backend));
+        self
+    }
+
+    /// C# bindings import the functions from the library built from the crate,
+    /// unless configured otherwise.
+    fn set_default_library(&mut self, cargo: &Cargo) {
+        if self.config.csharp.library.is_none() {
+            self.config.csharp.library = Some(cargo.binding_crate_name().replace('-', "_"));
+        }
+    }
+
+    pub fn generate(mut self) -> Result<Bindings, Error> {
+        let language_backend = self
+            .language_backend
+            .take()
+            .unwrap_or_else(|| language_backend::for_language(self.config.language));
+
         // If macro expansion is enabled, then cbindgen will attempt to build the crate
         // and will run its build script which may run cbindgen again. That second run may start
         // infinite recursion, or overwrite previously written files with bindings.
@@ -360,7 +385,10 @@ impl Builder {
                 Default::default(),
                 Default::default(),
                 Default::default(),
+                None,
+                Default::default(),
                 true,
+                language_backend,
             ));
         }
 
@@ -387,12 +415,15 @@ impl Builder {
                 /* existing_metadata = */ None,
             )?;
 
+            self.set_default_library(&cargo);
             result.extend_with(&parser::parse_lib(cargo, &self.config)?);
         } else if let Some(cargo) = self.lib_cargo.clone() {
+            self.set_default_library(&cargo);
             result.extend_with(&parser::parse_lib(cargo, &self.config)?);
         }
 
         result.source_files.extend_from_slice(self.srcs.as_slice());
+        result.resolve_renamed_uses();
 
         Library::new(
             self.config,
@@ -405,6 +436,8 @@ impl Builder {
             result.typedefs,
             result.functions,
             result.source_files,
+            result.modules,
+            language_backend,
         )
         .generate()
     }
@@ -55,7 +55,7 @@ impl Cargo {
             match cargo_lock::lock(&lock_path) {
                 Ok(lock) => Some(lock),
                 Err(x) => {
-                    warn!("Couldn't load lock file {:?}: {:?}", lock_path, x);
+                    warn!("Couldn't load lock file {:?}: {}", lock_path, x);
                     None
                 }
             }
@@ -192,7 +192,6 @@ impl Cargo {
     }
 
     /// Finds the directory for a specified package reference.
-    #[allow(unused)]
     pub(crate) fn find_crate_dir(&self, package: &PackageRef) -> Option<PathBuf> {
         self.metadata
             .packages
@@ -57,11 +57,39 @@ pub struct Package {
     pub name: String,
 }
 
-/// Parse the Cargo.toml for a given path
-pub fn manifest(manifest_path: &Path) -> Result<Manifest, Error> {
+#[derive(Clone, Deserialize, Debug)]
+struct MetadataManifest {
+    package: Option<MetadataPackage>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct MetadataPackage {
+    metadata: Option<Metadata>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct Metadata {
+    cbindgen: Option<toml::Value>,
+}
+
+fn read(manifest_path: &Path) -> Result<String, Error> {
     let mut s = String::new();
     let mut f = File::open(manifest_path)?;
     f.read_to_string(&mut s)?;
+    Ok(s)
+}
+
+/// Parse the Cargo.toml for a given path
+pub fn manifest(manifest_path: &Path) -> Result<Manifest, Error> {
+    toml::from_str::<Manifest>(&read(manifest_path)?).map_err(|x| x.into())
+}
 
-    toml::from_str::<Manifest>(&s).map_err(|x| x.into())
+/// Get the `[package.metadata.cbindgen]` of the Cargo.toml for a given path,
+/// also for virtual manifests, which have none
+pub fn cbindgen_metadata(manifest_path: &Path) -> Result<Option<toml::Value>, Error> {
+    let manifest = toml::from_str::<MetadataManifest>(&read(manifest_path)?)?;
+    Ok(manifest
+        .package
+        .and_then(|package| package.metadata)
+        .and_then(|metadata| metadata.cbindgen))
 }
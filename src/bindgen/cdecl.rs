@@ -2,11 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::io::Write;
-
 use crate::bindgen::config::Layout;
 use crate::bindgen::declarationtyperesolver::DeclarationType;
-use crate::bindgen::ir::{ConstExpr, Function, GenericArgument, Type};
+use crate::bindgen::ir::{Abi, ConstExpr, Function, GenericArgument, Type};
 use crate::bindgen::writer::{ListType, SourceWriter};
 use crate::bindgen::{Config, Language};
 
@@ -23,6 +21,7 @@ enum CDeclarator {
     Array(String),
     Func {
         args: Vec<(Option<String>, CDecl)>,
+        abi: Abi,
         layout: Layout,
         never_return: bool,
     },
@@ -96,6 +95,7 @@ impl CDecl {
             .collect();
         self.declarators.push(CDeclarator::Func {
             args,
+            abi: f.abi,
             layout,
             never_return: f.never_return,
         });
@@ -143,7 +143,11 @@ impl CDecl {
                     "error generating cdecl for {:?}",
                     t
                 );
-                self.type_name = p.to_repr_c(config).to_string();
+                self.type_name = if config.language == Language::Cython {
+                    p.to_repr_cython(config).to_string()
+                } else {
+                    p.to_repr_c(config).to_string()
+                };
             }
             Type::Ptr {
                 ref ty,
@@ -158,6 +162,30 @@ impl CDecl {
                 });
                 self.build_type(ty, *ptr_is_const, config);
             }
+            Type::Atomic(ref ty) => {
+                if is_const {
+                    assert!(
+                        self.type_qualifers.is_empty(),
+                        "error generating cdecl for {:?}",
+                        t
+                    );
+                    self.type_qualifers = "const".to_owned();
+                }
+
+                assert!(
+                    self.type_name.is_empty(),
+                    "error generating cdecl for {:?}",
+                    t
+                );
+                // Both `_Atomic(T)` and `std::atomic<T>` take the type as a
+                // whole, like a template argument.
+                self.type_name = if config.language == Language::Cxx {
+                    "std::atomic".to_owned()
+                } else {
+                    "_Atomic".to_owned()
+                };
+                self.type_generic_args = vec![GenericArgument::Type((**ty).clone())];
+            }
             Type::Array(ref t, ref constant) => {
                 let len = constant.as_str().to_owned();
                 self.declarators.push(CDeclarator::Array(len));
@@ -166,6 +194,7 @@ impl CDecl {
             Type::FuncPtr {
                 ref ret,
                 ref args,
+                abi,
                 is_nullable: _,
                 never_return,
             } => {
@@ -180,6 +209,7 @@ impl CDecl {
                 });
                 self.declarators.push(CDeclarator::Func {
                     args,
+                    abi: *abi,
                     layout: config.function.args.clone(),
                     never_return: *never_return,
                 });
@@ -188,7 +218,7 @@ impl CDecl {
         }
     }
 
-    fn write<F: Write>(&self, out: &mut SourceWriter<F>, ident: Option<&str>, config: &Config) {
+    fn write(&self, out: &mut SourceWriter, ident: Option<&str>, config: &Config) {
         // Write the type-specifier and type-qualifier first
         if !self.type_qualifers.is_empty() {
             write!(out, "{} ", self.type_qualifers);
@@ -203,9 +233,15 @@ impl CDecl {
         write!(out, "{}", self.type_name);
 
         if !self.type_generic_args.is_empty() {
-            out.write("<");
+            // C11's `_Atomic(T)` is the one C type that takes an argument.
+            let (open, close) = if self.type_name == "_Atomic" {
+                ("(", ")")
+            } else {
+                ("<", ">")
+            };
+            out.write(open);
             out.write_horizontal_source_list(&self.type_generic_args, ListType::Join(", "));
-            out.write(">");
+            out.write(close);
         }
 
         // When we have an identifier, put a space between the type and the declarators
@@ -241,10 +277,15 @@ impl CDecl {
                         out.write("(");
                     }
                 }
-                CDeclarator::Func { .. } => {
+                CDeclarator::Func { abi, .. } => {
                     if next_is_pointer {
                         out.write("(");
                     }
+                    if config.language != Language::Cython {
+                        if let Some(abi_macro) = config.function.abi_macro(abi) {
+                            write!(out, "{} ", abi_macro);
+                        }
+                    }
                 }
             }
         }
@@ -276,6 +317,7 @@ impl CDecl {
                     ref args,
                     ref layout,
                     never_return,
+                    ..
                 } => {
                     if last_was_pointer {
                         out.write(")");
@@ -286,8 +328,8 @@ impl CDecl {
                         out.write("void");
                     }
 
-                    fn write_vertical<F: Write>(
-                        out: &mut SourceWriter<F>,
+                    fn write_vertical(
+                        out: &mut SourceWriter,
                         config: &Config,
                         args: &[(Option<String>, CDecl)],
                     ) {
@@ -307,8 +349,8 @@ impl CDecl {
                         out.pop_tab();
                     }
 
-                    fn write_horizontal<F: Write>(
-                        out: &mut SourceWriter<F>,
+                    fn write_horizontal(
+                        out: &mut SourceWriter,
                         config: &Config,
                         args: &[(Option<String>, CDecl)],
                     ) {
@@ -351,19 +393,22 @@ impl CDecl {
     }
 }
 
-pub fn write_func<F: Write>(
-    out: &mut SourceWriter<F>,
-    f: &Function,
-    layout: Layout,
-    config: &Config,
-) {
+pub fn write_func(out: &mut SourceWriter, f: &Function, layout: Layout, config: &Config) {
     CDecl::from_func(f, layout, config).write(out, Some(f.path().name()), config);
 }
 
-pub fn write_field<F: Write>(out: &mut SourceWriter<F>, t: &Type, ident: &str, config: &Config) {
+pub fn write_field(out: &mut SourceWriter, t: &Type, ident: &str, config: &Config) {
     CDecl::from_type(t, config).write(out, Some(ident), config);
 }
 
-pub fn write_type<F: Write>(out: &mut SourceWriter<F>, t: &Type, config: &Config) {
+/// Writes a `[T; 0]` field as a flexible array member, `T ident[]`.
+pub fn write_flexible_array_field(out: &mut SourceWriter, t: &Type, ident: &str, config: &Config) {
+    let mut cdecl = CDecl::from_type(t, config);
+    // The outermost array is the first declarator.
+    cdecl.declarators[0] = CDeclarator::Array(String::new());
+    cdecl.write(out, Some(ident), config);
+}
+
+pub fn write_type(out: &mut SourceWriter, t: &Type, config: &Config) {
     CDecl::from_type(t, config).write(out, None, config);
 }
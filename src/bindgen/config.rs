@@ -2,18 +2,23 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 use std::default::Default;
 use std::str::FromStr;
 use std::{fmt, fs, path::Path as StdPath, path::PathBuf as StdPathBuf};
 
 use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
-use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
 
-use crate::bindgen::ir::annotation::AnnotationSet;
+use crate::bindgen::cargo::cargo_toml;
+use crate::bindgen::ir::annotation::{AnnotationSet, AnnotationValue};
 use crate::bindgen::ir::path::Path;
 use crate::bindgen::ir::repr::ReprAlign;
+use crate::bindgen::ir::Abi;
+use crate::bindgen::pattern::{self, NamePattern};
 pub use crate::bindgen::rename::RenameRule;
+use crate::bindgen::schema::Schema;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -23,6 +28,9 @@ pub enum Language {
     Cxx,
     C,
     Cython,
+    Zig,
+    Python,
+    CSharp,
 }
 
 impl FromStr for Language {
@@ -42,21 +50,25 @@ impl FromStr for Language {
             "C" => Ok(Language::C),
             "cython" => Ok(Language::Cython),
             "Cython" => Ok(Language::Cython),
+            "zig" => Ok(Language::Zig),
+            "Zig" => Ok(Language::Zig),
+            "python" => Ok(Language::Python),
+            "Python" => Ok(Language::Python),
+            "csharp" => Ok(Language::CSharp),
+            "CSharp" => Ok(Language::CSharp),
+            "C#" => Ok(Language::CSharp),
             _ => Err(format!("Unrecognized Language: '{}'.", s)),
         }
     }
 }
 
-deserialize_enum_str!(Language);
-
-impl Language {
-    pub(crate) fn typedef(self) -> &'static str {
-        match self {
-            Language::Cxx | Language::C => "typedef",
-            Language::Cython => "ctypedef",
-        }
-    }
-}
+deserialize_enum_str!(
+    Language,
+    [
+        "C", "c", "C++", "c++", "cxx", "Cxx", "CXX", "cpp", "Cpp", "CPP", "Cython", "cython",
+        "Zig", "zig", "Python", "python", "C#", "csharp", "CSharp"
+    ]
+);
 
 /// Controls what type of line endings are used in the generated code.
 #[derive(Debug, Clone, Copy)]
@@ -112,7 +124,10 @@ impl FromStr for LineEndingStyle {
     }
 }
 
-deserialize_enum_str!(LineEndingStyle);
+deserialize_enum_str!(
+    LineEndingStyle,
+    ["native", "Native", "lf", "LF", "crlf", "CRLF", "cr", "CR"]
+);
 
 /// A style of braces to use for generating code.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -135,7 +150,7 @@ impl FromStr for Braces {
     }
 }
 
-deserialize_enum_str!(Braces);
+deserialize_enum_str!(Braces, ["SameLine", "same_line", "NextLine", "next_line"]);
 
 /// A type of layout to use when generating long lines of code.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -161,7 +176,17 @@ impl FromStr for Layout {
     }
 }
 
-deserialize_enum_str!(Layout);
+deserialize_enum_str!(
+    Layout,
+    [
+        "Horizontal",
+        "horizontal",
+        "Vertical",
+        "vertical",
+        "Auto",
+        "auto"
+    ]
+);
 
 /// How the comments containing documentation should be styled.
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
@@ -189,7 +214,10 @@ impl FromStr for DocumentationStyle {
     }
 }
 
-deserialize_enum_str!(DocumentationStyle);
+deserialize_enum_str!(
+    DocumentationStyle,
+    ["c", "C", "c99", "C99", "cxx", "Cxx", "c++", "C++", "doxy", "Doxy", "auto", "Auto"]
+);
 
 /// How much of the documentation to include in the header file.
 #[derive(Debug, Clone, Copy)]
@@ -210,7 +238,7 @@ impl FromStr for DocumentationLength {
     }
 }
 
-deserialize_enum_str!(DocumentationLength);
+deserialize_enum_str!(DocumentationLength, ["short", "Short", "full", "Full"]);
 
 /// A style of Style to use when generating structs and enums.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -267,7 +295,7 @@ impl FromStr for Style {
     }
 }
 
-deserialize_enum_str!(Style);
+deserialize_enum_str!(Style, ["Both", "both", "Tag", "tag", "Type", "type"]);
 
 /// Different item types that we can generate and filter.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -301,7 +329,27 @@ impl FromStr for ItemType {
     }
 }
 
-deserialize_enum_str!(ItemType);
+deserialize_enum_str!(
+    ItemType,
+    [
+        "constants",
+        "Constants",
+        "globals",
+        "Globals",
+        "enums",
+        "Enums",
+        "structs",
+        "Structs",
+        "unions",
+        "Unions",
+        "typedefs",
+        "Typedefs",
+        "opaque",
+        "Opaque",
+        "functions",
+        "Functions"
+    ]
+);
 
 /// Type which specifies the sort order of functions
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -323,7 +371,7 @@ impl FromStr for SortKey {
     }
 }
 
-deserialize_enum_str!(SortKey);
+deserialize_enum_str!(SortKey, ["name", "Name", "none", "None"]);
 
 /// Settings to apply when exporting items.
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -332,11 +380,13 @@ deserialize_enum_str!(SortKey);
 #[serde(default)]
 pub struct ExportConfig {
     /// A list of additional items not used by exported functions to include in
-    /// the generated bindings
+    /// the generated bindings. Entries can be globs or regular expressions.
     pub include: Vec<String>,
-    /// A list of items to not include in the generated bindings
+    /// A list of items to not include in the generated bindings. Entries can
+    /// be globs or regular expressions.
     pub exclude: Vec<String>,
-    /// Table of name conversions to apply to item names
+    /// Table of name conversions to apply to item names. Keys can be globs or
+    /// regular expressions, with `$n` in the values standing for their groups.
     pub rename: HashMap<String, String>,
     /// Table of raw strings to prepend to the body of items.
     pub pre_body: HashMap<String, String>,
@@ -350,6 +400,10 @@ pub struct ExportConfig {
     pub renaming_overrides_prefixing: bool,
     /// Mangling configuration.
     pub mangle: MangleConfig,
+    /// Table of annotations to add to items, keyed by the name of the item,
+    /// or by `Item::field` for the fields of structs and unions. They
+    /// override those of doc comments.
+    pub annotations: HashMap<String, HashMap<String, AnnotationValue>>,
 }
 
 /// Mangling-specific configuration.
@@ -377,9 +431,34 @@ impl ExportConfig {
         self.body.get(path.name()).map(|s| s.trim_matches('\n'))
     }
 
-    pub(crate) fn rename(&self, item_name: &mut String) {
+    pub(crate) fn excludes(&self, item_name: &str) -> bool {
+        self.exclude
+            .iter()
+            .any(|pattern| pattern::name_matches(pattern, item_name))
+    }
+
+    /// The new name of `item_name` in `rename`. Exact names come first, then
+    /// the patterns in sorted order.
+    fn renamed(&self, item_name: &str) -> Option<String> {
         if let Some(name) = self.rename.get(item_name) {
-            *item_name = name.clone();
+            return Some(name.clone());
+        }
+        let mut patterns: Vec<_> = self
+            .rename
+            .iter()
+            .filter(|&(pattern, _)| pattern::is_pattern(pattern))
+            .collect();
+        patterns.sort();
+        patterns.into_iter().find_map(|(pattern, replacement)| {
+            NamePattern::new(pattern)
+                .ok()?
+                .replace(item_name, replacement)
+        })
+    }
+
+    pub(crate) fn rename(&self, item_name: &mut String) {
+        if let Some(name) = self.renamed(item_name) {
+            *item_name = name;
             if self.renaming_overrides_prefixing {
                 return;
             }
@@ -390,20 +469,84 @@ impl ExportConfig {
     }
 }
 
+/// Only accepts the widths in bits of types on actual targets.
+fn deserialize_width<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let width = u64::deserialize(deserializer)?;
+    match width {
+        16 | 32 | 64 => Ok(width),
+        _ => Err(de::Error::custom(format!(
+            "invalid width {}, expected 16, 32 or 64",
+            width
+        ))),
+    }
+}
+
+fn deserialize_opt_width<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error> {
+    deserialize_width(deserializer).map(Some)
+}
+
+fn deserialize_align<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let align = u64::deserialize(deserializer)?;
+    match align {
+        1 | 2 | 4 | 8 => Ok(align),
+        _ => Err(de::Error::custom(format!(
+            "invalid alignment {}, expected 1, 2, 4 or 8",
+            align
+        ))),
+    }
+}
+
 /// Settings to apply to generated types with layout modifiers.
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
 pub struct LayoutConfig {
     /// The way to annotate C types as #[repr(packed)].
     pub packed: Option<String>,
+    /// The way to annotate C types as #[repr(packed(...))]. This is assumed to be a functional
+    /// macro which takes a single argument (the packing). Without it, the types are written
+    /// between `#pragma pack(push, n)` and `#pragma pack(pop)`.
+    pub packed_n: Option<String>,
     /// The way to annotate C types as #[repr(align(...))]. This is assumed to be a functional
     /// macro which takes a single argument (the alignment).
     pub aligned_n: Option<String>,
+    /// Whether to check the size, alignment and field offsets of structs, unions and enums with
+    /// static assertions after their definitions.
+    pub static_asserts: bool,
+    /// The width of pointers on the target, in bits, to compute layouts with.
+    #[serde(deserialize_with = "deserialize_width")]
+    pub pointer_width: u64,
+    /// The width of `c_long` on the target, in bits. Defaults to `pointer_width`.
+    #[serde(deserialize_with = "deserialize_opt_width")]
+    pub long_width: Option<u64>,
+    /// The alignment of 8-byte primitives like `u64` and `f64` on the target,
+    /// in bytes. That's 4 on 32-bit x86, except on Windows.
+    #[serde(deserialize_with = "deserialize_align")]
+    pub align_64: u64,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> LayoutConfig {
+        LayoutConfig {
+            packed: None,
+            packed_n: None,
+            aligned_n: None,
+            static_asserts: false,
+            pointer_width: 64,
+            long_width: None,
+            align_64: 8,
+        }
+    }
 }
 
 impl LayoutConfig {
+    pub(crate) fn long_width(&self) -> u64 {
+        self.long_width.unwrap_or(self.pointer_width)
+    }
+
     pub(crate) fn ensure_safe_to_represent(&self, align: &ReprAlign) -> Result<(), String> {
         match (align, &self.packed, &self.aligned_n) {
             (ReprAlign::Packed, None, _) => Err("Cannot safely represent #[repr(packed)] type without configured 'packed' annotation.".to_string()),
@@ -413,6 +556,34 @@ impl LayoutConfig {
     }
 }
 
+/// The C and C++ spellings of the primitive types which have no standard name
+/// there, usually a compiler extension or a typedef of the user.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct PrimitiveConfig {
+    /// The spelling of `i128`.
+    pub i128: String,
+    /// The spelling of `u128`.
+    pub u128: String,
+    /// The spelling of `f16`.
+    pub f16: String,
+    /// The spelling of `f128`.
+    pub f128: String,
+}
+
+impl Default for PrimitiveConfig {
+    fn default() -> PrimitiveConfig {
+        PrimitiveConfig {
+            i128: "__int128".to_owned(),
+            u128: "unsigned __int128".to_owned(),
+            f16: "_Float16".to_owned(),
+            f128: "__float128".to_owned(),
+        }
+    }
+}
+
 /// Settings to apply to generated functions.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -435,6 +606,9 @@ pub struct FunctionConfig {
     pub sort_by: Option<SortKey>,
     /// Optional text to output after functions which return `!`.
     pub no_return: Option<String>,
+    /// Macros to annotate functions and function pointers with, keyed by the
+    /// Rust name of their calling convention, e.g. `stdcall`.
+    pub abi_macros: HashMap<String, String>,
 }
 
 impl Default for FunctionConfig {
@@ -448,6 +622,7 @@ impl Default for FunctionConfig {
             swift_name_macro: None,
             sort_by: None,
             no_return: None,
+            abi_macros: HashMap::new(),
         }
     }
 }
@@ -466,6 +641,10 @@ impl FunctionConfig {
         }
         self.postfix.clone()
     }
+
+    pub(crate) fn abi_macro(&self, abi: Abi) -> Option<&str> {
+        self.abi_macros.get(abi.to_repr_rust()).map(String::as_str)
+    }
 }
 
 /// Settings to apply to generated structs.
@@ -498,6 +677,9 @@ pub struct StructConfig {
     pub associated_constants_in_body: bool,
     /// The way to annotate this struct as #[must_use].
     pub must_use: Option<String>,
+    /// Whether to write a trailing zero-length array as a flexible array
+    /// member, `T data[]` instead of `T data[0]`.
+    pub flexible_array: bool,
 }
 
 impl StructConfig {
@@ -549,6 +731,12 @@ impl StructConfig {
         }
         self.derive_ostream
     }
+    pub(crate) fn flexible_array(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("flexible-array") {
+            return x;
+        }
+        self.flexible_array
+    }
 }
 
 /// Settings to apply to generated enums.
@@ -741,7 +929,7 @@ impl FromStr for Profile {
     }
 }
 
-deserialize_enum_str!(Profile);
+deserialize_enum_str!(Profile, ["debug", "Debug", "release", "Release"]);
 
 /// Settings to apply when running `rustc -Zunpretty=expanded`
 #[derive(Debug, Clone, Deserialize)]
@@ -828,9 +1016,11 @@ pub struct ParseConfig {
     /// before parsing. A crate marked in `expand` doesn't need to be added to any
     /// whitelist.
     pub parse_deps: bool,
-    /// An optional whitelist of names of crates to parse
+    /// An optional whitelist of names of crates to parse. Entries can be globs
+    /// or regular expressions.
     pub include: Option<Vec<String>>,
-    /// The names of crates to not parse
+    /// The names of crates to not parse. Entries can be globs or regular
+    /// expressions.
     pub exclude: Vec<String>,
     /// The configuration options for `rustc -Zunpretty=expanded`
     #[serde(deserialize_with = "retrocomp_parse_expand_config_deserialize")]
@@ -841,6 +1031,9 @@ pub struct ParseConfig {
     /// List of crate names which generate consts, statics, and fns. By default
     /// no dependent crates generate them.
     pub extra_bindings: Vec<String>,
+    /// The directory `env!("OUT_DIR")` stands for in `include!`s. Defaults to
+    /// the `OUT_DIR` environment variable, which is set in build scripts.
+    pub out_dir: Option<String>,
 }
 
 impl ParseConfig {
@@ -881,6 +1074,135 @@ pub struct CythonConfig {
     pub cimports: BTreeMap<String, Vec<String>>,
 }
 
+/// Settings specific to C# bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct CSharpConfig {
+    /// The native library the functions are imported from. Defaults to the
+    /// name of the crate the bindings are generated for, or to `__Internal`,
+    /// that is the executable itself, when generating them from a single file.
+    pub library: Option<String>,
+    /// The name of the static class holding all the declarations. Defaults to
+    /// `NativeMethods`.
+    pub class: Option<String>,
+    /// Whether to use pointers (`T*`) and function pointers (`delegate* unmanaged`)
+    /// rather than `IntPtr`. The bindings must then be compiled with `/unsafe`.
+    #[serde(rename = "unsafe")]
+    pub use_unsafe: bool,
+    /// Whether to use the source-generated `[LibraryImport]` (.NET 7+) rather than
+    /// `[DllImport]`.
+    pub library_import: bool,
+}
+
+impl CSharpConfig {
+    pub(crate) fn class(&self) -> &str {
+        self.class.as_deref().unwrap_or("NativeMethods")
+    }
+}
+
+/// Settings to split the bindings into several headers.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SplitConfig {
+    /// The headers to move items to out of the main one. An item goes to the
+    /// first header matching it.
+    pub headers: Vec<SplitHeader>,
+    /// The file name of a header including the main one and all the others,
+    /// relative to the main one.
+    pub umbrella: Option<String>,
+}
+
+impl SplitConfig {
+    pub(crate) fn is_enabled(&self, language: Language) -> bool {
+        !self.headers.is_empty() && matches!(language, Language::C | Language::Cxx)
+    }
+}
+
+/// Settings for a table of function pointers to load the functions at
+/// runtime with.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct LoaderConfig {
+    /// The name of the struct of function pointers to write, which enables
+    /// the table and its loader.
+    pub table: Option<String>,
+    /// The name of the loader function. Defaults to `load_{table}`.
+    pub function: Option<String>,
+    /// The function or macro to look symbols up with, which takes the handle
+    /// returned by `dlopen` and the name of the symbol. Defaults to `dlsym`.
+    pub symbol_lookup: Option<String>,
+}
+
+impl LoaderConfig {
+    pub(crate) fn function(&self) -> Option<Cow<'_, str>> {
+        let table = self.table.as_deref()?;
+        Some(match self.function {
+            Some(ref function) => Cow::Borrowed(function),
+            None => Cow::Owned(format!("load_{}", table)),
+        })
+    }
+
+    pub(crate) fn symbol_lookup(&self) -> &str {
+        self.symbol_lookup.as_deref().unwrap_or("dlsym")
+    }
+}
+
+/// Settings for the linker files listing the exported symbols.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SymbolsConfig {
+    /// The name of the version node of the version script, like `MYLIB_1.0`.
+    /// The symbols are unversioned without one.
+    pub version_node: Option<String>,
+    /// The name of the library for the `LIBRARY` statement of the `.def` file.
+    pub library: Option<String>,
+    /// The cfgs enabled in the build of the library, written like the keys
+    /// of `[defines]`. Symbols under a cfg are only listed if it holds with
+    /// those, or listed regardless with a warning if this is unset.
+    pub cfgs: Option<Vec<String>>,
+}
+
+/// A header to move items to out of the main one.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct SplitHeader {
+    /// The file name of the header, relative to the main one.
+    pub name: String,
+    /// The items to move, as Rust module paths like `my_crate::ffi`, which
+    /// also match the submodules, or as globs of item names like `Foo*`.
+    #[serde(default)]
+    pub items: Vec<String>,
+}
+
+impl SplitHeader {
+    /// Whether the item at `path`, defined in `module`, goes to this header.
+    pub(crate) fn matches(&self, path: &Path, module: Option<&str>) -> bool {
+        self.items.iter().any(|item| {
+            let pattern = NamePattern::glob(item);
+            if !item.contains("::") {
+                return pattern.matches(path.name());
+            }
+            let module = match module {
+                Some(module) => module,
+                None => return false,
+            };
+            pattern.matches(module)
+                || module
+                    .strip_prefix(item.as_str())
+                    .map_or(false, |rest| rest.starts_with("::"))
+        })
+    }
+}
+
 /// A collection of settings to customize the generated bindings.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -910,9 +1232,9 @@ pub struct Config {
     pub autogen_warning: Option<String>,
     /// Include a comment with the version of cbindgen used to generate the file
     pub include_version: bool,
-    /// An optional name for the root namespace. Only applicable when language="C++"
+    /// An optional name for the root namespace. Only applicable when language="C++" or "C#"
     pub namespace: Option<String>,
-    /// An optional list of namespaces. Only applicable when language="C++"
+    /// An optional list of namespaces. Only applicable when language="C++" or "C#"
     pub namespaces: Option<Vec<String>>,
     /// An optional list of namespaces to declare as using. Only applicable when language="C++"
     pub using_namespaces: Option<Vec<String>>,
@@ -935,6 +1257,13 @@ pub struct Config {
     /// If this option is true `usize` and `isize` will be converted into `size_t` and `ptrdiff_t`
     /// instead of `uintptr_t` and `intptr_t` respectively.
     pub usize_is_size_t: bool,
+    /// If this option is true, the `core::sync::atomic` types are written as C11
+    /// `_Atomic(T)` in C, instead of as the integer or pointer they wrap. C++
+    /// always uses `std::atomic<T>`.
+    pub c11_atomics: bool,
+    /// The generic wrappers with a single type parameter which are written as
+    /// the type they wrap, like `MaybeUninit<T>`, in all languages.
+    pub transparent_wrappers: Vec<String>,
     /// The configuration options for parsing
     pub parse: ParseConfig,
     /// The configuration options for exporting
@@ -943,6 +1272,17 @@ pub struct Config {
     pub macro_expansion: MacroExpansionConfig,
     /// The configuration options for type layouts.
     pub layout: LayoutConfig,
+    /// The configuration options for the primitive types without a standard
+    /// C name.
+    pub primitive: PrimitiveConfig,
+    /// The configuration options for splitting the bindings into several headers.
+    pub split: SplitConfig,
+    /// The configuration options for the table of function pointers to load
+    /// the functions at runtime with.
+    pub loader: LoaderConfig,
+    /// The configuration options for the linker files listing the exported
+    /// symbols.
+    pub symbols: SymbolsConfig,
     /// The configuration options for functions
     #[serde(rename = "fn")]
     pub function: FunctionConfig,
@@ -1003,8 +1343,11 @@ pub struct Config {
     pub only_target_dependencies: bool,
     /// Configuration options specific to Cython.
     pub cython: CythonConfig,
+    /// Configuration options specific to C#.
+    pub csharp: CSharpConfig,
+    /// The files the config was read from, for depfiles.
     #[serde(skip)]
-    pub(crate) config_path: Option<StdPathBuf>,
+    pub(crate) config_paths: Vec<StdPathBuf>,
 }
 
 impl Default for Config {
@@ -1031,11 +1374,29 @@ impl Default for Config {
             cpp_compat: false,
             style: Style::default(),
             usize_is_size_t: false,
+            c11_atomics: false,
+            transparent_wrappers: [
+                "Cell",
+                "UnsafeCell",
+                "SyncUnsafeCell",
+                "ManuallyDrop",
+                "MaybeUninit",
+                "Pin",
+                "Wrapping",
+                "Saturating",
+            ]
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
             sort_by: SortKey::None,
             macro_expansion: Default::default(),
             parse: ParseConfig::default(),
             export: ExportConfig::default(),
             layout: LayoutConfig::default(),
+            primitive: PrimitiveConfig::default(),
+            split: SplitConfig::default(),
+            loader: LoaderConfig::default(),
+            symbols: SymbolsConfig::default(),
             function: FunctionConfig::default(),
             structure: StructConfig::default(),
             enumeration: EnumConfig::default(),
@@ -1047,7 +1408,8 @@ impl Default for Config {
             pointer: PtrConfig::default(),
             only_target_dependencies: false,
             cython: CythonConfig::default(),
-            config_path: None,
+            csharp: CSharpConfig::default(),
+            config_paths: Vec::new(),
         }
     }
 }
@@ -1058,7 +1420,10 @@ impl Config {
     }
 
     pub(crate) fn include_guard(&self) -> Option<&str> {
-        if self.language == Language::Cython {
+        if matches!(
+            self.language,
+            Language::Cython | Language::Zig | Language::Python | Language::CSharp
+        ) {
             None
         } else {
             self.include_guard.as_deref()
@@ -1066,7 +1431,10 @@ impl Config {
     }
 
     pub(crate) fn includes(&self) -> &[String] {
-        if self.language == Language::Cython {
+        if matches!(
+            self.language,
+            Language::Cython | Language::Zig | Language::Python | Language::CSharp
+        ) {
             &[]
         } else {
             &self.includes
@@ -1074,34 +1442,166 @@ impl Config {
     }
 
     pub(crate) fn sys_includes(&self) -> &[String] {
-        if self.language == Language::Cython {
+        if matches!(
+            self.language,
+            Language::Cython | Language::Zig | Language::Python | Language::CSharp
+        ) {
             &[]
         } else {
             &self.sys_includes
         }
     }
 
+    fn from_table(table: toml::value::Table, paths: Vec<StdPathBuf>) -> Result<Config, String> {
+        let mut config = toml::Value::Table(table)
+            .try_into::<Config>()
+            .map_err(|e| format!("Couldn't parse config file: {}.", e))?;
+        config.config_paths = paths;
+        Ok(config)
+    }
+
+    /// A JSON Schema of the config files, for editors to validate and
+    /// complete them.
+    pub fn json_schema() -> String {
+        let mut schema = Schema::of::<Config>().to_json();
+        schema["properties"]["extends"] = serde_json::json!({ "type": "string" });
+        schema["$schema"] = "http://json-schema.org/draft-07/schema#".into();
+        schema["title"] = "cbindgen config".into();
+        serde_json::to_string_pretty(&schema).unwrap()
+    }
+
+    /// Loads the config at `file_name`, merged over the config files it
+    /// `extends`.
     pub fn from_file<P: AsRef<StdPath>>(file_name: P) -> Result<Config, String> {
+        let mut paths = vec![];
+        let table = read_config_table(file_name.as_ref(), &mut paths)?;
+        check_config_keys(&table)?;
+        if paths.len() > 1 {
+            return Config::from_table(table, paths);
+        }
+
+        // Parse the text itself, whose errors have line numbers.
         let config_text = fs::read_to_string(file_name.as_ref()).map_err(|_| {
             format!(
                 "Couldn't open config file: {}.",
                 file_name.as_ref().display()
             )
         })?;
-
         let mut config = toml::from_str::<Config>(&config_text)
             .map_err(|e| format!("Couldn't parse config file: {}.", e))?;
-        config.config_path = Some(StdPathBuf::from(file_name.as_ref()));
+        config.config_paths = paths;
         Ok(config)
     }
 
+    /// Loads the config in the `[package.metadata.cbindgen]` table of the
+    /// Cargo.toml at `manifest_path`, if any. Paths in `extends` are relative
+    /// to the crate's directory.
+    pub fn from_manifest<P: AsRef<StdPath>>(manifest_path: P) -> Result<Option<Config>, String> {
+        let manifest_path = manifest_path.as_ref();
+        let metadata = cargo_toml::cbindgen_metadata(manifest_path)
+            .map_err(|e| format!("Couldn't read manifest {}: {}.", manifest_path.display(), e))?;
+        let table = match metadata {
+            Some(toml::Value::Table(table)) => table,
+            Some(_) => {
+                return Err(format!(
+                    "`package.metadata.cbindgen` of {} isn't a table.",
+                    manifest_path.display()
+                ))
+            }
+            None => return Ok(None),
+        };
+        let mut paths = vec![manifest_path.to_owned()];
+        let table = resolve_extends(table, manifest_path, &mut paths)?;
+        check_config_keys(&table)?;
+        Config::from_table(table, paths).map(Some)
+    }
+
+    /// Loads the `cbindgen.toml` of `root`, or else the
+    /// `[package.metadata.cbindgen]` of its `Cargo.toml`, or else the default
+    /// config.
     pub fn from_root_or_default<P: AsRef<StdPath>>(root: P) -> Config {
         let c = root.as_ref().join("cbindgen.toml");
+        let manifest = root.as_ref().join("Cargo.toml");
 
         if c.exists() {
+            if manifest.exists() && matches!(cargo_toml::cbindgen_metadata(&manifest), Ok(Some(_)))
+            {
+                warn!(
+                    "Ignoring `[package.metadata.cbindgen]` of {} in favor of {}.",
+                    manifest.display(),
+                    c.display()
+                );
+            }
             Config::from_file(c).unwrap()
+        } else if manifest.exists() {
+            Config::from_manifest(manifest).unwrap().unwrap_or_default()
         } else {
             Config::default()
         }
     }
 }
+
+/// Checks that the keys of `table` are all known, suggesting the key meant
+/// for misspelled ones, which serde alone only lists the known keys for.
+fn check_config_keys(table: &toml::value::Table) -> Result<(), String> {
+    Schema::of::<Config>()
+        .check_keys(&toml::Value::Table(table.clone()))
+        .map_err(|e| format!("Couldn't parse config file: {}", e))
+}
+
+/// Reads the config table of `path`, merged over the tables of the files it
+/// `extends`, and appends the paths of all of them to `paths`.
+fn read_config_table(
+    path: &StdPath,
+    paths: &mut Vec<StdPathBuf>,
+) -> Result<toml::value::Table, String> {
+    let config_text = fs::read_to_string(path)
+        .map_err(|_| format!("Couldn't open config file: {}.", path.display()))?;
+    let canonical = |path: &StdPath| path.canonicalize().unwrap_or_else(|_| path.to_owned());
+    if paths.iter().any(|seen| canonical(seen) == canonical(path)) {
+        return Err(format!("Config file {} extends itself.", path.display()));
+    }
+    paths.push(path.to_owned());
+
+    let table = toml::from_str::<toml::value::Table>(&config_text)
+        .map_err(|e| format!("Couldn't parse config file: {}.", e))?;
+    resolve_extends(table, path, paths)
+}
+
+/// Merges `table`, read from `path`, over the table of the file its
+/// `extends` key names, if any.
+fn resolve_extends(
+    mut table: toml::value::Table,
+    path: &StdPath,
+    paths: &mut Vec<StdPathBuf>,
+) -> Result<toml::value::Table, String> {
+    let base = match table.remove("extends") {
+        Some(toml::Value::String(base)) => base,
+        Some(_) => {
+            return Err(format!(
+                "`extends` of {} isn't the path of a config file.",
+                path.display()
+            ))
+        }
+        None => return Ok(table),
+    };
+    let base_path = path.parent().unwrap_or_else(|| StdPath::new("")).join(base);
+    let mut merged = read_config_table(&base_path, paths)?;
+    merge_config_tables(&mut merged, table);
+    Ok(merged)
+}
+
+/// Merges `table` into `base`, recursing into the tables they both have.
+/// Other values, arrays included, of `table` replace those of `base`.
+fn merge_config_tables(base: &mut toml::value::Table, table: toml::value::Table) {
+    for (key, value) in table {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(table)) => {
+                merge_config_tables(base, table)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
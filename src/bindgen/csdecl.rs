@@ -0,0 +1,273 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindgen::config::{Config, Layout};
+use crate::bindgen::ir::{Abi, Function, IntKind, PrimitiveType, Type};
+use crate::bindgen::writer::SourceWriter;
+
+// This code is for translating Rust types into C# declarations meant for
+// P/Invoke.
+// https://learn.microsoft.com/en-us/dotnet/standard/native-interop/type-marshalling
+
+/// C# has no type aliases that could be declared next to the other items, so
+/// typedefs and transparent structs are replaced by what they alias.
+pub fn resolve(out: &SourceWriter, t: &Type) -> Type {
+    let mut t = t.clone();
+    while let Type::Path(ref generic) = t {
+        match out.bindings().aliased_type(generic.path()) {
+            Some(aliased) => t = aliased,
+            None => break,
+        }
+    }
+    t
+}
+
+/// The `CallConv*` name of `abi`, as used by `UnmanagedCallConv` and
+/// unmanaged function pointers, if C# has one. `system` has none, it's the
+/// platform default.
+fn call_conv(abi: Abi) -> Option<&'static str> {
+    match abi {
+        Abi::C | Abi::Cdecl => Some("Cdecl"),
+        Abi::Stdcall => Some("Stdcall"),
+        Abi::Fastcall => Some("Fastcall"),
+        Abi::Thiscall => Some("Thiscall"),
+        _ => None,
+    }
+}
+
+/// Whether C# can call function pointers of `abi`, others are `IntPtr`s.
+pub fn supports_pointer(abi: Abi) -> bool {
+    abi == Abi::System || call_conv(abi).is_some()
+}
+
+/// Whether C# can import functions of `abi`, others are left out. `DllImport`
+/// has no `fastcall`.
+pub fn supports_function(abi: Abi, config: &Config) -> bool {
+    match abi {
+        Abi::Fastcall => config.csharp.library_import,
+        abi => supports_pointer(abi),
+    }
+}
+
+/// Whether `t` can be the element type of a `fixed` size buffer.
+fn is_fixed_buffer_element(t: &Type) -> bool {
+    match *t {
+        Type::Primitive(PrimitiveType::Integer { kind, .. }) => !matches!(
+            kind,
+            IntKind::Long | IntKind::SizeT | IntKind::Size | IntKind::B128
+        ),
+        Type::Primitive(
+            PrimitiveType::PtrDiffT
+            | PrimitiveType::VaList
+            | PrimitiveType::Float16
+            | PrimitiveType::Float128,
+        ) => false,
+        Type::Primitive(PrimitiveType::Void) => false,
+        Type::Primitive(..) => true,
+        _ => false,
+    }
+}
+
+fn write_pointee(out: &mut SourceWriter, t: &Type, config: &Config) {
+    match *t {
+        // An array decays to a pointer to its first element.
+        Type::Array(ref ty, _) => write_pointee(out, ty, config),
+        _ => write_type(out, t, config),
+    }
+}
+
+fn write_ret(out: &mut SourceWriter, ret: &Type, never_return: bool, config: &Config) {
+    if never_return {
+        out.write("void");
+    } else {
+        write_type(out, ret, config);
+    }
+}
+
+pub fn write_type(out: &mut SourceWriter, t: &Type, config: &Config) {
+    match *t {
+        Type::Path(ref generic) => match out.bindings().aliased_type(generic.path()) {
+            Some(aliased) => write_type(out, &aliased, config),
+            None => write!(out, "{}", generic.export_name()),
+        },
+        Type::Primitive(ref p) => out.write(p.to_repr_csharp()),
+        // Atomics are only kept for C and C++, the layout is the same.
+        Type::Atomic(ref ty) => write_type(out, ty, config),
+        Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => {
+            if config.csharp.use_unsafe {
+                write_pointee(out, ty, config);
+                out.write("*");
+            } else {
+                out.write("IntPtr");
+            }
+        }
+        Type::FuncPtr {
+            ref ret,
+            ref args,
+            abi,
+            never_return,
+            ..
+        } => {
+            if config.csharp.use_unsafe && supports_pointer(abi) {
+                match call_conv(abi) {
+                    Some(call_conv) => write!(out, "delegate* unmanaged[{}]<", call_conv),
+                    None => out.write("delegate* unmanaged<"),
+                }
+                for (_, ty) in args {
+                    write_type(out, ty, config);
+                    out.write(", ");
+                }
+                write_ret(out, ret, never_return, config);
+                out.write(">");
+            } else {
+                out.write("IntPtr");
+            }
+        }
+    }
+}
+
+/// The default marshalling of `bool` is the 4 bytes Win32 `BOOL`, so it
+/// needs to be overridden wherever a `bool` is passed by value.
+fn is_bool(out: &SourceWriter, t: &Type) -> bool {
+    matches!(resolve(out, t), Type::Primitive(PrimitiveType::Bool))
+}
+
+pub fn write_field(out: &mut SourceWriter, t: &Type, ident: &str, config: &Config) {
+    let t = resolve(out, t);
+    if let Type::Array(..) = t {
+        // Nested arrays are flattened, C# only has one-dimensional inline arrays.
+        let mut element = &t;
+        let mut lengths = vec![];
+        while let Type::Array(ref ty, ref len) = *element {
+            lengths.push(len.as_str());
+            element = ty;
+        }
+        let element = resolve(out, element);
+        let length = lengths.join(" * ");
+        let literal_length = lengths
+            .iter()
+            .map(|len| len.parse::<usize>().ok())
+            .product::<Option<usize>>();
+
+        if config.csharp.use_unsafe && is_fixed_buffer_element(&element) {
+            out.write("public fixed ");
+            write_type(out, &element, config);
+            write!(out, " {}[{}];", ident, length);
+        } else if let (true, Some(length)) = (config.csharp.use_unsafe, literal_length) {
+            // A marshalled array would make the struct a managed type, which
+            // can't be pointed to, so there's a field for each element instead.
+            out.write("public ");
+            write_type(out, &element, config);
+            for i in 0..length {
+                out.write(if i == 0 { " " } else { ", " });
+                write!(out, "{}_{}", ident, i);
+            }
+            out.write(";");
+        } else {
+            write!(
+                out,
+                "[MarshalAs(UnmanagedType.ByValArray, SizeConst = {}",
+                length
+            );
+            if let Type::Primitive(PrimitiveType::Bool) = element {
+                out.write(", ArraySubType = UnmanagedType.U1");
+            }
+            out.write(")] public ");
+            write_type(out, &element, config);
+            write!(out, "[] {};", ident);
+        }
+        return;
+    }
+
+    if is_bool(out, &t) {
+        out.write("[MarshalAs(UnmanagedType.U1)] ");
+    }
+    out.write("public ");
+    write_type(out, &t, config);
+    write!(out, " {};", ident);
+}
+
+fn write_func_arg(out: &mut SourceWriter, i: usize, name: Option<&str>, t: &Type, config: &Config) {
+    if is_bool(out, t) {
+        out.write("[MarshalAs(UnmanagedType.U1)] ");
+    }
+    write_type(out, t, config);
+    match name {
+        Some(name) => write!(out, " {}", name),
+        None => write!(out, " arg{}", i),
+    }
+}
+
+/// Writes the attributes and signature of a `static extern` method importing `f`.
+pub fn write_func(out: &mut SourceWriter, f: &Function, layout: Layout, config: &Config) {
+    if config.csharp.library_import {
+        out.write("[LibraryImport(__DllName)]");
+        if let Some(call_conv) = call_conv(f.abi) {
+            out.new_line();
+            write!(
+                out,
+                "[UnmanagedCallConv(CallConvs = new[] {{ typeof(CallConv{}) }})]",
+                call_conv
+            );
+        }
+    } else {
+        let calling_convention = match f.abi {
+            Abi::Stdcall => "StdCall",
+            Abi::Thiscall => "ThisCall",
+            Abi::System => "Winapi",
+            _ => "Cdecl",
+        };
+        write!(
+            out,
+            "[DllImport(__DllName, CallingConvention = CallingConvention.{})]",
+            calling_convention
+        );
+    }
+    out.new_line();
+    if !f.never_return && is_bool(out, &f.ret) {
+        out.write("[return: MarshalAs(UnmanagedType.U1)]");
+        out.new_line();
+    }
+
+    if config.csharp.library_import {
+        out.write("public static partial ");
+    } else {
+        out.write("public static extern ");
+    }
+    write_ret(out, &f.ret, f.never_return, config);
+    write!(out, " {}(", f.path().name());
+
+    fn write_vertical(out: &mut SourceWriter, f: &Function, config: &Config) {
+        let align_length = out.line_length_for_align();
+        out.push_set_spaces(align_length);
+        for (i, arg) in f.args.iter().enumerate() {
+            if i != 0 {
+                out.write(",");
+                out.new_line();
+            }
+            write_func_arg(out, i, arg.name.as_deref(), &arg.ty, config);
+        }
+        out.pop_set_spaces();
+    }
+
+    fn write_horizontal(out: &mut SourceWriter, f: &Function, config: &Config) {
+        for (i, arg) in f.args.iter().enumerate() {
+            if i != 0 {
+                out.write(", ");
+            }
+            write_func_arg(out, i, arg.name.as_deref(), &arg.ty, config);
+        }
+    }
+
+    match layout {
+        Layout::Vertical => write_vertical(out, f, config),
+        Layout::Horizontal => write_horizontal(out, f, config),
+        Layout::Auto => {
+            if !out.try_write(|out| write_horizontal(out, f, config), config.line_length) {
+                write_vertical(out, f, config)
+            }
+        }
+    }
+    out.write(")");
+}
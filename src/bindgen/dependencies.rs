@@ -12,6 +12,10 @@ use crate::bindgen::ir::{ItemContainer, Path};
 pub struct Dependencies {
     pub order: Vec<ItemContainer>,
     pub items: HashSet<Path>,
+    /// Whether to follow pointers to the items they point to.
+    pub follow_pointers: bool,
+    /// The items pointers point to when they aren't followed.
+    pub pointees: HashSet<Path>,
 }
 
 impl Dependencies {
@@ -19,6 +23,18 @@ impl Dependencies {
         Dependencies {
             order: Vec::new(),
             items: HashSet::new(),
+            follow_pointers: true,
+            pointees: HashSet::new(),
+        }
+    }
+
+    /// The dependencies of what needs the complete definitions of the items,
+    /// leaving out the ones only used behind pointers, which can be
+    /// forward-declared instead.
+    pub fn by_value() -> Dependencies {
+        Dependencies {
+            follow_pointers: false,
+            ..Dependencies::new()
         }
     }
 
@@ -0,0 +1,746 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::bindgen::ir::{
+    Enum, Field, Function, GenericArgument, ItemContainer, Literal, Path, PrimitiveType, Static,
+    Struct, Type, Union, VariantBody,
+};
+use crate::bindgen::writer::{Source, SourceWriter};
+use crate::bindgen::Bindings;
+
+/// A difference between the ABI of two versions of the bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiChange {
+    /// The export name of the item that changed, or `Enum::Variant` for the
+    /// body of a variant.
+    pub item: String,
+    pub kind: AbiChangeKind,
+}
+
+/// What changed about an item. Types and discriminants are written in the
+/// language of the bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiChangeKind {
+    Added,
+    Removed,
+    /// The item is now a different kind of item, like a union instead of a
+    /// struct.
+    KindChanged {
+        old: &'static str,
+        new: &'static str,
+    },
+    SizeChanged {
+        old: u64,
+        new: u64,
+    },
+    AlignmentChanged {
+        old: u64,
+        new: u64,
+    },
+    /// The type of a global or the aliased type of a typedef changed.
+    TypeChanged {
+        old: String,
+        new: String,
+    },
+    FieldAdded {
+        field: String,
+    },
+    FieldRemoved {
+        field: String,
+    },
+    FieldTypeChanged {
+        field: String,
+        old: String,
+        new: String,
+    },
+    /// The fields both versions have aren't in the same order.
+    FieldsReordered {
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+    FieldOffsetChanged {
+        field: String,
+        old: u64,
+        new: u64,
+    },
+    VariantAdded {
+        variant: String,
+    },
+    VariantRemoved {
+        variant: String,
+    },
+    DiscriminantChanged {
+        variant: String,
+        old: String,
+        new: String,
+    },
+    ReturnTypeChanged {
+        old: String,
+        new: String,
+    },
+    /// The calling convention changed, given by its Rust name.
+    CallingConventionChanged {
+        old: &'static str,
+        new: &'static str,
+    },
+    /// An argument was added at `index`, counting from 0.
+    ArgumentAdded {
+        index: usize,
+        ty: String,
+    },
+    ArgumentRemoved {
+        index: usize,
+        ty: String,
+    },
+    ArgumentTypeChanged {
+        index: usize,
+        old: String,
+        new: String,
+    },
+}
+
+impl AbiChangeKind {
+    /// Whether code built against the old bindings can break with the new
+    /// ones. Only new items and new enum variants are compatible, as long as
+    /// the discriminants and the size of the enum are unchanged, which is
+    /// reported separately.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(
+            *self,
+            AbiChangeKind::Added | AbiChangeKind::VariantAdded { .. }
+        )
+    }
+}
+
+impl fmt::Display for AbiChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AbiChangeKind::Added => write!(f, "added"),
+            AbiChangeKind::Removed => write!(f, "removed"),
+            AbiChangeKind::KindChanged { old, new } => {
+                write!(f, "changed from a {} to a {}", old, new)
+            }
+            AbiChangeKind::SizeChanged { old, new } => {
+                write!(f, "size changed from {} to {} bytes", old, new)
+            }
+            AbiChangeKind::AlignmentChanged { old, new } => {
+                write!(f, "alignment changed from {} to {} bytes", old, new)
+            }
+            AbiChangeKind::TypeChanged { ref old, ref new } => {
+                write!(f, "type changed from `{}` to `{}`", old, new)
+            }
+            AbiChangeKind::FieldAdded { ref field } => write!(f, "field `{}` added", field),
+            AbiChangeKind::FieldRemoved { ref field } => write!(f, "field `{}` removed", field),
+            AbiChangeKind::FieldTypeChanged {
+                ref field,
+                ref old,
+                ref new,
+            } => write!(
+                f,
+                "type of field `{}` changed from `{}` to `{}`",
+                field, old, new
+            ),
+            AbiChangeKind::FieldsReordered { ref old, ref new } => write!(
+                f,
+                "fields reordered from `{}` to `{}`",
+                old.join(", "),
+                new.join(", ")
+            ),
+            AbiChangeKind::FieldOffsetChanged {
+                ref field,
+                old,
+                new,
+            } => write!(
+                f,
+                "offset of field `{}` changed from {} to {}",
+                field, old, new
+            ),
+            AbiChangeKind::VariantAdded { ref variant } => {
+                write!(f, "variant `{}` added", variant)
+            }
+            AbiChangeKind::VariantRemoved { ref variant } => {
+                write!(f, "variant `{}` removed", variant)
+            }
+            AbiChangeKind::DiscriminantChanged {
+                ref variant,
+                ref old,
+                ref new,
+            } => write!(
+                f,
+                "discriminant of variant `{}` changed from `{}` to `{}`",
+                variant, old, new
+            ),
+            AbiChangeKind::ReturnTypeChanged { ref old, ref new } => {
+                write!(f, "return type changed from `{}` to `{}`", old, new)
+            }
+            AbiChangeKind::CallingConventionChanged { old, new } => {
+                write!(f, "calling convention changed from `{}` to `{}`", old, new)
+            }
+            AbiChangeKind::ArgumentAdded { index, ref ty } => {
+                write!(f, "argument {} of type `{}` added", index, ty)
+            }
+            AbiChangeKind::ArgumentRemoved { index, ref ty } => {
+                write!(f, "argument {} of type `{}` removed", index, ty)
+            }
+            AbiChangeKind::ArgumentTypeChanged {
+                index,
+                ref old,
+                ref new,
+            } => write!(
+                f,
+                "type of argument {} changed from `{}` to `{}`",
+                index, old, new
+            ),
+        }
+    }
+}
+
+impl fmt::Display for AbiChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let compatibility = if self.kind.is_breaking() {
+            "breaking"
+        } else {
+            "compatible"
+        };
+        write!(f, "{}: {}: {}", compatibility, self.item, self.kind)
+    }
+}
+
+/// The differences between the ABI of two versions of the bindings, as
+/// returned by `Bindings::diff`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AbiDiff {
+    pub changes: Vec<AbiChange>,
+}
+
+impl AbiDiff {
+    /// Whether any of the changes is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|x| x.kind.is_breaking())
+    }
+}
+
+impl fmt::Display for AbiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the items, globals and functions of `old` and `new`.
+///
+/// Items are matched by export name and cfg, so an item moving under another
+/// cfg shows up as removed and added. Constants aren't compared, as they
+/// aren't part of the ABI, and sizes and offsets are only compared when
+/// `[layout]` can compute them for both versions.
+pub(crate) fn diff(old: &Bindings, new: &Bindings) -> AbiDiff {
+    let mut differ = Differ {
+        old,
+        new,
+        changes: vec![],
+    };
+    differ.matched(old.items(), new.items(), |d, old, new| d.item(old, new));
+    differ.matched(old.globals(), new.globals(), |d, old, new| {
+        d.global(old, new)
+    });
+    differ.matched(old.functions(), new.functions(), |d, old, new| {
+        d.function(old, new)
+    });
+    AbiDiff {
+        changes: differ.changes,
+    }
+}
+
+/// The identity of an item across the two versions.
+trait Key {
+    fn key(&self) -> (&str, Option<String>);
+}
+
+impl Key for ItemContainer {
+    fn key(&self) -> (&str, Option<String>) {
+        let item = self.deref();
+        (item.export_name(), item.cfg().map(ToString::to_string))
+    }
+}
+
+impl Key for Static {
+    fn key(&self) -> (&str, Option<String>) {
+        (
+            &self.export_name,
+            self.cfg.as_ref().map(ToString::to_string),
+        )
+    }
+}
+
+impl Key for Function {
+    fn key(&self) -> (&str, Option<String>) {
+        (self.path.name(), self.cfg.as_ref().map(ToString::to_string))
+    }
+}
+
+struct Differ<'a> {
+    old: &'a Bindings,
+    new: &'a Bindings,
+    changes: Vec<AbiChange>,
+}
+
+impl<'a> Differ<'a> {
+    fn change(&mut self, item: &str, kind: AbiChangeKind) {
+        self.changes.push(AbiChange {
+            item: item.to_owned(),
+            kind,
+        });
+    }
+
+    /// Calls `compare` on the items of `old` and `new` with the same key, and
+    /// reports the others as removed and added.
+    fn matched<T: Key>(&mut self, old: &[T], new: &[T], compare: impl Fn(&mut Self, &T, &T)) {
+        for old_item in old {
+            match new.iter().find(|x| x.key() == old_item.key()) {
+                Some(new_item) => compare(self, old_item, new_item),
+                None => self.change(old_item.key().0, AbiChangeKind::Removed),
+            }
+        }
+        for new_item in new {
+            if !old.iter().any(|x| x.key() == new_item.key()) {
+                self.change(new_item.key().0, AbiChangeKind::Added);
+            }
+        }
+    }
+
+    fn item(&mut self, old: &ItemContainer, new: &ItemContainer) {
+        match (old, new) {
+            (ItemContainer::Struct(ref old), ItemContainer::Struct(ref new)) => {
+                self.structure(&old.export_name, old, new)
+            }
+            (ItemContainer::Union(ref old), ItemContainer::Union(ref new)) => self.union(old, new),
+            (ItemContainer::Enum(ref old), ItemContainer::Enum(ref new)) => {
+                self.enumeration(old, new)
+            }
+            (ItemContainer::Typedef(ref old_t), ItemContainer::Typedef(ref new_t)) => {
+                self.ty(&old_t.export_name, &old_t.aliased, &new_t.aliased);
+                self.size(&old_t.export_name, &old_t.path, &new_t.path);
+            }
+            (ItemContainer::OpaqueItem(..), ItemContainer::OpaqueItem(..))
+            | (ItemContainer::Constant(..), ItemContainer::Constant(..))
+            | (ItemContainer::Static(..), ItemContainer::Static(..)) => {}
+            _ => self.change(
+                old.deref().export_name(),
+                AbiChangeKind::KindChanged {
+                    old: kind_name(old),
+                    new: kind_name(new),
+                },
+            ),
+        }
+    }
+
+    fn global(&mut self, old: &Static, new: &Static) {
+        self.ty(&old.export_name, &old.ty, &new.ty);
+    }
+
+    /// Reports a change of the type of a global or typedef.
+    fn ty(&mut self, item: &str, old: &Type, new: &Type) {
+        if !same_type(old, new) {
+            let kind = AbiChangeKind::TypeChanged {
+                old: source(self.old, old),
+                new: source(self.new, new),
+            };
+            self.change(item, kind);
+        }
+    }
+
+    /// Reports a change of the size or alignment of the items at `old` and
+    /// `new`.
+    fn size(&mut self, item: &str, old: &Path, new: &Path) {
+        let (old, new) = match (self.old.layout(old), self.new.layout(new)) {
+            (Some(old), Some(new)) => (old, new),
+            _ => return,
+        };
+        if old.size != new.size {
+            let kind = AbiChangeKind::SizeChanged {
+                old: old.size,
+                new: new.size,
+            };
+            self.change(item, kind);
+        }
+        if old.align != new.align {
+            let kind = AbiChangeKind::AlignmentChanged {
+                old: old.align,
+                new: new.align,
+            };
+            self.change(item, kind);
+        }
+    }
+
+    fn structure(&mut self, item: &str, old: &Struct, new: &Struct) {
+        let reordered = self.fields(item, &old.fields, &new.fields, true);
+        self.size(item, &old.path, &new.path);
+        if reordered {
+            return;
+        }
+
+        let (old, new) = match (self.old.layout(&old.path), self.new.layout(&new.path)) {
+            (Some(old), Some(new)) => (old, new),
+            _ => return,
+        };
+        for &(ref field, old_offset) in &old.field_offsets {
+            let new_offset = new
+                .field_offsets
+                .iter()
+                .find(|(name, _)| name == field)
+                .map(|&(_, offset)| offset);
+            match new_offset {
+                Some(new_offset) if new_offset != old_offset => {
+                    let kind = AbiChangeKind::FieldOffsetChanged {
+                        field: field.clone(),
+                        old: old_offset,
+                        new: new_offset,
+                    };
+                    self.change(item, kind);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn union(&mut self, old: &Union, new: &Union) {
+        self.fields(&old.export_name, &old.fields, &new.fields, false);
+        self.size(&old.export_name, &old.path, &new.path);
+    }
+
+    /// Reports the fields added, removed or whose type changed, and whether
+    /// the fields both have are in another order if `ordered`. Returns
+    /// whether they are.
+    fn fields(&mut self, item: &str, old: &[Field], new: &[Field], ordered: bool) -> bool {
+        for old_field in old {
+            match new.iter().find(|x| x.name == old_field.name) {
+                Some(new_field) if !same_type(&old_field.ty, &new_field.ty) => {
+                    let kind = AbiChangeKind::FieldTypeChanged {
+                        field: old_field.name.clone(),
+                        old: source(self.old, &old_field.ty),
+                        new: source(self.new, &new_field.ty),
+                    };
+                    self.change(item, kind);
+                }
+                Some(..) => {}
+                None => {
+                    let kind = AbiChangeKind::FieldRemoved {
+                        field: old_field.name.clone(),
+                    };
+                    self.change(item, kind);
+                }
+            }
+        }
+        for new_field in new {
+            if !old.iter().any(|x| x.name == new_field.name) {
+                let kind = AbiChangeKind::FieldAdded {
+                    field: new_field.name.clone(),
+                };
+                self.change(item, kind);
+            }
+        }
+
+        if !ordered {
+            return false;
+        }
+        let common = |fields: &[Field], others: &[Field]| -> Vec<String> {
+            fields
+                .iter()
+                .filter(|x| others.iter().any(|y| y.name == x.name))
+                .map(|x| x.name.clone())
+                .collect()
+        };
+        let (old, new) = (common(old, new), common(new, old));
+        if old == new {
+            return false;
+        }
+        self.change(item, AbiChangeKind::FieldsReordered { old, new });
+        true
+    }
+
+    fn enumeration(&mut self, old: &Enum, new: &Enum) {
+        let item = &old.export_name;
+        let old_discriminants = discriminants(self.old, old);
+        let new_discriminants = discriminants(self.new, new);
+        for (i, old_variant) in old.variants.iter().enumerate() {
+            let j = match new
+                .variants
+                .iter()
+                .position(|x| x.export_name == old_variant.export_name)
+            {
+                Some(j) => j,
+                None => {
+                    let kind = AbiChangeKind::VariantRemoved {
+                        variant: old_variant.export_name.clone(),
+                    };
+                    self.change(item, kind);
+                    continue;
+                }
+            };
+            if old_discriminants[i] != new_discriminants[j] {
+                let kind = AbiChangeKind::DiscriminantChanged {
+                    variant: old_variant.export_name.clone(),
+                    old: old_discriminants[i].to_string(),
+                    new: new_discriminants[j].to_string(),
+                };
+                self.change(item, kind);
+            }
+
+            let body = format!("{}::{}", item, old_variant.export_name);
+            match (&old_variant.body, &new.variants[j].body) {
+                (
+                    VariantBody::Body { body: ref old, .. },
+                    VariantBody::Body { body: ref new, .. },
+                ) => self.structure(&body, old, new),
+                (VariantBody::Empty(..), VariantBody::Empty(..)) => {}
+                (VariantBody::Body { .. }, VariantBody::Empty(..)) => {
+                    self.change(&body, AbiChangeKind::Removed)
+                }
+                (VariantBody::Empty(..), VariantBody::Body { .. }) => {
+                    self.change(&body, AbiChangeKind::Added)
+                }
+            }
+        }
+        for new_variant in &new.variants {
+            if !old
+                .variants
+                .iter()
+                .any(|x| x.export_name == new_variant.export_name)
+            {
+                let kind = AbiChangeKind::VariantAdded {
+                    variant: new_variant.export_name.clone(),
+                };
+                self.change(item, kind);
+            }
+        }
+        self.size(item, &old.path, &new.path);
+    }
+
+    fn function(&mut self, old: &Function, new: &Function) {
+        let item = old.path.name();
+        if !same_type(&old.ret, &new.ret) {
+            let kind = AbiChangeKind::ReturnTypeChanged {
+                old: source(self.old, &old.ret),
+                new: source(self.new, &new.ret),
+            };
+            self.change(item, kind);
+        }
+        if old.abi != new.abi {
+            let kind = AbiChangeKind::CallingConventionChanged {
+                old: old.abi.to_repr_rust(),
+                new: new.abi.to_repr_rust(),
+            };
+            self.change(item, kind);
+        }
+        for (index, old_arg) in old.args.iter().enumerate() {
+            let kind = match new.args.get(index) {
+                Some(new_arg) if same_type(&old_arg.ty, &new_arg.ty) => continue,
+                Some(new_arg) => AbiChangeKind::ArgumentTypeChanged {
+                    index,
+                    old: source(self.old, &old_arg.ty),
+                    new: source(self.new, &new_arg.ty),
+                },
+                None => AbiChangeKind::ArgumentRemoved {
+                    index,
+                    ty: source(self.old, &old_arg.ty),
+                },
+            };
+            self.change(item, kind);
+        }
+        for (index, new_arg) in new.args.iter().enumerate().skip(old.args.len()) {
+            let kind = AbiChangeKind::ArgumentAdded {
+                index,
+                ty: source(self.new, &new_arg.ty),
+            };
+            self.change(item, kind);
+        }
+    }
+}
+
+/// Whether `old` and `new` are the same type for the ABI, which doesn't care
+/// about the names of the arguments of function pointers, whether pointers are
+/// references or nullable, or whether integers are non-zero. Paths are
+/// compared by export name.
+fn same_type(old: &Type, new: &Type) -> bool {
+    match (old, new) {
+        (
+            Type::Ptr {
+                ty: ref old_ty,
+                is_const: old_const,
+                ..
+            },
+            Type::Ptr {
+                ty: ref new_ty,
+                is_const: new_const,
+                ..
+            },
+        ) => old_const == new_const && same_type(old_ty, new_ty),
+        (Type::Path(ref old), Type::Path(ref new)) => {
+            old.export_name() == new.export_name()
+                && old.generics().len() == new.generics().len()
+                && old
+                    .generics()
+                    .iter()
+                    .zip(new.generics())
+                    .all(|pair| match pair {
+                        (GenericArgument::Type(ref old), GenericArgument::Type(ref new)) => {
+                            same_type(old, new)
+                        }
+                        (GenericArgument::Const(ref old), GenericArgument::Const(ref new)) => {
+                            old.as_str() == new.as_str()
+                        }
+                        _ => false,
+                    })
+        }
+        (Type::Primitive(ref old), Type::Primitive(ref new)) => zeroable(old) == zeroable(new),
+        (Type::Array(ref old_ty, ref old_len), Type::Array(ref new_ty, ref new_len)) => {
+            old_len.as_str() == new_len.as_str() && same_type(old_ty, new_ty)
+        }
+        (
+            Type::FuncPtr {
+                ret: ref old_ret,
+                args: ref old_args,
+                abi: old_abi,
+                ..
+            },
+            Type::FuncPtr {
+                ret: ref new_ret,
+                args: ref new_args,
+                abi: new_abi,
+                ..
+            },
+        ) => {
+            old_abi == new_abi
+                && same_type(old_ret, new_ret)
+                && old_args.len() == new_args.len()
+                && old_args
+                    .iter()
+                    .zip(new_args)
+                    .all(|((_, old), (_, new))| same_type(old, new))
+        }
+        (Type::Atomic(ref old), Type::Atomic(ref new)) => same_type(old, new),
+        _ => false,
+    }
+}
+
+/// `ty`, or the integer it's a non-zero version of.
+fn zeroable(ty: &PrimitiveType) -> PrimitiveType {
+    match *ty {
+        PrimitiveType::Integer { kind, signed, .. } => PrimitiveType::Integer {
+            kind,
+            signed,
+            zeroable: true,
+        },
+        ref ty => ty.clone(),
+    }
+}
+
+fn kind_name(item: &ItemContainer) -> &'static str {
+    match *item {
+        ItemContainer::Constant(..) => "constant",
+        ItemContainer::Static(..) => "global",
+        ItemContainer::OpaqueItem(..) => "opaque item",
+        ItemContainer::Struct(..) => "struct",
+        ItemContainer::Union(..) => "union",
+        ItemContainer::Enum(..) => "enum",
+        ItemContainer::Typedef(..) => "typedef",
+    }
+}
+
+/// Writes `s` the way `bindings` would.
+fn source<S: Source>(bindings: &Bindings, s: &S) -> String {
+    let mut out = Vec::new();
+    s.write(&bindings.config, &mut SourceWriter::new(&mut out, bindings));
+    String::from_utf8(out).unwrap()
+}
+
+/// The value of the discriminant of a variant, as an integer or as an
+/// expression plus the number of variants since it when it can't be evaluated.
+#[derive(Clone, PartialEq, Eq)]
+enum Discriminant {
+    Value(i128),
+    After(String, i128),
+}
+
+impl fmt::Display for Discriminant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Discriminant::Value(value) => write!(f, "{}", value),
+            Discriminant::After(ref expr, 0) => write!(f, "{}", expr),
+            Discriminant::After(ref expr, offset) => write!(f, "{} + {}", expr, offset),
+        }
+    }
+}
+
+/// The discriminants of the variants of `e`, which Rust numbers from the
+/// previous explicit one.
+fn discriminants(bindings: &Bindings, e: &Enum) -> Vec<Discriminant> {
+    let mut previous: Option<Discriminant> = None;
+    e.variants
+        .iter()
+        .map(|variant| {
+            let discriminant = match (&variant.discriminant, previous.take()) {
+                (Some(literal), _) => match evaluate(literal) {
+                    Some(value) => Discriminant::Value(value),
+                    None => Discriminant::After(source(bindings, literal), 0),
+                },
+                (None, None) => Discriminant::Value(0),
+                (None, Some(Discriminant::Value(value))) => Discriminant::Value(value + 1),
+                (None, Some(Discriminant::After(expr, offset))) => {
+                    Discriminant::After(expr, offset + 1)
+                }
+            };
+            previous = Some(discriminant.clone());
+            discriminant
+        })
+        .collect()
+}
+
+/// Evaluates an integer literal, or arithmetic on them.
+fn evaluate(literal: &Literal) -> Option<i128> {
+    match *literal {
+        Literal::Expr(ref expr) => parse_integer(expr),
+        Literal::PostfixUnaryOp { op: "-", ref value } => evaluate(value)?.checked_neg(),
+        Literal::BinOp {
+            ref left,
+            op,
+            ref right,
+        } => {
+            let (left, right) = (evaluate(left)?, evaluate(right)?);
+            match op {
+                "+" => left.checked_add(right),
+                "-" => left.checked_sub(right),
+                "*" => left.checked_mul(right),
+                "<<" => left.checked_shl(u32::try_from(right).ok()?),
+                ">>" => left.checked_shr(u32::try_from(right).ok()?),
+                "|" => Some(left | right),
+                "&" => Some(left & right),
+                "^" => Some(left ^ right),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Parses an integer in any base, with the `0x`, `0o` or `0b` prefix of Rust
+/// or the leading `0` of C octals, and with any suffix cbindgen writes.
+fn parse_integer(expr: &str) -> Option<i128> {
+    let expr = expr
+        .trim_end_matches(|c| matches!(c, 'u' | 'U' | 'l' | 'L'))
+        .replace('_', "");
+    let (digits, radix) = match expr.get(..2) {
+        Some("0x" | "0X") => (&expr[2..], 16),
+        Some("0o" | "0O") => (&expr[2..], 8),
+        Some("0b" | "0B") => (&expr[2..], 2),
+        Some(..) if expr.starts_with('0') => (&expr[1..], 8),
+        _ => (&expr[..], 10),
+    };
+    i128::from_str_radix(digits, radix).ok()
+}
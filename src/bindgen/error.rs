@@ -25,6 +25,10 @@ pub enum Error {
         crate_name: String,
         src_path: String,
     },
+    /// The headers of `[split]` would include each other, in this order.
+    SplitHeaderCycle(Vec<String>),
+    /// A JSON dump of bindings couldn't be read back.
+    InvalidJson(String),
 }
 
 impl fmt::Display for Error {
@@ -71,6 +75,14 @@ impl fmt::Display for Error {
                 "Parsing crate `{}`: cannot open file `{}`.",
                 crate_name, src_path
             ),
+            Error::SplitHeaderCycle(ref headers) => write!(
+                f,
+                "Splitting the bindings: the headers {} would include each other.",
+                headers.join(" -> ")
+            ),
+            Error::InvalidJson(ref error) => {
+                write!(f, "Couldn't read the bindings from JSON: {}.", error)
+            }
         }
     }
 }
@@ -83,6 +95,8 @@ impl error::Error for Error {
             Error::CargoExpand(_, ref error) => Some(error),
             Error::ParseSyntaxError { ref error, .. } => Some(error),
             Error::ParseCannotOpenFile { .. } => None,
+            Error::SplitHeaderCycle(..) => None,
+            Error::InvalidJson(..) => None,
         }
     }
 }
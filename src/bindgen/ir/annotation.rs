@@ -22,8 +22,10 @@ use crate::bindgen::utilities::SynAttributeHelpers;
 //  * cbindgen:field-names=[mHandle, mNamespace]
 //  * cbindgen:function-postfix=WR_DESTRUCTOR_SAFE
 
-/// A value specified by an annotation.
-#[derive(Debug, Clone)]
+/// A value specified by an annotation. In `[export.annotations]`, it's a
+/// list of strings, a string or a bool.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
 pub enum AnnotationValue {
     List(Vec<String>),
     Atom(Option<String>),
@@ -50,7 +52,7 @@ impl AnnotationSet {
     }
 
     pub(crate) fn must_use(&self, config: &Config) -> bool {
-        self.must_use && config.language != Language::Cython
+        self.must_use && config.language != Language::Cython && config.language != Language::Zig
     }
 
     pub fn load(attrs: &[syn::Attribute]) -> Result<AnnotationSet, String> {
@@ -128,6 +130,13 @@ impl AnnotationSet {
         }
     }
 
+    /// Adds annotations, overriding those with the same name.
+    pub fn merge(&mut self, annotations: &HashMap<String, AnnotationValue>) {
+        for (name, value) in annotations {
+            self.annotations.insert(name.clone(), value.clone());
+        }
+    }
+
     pub fn list(&self, name: &str) -> Option<Vec<String>> {
         match self.annotations.get(name) {
             Some(AnnotationValue::List(x)) => Some(x.clone()),
@@ -147,6 +156,10 @@ impl AnnotationSet {
         }
     }
 
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&String, &AnnotationValue)> {
+        self.annotations.iter()
+    }
+
     pub fn parse_atom<T>(&self, name: &str) -> Option<T>
     where
         T: Default + FromStr,
@@ -3,10 +3,10 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::fmt;
-use std::io::Write;
 
 use crate::bindgen::cargo::cargo_metadata::Dependency;
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::Config;
+use crate::bindgen::utilities;
 use crate::bindgen::writer::SourceWriter;
 
 #[derive(PartialEq, Eq)]
@@ -16,7 +16,7 @@ enum DefineKey<'a> {
 }
 
 impl<'a> DefineKey<'a> {
-    fn load(key: &str) -> DefineKey {
+    fn load(key: &str) -> DefineKey<'_> {
         // TODO: dirty parser
         if !key.contains('=') {
             return DefineKey::Boolean(key);
@@ -99,19 +99,65 @@ impl Cfg {
         }
     }
 
+    /// Whether this holds when exactly the cfgs in `enabled` do, which are
+    /// written like the keys of `[defines]`, e.g. `unix` or `feature = foo`.
+    pub fn holds(&self, enabled: &[String]) -> bool {
+        match *self {
+            Cfg::Boolean(ref name) => enabled
+                .iter()
+                .any(|key| DefineKey::load(key) == DefineKey::Boolean(name)),
+            Cfg::Named(ref name, ref value) => enabled
+                .iter()
+                .any(|key| DefineKey::load(key) == DefineKey::Named(name, value)),
+            Cfg::Any(ref cfgs) => cfgs.iter().any(|cfg| cfg.holds(enabled)),
+            Cfg::All(ref cfgs) => cfgs.iter().all(|cfg| cfg.holds(enabled)),
+            Cfg::Not(ref cfg) => !cfg.holds(enabled),
+        }
+    }
+
+    /// Loads the cfg of an item from its `#[cfg]`s, and from the predicates of
+    /// the `#[cfg_attr]`s around its `no_mangle` or `export_name`, as it's only
+    /// exported when one of them holds, unless it also has one outside of
+    /// them. Those around a `repr` are up to `Repr::load`.
     pub fn load(attrs: &[syn::Attribute]) -> Option<Cfg> {
         let mut configs = Vec::new();
+        // `None` once the item is exported regardless of any predicate.
+        let mut exported_when = Some(Vec::new());
 
-        for attr in attrs {
-            if let Ok(syn::Meta::List(syn::MetaList { path, nested, .. })) = attr.parse_meta() {
-                if !path.is_ident("cfg") || nested.len() != 1 {
-                    continue;
+        for (predicate, meta) in utilities::expand_attrs(attrs) {
+            let path = meta.path();
+            if path.is_ident("no_mangle") || path.is_ident("export_name") {
+                match (predicate, exported_when.as_mut()) {
+                    (Some(predicate), Some(predicates)) => predicates.push(predicate),
+                    (Some(..), None) => {}
+                    (None, _) => exported_when = None,
                 }
+                continue;
+            }
 
-                if let Some(config) = Cfg::load_single(nested.first().unwrap()) {
-                    configs.push(config);
-                }
+            let nested = match meta {
+                syn::Meta::List(syn::MetaList { ref nested, .. }) if nested.len() == 1 => nested,
+                _ => continue,
+            };
+            if !path.is_ident("cfg") {
+                continue;
             }
+
+            if let Some(config) = Cfg::load_single(nested.first().unwrap()) {
+                // `#[cfg_attr(a, cfg(b))]` only requires `b` when `a` holds.
+                configs.push(match predicate {
+                    Some(predicate) => Cfg::Any(vec![Cfg::Not(Box::new(predicate)), config]),
+                    None => config,
+                });
+            }
+        }
+
+        match exported_when {
+            Some(mut predicates) if predicates.len() == 1 => {
+                configs.push(predicates.pop().unwrap())
+            }
+            Some(predicates) if !predicates.is_empty() => configs.push(Cfg::Any(predicates)),
+            _ => {}
         }
 
         match configs.len() {
@@ -146,7 +192,7 @@ impl Cfg {
         }
     }
 
-    fn load_single(item: &syn::NestedMeta) -> Option<Cfg> {
+    pub(crate) fn load_single(item: &syn::NestedMeta) -> Option<Cfg> {
         Some(match *item {
             syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => {
                 Cfg::Boolean(format!("{}", path.segments.first().unwrap().ident))
@@ -276,90 +322,25 @@ pub enum Condition {
     Not(Box<Condition>),
 }
 
-impl Condition {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        match *self {
-            Condition::Define(ref define) => {
-                if config.language == Language::Cython {
-                    write!(out, "{}", define);
-                } else {
-                    out.write("defined(");
-                    write!(out, "{}", define);
-                    out.write(")");
-                }
-            }
-            Condition::Any(ref conditions) => {
-                out.write("(");
-                for (i, condition) in conditions.iter().enumerate() {
-                    if i != 0 {
-                        out.write(if config.language == Language::Cython {
-                            " or "
-                        } else {
-                            " || "
-                        });
-                    }
-                    condition.write(config, out);
-                }
-                out.write(")");
-            }
-            Condition::All(ref conditions) => {
-                out.write("(");
-                for (i, condition) in conditions.iter().enumerate() {
-                    if i != 0 {
-                        out.write(if config.language == Language::Cython {
-                            " and "
-                        } else {
-                            " && "
-                        });
-                    }
-                    condition.write(config, out);
-                }
-                out.write(")");
-            }
-            Condition::Not(ref condition) => {
-                out.write(if config.language == Language::Cython {
-                    "not "
-                } else {
-                    "!"
-                });
-                condition.write(config, out);
-            }
-        }
-    }
-}
-
 pub trait ConditionWrite {
-    fn write_before<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>);
-    fn write_after<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>);
+    fn write_before(&self, config: &Config, out: &mut SourceWriter);
+    fn write_after(&self, config: &Config, out: &mut SourceWriter);
 }
 
 impl ConditionWrite for Option<Condition> {
-    fn write_before<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+    fn write_before(&self, config: &Config, out: &mut SourceWriter) {
         if let Some(ref cfg) = *self {
-            if config.language == Language::Cython {
-                out.write("IF ");
-                cfg.write(config, out);
-                out.open_brace();
-            } else {
-                out.push_set_spaces(0);
-                out.write("#if ");
-                cfg.write(config, out);
-                out.pop_set_spaces();
-                out.new_line();
-            }
+            out.bindings()
+                .backend()
+                .write_condition_before(config, out, cfg);
         }
     }
 
-    fn write_after<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        if self.is_some() {
-            if config.language == Language::Cython {
-                out.close_brace(false);
-            } else {
-                out.new_line();
-                out.push_set_spaces(0);
-                out.write("#endif");
-                out.pop_set_spaces();
-            }
+    fn write_after(&self, config: &Config, out: &mut SourceWriter) {
+        if let Some(ref cfg) = *self {
+            out.bindings()
+                .backend()
+                .write_condition_after(config, out, cfg);
         }
     }
 }
@@ -4,17 +4,15 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
-use std::io::Write;
 
 use syn::ext::IdentExt;
 use syn::{self, UnOp};
 
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::Config;
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Struct, ToCondition, Type,
+    AnnotationSet, Cfg, Documentation, GenericParams, Item, ItemContainer, Path, Struct, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::writer::{Source, SourceWriter};
@@ -27,56 +25,6 @@ fn member_to_ident(member: &syn::Member) -> String {
     }
 }
 
-// TODO: Maybe add support to more std associated constants.
-fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
-    use crate::bindgen::ir::{IntKind, PrimitiveType};
-
-    if name != "MAX" && name != "MIN" {
-        return None;
-    }
-
-    let prim = PrimitiveType::maybe(associated_to.name())?;
-    let prefix = match prim {
-        PrimitiveType::Integer {
-            kind,
-            signed,
-            zeroable: _,
-        } => match kind {
-            IntKind::B8 => {
-                if signed {
-                    "INT8"
-                } else {
-                    "UINT8"
-                }
-            }
-            IntKind::B16 => {
-                if signed {
-                    "INT16"
-                } else {
-                    "UINT16"
-                }
-            }
-            IntKind::B32 => {
-                if signed {
-                    "INT32"
-                } else {
-                    "UINT32"
-                }
-            }
-            IntKind::B64 => {
-                if signed {
-                    "INT64"
-                } else {
-                    "UINT64"
-                }
-            }
-            _ => return None,
-        },
-        _ => return None,
-    };
-    Some(format!("{}_{}", prefix, name))
-}
-
 #[derive(Debug, Clone)]
 pub enum Literal {
     Expr(String),
@@ -167,7 +115,10 @@ impl Literal {
             } => {
                 if let Some((ref path, _export_name)) = associated_to {
                     return bindings.struct_exists(path)
-                        || to_known_assoc_constant(path, name).is_some();
+                        || bindings
+                            .backend()
+                            .known_assoc_constant(path, name)
+                            .is_some();
                 }
                 true
             }
@@ -183,7 +134,7 @@ impl Literal {
         }
     }
 
-    fn can_be_constexpr(&self) -> bool {
+    pub fn can_be_constexpr(&self) -> bool {
         !self.has_pointer_casts()
     }
 
@@ -340,6 +291,13 @@ impl Literal {
                         other_code => format!(r"U'\U{:08X}'", other_code),
                     })),
                     syn::Lit::Int(ref value) => {
+                        // There are no wider literals in C.
+                        if value.base10_parse::<u64>().is_err() {
+                            return Err(format!(
+                                "Integer literal {} doesn't fit in 64 bits.",
+                                value.base10_digits()
+                            ));
+                        }
                         let suffix = match value.suffix() {
                             "u64" => "ull",
                             "i64" => "ll",
@@ -472,106 +430,11 @@ impl Literal {
             _ => Err(format!("Unsupported expression. {:?}", *expr)),
         }
     }
+}
 
-    pub(crate) fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        match self {
-            Literal::Expr(v) => match (&**v, config.language) {
-                ("true", Language::Cython) => write!(out, "True"),
-                ("false", Language::Cython) => write!(out, "False"),
-                (v, _) => write!(out, "{}", v),
-            },
-            Literal::Path {
-                ref associated_to,
-                ref name,
-            } => {
-                if let Some((ref path, ref export_name)) = associated_to {
-                    if let Some(known) = to_known_assoc_constant(path, name) {
-                        return write!(out, "{}", known);
-                    }
-                    let path_separator = match config.language {
-                        Language::Cython | Language::C => "_",
-                        Language::Cxx => {
-                            if config.structure.associated_constants_in_body {
-                                "::"
-                            } else {
-                                "_"
-                            }
-                        }
-                    };
-                    write!(out, "{}{}", export_name, path_separator)
-                }
-                write!(out, "{}", name)
-            }
-            Literal::FieldAccess {
-                ref base,
-                ref field,
-            } => {
-                write!(out, "(");
-                base.write(config, out);
-                write!(out, ").{}", field);
-            }
-            Literal::PostfixUnaryOp { op, ref value } => {
-                write!(out, "{}", op);
-                value.write(config, out);
-            }
-            Literal::BinOp {
-                ref left,
-                op,
-                ref right,
-            } => {
-                write!(out, "(");
-                left.write(config, out);
-                write!(out, " {} ", op);
-                right.write(config, out);
-                write!(out, ")");
-            }
-            Literal::Cast { ref ty, ref value } => {
-                out.write(if config.language == Language::Cython {
-                    "<"
-                } else {
-                    "("
-                });
-                ty.write(config, out);
-                out.write(if config.language == Language::Cython {
-                    ">"
-                } else {
-                    ")"
-                });
-                value.write(config, out);
-            }
-            Literal::Struct {
-                export_name,
-                fields,
-                path,
-            } => {
-                match config.language {
-                    Language::C => write!(out, "({})", export_name),
-                    Language::Cxx => write!(out, "{}", export_name),
-                    Language::Cython => write!(out, "<{}>", export_name),
-                }
-
-                write!(out, "{{ ");
-                let mut is_first_field = true;
-                // In C++, same order as defined is required.
-                let ordered_fields = out.bindings().struct_field_names(path);
-                for ordered_key in ordered_fields.iter() {
-                    if let Some(lit) = fields.get(ordered_key) {
-                        if !is_first_field {
-                            write!(out, ", ");
-                        } else {
-                            is_first_field = false;
-                        }
-                        match config.language {
-                            Language::Cxx => write!(out, "/* .{} = */ ", ordered_key),
-                            Language::C => write!(out, ".{} = ", ordered_key),
-                            Language::Cython => {}
-                        }
-                        lit.write(config, out);
-                    }
-                }
-                write!(out, " }}");
-            }
-        }
+impl Source for Literal {
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
+        out.bindings().backend().write_literal(config, out, self);
     }
 }
 
@@ -692,122 +555,62 @@ impl Item for Constant {
 }
 
 impl Constant {
-    pub fn write_declaration<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        associated_to_struct: &Struct,
-    ) {
-        debug_assert!(self.associated_to.is_some());
-        debug_assert!(config.language == Language::Cxx);
-        debug_assert!(!associated_to_struct.is_transparent);
-        debug_assert!(config.structure.associated_constants_in_body);
-        debug_assert!(config.constant.allow_static_const);
-
-        if let Type::Ptr { is_const: true, .. } = self.ty {
-            out.write("static ");
-        } else {
-            out.write("static const ");
+    /// Whether this can be written, which isn't the case for the constants
+    /// associated to generic structs, or with a value referring to things
+    /// which aren't written.
+    pub fn can_write(&self, bindings: &Bindings, associated_to_struct: Option<&Struct>) -> bool {
+        if let Some(assoc) = associated_to_struct {
+            if assoc.is_generic() {
+                return false; // Not tested / implemented yet, so bail out.
+            }
         }
-        self.ty.write(config, out);
-        write!(out, " {};", self.export_name())
+
+        self.value.is_valid(bindings)
     }
 
-    pub fn write<F: Write>(
+    /// The name of this, prefixed with the struct it's associated to, if any.
+    pub fn prefixed_name(
         &self,
         config: &Config,
-        out: &mut SourceWriter<F>,
         associated_to_struct: Option<&Struct>,
-    ) {
-        if let Some(assoc) = associated_to_struct {
-            if assoc.is_generic() {
-                return; // Not tested / implemented yet, so bail out.
-            }
-        }
-
-        if !self.value.is_valid(out.bindings()) {
-            return;
+    ) -> Cow<'_, str> {
+        if self.associated_to.is_none() {
+            return Cow::Borrowed(self.export_name());
         }
 
-        let associated_to_transparent = associated_to_struct.map_or(false, |s| s.is_transparent);
-
-        let in_body = associated_to_struct.is_some()
-            && config.language == Language::Cxx
-            && config.structure.associated_constants_in_body
-            && config.constant.allow_static_const
-            && !associated_to_transparent;
-
-        let condition = self.cfg.to_condition(config);
-        condition.write_before(config, out);
-
-        let name = if in_body {
-            Cow::Owned(format!(
-                "{}::{}",
-                associated_to_struct.unwrap().export_name(),
-                self.export_name(),
-            ))
-        } else if self.associated_to.is_none() {
-            Cow::Borrowed(self.export_name())
-        } else {
-            let associated_name = match associated_to_struct {
-                Some(s) => Cow::Borrowed(s.export_name()),
-                None => {
-                    let mut name = self.associated_to.as_ref().unwrap().name().to_owned();
-                    config.export.rename(&mut name);
-                    Cow::Owned(name)
-                }
-            };
-
-            Cow::Owned(format!("{}_{}", associated_name, self.export_name()))
+        let associated_name = match associated_to_struct {
+            Some(s) => Cow::Borrowed(s.export_name()),
+            None => {
+                let mut name = self.associated_to.as_ref().unwrap().name().to_owned();
+                config.export.rename(&mut name);
+                Cow::Owned(name)
+            }
         };
 
-        let value = match self.value {
+        Cow::Owned(format!("{}_{}", associated_name, self.export_name()))
+    }
+
+    /// The value to write, which is the value of the only field of
+    /// transparent structs.
+    pub fn written_value<'a>(&'a self, bindings: &Bindings) -> &'a Literal {
+        match self.value {
             Literal::Struct {
                 ref fields,
                 ref path,
                 ..
-            } if out.bindings().struct_is_transparent(path) => fields.iter().next().unwrap().1,
+            } if bindings.struct_is_transparent(path) => fields.iter().next().unwrap().1,
             _ => &self.value,
-        };
-
-        self.documentation.write(config, out);
-
-        let allow_constexpr = config.constant.allow_constexpr && self.value.can_be_constexpr();
-        match config.language {
-            Language::Cxx if config.constant.allow_static_const || allow_constexpr => {
-                if allow_constexpr {
-                    out.write("constexpr ")
-                }
-
-                if config.constant.allow_static_const {
-                    out.write(if in_body { "inline " } else { "static " });
-                }
-
-                if let Type::Ptr { is_const: true, .. } = self.ty {
-                    // Nothing.
-                } else {
-                    out.write("const ");
-                }
-
-                self.ty.write(config, out);
-                write!(out, " {} = ", name);
-                value.write(config, out);
-                write!(out, ";");
-            }
-            Language::Cxx | Language::C => {
-                write!(out, "#define {} ", name);
-                value.write(config, out);
-            }
-            Language::Cython => {
-                out.write("const ");
-                self.ty.write(config, out);
-                // For extern Cython declarations the initializer is ignored,
-                // but still useful as documentation, so we write it as a comment.
-                write!(out, " {} # = ", name);
-                value.write(config, out);
-            }
         }
+    }
 
-        condition.write_after(config, out);
+    pub fn write(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        out.bindings()
+            .backend()
+            .write_constant(config, out, self, associated_to_struct);
     }
 }
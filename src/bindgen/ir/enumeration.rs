@@ -2,24 +2,21 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::io::Write;
-
 use syn::ext::IdentExt;
 
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, AnnotationValue, Cfg, ConditionWrite, Documentation, Field, GenericArgument,
-    GenericParams, GenericPath, Item, ItemContainer, Literal, Path, Repr, ReprStyle, Struct,
-    ToCondition, Type,
+    AnnotationSet, AnnotationValue, Cfg, Documentation, Field, GenericArgument, GenericParams,
+    GenericPath, Item, ItemContainer, Literal, Path, Repr, ReprStyle, Struct, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::reserved;
-use crate::bindgen::writer::{ListType, Source, SourceWriter};
+use crate::bindgen::writer::{Source, SourceWriter};
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
@@ -46,7 +43,7 @@ impl VariantBody {
         Self::Empty(AnnotationSet::new())
     }
 
-    fn annotations(&self) -> &AnnotationSet {
+    pub fn annotations(&self) -> &AnnotationSet {
         match *self {
             Self::Empty(ref anno) => anno,
             Self::Body { ref body, .. } => &body.annotations,
@@ -126,18 +123,19 @@ impl EnumVariant {
             for (i, field) in fields.iter().enumerate() {
                 if let Some(mut ty) = Type::load(&field.ty)? {
                     ty.replace_self_with(self_path);
+                    let rust_name = match field.ident {
+                        Some(ref ident) => ident.unraw().to_string(),
+                        None => i.to_string(),
+                    };
                     res.push(Field {
-                        name: inline_name.map_or_else(
-                            || match field.ident {
-                                Some(ref ident) => ident.unraw().to_string(),
-                                None => i.to_string(),
-                            },
-                            |name| name.to_string(),
-                        ),
+                        name: inline_name
+                            .map_or_else(|| rust_name.clone(), |name| name.to_string()),
+                        rust_name,
                         ty,
                         cfg: Cfg::load(&field.attrs),
                         annotations: AnnotationSet::load(&field.attrs)?,
                         documentation: Documentation::load(&field.attrs),
+                        flexible_array: false,
                     });
                 }
             }
@@ -197,7 +195,9 @@ impl EnumVariant {
                 // Besides that for C++ we generate casts/getters that can be used instead of
                 // direct field accesses and also have a benefit of being checked.
                 // As a result we don't currently inline variant definitions in C++ mode at all.
-                let inline = inline_casts && config.language != Language::Cxx;
+                // C# has no unnamed structs at all.
+                let inline =
+                    inline_casts && !matches!(config.language, Language::Cxx | Language::CSharp);
                 let inline_name = if inline { Some(&*name) } else { None };
                 VariantBody::Body {
                     body: Struct::new(
@@ -292,31 +292,6 @@ impl EnumVariant {
     }
 }
 
-impl Source for EnumVariant {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        let condition = self.cfg.to_condition(config);
-        // Cython doesn't support conditional enum variants.
-        if config.language != Language::Cython {
-            condition.write_before(config, out);
-        }
-        self.documentation.write(config, out);
-        write!(out, "{}", self.export_name);
-        if let Some(discriminant) = &self.discriminant {
-            if config.language == Language::Cython {
-                // For extern Cython declarations the enumerator value is ignored,
-                // but still useful as documentation, so we write it as a comment.
-                out.write(" #")
-            }
-            out.write(" = ");
-            discriminant.write(config, out);
-        }
-        out.write(",");
-        if config.language != Language::Cython {
-            condition.write_after(config, out);
-        }
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct Enum {
     pub path: Path,
@@ -332,17 +307,17 @@ pub struct Enum {
 
 impl Enum {
     /// Name of the generated tag enum.
-    fn tag_name(&self) -> &str {
+    pub(crate) fn tag_name(&self) -> &str {
         self.tag.as_deref().unwrap_or_else(|| self.export_name())
     }
 
     /// Enum with data turns into a union of structs with each struct having its own tag field.
-    fn inline_tag_field(repr: &Repr) -> bool {
+    pub(crate) fn inline_tag_field(repr: &Repr) -> bool {
         repr.style != ReprStyle::C
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
-        if self.generic_params.len() > 0 {
+        if !self.generic_params.is_empty() {
             return;
         }
 
@@ -351,7 +326,7 @@ impl Enum {
         }
     }
 
-    fn can_derive_eq(&self) -> bool {
+    pub fn can_derive_eq(&self) -> bool {
         if self.tag.is_none() {
             return false;
         }
@@ -368,12 +343,25 @@ impl Enum {
         }
     }
 
+    /// Loads an enum for each of the reprs of `item`, see `Repr::load`.
     pub fn load(
         item: &syn::ItemEnum,
         mod_cfg: Option<&Cfg>,
         config: &Config,
+    ) -> Result<Vec<Enum>, String> {
+        Repr::load(&item.attrs)?
+            .into_iter()
+            .map(|(repr_cfg, repr)| Self::load_with_repr(item, mod_cfg, config, repr_cfg, repr))
+            .collect()
+    }
+
+    fn load_with_repr(
+        item: &syn::ItemEnum,
+        mod_cfg: Option<&Cfg>,
+        config: &Config,
+        repr_cfg: Option<Cfg>,
+        repr: Repr,
     ) -> Result<Enum, String> {
-        let repr = Repr::load(&item.attrs)?;
         if repr.style == ReprStyle::Rust && repr.ty.is_none() {
             return Err("Enum is not marked with a valid #[repr(prim)] or #[repr(C)].".to_owned());
         }
@@ -388,7 +376,12 @@ impl Enum {
         let mut variants = Vec::new();
         let mut has_data = false;
 
-        let annotations = AnnotationSet::load(&item.attrs)?;
+        let mut annotations = AnnotationSet::load(&item.attrs)?;
+        // The variants depend on the annotations, so those of the config are
+        // merged here already.
+        if let Some(extra) = config.export.annotations.get(path.name()) {
+            annotations.merge(extra);
+        }
 
         for variant in item.variants.iter() {
             let variant = EnumVariant::load(
@@ -438,7 +431,10 @@ impl Enum {
             repr,
             variants,
             tag,
-            Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
+            Cfg::append(
+                Cfg::append(mod_cfg, repr_cfg).as_ref(),
+                Cfg::load(&item.attrs),
+            ),
             annotations,
             Documentation::load(&item.attrs),
         ))
@@ -535,7 +531,7 @@ impl Item for Enum {
         }
 
         for variant in &mut self.variants {
-            reserved::escape(&mut variant.export_name);
+            reserved::escape(&mut variant.export_name, config.language);
             if let Some(discriminant) = &mut variant.discriminant {
                 discriminant.rename_for_config(config);
             }
@@ -546,7 +542,7 @@ impl Item for Enum {
             } = variant.body
             {
                 body.rename_for_config(config);
-                reserved::escape(name);
+                reserved::escape(name, config.language);
             }
         }
 
@@ -655,872 +651,12 @@ impl Item for Enum {
 }
 
 impl Source for Enum {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        let size = self.repr.ty.map(|ty| ty.to_primitive().to_repr_c(config));
-        let has_data = self.tag.is_some();
-        let inline_tag_field = Self::inline_tag_field(&self.repr);
-        let tag_name = self.tag_name();
-
-        let condition = self.cfg.to_condition(config);
-        condition.write_before(config, out);
-
-        self.documentation.write(config, out);
-        self.generic_params.write(config, out);
-
-        // If the enum has data, we need to emit a struct or union for the data
-        // and enum for the tag. C++ supports nested type definitions, so we open
-        // the struct or union here and define the tag enum inside it (*).
-        if has_data && config.language == Language::Cxx {
-            self.open_struct_or_union(config, out, inline_tag_field);
-        }
-
-        // Emit the tag enum and everything related to it.
-        self.write_tag_enum(config, out, size, has_data, tag_name);
-
-        // If the enum has data, we need to emit structs for the variants and gather them together.
-        if has_data {
-            self.write_variant_defs(config, out);
-            out.new_line();
-            out.new_line();
-
-            // Open the struct or union for the data (**), gathering all the variants with data
-            // together, unless it's C++, then we have already opened that struct/union at (*) and
-            // are currently inside it.
-            if config.language != Language::Cxx {
-                self.open_struct_or_union(config, out, inline_tag_field);
-            }
-
-            // Emit tag field that is separate from all variants.
-            self.write_tag_field(config, out, size, inline_tag_field, tag_name);
-            out.new_line();
-
-            // Open union of all variants with data, only in the non-inline tag scenario.
-            // Cython extern declarations don't manage layouts, layouts are defined entierly by the
-            // corresponding C code. So we can inline the unnamed union into the struct and get the
-            // same observable result. Moreother we have to do it because Cython doesn't support
-            // unnamed unions.
-            if !inline_tag_field && config.language != Language::Cython {
-                out.write("union");
-                out.open_brace();
-            }
-
-            // Emit fields for all variants with data.
-            self.write_variant_fields(config, out, inline_tag_field);
-
-            // Close union of all variants with data, only in the non-inline tag scenario.
-            // See the comment about Cython on `open_brace`.
-            if !inline_tag_field && config.language != Language::Cython {
-                out.close_brace(true);
-            }
-
-            // Emit convenience methods for the struct or enum for the data.
-            self.write_derived_functions_data(config, out, tag_name);
-
-            // Emit the post_body section, if relevant.
-            if let Some(body) = config.export.post_body(&self.path) {
-                out.new_line();
-                out.write_raw_block(body);
-            }
-
-            // Close the struct or union opened either at (*) or at (**).
-            if config.language == Language::C && config.style.generate_typedef() {
-                out.close_brace(false);
-                write!(out, " {};", self.export_name);
-            } else {
-                out.close_brace(true);
-            }
-        }
-
-        condition.write_after(config, out);
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
+        out.bindings().backend().write_enum(config, out, self);
     }
 }
 
 impl Enum {
-    /// Emit the tag enum and convenience methods for it.
-    /// For enums with data this is only a part of the output,
-    /// but for enums without data it's the whole output (modulo doc comments etc.).
-    fn write_tag_enum<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        size: Option<&str>,
-        has_data: bool,
-        tag_name: &str,
-    ) {
-        // Open the tag enum.
-        match config.language {
-            Language::C => {
-                if let Some(prim) = size {
-                    // If we need to specify size, then we have no choice but to create a typedef,
-                    // so `config.style` is not respected.
-                    write!(out, "enum {}", tag_name);
-
-                    if config.cpp_compatible_c() {
-                        out.new_line();
-                        out.write("#ifdef __cplusplus");
-                        out.new_line();
-                        write!(out, "  : {}", prim);
-                        out.new_line();
-                        out.write("#endif // __cplusplus");
-                        out.new_line();
-                    }
-                } else {
-                    if config.style.generate_typedef() {
-                        out.write("typedef ");
-                    }
-                    out.write("enum");
-                    if config.style.generate_tag() {
-                        write!(out, " {}", tag_name);
-                    }
-                }
-            }
-            Language::Cxx => {
-                if config.enumeration.enum_class(&self.annotations) {
-                    out.write("enum class");
-                } else {
-                    out.write("enum");
-                }
-
-                if self.annotations.must_use(config) {
-                    if let Some(ref anno) = config.enumeration.must_use {
-                        write!(out, " {}", anno)
-                    }
-                }
-
-                write!(out, " {}", tag_name);
-                if let Some(prim) = size {
-                    write!(out, " : {}", prim);
-                }
-            }
-            Language::Cython => {
-                if size.is_some() {
-                    // If we need to specify size, then we have no choice but to create a typedef,
-                    // so `config.style` is not respected.
-                    write!(out, "cdef enum");
-                } else {
-                    write!(out, "{}enum {}", config.style.cython_def(), tag_name);
-                }
-            }
-        }
-        out.open_brace();
-
-        // Emit enumerators for the tag enum.
-        for (i, variant) in self.variants.iter().enumerate() {
-            if i != 0 {
-                out.new_line()
-            }
-            variant.write(config, out);
-        }
-
-        // Close the tag enum.
-        if config.language == Language::C && size.is_none() && config.style.generate_typedef() {
-            out.close_brace(false);
-            write!(out, " {};", tag_name);
-        } else {
-            out.close_brace(true);
-        }
-
-        // Emit typedef specifying the tag enum's size if necessary.
-        // In C++ enums can "inherit" from numeric types (`enum E: uint8_t { ... }`),
-        // but in C `typedef uint8_t E` is the only way to give a fixed size to `E`.
-        if let Some(prim) = size {
-            if config.cpp_compatible_c() {
-                out.new_line_if_not_start();
-                out.write("#ifndef __cplusplus");
-            }
-
-            if config.language != Language::Cxx {
-                out.new_line();
-                write!(out, "{} {} {};", config.language.typedef(), prim, tag_name);
-            }
-
-            if config.cpp_compatible_c() {
-                out.new_line_if_not_start();
-                out.write("#endif // __cplusplus");
-            }
-        }
-
-        // Emit convenience methods for the tag enum.
-        self.write_derived_functions_enum(config, out, has_data, tag_name);
-    }
-
-    /// The code here mirrors the beginning of `Struct::write` and `Union::write`.
-    fn open_struct_or_union<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        inline_tag_field: bool,
-    ) {
-        match config.language {
-            Language::C if config.style.generate_typedef() => out.write("typedef "),
-            Language::C | Language::Cxx => {}
-            Language::Cython => out.write(config.style.cython_def()),
-        }
-
-        out.write(if inline_tag_field { "union" } else { "struct" });
-
-        if self.annotations.must_use(config) {
-            if let Some(ref anno) = config.structure.must_use {
-                write!(out, " {}", anno);
-            }
-        }
-
-        if config.language != Language::C || config.style.generate_tag() {
-            write!(out, " {}", self.export_name());
-        }
-
-        out.open_brace();
-
-        // Emit the pre_body section, if relevant.
-        if let Some(body) = config.export.pre_body(&self.path) {
-            out.write_raw_block(body);
-            out.new_line();
-        }
-    }
-
-    /// Emit struct definitions for variants having data.
-    fn write_variant_defs<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        for variant in &self.variants {
-            if let VariantBody::Body {
-                ref body,
-                inline: false,
-                ..
-            } = variant.body
-            {
-                out.new_line();
-                out.new_line();
-                let condition = variant.cfg.to_condition(config);
-                // Cython doesn't support conditional enum variants.
-                if config.language != Language::Cython {
-                    condition.write_before(config, out);
-                }
-                body.write(config, out);
-                if config.language != Language::Cython {
-                    condition.write_after(config, out);
-                }
-            }
-        }
-    }
-
-    /// Emit tag field that is separate from all variants.
-    /// For non-inline tag scenario this is *the* tag field, and it does not exist in the variants.
-    /// For the inline tag scenario this is just a convenience and another way
-    /// to refer to the same tag that exist in all the variants.
-    fn write_tag_field<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        size: Option<&str>,
-        inline_tag_field: bool,
-        tag_name: &str,
-    ) {
-        // C++ allows accessing only common initial sequence of union
-        // fields so we have to wrap the tag field into an anonymous struct.
-        let wrap_tag = inline_tag_field && config.language == Language::Cxx;
-
-        if wrap_tag {
-            out.write("struct");
-            out.open_brace();
-        }
-
-        if config.language == Language::C && size.is_none() && !config.style.generate_typedef() {
-            out.write("enum ");
-        }
-
-        write!(out, "{} tag;", tag_name);
-
-        if wrap_tag {
-            out.close_brace(true);
-        }
-    }
-
-    /// Emit fields for all variants with data.
-    fn write_variant_fields<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        inline_tag_field: bool,
-    ) {
-        let mut first = true;
-        for variant in &self.variants {
-            if let VariantBody::Body {
-                name, body, inline, ..
-            } = &variant.body
-            {
-                if !first {
-                    out.new_line();
-                }
-                first = false;
-                let condition = variant.cfg.to_condition(config);
-                // Cython doesn't support conditional enum variants.
-                if config.language != Language::Cython {
-                    condition.write_before(config, out);
-                }
-                if *inline {
-                    // Write definition of an inlined variant with data.
-                    // Cython extern declarations don't manage layouts, layouts are defined entierly
-                    // by the corresponding C code. So we can inline the unnamed struct and get the
-                    // same observable result. Moreother we have to do it because Cython doesn't
-                    // support unnamed structs.
-                    // For the same reason with Cython we can omit per-variant tags (the first
-                    // field) to avoid extra noise, the main `tag` is enough in this case.
-                    if config.language != Language::Cython {
-                        out.write("struct");
-                        out.open_brace();
-                    }
-                    let start_field =
-                        usize::from(inline_tag_field && config.language == Language::Cython);
-                    out.write_vertical_source_list(&body.fields[start_field..], ListType::Cap(";"));
-                    if config.language != Language::Cython {
-                        out.close_brace(true);
-                    }
-                } else if config.style.generate_typedef() || config.language == Language::Cython {
-                    write!(out, "{} {};", body.export_name(), name);
-                } else {
-                    write!(out, "struct {} {};", body.export_name(), name);
-                }
-                if config.language != Language::Cython {
-                    condition.write_after(config, out);
-                }
-            }
-        }
-    }
-
-    // Emit convenience methods for enums themselves.
-    fn write_derived_functions_enum<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        has_data: bool,
-        tag_name: &str,
-    ) {
-        if config.language != Language::Cxx {
-            return;
-        }
-
-        // Emit an ostream function if required.
-        if config.enumeration.derive_ostream(&self.annotations) {
-            // For enums without data, this emits the serializer function for the
-            // enum. For enums with data, this emits the serializer function for
-            // the tag enum. In the latter case we need a couple of minor changes
-            // due to the function living inside the top-level struct or enum.
-            let stream = config
-                .function
-                .rename_args
-                .apply("stream", IdentifierType::FunctionArg);
-            let instance = config
-                .function
-                .rename_args
-                .apply("instance", IdentifierType::FunctionArg);
-
-            out.new_line();
-            out.new_line();
-            // For enums without data, we mark the function inline because the
-            // header might get included into multiple compilation units that
-            // get linked together, and not marking it inline would result in
-            // multiply-defined symbol errors. For enums with data we don't have
-            // the same problem, but mark it as a friend function of the
-            // containing union/struct.
-            // Note also that for enums with data, the case labels for switch
-            // statements apparently need to be qualified to the top-level
-            // generated struct or union. This is why the generated case labels
-            // below use the A::B::C format for enums with data, with A being
-            // self.export_name(). Failure to have that qualification results
-            // in a surprising compilation failure for the generated header.
-            write!(
-                out,
-                "{} std::ostream& operator<<(std::ostream& {}, const {}& {})",
-                if has_data { "friend" } else { "inline" },
-                stream,
-                tag_name,
-                instance,
-            );
-
-            out.open_brace();
-            if has_data {
-                // C++ name resolution rules are weird.
-                write!(
-                    out,
-                    "using {} = {}::{};",
-                    tag_name,
-                    self.export_name(),
-                    tag_name
-                );
-                out.new_line();
-            }
-            write!(out, "switch ({})", instance);
-            out.open_brace();
-            let vec: Vec<_> = self
-                .variants
-                .iter()
-                .map(|x| {
-                    format!(
-                        "case {}::{}: {} << \"{}\"; break;",
-                        tag_name, x.export_name, stream, x.export_name
-                    )
-                })
-                .collect();
-            out.write_vertical_source_list(&vec[..], ListType::Join(""));
-            out.close_brace(false);
-            out.new_line();
-
-            write!(out, "return {};", stream);
-            out.close_brace(false);
-
-            if has_data {
-                // For enums with data, this emits the serializer function for
-                // the top-level union or struct.
-                out.new_line();
-                out.new_line();
-                write!(
-                    out,
-                    "friend std::ostream& operator<<(std::ostream& {}, const {}& {})",
-                    stream,
-                    self.export_name(),
-                    instance,
-                );
-
-                out.open_brace();
-
-                // C++ name resolution rules are weird.
-                write!(
-                    out,
-                    "using {} = {}::{};",
-                    tag_name,
-                    self.export_name(),
-                    tag_name
-                );
-                out.new_line();
-
-                write!(out, "switch ({}.tag)", instance);
-                out.open_brace();
-                let vec: Vec<_> = self
-                    .variants
-                    .iter()
-                    .map(|x| {
-                        let tag_str = format!("\"{}\"", x.export_name);
-                        if let VariantBody::Body {
-                            ref name, ref body, ..
-                        } = x.body
-                        {
-                            format!(
-                                "case {}::{}: {} << {}{}{}.{}; break;",
-                                tag_name,
-                                x.export_name,
-                                stream,
-                                if body.has_tag_field { "" } else { &tag_str },
-                                if body.has_tag_field { "" } else { " << " },
-                                instance,
-                                name,
-                            )
-                        } else {
-                            format!(
-                                "case {}::{}: {} << {}; break;",
-                                tag_name, x.export_name, stream, tag_str,
-                            )
-                        }
-                    })
-                    .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(""));
-                out.close_brace(false);
-                out.new_line();
-
-                write!(out, "return {};", stream);
-                out.close_brace(false);
-            }
-        }
-    }
-
-    // Emit convenience methods for structs or unions produced for enums with data.
-    fn write_derived_functions_data<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        tag_name: &str,
-    ) {
-        if config.language != Language::Cxx {
-            return;
-        }
-
-        if config.enumeration.derive_helper_methods(&self.annotations) {
-            for variant in &self.variants {
-                out.new_line();
-                out.new_line();
-
-                let condition = variant.cfg.to_condition(config);
-                condition.write_before(config, out);
-
-                let arg_renamer = |name: &str| {
-                    config
-                        .function
-                        .rename_args
-                        .apply(name, IdentifierType::FunctionArg)
-                        .into_owned()
-                };
-
-                macro_rules! write_attrs {
-                    ($op:expr) => {{
-                        if let Some(Some(attrs)) =
-                            variant
-                                .body
-                                .annotations()
-                                .atom(concat!("variant-", $op, "-attributes"))
-                        {
-                            write!(out, "{} ", attrs);
-                        }
-                    }};
-                }
-
-                write_attrs!("constructor");
-                write!(out, "static {} {}(", self.export_name, variant.export_name);
-
-                if let VariantBody::Body { ref body, .. } = variant.body {
-                    let skip_fields = body.has_tag_field as usize;
-                    let vec: Vec<_> = body
-                        .fields
-                        .iter()
-                        .skip(skip_fields)
-                        .map(|field| {
-                            Field::from_name_and_type(
-                                // const-ref args to constructor
-                                arg_renamer(&field.name),
-                                Type::const_ref_to(&field.ty),
-                            )
-                        })
-                        .collect();
-                    out.write_vertical_source_list(&vec[..], ListType::Join(","));
-                }
-
-                write!(out, ")");
-                out.open_brace();
-
-                write!(out, "{} result;", self.export_name);
-
-                if let VariantBody::Body {
-                    name: ref variant_name,
-                    ref body,
-                    ..
-                } = variant.body
-                {
-                    let skip_fields = body.has_tag_field as usize;
-                    for field in body.fields.iter().skip(skip_fields) {
-                        out.new_line();
-                        match field.ty {
-                            Type::Array(ref ty, ref length) => {
-                                // arrays are not assignable in C++ so we
-                                // need to manually copy the elements
-                                write!(out, "for (int i = 0; i < {}; i++)", length.as_str());
-                                out.open_brace();
-                                write!(out, "::new (&result.{}.{}[i]) (", variant_name, field.name);
-                                ty.write(config, out);
-                                write!(out, ")({}[i]);", arg_renamer(&field.name));
-                                out.close_brace(false);
-                            }
-                            ref ty => {
-                                write!(out, "::new (&result.{}.{}) (", variant_name, field.name);
-                                ty.write(config, out);
-                                write!(out, ")({});", arg_renamer(&field.name));
-                            }
-                        }
-                    }
-                }
-
-                out.new_line();
-                write!(out, "result.tag = {}::{};", tag_name, variant.export_name);
-                out.new_line();
-                write!(out, "return result;");
-                out.close_brace(false);
-
-                out.new_line();
-                out.new_line();
-
-                write_attrs!("is");
-                // FIXME: create a config for method case
-                write!(out, "bool Is{}() const", variant.export_name);
-                out.open_brace();
-                write!(out, "return tag == {}::{};", tag_name, variant.export_name);
-                out.close_brace(false);
-
-                let assert_name = match config.enumeration.cast_assert_name {
-                    Some(ref n) => &**n,
-                    None => "assert",
-                };
-
-                let mut derive_casts = |const_casts: bool| {
-                    let (member_name, body, inline_casts) = match variant.body {
-                        VariantBody::Body {
-                            ref name,
-                            ref body,
-                            inline_casts,
-                            ..
-                        } => (name, body, inline_casts),
-                        VariantBody::Empty(..) => return,
-                    };
-
-                    let skip_fields = body.has_tag_field as usize;
-                    let field_count = body.fields.len() - skip_fields;
-                    if field_count == 0 {
-                        return;
-                    }
-
-                    out.new_line();
-                    out.new_line();
-
-                    if const_casts {
-                        write_attrs!("const-cast");
-                    } else {
-                        write_attrs!("mut-cast");
-                    }
-                    if inline_casts {
-                        let field = body.fields.last().unwrap();
-                        let return_type = field.ty.clone();
-                        let return_type = Type::Ptr {
-                            ty: Box::new(return_type),
-                            is_const: const_casts,
-                            is_ref: true,
-                            is_nullable: false,
-                        };
-                        return_type.write(config, out);
-                    } else if const_casts {
-                        write!(out, "const {}&", body.export_name());
-                    } else {
-                        write!(out, "{}&", body.export_name());
-                    }
-
-                    write!(out, " As{}()", variant.export_name);
-                    if const_casts {
-                        write!(out, " const");
-                    }
-                    out.open_brace();
-                    write!(out, "{}(Is{}());", assert_name, variant.export_name);
-                    out.new_line();
-                    write!(out, "return {}", member_name);
-                    if inline_casts {
-                        write!(out, "._0");
-                    }
-                    write!(out, ";");
-                    out.close_brace(false);
-                };
-
-                if config.enumeration.derive_const_casts(&self.annotations) {
-                    derive_casts(true)
-                }
-
-                if config.enumeration.derive_mut_casts(&self.annotations) {
-                    derive_casts(false)
-                }
-
-                condition.write_after(config, out);
-            }
-        }
-
-        let other = config
-            .function
-            .rename_args
-            .apply("other", IdentifierType::FunctionArg);
-
-        macro_rules! write_attrs {
-            ($op:expr) => {{
-                if let Some(Some(attrs)) = self.annotations.atom(concat!($op, "-attributes")) {
-                    write!(out, "{} ", attrs);
-                }
-            }};
-        }
-
-        if self.can_derive_eq() && config.structure.derive_eq(&self.annotations) {
-            out.new_line();
-            out.new_line();
-            write_attrs!("eq");
-            write!(
-                out,
-                "bool operator==(const {}& {}) const",
-                self.export_name, other
-            );
-            out.open_brace();
-            write!(out, "if (tag != {}.tag)", other);
-            out.open_brace();
-            write!(out, "return false;");
-            out.close_brace(false);
-            out.new_line();
-            write!(out, "switch (tag)");
-            out.open_brace();
-            let mut exhaustive = true;
-            for variant in &self.variants {
-                if let VariantBody::Body {
-                    name: ref variant_name,
-                    ..
-                } = variant.body
-                {
-                    let condition = variant.cfg.to_condition(config);
-                    condition.write_before(config, out);
-                    write!(
-                        out,
-                        "case {}::{}: return {} == {}.{};",
-                        self.tag.as_ref().unwrap(),
-                        variant.export_name,
-                        variant_name,
-                        other,
-                        variant_name
-                    );
-                    condition.write_after(config, out);
-                    out.new_line();
-                } else {
-                    exhaustive = false;
-                }
-            }
-            if !exhaustive {
-                write!(out, "default: break;");
-            }
-            out.close_brace(false);
-
-            out.new_line();
-            write!(out, "return true;");
-
-            out.close_brace(false);
-
-            if config.structure.derive_neq(&self.annotations) {
-                out.new_line();
-                out.new_line();
-                write_attrs!("neq");
-                write!(
-                    out,
-                    "bool operator!=(const {}& {}) const",
-                    self.export_name, other
-                );
-                out.open_brace();
-                write!(out, "return !(*this == {});", other);
-                out.close_brace(false);
-            }
-        }
-
-        if config
-            .enumeration
-            .private_default_tagged_enum_constructor(&self.annotations)
-        {
-            out.new_line();
-            out.new_line();
-            write!(out, "private:");
-            out.new_line();
-            write!(out, "{}()", self.export_name);
-            out.open_brace();
-            out.close_brace(false);
-            out.new_line();
-            write!(out, "public:");
-            out.new_line();
-        }
-
-        if config
-            .enumeration
-            .derive_tagged_enum_destructor(&self.annotations)
-        {
-            out.new_line();
-            out.new_line();
-            write_attrs!("destructor");
-            write!(out, "~{}()", self.export_name);
-            out.open_brace();
-            write!(out, "switch (tag)");
-            out.open_brace();
-            let mut exhaustive = true;
-            for variant in &self.variants {
-                if let VariantBody::Body {
-                    ref name, ref body, ..
-                } = variant.body
-                {
-                    let condition = variant.cfg.to_condition(config);
-                    condition.write_before(config, out);
-                    write!(
-                        out,
-                        "case {}::{}: {}.~{}(); break;",
-                        self.tag.as_ref().unwrap(),
-                        variant.export_name,
-                        name,
-                        body.export_name(),
-                    );
-                    condition.write_after(config, out);
-                    out.new_line();
-                } else {
-                    exhaustive = false;
-                }
-            }
-            if !exhaustive {
-                write!(out, "default: break;");
-            }
-            out.close_brace(false);
-            out.close_brace(false);
-        }
-
-        if config
-            .enumeration
-            .derive_tagged_enum_copy_constructor(&self.annotations)
-        {
-            out.new_line();
-            out.new_line();
-            write_attrs!("copy-constructor");
-            write!(
-                out,
-                "{}(const {}& {})",
-                self.export_name, self.export_name, other
-            );
-            out.new_line();
-            write!(out, " : tag({}.tag)", other);
-            out.open_brace();
-            write!(out, "switch (tag)");
-            out.open_brace();
-            let mut exhaustive = true;
-            for variant in &self.variants {
-                if let VariantBody::Body {
-                    ref name, ref body, ..
-                } = variant.body
-                {
-                    let condition = variant.cfg.to_condition(config);
-                    condition.write_before(config, out);
-                    write!(
-                        out,
-                        "case {}::{}: ::new (&{}) ({})({}.{}); break;",
-                        self.tag.as_ref().unwrap(),
-                        variant.export_name,
-                        name,
-                        body.export_name(),
-                        other,
-                        name,
-                    );
-                    condition.write_after(config, out);
-                    out.new_line();
-                } else {
-                    exhaustive = false;
-                }
-            }
-            if !exhaustive {
-                write!(out, "default: break;");
-            }
-            out.close_brace(false);
-            out.close_brace(false);
-
-            if config
-                .enumeration
-                .derive_tagged_enum_copy_assignment(&self.annotations)
-            {
-                out.new_line();
-                write_attrs!("copy-assignment");
-                write!(
-                    out,
-                    "{}& operator=(const {}& {})",
-                    self.export_name, self.export_name, other
-                );
-                out.open_brace();
-                write!(out, "if (this != &{})", other);
-                out.open_brace();
-                write!(out, "this->~{}();", self.export_name);
-                out.new_line();
-                write!(out, "new (this) {}({});", self.export_name, other);
-                out.close_brace(false);
-                out.new_line();
-                write!(out, "return *this;");
-                out.close_brace(false);
-            }
-        }
-    }
-
     pub fn simplify_standard_types(&mut self, config: &Config) {
         for variant in &mut self.variants {
             variant.simplify_standard_types(config);
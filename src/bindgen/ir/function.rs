@@ -3,17 +3,13 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::collections::HashMap;
-use std::io::Write;
 
 use syn::ext::IdentExt;
 
-use crate::bindgen::cdecl;
-use crate::bindgen::config::{Config, Language, Layout};
+use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
-use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericPath, Path, ToCondition, Type,
-};
+use crate::bindgen::ir::{AnnotationSet, Cfg, Documentation, GenericPath, Path, Type};
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
@@ -21,6 +17,66 @@ use crate::bindgen::reserved;
 use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
+/// The calling convention of a function or function pointer. The `-unwind`
+/// ABIs of Rust are the same as the ones without it as far as C is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Abi {
+    C,
+    Cdecl,
+    Stdcall,
+    Fastcall,
+    Vectorcall,
+    Thiscall,
+    Aapcs,
+    Win64,
+    Sysv64,
+    Efiapi,
+    System,
+}
+
+impl Abi {
+    /// The ABI of an `extern "name"`, if it's one C code can call.
+    pub fn maybe(name: &str) -> Option<Abi> {
+        Some(match name.strip_suffix("-unwind").unwrap_or(name) {
+            "C" => Abi::C,
+            "cdecl" => Abi::Cdecl,
+            "stdcall" => Abi::Stdcall,
+            "fastcall" => Abi::Fastcall,
+            "vectorcall" => Abi::Vectorcall,
+            "thiscall" => Abi::Thiscall,
+            "aapcs" => Abi::Aapcs,
+            "win64" => Abi::Win64,
+            "sysv64" => Abi::Sysv64,
+            // efiapi has no -unwind variant.
+            "efiapi" if name == "efiapi" => Abi::Efiapi,
+            "system" => Abi::System,
+            _ => return None,
+        })
+    }
+
+    pub fn to_repr_rust(self) -> &'static str {
+        match self {
+            Abi::C => "C",
+            Abi::Cdecl => "cdecl",
+            Abi::Stdcall => "stdcall",
+            Abi::Fastcall => "fastcall",
+            Abi::Vectorcall => "vectorcall",
+            Abi::Thiscall => "thiscall",
+            Abi::Aapcs => "aapcs",
+            Abi::Win64 => "win64",
+            Abi::Sysv64 => "sysv64",
+            Abi::Efiapi => "efiapi",
+            Abi::System => "system",
+        }
+    }
+}
+
+impl Default for Abi {
+    fn default() -> Abi {
+        Abi::C
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FunctionArgument {
     pub name: Option<String>,
@@ -36,6 +92,7 @@ pub struct Function {
     pub self_type_path: Option<Path>,
     pub ret: Type,
     pub args: Vec<FunctionArgument>,
+    pub abi: Abi,
     pub extern_decl: bool,
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
@@ -48,6 +105,7 @@ impl Function {
         path: Path,
         self_type_path: Option<&Path>,
         sig: &syn::Signature,
+        abi: Abi,
         extern_decl: bool,
         attrs: &[syn::Attribute],
         mod_cfg: Option<&Cfg>,
@@ -68,6 +126,7 @@ impl Function {
             self_type_path: self_type_path.cloned(),
             ret,
             args,
+            abi,
             extern_decl,
             cfg: Cfg::append(mod_cfg, Cfg::load(attrs)),
             annotations: AnnotationSet::load(attrs)?,
@@ -181,7 +240,7 @@ impl Function {
         for arg in &mut self.args {
             arg.ty.rename_for_config(config, &generic_params);
             if let Some(ref mut name) = arg.name {
-                reserved::escape(name);
+                reserved::escape(name, config.language);
             }
         }
 
@@ -220,99 +279,8 @@ impl Function {
 }
 
 impl Source for Function {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        fn write_1<W: Write>(func: &Function, config: &Config, out: &mut SourceWriter<W>) {
-            let prefix = config.function.prefix(&func.annotations);
-            let postfix = config.function.postfix(&func.annotations);
-
-            let condition = func.cfg.to_condition(config);
-            condition.write_before(config, out);
-
-            func.documentation.write(config, out);
-
-            if func.extern_decl {
-                out.write("extern ");
-            } else {
-                if let Some(ref prefix) = prefix {
-                    write!(out, "{} ", prefix);
-                }
-                if func.annotations.must_use(config) {
-                    if let Some(ref anno) = config.function.must_use {
-                        write!(out, "{} ", anno);
-                    }
-                }
-            }
-            cdecl::write_func(out, func, Layout::Horizontal, config);
-
-            if !func.extern_decl {
-                if let Some(ref postfix) = postfix {
-                    write!(out, " {}", postfix);
-                }
-            }
-
-            if let Some(ref swift_name_macro) = config.function.swift_name_macro {
-                if let Some(swift_name) = func.swift_name(config) {
-                    write!(out, " {}({})", swift_name_macro, swift_name);
-                }
-            }
-
-            out.write(";");
-
-            condition.write_after(config, out);
-        }
-
-        fn write_2<W: Write>(func: &Function, config: &Config, out: &mut SourceWriter<W>) {
-            let prefix = config.function.prefix(&func.annotations);
-            let postfix = config.function.postfix(&func.annotations);
-
-            let condition = func.cfg.to_condition(config);
-
-            condition.write_before(config, out);
-
-            func.documentation.write(config, out);
-
-            if func.extern_decl {
-                out.write("extern ");
-            } else {
-                if let Some(ref prefix) = prefix {
-                    write!(out, "{}", prefix);
-                    out.new_line();
-                }
-                if func.annotations.must_use(config) {
-                    if let Some(ref anno) = config.function.must_use {
-                        write!(out, "{}", anno);
-                        out.new_line();
-                    }
-                }
-            }
-            cdecl::write_func(out, func, Layout::Vertical, config);
-            if !func.extern_decl {
-                if let Some(ref postfix) = postfix {
-                    out.new_line();
-                    write!(out, "{}", postfix);
-                }
-            }
-
-            if let Some(ref swift_name_macro) = config.function.swift_name_macro {
-                if let Some(swift_name) = func.swift_name(config) {
-                    write!(out, " {}({})", swift_name_macro, swift_name);
-                }
-            }
-
-            out.write(";");
-
-            condition.write_after(config, out);
-        }
-
-        match config.function.args {
-            Layout::Horizontal => write_1(self, config, out),
-            Layout::Vertical => write_2(self, config, out),
-            Layout::Auto => {
-                if !out.try_write(|out| write_1(self, config, out), config.line_length) {
-                    write_2(self, config, out)
-                }
-            }
-        }
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
+        out.bindings().backend().write_function(config, out, self);
     }
 }
 
@@ -1,10 +1,8 @@
-use std::io::Write;
 use std::ops::Deref;
 
 use syn::ext::IdentExt;
 
-use crate::bindgen::cdecl;
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::Config;
 use crate::bindgen::declarationtyperesolver::{DeclarationType, DeclarationTypeResolver};
 use crate::bindgen::ir::{ConstExpr, Path, Type};
 use crate::bindgen::utilities::IterHelpers;
@@ -57,6 +55,10 @@ impl GenericParam {
     pub fn name(&self) -> &Path {
         &self.name
     }
+
+    pub fn ty(&self) -> &GenericParamType {
+        &self.ty
+    }
 }
 
 #[derive(Default, Debug, Clone)]
@@ -80,7 +82,7 @@ impl GenericParams {
         item_name: &str,
         arguments: &'out [GenericArgument],
     ) -> Vec<(&'out Path, &'out GenericArgument)> {
-        assert!(self.len() > 0, "{} is not generic", item_name);
+        assert!(!self.is_empty(), "{} is not generic", item_name);
         assert!(
             self.len() == arguments.len(),
             "{} has {} params but is being instantiated with {} values",
@@ -93,42 +95,6 @@ impl GenericParams {
             .zip(arguments.iter())
             .collect()
     }
-
-    fn write_internal<F: Write>(
-        &self,
-        config: &Config,
-        out: &mut SourceWriter<F>,
-        with_default: bool,
-    ) {
-        if !self.0.is_empty() && config.language == Language::Cxx {
-            out.write("template<");
-            for (i, item) in self.0.iter().enumerate() {
-                if i != 0 {
-                    out.write(", ");
-                }
-                match item.ty {
-                    GenericParamType::Type => {
-                        write!(out, "typename {}", item.name);
-                        if with_default {
-                            write!(out, " = void");
-                        }
-                    }
-                    GenericParamType::Const(ref ty) => {
-                        cdecl::write_field(out, ty, item.name.name(), config);
-                        if with_default {
-                            write!(out, " = 0");
-                        }
-                    }
-                }
-            }
-            out.write(">");
-            out.new_line();
-        }
-    }
-
-    pub fn write_with_default<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        self.write_internal(config, out, true);
-    }
 }
 
 impl Deref for GenericParams {
@@ -139,12 +105,6 @@ impl Deref for GenericParams {
     }
 }
 
-impl Source for GenericParams {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        self.write_internal(config, out, false);
-    }
-}
-
 /// A (non-lifetime) argument passed to a generic, either a type or a constant expression.
 ///
 /// Note: Both arguments in a type like `Array<T, N>` are represented as
@@ -186,7 +146,7 @@ impl GenericArgument {
 }
 
 impl Source for GenericArgument {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
         match *self {
             GenericArgument::Type(ref ty) => ty.write(config, out),
             GenericArgument::Const(ref expr) => expr.write(config, out),
@@ -213,6 +173,12 @@ impl GenericPath {
         }
     }
 
+    /// A path exported under another name, as if renamed for the config.
+    pub(crate) fn with_export_name(mut self, export_name: String) -> Self {
+        self.export_name = export_name;
+        self
+    }
+
     pub fn self_path() -> Self {
         Self::new(Path::new("Self"), vec![])
     }
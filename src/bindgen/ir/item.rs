@@ -9,8 +9,8 @@ use crate::bindgen::config::Config;
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, Constant, Enum, GenericArgument, OpaqueItem, Path, Static, Struct, Typedef,
-    Union,
+    AnnotationSet, Cfg, Constant, Enum, GenericArgument, OpaqueItem, Path, Static, Struct, Type,
+    Typedef, Union, VariantBody,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
@@ -60,6 +60,7 @@ pub enum ItemContainer {
 }
 
 impl ItemContainer {
+    #[allow(clippy::should_implement_trait)]
     pub fn deref(&self) -> &dyn Item {
         match *self {
             ItemContainer::Constant(ref x) => x,
@@ -71,6 +72,27 @@ impl ItemContainer {
             ItemContainer::Typedef(ref x) => x,
         }
     }
+
+    /// The types of the fields, variants or value of the item.
+    pub(crate) fn types(&self) -> Vec<&Type> {
+        match *self {
+            ItemContainer::Struct(ref x) => x.fields.iter().map(|field| &field.ty).collect(),
+            ItemContainer::Union(ref x) => x.fields.iter().map(|field| &field.ty).collect(),
+            ItemContainer::Enum(ref x) => x
+                .variants
+                .iter()
+                .flat_map(|variant| match variant.body {
+                    VariantBody::Body { ref body, .. } => &body.fields[..],
+                    VariantBody::Empty(..) => &[],
+                })
+                .map(|field| &field.ty)
+                .collect(),
+            ItemContainer::Typedef(ref x) => vec![&x.aliased],
+            ItemContainer::Constant(ref x) => vec![&x.ty],
+            ItemContainer::Static(ref x) => vec![&x.ty],
+            ItemContainer::OpaqueItem(..) => vec![],
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -5,6 +5,8 @@
 use syn::ext::IdentExt;
 
 use crate::bindgen::ir::ty::{IntKind, PrimitiveType};
+use crate::bindgen::ir::Cfg;
+use crate::bindgen::utilities;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ReprStyle {
@@ -26,6 +28,19 @@ pub struct ReprType {
 }
 
 impl ReprType {
+    /// The repr of an integer type, which the types of `#[repr(prim)]` are.
+    pub(crate) fn from_primitive(ty: &PrimitiveType) -> Option<ReprType> {
+        match *ty {
+            PrimitiveType::Integer {
+                kind:
+                    kind @ (IntKind::B8 | IntKind::B16 | IntKind::B32 | IntKind::B64 | IntKind::Size),
+                signed,
+                ..
+            } => Some(ReprType { kind, signed }),
+            _ => None,
+        }
+    }
+
     pub(crate) fn to_primitive(self) -> PrimitiveType {
         PrimitiveType::Integer {
             kind: self.kind,
@@ -38,9 +53,28 @@ impl ReprType {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ReprAlign {
     Packed,
+    /// `#[repr(packed(n))]` with `n` greater than one, which caps the
+    /// alignment of the fields at `n`.
+    PackedN(u64),
     Align(u64),
 }
 
+impl ReprAlign {
+    fn parse_packed_n(args: &[String]) -> Result<u64, String> {
+        let packed = match args {
+            [arg] => arg.parse::<u64>().ok(),
+            _ => None,
+        };
+        match packed {
+            Some(n) if n.is_power_of_two() => Ok(n),
+            _ => Err(format!(
+                "Invalid #[repr(packed({}))], packing must be a power of two.",
+                args.join(", ")
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 pub struct Repr {
     pub style: ReprStyle,
@@ -49,13 +83,58 @@ pub struct Repr {
 }
 
 impl Repr {
-    pub fn load(attrs: &[syn::Attribute]) -> Result<Repr, String> {
-        let ids = attrs
-            .iter()
-            .filter_map(|attr| {
-                if let syn::Meta::List(syn::MetaList { path, nested, .. }) =
-                    attr.parse_meta().ok()?
+    /// Loads the repr of an item for each predicate of the `#[cfg_attr]`s
+    /// around its `#[repr]`s, along with that predicate, as it only has their
+    /// modifiers when it holds, plus the repr it has when none do. Those which
+    /// don't export the item are left out, unless none does, e.g. for
+    /// `#[cfg_attr(windows, repr(C, packed))] #[cfg_attr(not(windows), repr(C))]`
+    /// these are a packed one for `windows` and another for `not(windows)`.
+    pub fn load(attrs: &[syn::Attribute]) -> Result<Vec<(Option<Cfg>, Repr)>, String> {
+        let mut predicates: Vec<Cfg> = Vec::new();
+        for (predicate, meta) in utilities::expand_attrs(attrs) {
+            if let Some(predicate) = predicate {
+                if meta.path().is_ident("repr")
+                    && !predicates
+                        .iter()
+                        .any(|x| x.to_string() == predicate.to_string())
                 {
+                    predicates.push(predicate);
+                }
+            }
+        }
+
+        let unconditional = Repr::load_under(attrs, None)?;
+        if predicates.is_empty() {
+            return Ok(vec![(None, unconditional)]);
+        }
+
+        let mut reprs = Vec::new();
+        for predicate in &predicates {
+            let repr = Repr::load_under(attrs, Some(predicate))?;
+            reprs.push((Some(predicate.clone()), repr));
+        }
+        let any = match predicates.len() {
+            1 => predicates.pop().unwrap(),
+            _ => Cfg::Any(predicates),
+        };
+        reprs.push((Some(Cfg::Not(Box::new(any))), unconditional));
+
+        reprs.retain(|(_, repr)| repr.style != ReprStyle::Rust || repr.ty.is_some());
+        if reprs.is_empty() {
+            reprs.push((None, unconditional));
+        }
+        Ok(reprs)
+    }
+
+    /// Loads the repr from the `#[repr]`s outside of any `#[cfg_attr]`, and
+    /// those under `predicate`.
+    fn load_under(attrs: &[syn::Attribute], predicate: Option<&Cfg>) -> Result<Repr, String> {
+        let predicate = predicate.map(ToString::to_string);
+        let ids = utilities::expand_attrs(attrs)
+            .into_iter()
+            .filter(|(cfg, _)| cfg.is_none() || cfg.as_ref().map(ToString::to_string) == predicate)
+            .filter_map(|(_, attr)| {
+                if let syn::Meta::List(syn::MetaList { path, nested, .. }) = attr {
                     if path.is_ident("repr") {
                         return Some(nested.into_iter().collect::<Vec<_>>());
                     }
@@ -75,7 +154,7 @@ impl Repr {
                             nested
                                 .iter()
                                 .filter_map(|meta| match meta {
-                                    // Only used for #[repr(align(...))].
+                                    // Only used for #[repr(align(...))] and #[repr(packed(...))].
                                     syn::NestedMeta::Lit(syn::Lit::Int(literal)) => {
                                         Some(literal.base10_digits().to_string())
                                     }
@@ -111,14 +190,14 @@ impl Repr {
                     continue;
                 }
                 ("packed", args) => {
-                    // #[repr(packed(n))] not supported because of some open questions about how
-                    // to calculate the native alignment of types. See eqrion/cbindgen#433.
-                    if args.is_some() {
-                        return Err(
-                            "Not-yet-implemented #[repr(packed(...))] encountered.".to_string()
-                        );
-                    }
-                    let align = ReprAlign::Packed;
+                    let align = match args {
+                        None => ReprAlign::Packed,
+                        Some(args) => match ReprAlign::parse_packed_n(&args)? {
+                            // #[repr(packed(1))] is the same as #[repr(packed)].
+                            1 => ReprAlign::Packed,
+                            n => ReprAlign::PackedN(n),
+                        },
+                    };
                     // Only permit a single alignment-setting repr.
                     if let Some(old_align) = repr.align {
                         return Err(format!(
@@ -2,17 +2,14 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::io::Write;
-
 use syn::ext::IdentExt;
 
 use crate::bindgen::config::{Config, Language, LayoutConfig};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, Field, GenericArgument,
-    GenericParams, Item, ItemContainer, Path, Repr, ReprAlign, ReprStyle, ToCondition, Type,
-    Typedef,
+    AnnotationSet, Cfg, ConstExpr, Constant, Documentation, Field, GenericArgument, GenericParams,
+    Item, ItemContainer, Path, Repr, ReprAlign, ReprStyle, Type, Typedef,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -20,7 +17,7 @@ use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::reserved;
 use crate::bindgen::utilities::IterHelpers;
-use crate::bindgen::writer::{ListType, Source, SourceWriter};
+use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
 pub struct Struct {
@@ -51,12 +48,27 @@ impl Struct {
         self.associated_constants.push(c);
     }
 
+    /// Loads a struct for each of the reprs of `item`, see `Repr::load`.
     pub fn load(
         layout_config: &LayoutConfig,
         item: &syn::ItemStruct,
         mod_cfg: Option<&Cfg>,
+    ) -> Result<Vec<Self>, String> {
+        Repr::load(&item.attrs)?
+            .into_iter()
+            .map(|(repr_cfg, repr)| {
+                Self::load_with_repr(layout_config, item, mod_cfg, repr_cfg, repr)
+            })
+            .collect()
+    }
+
+    fn load_with_repr(
+        layout_config: &LayoutConfig,
+        item: &syn::ItemStruct,
+        mod_cfg: Option<&Cfg>,
+        repr_cfg: Option<Cfg>,
+        repr: Repr,
     ) -> Result<Self, String> {
-        let repr = Repr::load(&item.attrs)?;
         let is_transparent = match repr.style {
             ReprStyle::C => false,
             ReprStyle::Transparent => true,
@@ -86,10 +98,12 @@ impl Struct {
                         ty.replace_self_with(&path);
                         out.push(Field {
                             name: format!("{}", current),
+                            rust_name: format!("{}", current),
                             ty,
                             cfg: Cfg::load(&field.attrs),
                             annotations: AnnotationSet::load(&field.attrs)?,
                             documentation: Documentation::load(&field.attrs),
+                            flexible_array: false,
                         });
                         current += 1;
                     }
@@ -109,7 +123,10 @@ impl Struct {
             is_enum_variant_body,
             repr.align,
             is_transparent,
-            Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
+            Cfg::append(
+                Cfg::append(mod_cfg, repr_cfg).as_ref(),
+                Cfg::load(&item.attrs),
+            ),
             AnnotationSet::load(&item.attrs)?,
             Documentation::load(&item.attrs),
         ))
@@ -152,7 +169,7 @@ impl Struct {
     }
 
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -187,10 +204,12 @@ impl Struct {
                 .iter()
                 .map(|field| Field {
                     name: field.name.clone(),
+                    rust_name: field.rust_name.clone(),
                     ty: field.ty.specialize(mappings),
                     cfg: field.cfg.clone(),
                     annotations: field.annotations.clone(),
                     documentation: field.documentation.clone(),
+                    flexible_array: field.flexible_array,
                 })
                 .collect(),
             self.has_tag_field,
@@ -203,47 +222,41 @@ impl Struct {
         )
     }
 
-    fn emit_bitflags_binop<F: Write>(
-        &self,
-        constexpr_prefix: &str,
-        operator: char,
-        other: &str,
-        out: &mut SourceWriter<F>,
-    ) {
-        out.new_line();
-        write!(
-            out,
-            "{}{} operator{}(const {}& {}) const",
-            constexpr_prefix,
-            self.export_name(),
-            operator,
-            self.export_name(),
-            other
-        );
-        out.open_brace();
-        write!(
-            out,
-            "return {} {{ static_cast<decltype(bits)>(this->bits {} {}.bits) }};",
-            self.export_name(),
-            operator,
-            other
-        );
-        out.close_brace(false);
-
-        out.new_line();
-        write!(
-            out,
-            "{}& operator{}=(const {}& {})",
-            self.export_name(),
-            operator,
-            self.export_name(),
-            other
-        );
-        out.open_brace();
-        write!(out, "*this = (*this {} {});", operator, other);
-        out.new_line();
-        write!(out, "return *this;");
-        out.close_brace(false);
+    /// Marks a trailing zero-length array as a flexible array member, and
+    /// warns about the zero-length arrays which can't be one.
+    fn mark_flexible_array(&mut self) {
+        let count = self.fields.len();
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            if !matches!(field.ty, Type::Array(_, ConstExpr::Value(ref len)) if len == "0") {
+                continue;
+            }
+            if i != count - 1 {
+                warn!(
+                    "Can't write field `{}` of `{}` as a flexible array member, it isn't the last field.",
+                    field.name, self.export_name
+                );
+            } else if count == 1 {
+                warn!(
+                    "Can't write field `{}` of `{}` as a flexible array member, it's the only field.",
+                    field.name, self.export_name
+                );
+            } else {
+                field.flexible_array = true;
+            }
+        }
+    }
+
+    /// The typedef a transparent struct is written as.
+    pub fn as_typedef(&self) -> Typedef {
+        Typedef {
+            path: self.path.clone(),
+            export_name: self.export_name.to_owned(),
+            generic_params: self.generic_params.clone(),
+            aliased: self.fields[0].ty.clone(),
+            cfg: self.cfg.clone(),
+            annotations: self.annotations.clone(),
+            documentation: self.documentation.clone(),
+        }
     }
 }
 
@@ -338,12 +351,16 @@ impl Item for Struct {
         }
 
         for field in &mut self.fields {
-            reserved::escape(&mut field.name);
+            reserved::escape(&mut field.name, config.language);
         }
 
         for c in self.associated_constants.iter_mut() {
             c.rename_for_config(config);
         }
+
+        if !self.is_transparent && config.structure.flexible_array(&self.annotations) {
+            self.mark_flexible_array();
+        }
     }
 
     fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
@@ -378,333 +395,7 @@ impl Item for Struct {
 }
 
 impl Source for Struct {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        if self.is_transparent {
-            let typedef = Typedef {
-                path: self.path.clone(),
-                export_name: self.export_name.to_owned(),
-                generic_params: self.generic_params.clone(),
-                aliased: self.fields[0].ty.clone(),
-                cfg: self.cfg.clone(),
-                annotations: self.annotations.clone(),
-                documentation: self.documentation.clone(),
-            };
-            typedef.write(config, out);
-            for constant in &self.associated_constants {
-                out.new_line();
-                constant.write(config, out, Some(self));
-            }
-            return;
-        }
-
-        let condition = self.cfg.to_condition(config);
-        condition.write_before(config, out);
-
-        self.documentation.write(config, out);
-
-        if !self.is_enum_variant_body {
-            self.generic_params.write(config, out);
-        }
-
-        // The following results in
-        // C++ or C with Tag as style:
-        //   struct Name {
-        // C with Type only style:
-        //   typedef struct {
-        // C with Both as style:
-        //   typedef struct Name {
-        match config.language {
-            Language::C if config.style.generate_typedef() => out.write("typedef "),
-            Language::C | Language::Cxx => {}
-            Language::Cython => out.write(config.style.cython_def()),
-        }
-
-        // Cython extern declarations don't manage layouts, layouts are defined entierly by the
-        // corresponding C code. So this `packed` is only for documentation, and missing
-        // `aligned(n)` is also not a problem.
-        if config.language == Language::Cython {
-            if let Some(align) = self.alignment {
-                match align {
-                    ReprAlign::Packed => out.write("packed "),
-                    ReprAlign::Align(_) => {} // Not supported
-                }
-            }
-        }
-
-        out.write("struct");
-
-        if config.language != Language::Cython {
-            if let Some(align) = self.alignment {
-                match align {
-                    ReprAlign::Packed => {
-                        if let Some(ref anno) = config.layout.packed {
-                            write!(out, " {}", anno);
-                        }
-                    }
-                    ReprAlign::Align(n) => {
-                        if let Some(ref anno) = config.layout.aligned_n {
-                            write!(out, " {}({})", anno, n);
-                        }
-                    }
-                }
-            }
-        }
-
-        if self.annotations.must_use(config) {
-            if let Some(ref anno) = config.structure.must_use {
-                write!(out, " {}", anno);
-            }
-        }
-
-        if config.language != Language::C || config.style.generate_tag() {
-            write!(out, " {}", self.export_name());
-        }
-
-        out.open_brace();
-
-        // Emit the pre_body section, if relevant
-        if let Some(body) = config.export.pre_body(&self.path) {
-            out.write_raw_block(body);
-            out.new_line();
-        }
-
-        out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
-        if config.language == Language::Cython && self.fields.is_empty() {
-            out.write("pass");
-        }
-
-        if config.language == Language::Cxx {
-            let mut wrote_start_newline = false;
-
-            if config.structure.derive_constructor(&self.annotations) && !self.fields.is_empty() {
-                if !wrote_start_newline {
-                    wrote_start_newline = true;
-                    out.new_line();
-                }
-
-                out.new_line();
-
-                let arg_renamer = |name: &str| {
-                    config
-                        .function
-                        .rename_args
-                        .apply(name, IdentifierType::FunctionArg)
-                        .into_owned()
-                };
-                write!(out, "{}(", self.export_name());
-                let vec: Vec<_> = self
-                    .fields
-                    .iter()
-                    .map(|field| {
-                        Field::from_name_and_type(
-                            // const-ref args to constructor
-                            format!("const& {}", arg_renamer(&field.name)),
-                            field.ty.clone(),
-                        )
-                    })
-                    .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(","));
-                write!(out, ")");
-                out.new_line();
-                write!(out, "  : ");
-                let vec: Vec<_> = self
-                    .fields
-                    .iter()
-                    .map(|field| format!("{}({})", field.name, arg_renamer(&field.name)))
-                    .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(","));
-                out.new_line();
-                write!(out, "{{}}");
-                out.new_line();
-            }
-
-            let other = config
-                .function
-                .rename_args
-                .apply("other", IdentifierType::FunctionArg);
-
-            if self
-                .annotations
-                .bool("internal-derive-bitflags")
-                .unwrap_or(false)
-            {
-                if !wrote_start_newline {
-                    wrote_start_newline = true;
-                    out.new_line();
-                }
-                let constexpr_prefix = if config.constant.allow_constexpr {
-                    "constexpr "
-                } else {
-                    ""
-                };
-
-                out.new_line();
-                write!(out, "{}explicit operator bool() const", constexpr_prefix);
-                out.open_brace();
-                write!(out, "return !!bits;");
-                out.close_brace(false);
-
-                out.new_line();
-                write!(
-                    out,
-                    "{}{} operator~() const",
-                    constexpr_prefix,
-                    self.export_name()
-                );
-                out.open_brace();
-                write!(
-                    out,
-                    "return {} {{ static_cast<decltype(bits)>(~bits) }};",
-                    self.export_name()
-                );
-                out.close_brace(false);
-                self.emit_bitflags_binop(constexpr_prefix, '|', &other, out);
-                self.emit_bitflags_binop(constexpr_prefix, '&', &other, out);
-                self.emit_bitflags_binop(constexpr_prefix, '^', &other, out);
-            }
-
-            // Generate a serializer function that allows dumping this struct
-            // to an std::ostream. It's defined as a friend function inside the
-            // struct definition, and doesn't need the `inline` keyword even
-            // though it's implemented right in the generated header file.
-            if config.structure.derive_ostream(&self.annotations) {
-                if !wrote_start_newline {
-                    wrote_start_newline = true;
-                    out.new_line();
-                }
-
-                out.new_line();
-                let stream = config
-                    .function
-                    .rename_args
-                    .apply("stream", IdentifierType::FunctionArg);
-                let instance = config
-                    .function
-                    .rename_args
-                    .apply("instance", IdentifierType::FunctionArg);
-                write!(
-                    out,
-                    "friend std::ostream& operator<<(std::ostream& {}, const {}& {})",
-                    stream,
-                    self.export_name(),
-                    instance,
-                );
-                out.open_brace();
-                write!(out, "return {} << \"{{ \"", stream);
-                let vec: Vec<_> = self
-                    .fields
-                    .iter()
-                    .map(|x| format!(" << \"{}=\" << {}.{}", x.name, instance, x.name))
-                    .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(" << \", \""));
-                out.write(" << \" }\";");
-                out.close_brace(false);
-            }
-
-            let skip_fields = self.has_tag_field as usize;
-
-            macro_rules! emit_op {
-                ($op_name:expr, $op:expr, $conjuc:expr) => {{
-                    if !wrote_start_newline {
-                        #[allow(unused_assignments)]
-                        {
-                            wrote_start_newline = true;
-                        }
-                        out.new_line();
-                    }
-
-                    out.new_line();
-
-                    if let Some(Some(attrs)) =
-                        self.annotations.atom(concat!($op_name, "-attributes"))
-                    {
-                        write!(out, "{} ", attrs);
-                    }
-
-                    write!(
-                        out,
-                        "bool operator{}(const {}& {}) const",
-                        $op,
-                        self.export_name(),
-                        other
-                    );
-                    out.open_brace();
-                    out.write("return ");
-                    let vec: Vec<_> = self
-                        .fields
-                        .iter()
-                        .skip(skip_fields)
-                        .map(|field| format!("{} {} {}.{}", field.name, $op, other, field.name))
-                        .collect();
-                    out.write_vertical_source_list(
-                        &vec[..],
-                        ListType::Join(&format!(" {}", $conjuc)),
-                    );
-                    out.write(";");
-                    out.close_brace(false);
-                }};
-            }
-
-            if config.structure.derive_eq(&self.annotations) && self.can_derive_eq() {
-                emit_op!("eq", "==", "&&");
-            }
-            if config.structure.derive_neq(&self.annotations) && self.can_derive_eq() {
-                emit_op!("neq", "!=", "||");
-            }
-            if config.structure.derive_lt(&self.annotations)
-                && self.fields.len() == 1
-                && self.fields[0].ty.can_cmp_order()
-            {
-                emit_op!("lt", "<", "&&");
-            }
-            if config.structure.derive_lte(&self.annotations)
-                && self.fields.len() == 1
-                && self.fields[0].ty.can_cmp_order()
-            {
-                emit_op!("lte", "<=", "&&");
-            }
-            if config.structure.derive_gt(&self.annotations)
-                && self.fields.len() == 1
-                && self.fields[0].ty.can_cmp_order()
-            {
-                emit_op!("gt", ">", "&&");
-            }
-            if config.structure.derive_gte(&self.annotations)
-                && self.fields.len() == 1
-                && self.fields[0].ty.can_cmp_order()
-            {
-                emit_op!("gte", ">=", "&&");
-            }
-        }
-
-        // Emit the post_body section, if relevant
-        if let Some(body) = config.export.post_body(&self.path) {
-            out.new_line();
-            out.write_raw_block(body);
-        }
-
-        if config.language == Language::Cxx
-            && config.structure.associated_constants_in_body
-            && config.constant.allow_static_const
-        {
-            for constant in &self.associated_constants {
-                out.new_line();
-                constant.write_declaration(config, out, self);
-            }
-        }
-
-        if config.language == Language::C && config.style.generate_typedef() {
-            out.close_brace(false);
-            write!(out, " {};", self.export_name());
-        } else {
-            out.close_brace(true);
-        }
-
-        for constant in &self.associated_constants {
-            out.new_line();
-            constant.write(config, out, Some(self));
-        }
-
-        condition.write_after(config, out);
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
+        out.bindings().backend().write_struct(config, out, self);
     }
 }
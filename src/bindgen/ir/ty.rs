@@ -3,18 +3,17 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::borrow::Cow;
-use std::io::Write;
+use std::collections::BTreeSet;
 
 use syn::ext::IdentExt;
 
-use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
-use crate::bindgen::ir::{GenericArgument, GenericParams, GenericPath, Path};
+use crate::bindgen::ir::{Abi, GenericArgument, GenericParams, GenericPath, Path};
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
-use crate::bindgen::utilities::IterHelpers;
+use crate::bindgen::utilities::{IterHelpers, SynAbiHelpers};
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -27,6 +26,8 @@ pub enum PrimitiveType {
     Char32,
     Float,
     Double,
+    Float16,
+    Float128,
     VaList,
     PtrDiffT,
     Integer {
@@ -48,6 +49,7 @@ pub enum IntKind {
     B16,
     B32,
     B64,
+    B128,
 }
 
 impl PrimitiveType {
@@ -64,8 +66,10 @@ impl PrimitiveType {
             "bool" => PrimitiveType::Bool,
             "char" => PrimitiveType::Char32,
 
+            "f16" => PrimitiveType::Float16,
             "f32" => PrimitiveType::Float,
             "f64" => PrimitiveType::Double,
+            "f128" => PrimitiveType::Float128,
 
             _ => {
                 let (kind, signed) = match path {
@@ -88,10 +92,12 @@ impl PrimitiveType {
                     "u16" | "uint16_t" => (IntKind::B16, false),
                     "u32" | "uint32_t" => (IntKind::B32, false),
                     "u64" | "uint64_t" => (IntKind::B64, false),
+                    "u128" => (IntKind::B128, false),
                     "i8" | "int8_t" => (IntKind::B8, true),
                     "i16" | "int16_t" => (IntKind::B16, true),
                     "i32" | "int32_t" => (IntKind::B32, true),
                     "i64" | "int64_t" => (IntKind::B64, true),
+                    "i128" => (IntKind::B128, true),
                     _ => return None,
                 };
                 PrimitiveType::Integer {
@@ -186,15 +192,24 @@ impl PrimitiveType {
                         "u64"
                     }
                 }
+                IntKind::B128 => {
+                    if signed {
+                        "i128"
+                    } else {
+                        "u128"
+                    }
+                }
             },
             PrimitiveType::Float => "f32",
             PrimitiveType::Double => "f64",
+            PrimitiveType::Float16 => "f16",
+            PrimitiveType::Float128 => "f128",
             PrimitiveType::PtrDiffT => "ptrdiff_t",
             PrimitiveType::VaList => "va_list",
         }
     }
 
-    pub fn to_repr_c(&self, config: &Config) -> &'static str {
+    pub fn to_repr_c<'a>(&self, config: &'a Config) -> &'a str {
         match *self {
             PrimitiveType::Void => "void",
             PrimitiveType::Bool => "bool",
@@ -291,14 +306,237 @@ impl PrimitiveType {
                         "uint64_t"
                     }
                 }
+                IntKind::B128 => {
+                    if signed {
+                        &config.primitive.i128
+                    } else {
+                        &config.primitive.u128
+                    }
+                }
             },
             PrimitiveType::Float => "float",
             PrimitiveType::Double => "double",
+            PrimitiveType::Float16 => &config.primitive.f16,
+            PrimitiveType::Float128 => &config.primitive.f128,
             PrimitiveType::PtrDiffT => "ptrdiff_t",
             PrimitiveType::VaList => "va_list",
         }
     }
 
+    /// Cython only knows about the extended primitives through the
+    /// `ctypedef`s the Cython backend declares, named after the Rust ones.
+    pub fn to_repr_cython<'a>(&self, config: &'a Config) -> &'a str {
+        match *self {
+            PrimitiveType::Float16
+            | PrimitiveType::Float128
+            | PrimitiveType::Integer {
+                kind: IntKind::B128,
+                ..
+            } => self.to_repr_rust(),
+            _ => self.to_repr_c(config),
+        }
+    }
+
+    pub fn to_repr_zig(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "void",
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "c_char",
+            PrimitiveType::SChar => "i8",
+            PrimitiveType::UChar => "u8",
+            // See the note about `char32_t` in `to_repr_c`.
+            PrimitiveType::Char32 => "u32",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match kind {
+                IntKind::Short => {
+                    if signed {
+                        "c_short"
+                    } else {
+                        "c_ushort"
+                    }
+                }
+                IntKind::Int => {
+                    if signed {
+                        "c_int"
+                    } else {
+                        "c_uint"
+                    }
+                }
+                IntKind::Long => {
+                    if signed {
+                        "c_long"
+                    } else {
+                        "c_ulong"
+                    }
+                }
+                IntKind::LongLong => {
+                    if signed {
+                        "c_longlong"
+                    } else {
+                        "c_ulonglong"
+                    }
+                }
+                IntKind::SizeT | IntKind::Size => {
+                    if signed {
+                        "isize"
+                    } else {
+                        "usize"
+                    }
+                }
+                _ => self.to_repr_rust(),
+            },
+            PrimitiveType::Float => "f32",
+            PrimitiveType::Double => "f64",
+            PrimitiveType::Float16 => "f16",
+            PrimitiveType::Float128 => "f128",
+            PrimitiveType::PtrDiffT => "isize",
+            PrimitiveType::VaList => "std.builtin.VaList",
+        }
+    }
+
+    pub fn to_repr_python(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "None",
+            PrimitiveType::Bool => "ctypes.c_bool",
+            PrimitiveType::Char => "ctypes.c_char",
+            PrimitiveType::SChar => "ctypes.c_byte",
+            PrimitiveType::UChar => "ctypes.c_ubyte",
+            // See the note about `char32_t` in `to_repr_c`, `c_wchar` isn't
+            // 32 bits everywhere either.
+            PrimitiveType::Char32 => "ctypes.c_uint32",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match kind {
+                IntKind::Short => {
+                    if signed {
+                        "ctypes.c_short"
+                    } else {
+                        "ctypes.c_ushort"
+                    }
+                }
+                IntKind::Int => {
+                    if signed {
+                        "ctypes.c_int"
+                    } else {
+                        "ctypes.c_uint"
+                    }
+                }
+                IntKind::Long => {
+                    if signed {
+                        "ctypes.c_long"
+                    } else {
+                        "ctypes.c_ulong"
+                    }
+                }
+                IntKind::LongLong => {
+                    if signed {
+                        "ctypes.c_longlong"
+                    } else {
+                        "ctypes.c_ulonglong"
+                    }
+                }
+                IntKind::SizeT | IntKind::Size => {
+                    if signed {
+                        "ctypes.c_ssize_t"
+                    } else {
+                        "ctypes.c_size_t"
+                    }
+                }
+                IntKind::B8 => {
+                    if signed {
+                        "ctypes.c_int8"
+                    } else {
+                        "ctypes.c_uint8"
+                    }
+                }
+                IntKind::B16 => {
+                    if signed {
+                        "ctypes.c_int16"
+                    } else {
+                        "ctypes.c_uint16"
+                    }
+                }
+                IntKind::B32 => {
+                    if signed {
+                        "ctypes.c_int32"
+                    } else {
+                        "ctypes.c_uint32"
+                    }
+                }
+                IntKind::B64 => {
+                    if signed {
+                        "ctypes.c_int64"
+                    } else {
+                        "ctypes.c_uint64"
+                    }
+                }
+                // ctypes has no 128-bit integers, so the items using these
+                // are left out, see `PythonLanguageBackend::supports_primitive`.
+                IntKind::B128 => {
+                    if signed {
+                        "(ctypes.c_int64 * 2)"
+                    } else {
+                        "(ctypes.c_uint64 * 2)"
+                    }
+                }
+            },
+            PrimitiveType::Float => "ctypes.c_float",
+            PrimitiveType::Double => "ctypes.c_double",
+            // Nor half or quad floats.
+            PrimitiveType::Float16 => "ctypes.c_uint16",
+            PrimitiveType::Float128 => "(ctypes.c_uint64 * 2)",
+            PrimitiveType::PtrDiffT => "ctypes.c_ssize_t",
+            // There's no portable way to build a `va_list` from Python.
+            PrimitiveType::VaList => "ctypes.c_void_p",
+        }
+    }
+
+    pub fn to_repr_csharp(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "void",
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "byte",
+            PrimitiveType::SChar => "sbyte",
+            PrimitiveType::UChar => "byte",
+            // C#'s `char` is a UTF-16 code unit.
+            PrimitiveType::Char32 => "uint",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match (kind, signed) {
+                (IntKind::Short, true) | (IntKind::B16, true) => "short",
+                (IntKind::Short, false) | (IntKind::B16, false) => "ushort",
+                (IntKind::Int, true) | (IntKind::B32, true) => "int",
+                (IntKind::Int, false) | (IntKind::B32, false) => "uint",
+                // `long` is 32 bits on Windows, and 64 bits elsewhere.
+                (IntKind::Long, true) => "CLong",
+                (IntKind::Long, false) => "CULong",
+                (IntKind::LongLong, true) | (IntKind::B64, true) => "long",
+                (IntKind::LongLong, false) | (IntKind::B64, false) => "ulong",
+                (IntKind::SizeT, true) | (IntKind::Size, true) => "nint",
+                (IntKind::SizeT, false) | (IntKind::Size, false) => "nuint",
+                (IntKind::B8, true) => "sbyte",
+                (IntKind::B8, false) => "byte",
+                (IntKind::B128, true) => "Int128",
+                (IntKind::B128, false) => "UInt128",
+            },
+            PrimitiveType::Float => "float",
+            PrimitiveType::Double => "double",
+            PrimitiveType::Float16 => "Half",
+            // There's no quad float in .NET, so the items using it are left
+            // out, see `CSharpLanguageBackend::supports_primitive`.
+            PrimitiveType::Float128 => "UInt128",
+            PrimitiveType::PtrDiffT => "nint",
+            PrimitiveType::VaList => "IntPtr",
+        }
+    }
+
     fn can_cmp_order(&self) -> bool {
         !matches!(*self, PrimitiveType::Bool)
     }
@@ -381,7 +619,7 @@ impl ConstExpr {
 }
 
 impl Source for ConstExpr {
-    fn write<F: Write>(&self, _config: &Config, out: &mut SourceWriter<F>) {
+    fn write(&self, _config: &Config, out: &mut SourceWriter) {
         write!(out, "{}", self.as_str());
     }
 }
@@ -403,9 +641,13 @@ pub enum Type {
     FuncPtr {
         ret: Box<Type>,
         args: Vec<(Option<String>, Type)>,
+        abi: Abi,
         is_nullable: bool,
         never_return: bool,
     },
+    /// One of the `core::sync::atomic` types, in the languages that can
+    /// express them. The others use the underlying integer or pointer.
+    Atomic(Box<Type>),
 }
 
 impl Type {
@@ -525,6 +767,8 @@ impl Type {
                 Type::FuncPtr {
                     ret: Box::new(ret),
                     args,
+                    // Non-`extern` function pointers have always been treated as C ones.
+                    abi: function.abi.abi().unwrap_or_default(),
                     is_nullable: false,
                     never_return,
                 }
@@ -548,6 +792,79 @@ impl Type {
         matches!(*self, Type::Ptr { .. } | Type::FuncPtr { .. })
     }
 
+    /// Whether this is or refers to an atomic type.
+    pub fn has_atomic(&self) -> bool {
+        match *self {
+            Type::Atomic(..) => true,
+            Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => ty.has_atomic(),
+            Type::Path(ref path) => path.generics().iter().any(|generic| match *generic {
+                GenericArgument::Type(ref ty) => ty.has_atomic(),
+                GenericArgument::Const(_) => false,
+            }),
+            Type::Primitive(..) => false,
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => ret.has_atomic() || args.iter().any(|(_, ty)| ty.has_atomic()),
+        }
+    }
+
+    /// Adds the calling conventions of the function pointers in this type to `out`.
+    pub fn add_abis(&self, out: &mut BTreeSet<Abi>) {
+        match *self {
+            Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) | Type::Atomic(ref ty) => {
+                ty.add_abis(out)
+            }
+            Type::Path(ref path) => {
+                for generic in path.generics() {
+                    if let GenericArgument::Type(ref ty) = *generic {
+                        ty.add_abis(out);
+                    }
+                }
+            }
+            Type::Primitive(..) => {}
+            Type::FuncPtr {
+                ref ret,
+                ref args,
+                abi,
+                ..
+            } => {
+                out.insert(abi);
+                ret.add_abis(out);
+                for (_, ty) in args {
+                    ty.add_abis(out);
+                }
+            }
+        }
+    }
+
+    /// Adds the primitives this type is made of to `out`, including those
+    /// behind pointers and in function pointers.
+    pub fn add_primitives(&self, out: &mut BTreeSet<PrimitiveType>) {
+        match *self {
+            Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) | Type::Atomic(ref ty) => {
+                ty.add_primitives(out)
+            }
+            Type::Path(ref path) => {
+                for generic in path.generics() {
+                    if let GenericArgument::Type(ref ty) = *generic {
+                        ty.add_primitives(out);
+                    }
+                }
+            }
+            Type::Primitive(ref primitive) => {
+                out.insert(primitive.clone());
+            }
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => {
+                ret.add_primitives(out);
+                for (_, ty) in args {
+                    ty.add_primitives(out);
+                }
+            }
+        }
+    }
+
     pub fn is_primitive_or_ptr_primitive(&self) -> bool {
         match *self {
             Type::Primitive(..) => true,
@@ -589,11 +906,13 @@ impl Type {
             Type::FuncPtr {
                 ref ret,
                 ref args,
+                abi,
                 is_nullable: false,
                 never_return,
             } => Some(Type::FuncPtr {
                 ret: ret.clone(),
                 args: args.clone(),
+                abi,
                 is_nullable: true,
                 never_return,
             }),
@@ -637,7 +956,44 @@ impl Type {
         }))
     }
 
+    /// The type that `AtomicBool`, `AtomicU32`, `AtomicPtr<T>` and friends
+    /// wrap, if `path` is one of them.
+    fn atomic_inner_type(path: &GenericPath) -> Option<Type> {
+        let name = path.name().strip_prefix("Atomic")?;
+        match path.generics() {
+            [] => match name {
+                "Bool" | "I8" | "I16" | "I32" | "I64" | "Isize" | "U8" | "U16" | "U32" | "U64"
+                | "Usize" => PrimitiveType::maybe(&name.to_lowercase()).map(Type::Primitive),
+                _ => None,
+            },
+            [GenericArgument::Type(ref ty)] if name == "Ptr" => Some(Type::Ptr {
+                ty: Box::new(ty.clone()),
+                is_const: false,
+                is_nullable: true,
+                is_ref: false,
+            }),
+            _ => None,
+        }
+    }
+
+    fn atomic_to_type(&self, config: &Config) -> Option<Self> {
+        let path = match *self {
+            Type::Path(ref p) => p,
+            _ => return None,
+        };
+        let inner = Type::atomic_inner_type(path)?;
+        Some(match config.language {
+            Language::Cxx => Type::Atomic(Box::new(inner)),
+            Language::C if config.c11_atomics => Type::Atomic(Box::new(inner)),
+            _ => inner,
+        })
+    }
+
     fn simplified_type(&self, config: &Config) -> Option<Self> {
+        if let Some(atomic) = self.atomic_to_type(config) {
+            return Some(atomic);
+        }
+
         let path = match *self {
             Type::Path(ref p) => p,
             _ => return None,
@@ -682,8 +1038,7 @@ impl Type {
                 is_nullable: false,
                 is_ref: false,
             }),
-            "Cell" => Some(generic.into_owned()),
-            "ManuallyDrop" | "MaybeUninit" | "Pin" if config.language != Language::Cxx => {
+            name if config.transparent_wrappers.iter().any(|x| x == name) => {
                 Some(generic.into_owned())
             }
             _ => None,
@@ -691,6 +1046,22 @@ impl Type {
     }
 
     pub fn simplify_standard_types(&mut self, config: &Config) {
+        // Atomics can be modified through shared references, so pointers to
+        // them are never `const`.
+        if let Type::Ptr {
+            ref ty,
+            ref mut is_const,
+            ..
+        } = *self
+        {
+            match **ty {
+                Type::Path(ref path) if Type::atomic_inner_type(path).is_some() => {
+                    *is_const = false
+                }
+                Type::Atomic(..) => *is_const = false,
+                _ => {}
+            }
+        }
         self.visit_types(|ty| ty.simplify_standard_types(config));
         if let Some(ty) = self.simplified_type(config) {
             *self = ty;
@@ -706,7 +1077,9 @@ impl Type {
 
     fn visit_types(&mut self, mut visitor: impl FnMut(&mut Type)) {
         match *self {
-            Type::Array(ref mut ty, ..) | Type::Ptr { ref mut ty, .. } => visitor(ty),
+            Type::Array(ref mut ty, ..)
+            | Type::Ptr { ref mut ty, .. }
+            | Type::Atomic(ref mut ty) => visitor(ty),
             Type::Path(ref mut path) => {
                 for generic in path.generics_mut() {
                     match *generic {
@@ -746,6 +1119,9 @@ impl Type {
                 Type::FuncPtr { .. } => {
                     return None;
                 }
+                Type::Atomic(..) => {
+                    return None;
+                }
             };
         }
     }
@@ -790,6 +1166,7 @@ impl Type {
             Type::FuncPtr {
                 ref ret,
                 ref args,
+                abi,
                 is_nullable,
                 never_return,
             } => Type::FuncPtr {
@@ -799,9 +1176,11 @@ impl Type {
                     .cloned()
                     .map(|(name, ty)| (name, ty.specialize(mappings)))
                     .collect(),
+                abi,
                 is_nullable,
                 never_return,
             },
+            Type::Atomic(ref ty) => Type::Atomic(Box::new(ty.specialize(mappings))),
         }
     }
 
@@ -812,7 +1191,16 @@ impl Type {
         out: &mut Dependencies,
     ) {
         match *self {
-            Type::Ptr { ref ty, .. } => {
+            Type::Ptr { ref ty, .. } if !out.follow_pointers => match **ty {
+                Type::Path(ref generic) if generic.generics().is_empty() => {
+                    let path = generic.path();
+                    if !generic_params.iter().any(|param| param.name() == path) {
+                        out.pointees.insert(path.clone());
+                    }
+                }
+                _ => ty.add_dependencies_ignoring_generics(generic_params, library, out),
+            },
+            Type::Ptr { ref ty, .. } | Type::Atomic(ref ty) => {
                 ty.add_dependencies_ignoring_generics(generic_params, library, out);
             }
             Type::Path(ref generic) => {
@@ -864,7 +1252,7 @@ impl Type {
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
         match *self {
-            Type::Ptr { ref ty, .. } => {
+            Type::Ptr { ref ty, .. } | Type::Atomic(ref ty) => {
                 ty.add_monomorphs(library, out);
             }
             Type::Path(ref generic) => {
@@ -896,7 +1284,7 @@ impl Type {
 
     pub fn rename_for_config(&mut self, config: &Config, generic_params: &GenericParams) {
         match *self {
-            Type::Ptr { ref mut ty, .. } => {
+            Type::Ptr { ref mut ty, .. } | Type::Atomic(ref mut ty) => {
                 ty.rename_for_config(config, generic_params);
             }
             Type::Path(ref mut ty) => {
@@ -922,7 +1310,7 @@ impl Type {
 
     pub fn resolve_declaration_types(&mut self, resolver: &DeclarationTypeResolver) {
         match *self {
-            Type::Ptr { ref mut ty, .. } => {
+            Type::Ptr { ref mut ty, .. } | Type::Atomic(ref mut ty) => {
                 ty.resolve_declaration_types(resolver);
             }
             Type::Path(ref mut generic_path) => {
@@ -947,7 +1335,7 @@ impl Type {
 
     pub fn mangle_paths(&mut self, monomorphs: &Monomorphs) {
         match *self {
-            Type::Ptr { ref mut ty, .. } => {
+            Type::Ptr { ref mut ty, .. } | Type::Atomic(ref mut ty) => {
                 ty.mangle_paths(monomorphs);
             }
             Type::Path(ref mut generic_path) => {
@@ -990,6 +1378,7 @@ impl Type {
             Type::Primitive(ref p) => p.can_cmp_order(),
             Type::Array(..) => false,
             Type::FuncPtr { .. } => false,
+            Type::Atomic(..) => false,
         }
     }
 
@@ -1000,18 +1389,19 @@ impl Type {
             Type::Primitive(ref p) => p.can_cmp_eq(),
             Type::Array(..) => false,
             Type::FuncPtr { .. } => true,
+            Type::Atomic(..) => false,
         }
     }
 }
 
 impl Source for String {
-    fn write<F: Write>(&self, _config: &Config, out: &mut SourceWriter<F>) {
+    fn write(&self, _config: &Config, out: &mut SourceWriter) {
         write!(out, "{}", self);
     }
 }
 
 impl Source for Type {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        cdecl::write_type(out, self, config);
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
+        out.bindings().backend().write_type(config, out, self);
     }
 }
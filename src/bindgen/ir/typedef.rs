@@ -3,16 +3,15 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::collections::HashMap;
-use std::io::Write;
 
 use syn::ext::IdentExt;
 
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::Config;
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, Field, GenericArgument, GenericParams, Item,
-    ItemContainer, Path, ToCondition, Type,
+    AnnotationSet, Cfg, Documentation, GenericArgument, GenericParams, Item, ItemContainer, Path,
+    Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -92,7 +91,7 @@ impl Typedef {
     }
 
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -181,28 +180,7 @@ impl Item for Typedef {
 }
 
 impl Source for Typedef {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        let condition = self.cfg.to_condition(config);
-        condition.write_before(config, out);
-
-        self.documentation.write(config, out);
-
-        self.generic_params.write(config, out);
-
-        match config.language {
-            Language::Cxx => {
-                write!(out, "using {} = ", self.export_name());
-                self.aliased.write(config, out);
-            }
-            Language::C | Language::Cython => {
-                write!(out, "{} ", config.language.typedef());
-                Field::from_name_and_type(self.export_name().to_owned(), self.aliased.clone())
-                    .write(config, out);
-            }
-        }
-
-        out.write(";");
-
-        condition.write_after(config, out);
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
+        out.bindings().backend().write_type_def(config, out, self);
     }
 }
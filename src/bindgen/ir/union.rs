@@ -2,23 +2,21 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::io::Write;
-
 use syn::ext::IdentExt;
 
-use crate::bindgen::config::{Config, Language, LayoutConfig};
+use crate::bindgen::config::{Config, LayoutConfig};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, Field, GenericArgument, GenericParams, Item,
-    ItemContainer, Path, Repr, ReprAlign, ReprStyle, ToCondition,
+    AnnotationSet, Cfg, Documentation, Field, GenericArgument, GenericParams, Item, ItemContainer,
+    Path, Repr, ReprAlign, ReprStyle,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::utilities::IterHelpers;
-use crate::bindgen::writer::{ListType, Source, SourceWriter};
+use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
 pub struct Union {
@@ -34,12 +32,27 @@ pub struct Union {
 }
 
 impl Union {
+    /// Loads a union for each of the reprs of `item`, see `Repr::load`.
     pub fn load(
         layout_config: &LayoutConfig,
         item: &syn::ItemUnion,
         mod_cfg: Option<&Cfg>,
+    ) -> Result<Vec<Union>, String> {
+        Repr::load(&item.attrs)?
+            .into_iter()
+            .map(|(repr_cfg, repr)| {
+                Self::load_with_repr(layout_config, item, mod_cfg, repr_cfg, repr)
+            })
+            .collect()
+    }
+
+    fn load_with_repr(
+        layout_config: &LayoutConfig,
+        item: &syn::ItemUnion,
+        mod_cfg: Option<&Cfg>,
+        repr_cfg: Option<Cfg>,
+        repr: Repr,
     ) -> Result<Union, String> {
-        let repr = Repr::load(&item.attrs)?;
         if repr.style != ReprStyle::C {
             return Err("Union is not marked #[repr(C)].".to_owned());
         }
@@ -66,7 +79,10 @@ impl Union {
             fields,
             repr.align,
             tuple_union,
-            Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
+            Cfg::append(
+                Cfg::append(mod_cfg, repr_cfg).as_ref(),
+                Cfg::load(&item.attrs),
+            ),
             AnnotationSet::load(&item.attrs)?,
             Documentation::load(&item.attrs),
         ))
@@ -104,7 +120,7 @@ impl Union {
     }
 
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -181,10 +197,12 @@ impl Item for Union {
                 } else {
                     overriden_fields.push(Field {
                         name: o[i].clone(),
+                        rust_name: field.rust_name.clone(),
                         ty: field.ty.clone(),
                         cfg: field.cfg.clone(),
                         annotations: field.annotations.clone(),
                         documentation: field.documentation.clone(),
+                        flexible_array: field.flexible_array,
                     });
                 }
             }
@@ -198,10 +216,12 @@ impl Item for Union {
                     name: r
                         .apply(&field.name, IdentifierType::StructMember)
                         .into_owned(),
+                    rust_name: field.rust_name.clone(),
                     ty: field.ty.clone(),
                     cfg: field.cfg.clone(),
                     annotations: field.annotations.clone(),
                     documentation: field.documentation.clone(),
+                    flexible_array: field.flexible_array,
                 })
                 .collect();
         } else if self.tuple_union {
@@ -242,10 +262,12 @@ impl Item for Union {
                 .iter()
                 .map(|field| Field {
                     name: field.name.clone(),
+                    rust_name: field.rust_name.clone(),
                     ty: field.ty.specialize(&mappings),
                     cfg: field.cfg.clone(),
                     annotations: field.annotations.clone(),
                     documentation: field.documentation.clone(),
+                    flexible_array: field.flexible_array,
                 })
                 .collect(),
             self.alignment,
@@ -260,77 +282,7 @@ impl Item for Union {
 }
 
 impl Source for Union {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        let condition = self.cfg.to_condition(config);
-        condition.write_before(config, out);
-
-        self.documentation.write(config, out);
-
-        self.generic_params.write(config, out);
-
-        // The following results in
-        // C++ or C with Tag as style:
-        //   union Name {
-        // C with Type only style:
-        //   typedef union {
-        // C with Both as style:
-        //   typedef union Name {
-        match config.language {
-            Language::C if config.style.generate_typedef() => out.write("typedef "),
-            Language::C | Language::Cxx => {}
-            Language::Cython => out.write(config.style.cython_def()),
-        }
-
-        out.write("union");
-
-        // Cython supports `packed` on structs (see comments there), but not on unions.
-        if config.language != Language::Cython {
-            if let Some(align) = self.alignment {
-                match align {
-                    ReprAlign::Packed => {
-                        if let Some(ref anno) = config.layout.packed {
-                            write!(out, " {}", anno);
-                        }
-                    }
-                    ReprAlign::Align(n) => {
-                        if let Some(ref anno) = config.layout.aligned_n {
-                            write!(out, " {}({})", anno, n);
-                        }
-                    }
-                }
-            }
-        }
-
-        if config.language != Language::C || config.style.generate_tag() {
-            write!(out, " {}", self.export_name);
-        }
-
-        out.open_brace();
-
-        // Emit the pre_body section, if relevant
-        if let Some(body) = config.export.pre_body(&self.path) {
-            out.write_raw_block(body);
-            out.new_line();
-        }
-
-        out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
-        if config.language == Language::Cython && self.fields.is_empty() {
-            out.write("pass");
-        }
-
-        // Emit the post_body section, if relevant
-        if let Some(body) = config.export.post_body(&self.path) {
-            out.new_line();
-            out.write_raw_block(body);
-        }
-
-        if config.language == Language::C && config.style.generate_typedef() {
-            out.close_brace(false);
-            write!(out, " {};", self.export_name);
-        } else {
-            out.close_brace(true);
-        }
-
-        condition.write_after(config, out);
+    fn write(&self, config: &Config, out: &mut SourceWriter) {
+        out.bindings().backend().write_union(config, out, self);
     }
 }
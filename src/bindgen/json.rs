@@ -0,0 +1,891 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde_json::{json, Map, Value};
+
+use std::collections::HashMap;
+
+use crate::bindgen::config::{Config, Language};
+use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
+use crate::bindgen::ir::{
+    Abi, AnnotationSet, AnnotationValue, Cfg, ConstExpr, Constant, Documentation, Enum,
+    EnumVariant, Field, Function, FunctionArgument, GenericArgument, GenericParam, GenericParams,
+    GenericPath, Item, ItemContainer, Literal, OpaqueItem, Path, PrimitiveType, Repr, ReprAlign,
+    ReprStyle, ReprType, Static, Struct, Type, Typedef, Union, VariantBody,
+};
+
+// This code is for dumping the resolved IR as JSON, so that other tools can
+// generate their own output from it. Every object describing a type, literal
+// or cfg has a "kind" to tell the variants apart.
+//
+// Any change to the shape of the output needs to bump `SCHEMA_VERSION`, with
+// the exception of adding new keys to existing objects.
+//
+// `load` reads it back, so that `cbindgen diff` can compare a dump with a
+// crate. What the dump leaves out, like the Rust names of fields, is filled in
+// from what it has.
+
+/// The version of the JSON schema written by `Bindings::write_json`.
+///
+/// 2 added the `"atomic"` kind of types, and the `"f16"`, `"f128"`, `"i128"`
+/// and `"u128"` primitives. Dumps of version 1 are still read back.
+pub const SCHEMA_VERSION: u32 = 2;
+
+pub fn bindings(
+    constants: &[Constant],
+    globals: &[Static],
+    items: &[ItemContainer],
+    functions: &[Function],
+) -> Value {
+    json!({
+        "version": SCHEMA_VERSION,
+        "cbindgen_version": crate::bindgen::config::VERSION,
+        "constants": constants.iter().map(constant).collect::<Vec<_>>(),
+        "globals": globals.iter().map(global).collect::<Vec<_>>(),
+        "items": items.iter().map(item).collect::<Vec<_>>(),
+        "functions": functions.iter().map(function).collect::<Vec<_>>(),
+    })
+}
+
+fn item(i: &ItemContainer) -> Value {
+    match *i {
+        ItemContainer::Constant(ref x) => constant(x),
+        ItemContainer::Static(ref x) => global(x),
+        ItemContainer::OpaqueItem(ref x) => opaque(x),
+        ItemContainer::Struct(ref x) => structure(x),
+        ItemContainer::Union(ref x) => union(x),
+        ItemContainer::Enum(ref x) => enumeration(x),
+        ItemContainer::Typedef(ref x) => typedef(x),
+    }
+}
+
+fn cfg(c: Option<&Cfg>) -> Value {
+    match c {
+        None => Value::Null,
+        Some(Cfg::Boolean(name)) => json!({ "kind": "boolean", "name": name }),
+        Some(Cfg::Named(name, value)) => {
+            json!({ "kind": "named", "name": name, "value": value })
+        }
+        Some(Cfg::Any(cfgs)) => json!({
+            "kind": "any",
+            "cfgs": cfgs.iter().map(|x| cfg(Some(x))).collect::<Vec<_>>(),
+        }),
+        Some(Cfg::All(cfgs)) => json!({
+            "kind": "all",
+            "cfgs": cfgs.iter().map(|x| cfg(Some(x))).collect::<Vec<_>>(),
+        }),
+        Some(Cfg::Not(x)) => json!({ "kind": "not", "cfg": cfg(Some(x)) }),
+    }
+}
+
+fn annotations(a: &AnnotationSet) -> Value {
+    let mut map = Map::new();
+    for (name, value) in a.iter() {
+        let value = match *value {
+            AnnotationValue::List(ref list) => json!(list),
+            AnnotationValue::Atom(ref atom) => json!(atom),
+            AnnotationValue::Bool(b) => json!(b),
+        };
+        map.insert(name.clone(), value);
+    }
+    Value::Object(map)
+}
+
+fn documentation(d: &Documentation) -> Value {
+    json!(d.doc_comment)
+}
+
+fn generic_params(p: &GenericParams) -> Value {
+    json!(p.iter().map(|x| x.name().name()).collect::<Vec<_>>())
+}
+
+fn path(p: Option<&Path>) -> Value {
+    json!(p.map(Path::name))
+}
+
+fn ty(t: &Type) -> Value {
+    match *t {
+        Type::Ptr {
+            ty: ref pointee,
+            is_const,
+            is_nullable,
+            is_ref,
+        } => json!({
+            "kind": "pointer",
+            "pointee": ty(pointee),
+            "is_const": is_const,
+            "is_nullable": is_nullable,
+            "is_ref": is_ref,
+        }),
+        Type::Path(ref generic) => json!({
+            "kind": "path",
+            "name": generic.name(),
+            "export_name": generic.export_name(),
+            "generics": generic.generics().iter().map(|x| match *x {
+                GenericArgument::Type(ref t) => ty(t),
+                GenericArgument::Const(ref expr) => json!({ "kind": "const", "value": expr.as_str() }),
+            }).collect::<Vec<_>>(),
+        }),
+        Type::Primitive(ref p) => json!({ "kind": "primitive", "name": p.to_repr_rust() }),
+        Type::Array(ref element, ref len) => json!({
+            "kind": "array",
+            "element": ty(element),
+            "length": len.as_str(),
+        }),
+        Type::FuncPtr {
+            ref ret,
+            ref args,
+            abi,
+            is_nullable,
+            never_return,
+        } => json!({
+            "kind": "function_pointer",
+            "return": ty(ret),
+            "args": args.iter().map(|(name, t)| json!({ "name": name, "type": ty(t) })).collect::<Vec<_>>(),
+            "abi": abi.to_repr_rust(),
+            "is_nullable": is_nullable,
+            "never_return": never_return,
+        }),
+        Type::Atomic(ref inner) => json!({ "kind": "atomic", "type": ty(inner) }),
+    }
+}
+
+fn literal(l: &Literal) -> Value {
+    match *l {
+        Literal::Expr(ref expr) => json!({ "kind": "expr", "value": expr }),
+        Literal::Path {
+            ref associated_to,
+            ref name,
+        } => json!({
+            "kind": "path",
+            "name": name,
+            "associated_to": associated_to.as_ref().map(|(_, export_name)| export_name),
+        }),
+        Literal::PostfixUnaryOp { op, ref value } => {
+            json!({ "kind": "unary_op", "op": op, "value": literal(value) })
+        }
+        Literal::BinOp {
+            ref left,
+            op,
+            ref right,
+        } => json!({
+            "kind": "binary_op",
+            "left": literal(left),
+            "op": op,
+            "right": literal(right),
+        }),
+        Literal::FieldAccess {
+            ref base,
+            ref field,
+        } => json!({ "kind": "field_access", "base": literal(base), "field": field }),
+        Literal::Struct {
+            ref path,
+            ref export_name,
+            ref fields,
+        } => {
+            let fields: Map<String, Value> = fields
+                .iter()
+                .map(|(name, value)| (name.clone(), literal(value)))
+                .collect();
+            json!({
+                "kind": "struct",
+                "name": path.name(),
+                "export_name": export_name,
+                "fields": fields,
+            })
+        }
+        Literal::Cast {
+            ty: ref t,
+            ref value,
+        } => {
+            json!({ "kind": "cast", "type": ty(t), "value": literal(value) })
+        }
+    }
+}
+
+fn field(f: &Field) -> Value {
+    json!({
+        "name": f.name,
+        "type": ty(&f.ty),
+        "cfg": cfg(f.cfg.as_ref()),
+        "annotations": annotations(&f.annotations),
+        "documentation": documentation(&f.documentation),
+    })
+}
+
+fn alignment(a: Option<ReprAlign>) -> Value {
+    match a {
+        None => Value::Null,
+        Some(ReprAlign::Packed) => json!({ "kind": "packed" }),
+        Some(ReprAlign::PackedN(n)) => json!({ "kind": "packed", "value": n }),
+        Some(ReprAlign::Align(n)) => json!({ "kind": "align", "value": n }),
+    }
+}
+
+fn constant(c: &Constant) -> Value {
+    json!({
+        "kind": "constant",
+        "name": c.path.name(),
+        "export_name": c.export_name,
+        "type": ty(&c.ty),
+        "value": literal(&c.value),
+        "associated_to": path(c.associated_to.as_ref()),
+        "cfg": cfg(c.cfg.as_ref()),
+        "annotations": annotations(&c.annotations),
+        "documentation": documentation(&c.documentation),
+    })
+}
+
+fn global(s: &Static) -> Value {
+    json!({
+        "kind": "static",
+        "name": s.path.name(),
+        "export_name": s.export_name,
+        "type": ty(&s.ty),
+        "mutable": s.mutable,
+        "cfg": cfg(s.cfg.as_ref()),
+        "annotations": annotations(&s.annotations),
+        "documentation": documentation(&s.documentation),
+    })
+}
+
+fn opaque(o: &OpaqueItem) -> Value {
+    json!({
+        "kind": "opaque",
+        "name": o.path.name(),
+        "export_name": o.export_name,
+        "generic_params": generic_params(&o.generic_params),
+        "cfg": cfg(o.cfg.as_ref()),
+        "annotations": annotations(&o.annotations),
+        "documentation": documentation(&o.documentation),
+    })
+}
+
+fn structure(s: &Struct) -> Value {
+    json!({
+        "kind": "struct",
+        "name": s.path.name(),
+        "export_name": s.export_name,
+        "generic_params": generic_params(&s.generic_params),
+        "fields": s.fields.iter().map(field).collect::<Vec<_>>(),
+        "alignment": alignment(s.alignment),
+        "is_transparent": s.is_transparent,
+        "associated_constants": s.associated_constants.iter().map(constant).collect::<Vec<_>>(),
+        "must_use": s.annotations.must_use,
+        "cfg": cfg(s.cfg.as_ref()),
+        "annotations": annotations(&s.annotations),
+        "documentation": documentation(&s.documentation),
+    })
+}
+
+fn union(u: &Union) -> Value {
+    json!({
+        "kind": "union",
+        "name": u.path.name(),
+        "export_name": u.export_name,
+        "generic_params": generic_params(&u.generic_params),
+        "fields": u.fields.iter().map(field).collect::<Vec<_>>(),
+        "alignment": alignment(u.alignment),
+        "must_use": u.annotations.must_use,
+        "cfg": cfg(u.cfg.as_ref()),
+        "annotations": annotations(&u.annotations),
+        "documentation": documentation(&u.documentation),
+    })
+}
+
+fn variant(v: &EnumVariant) -> Value {
+    let (body, variant_annotations) = match v.body {
+        VariantBody::Empty(ref annotations) => (Value::Null, annotations),
+        VariantBody::Body {
+            ref name,
+            ref body,
+            inline,
+            ..
+        } => (
+            json!({ "name": name, "struct": structure(body), "inline": inline }),
+            &body.annotations,
+        ),
+    };
+    json!({
+        "name": v.name,
+        "export_name": v.export_name,
+        "discriminant": v.discriminant.as_ref().map(literal),
+        "body": body,
+        "cfg": cfg(v.cfg.as_ref()),
+        "annotations": annotations(variant_annotations),
+        "documentation": documentation(&v.documentation),
+    })
+}
+
+fn enumeration(e: &Enum) -> Value {
+    let style = match e.repr.style {
+        ReprStyle::Rust => "rust",
+        ReprStyle::C => "c",
+        ReprStyle::Transparent => "transparent",
+    };
+    json!({
+        "kind": "enum",
+        "name": e.path.name(),
+        "export_name": e.export_name,
+        "generic_params": generic_params(&e.generic_params),
+        "repr": {
+            "style": style,
+            "type": e.repr.ty.map(|ty| ty.to_primitive().to_repr_rust()),
+            "alignment": alignment(e.repr.align),
+        },
+        "tag": e.tag_name(),
+        "variants": e.variants.iter().map(variant).collect::<Vec<_>>(),
+        "must_use": e.annotations.must_use,
+        "cfg": cfg(e.cfg.as_ref()),
+        "annotations": annotations(&e.annotations),
+        "documentation": documentation(&e.documentation),
+    })
+}
+
+fn typedef(t: &Typedef) -> Value {
+    json!({
+        "kind": "typedef",
+        "name": t.path.name(),
+        "export_name": t.export_name,
+        "generic_params": generic_params(&t.generic_params),
+        "aliased": ty(&t.aliased),
+        "cfg": cfg(t.cfg.as_ref()),
+        "annotations": annotations(&t.annotations),
+        "documentation": documentation(&t.documentation),
+    })
+}
+
+fn function(f: &Function) -> Value {
+    json!({
+        "kind": "function",
+        "name": f.path.name(),
+        "self_type": path(f.self_type_path.as_ref()),
+        "return": ty(&f.ret),
+        "args": f.args.iter().map(|arg| json!({
+            "name": arg.name,
+            "type": ty(&arg.ty),
+            "array_length": arg.array_length,
+        })).collect::<Vec<_>>(),
+        "abi": f.abi.to_repr_rust(),
+        "never_return": f.never_return,
+        "must_use": f.annotations.must_use,
+        "cfg": cfg(f.cfg.as_ref()),
+        "annotations": annotations(&f.annotations),
+        "documentation": documentation(&f.documentation),
+    })
+}
+
+/// The IR read back from the output of `Bindings::write_json`.
+pub(crate) struct Loaded {
+    pub constants: Vec<Constant>,
+    pub globals: Vec<Static>,
+    pub items: Vec<ItemContainer>,
+    pub functions: Vec<Function>,
+}
+
+/// Reads back the output of `bindings`, resolving the declaration types of
+/// paths the way `Library` does for `config`.
+pub(crate) fn load(value: &Value, config: &Config) -> Result<Loaded, String> {
+    match value.get("version").and_then(Value::as_u64) {
+        Some(version) if (1..=u64::from(SCHEMA_VERSION)).contains(&version) => {}
+        Some(version) => {
+            return Err(format!(
+                "unsupported version {}, expected {}",
+                version, SCHEMA_VERSION
+            ))
+        }
+        None => return Err("missing `version`".to_owned()),
+    }
+    let mut loaded = Loaded {
+        constants: list(value, "constants", load_constant)?,
+        globals: list(value, "globals", load_global)?,
+        items: list(value, "items", load_item)?,
+        functions: list(value, "functions", load_function)?,
+    };
+    if config.language == Language::C && config.style.generate_tag() {
+        resolve_declaration_types(&mut loaded);
+    }
+    Ok(loaded)
+}
+
+fn resolve_declaration_types(loaded: &mut Loaded) {
+    let mut resolver = DeclarationTypeResolver::default();
+    // Opaque items go last, as in `Library::resolve_declaration_types`.
+    let (opaque, others): (Vec<_>, Vec<_>) = loaded
+        .items
+        .iter()
+        .partition(|x| matches!(x, ItemContainer::OpaqueItem(..)));
+    for item in others.into_iter().chain(opaque) {
+        match *item {
+            ItemContainer::Constant(..) | ItemContainer::Static(..) => {}
+            _ => item.deref().collect_declaration_types(&mut resolver),
+        }
+    }
+
+    for item in &mut loaded.items {
+        match *item {
+            ItemContainer::Constant(ref mut x) => x.resolve_declaration_types(&resolver),
+            ItemContainer::Static(ref mut x) => x.resolve_declaration_types(&resolver),
+            ItemContainer::OpaqueItem(..) => {}
+            ItemContainer::Struct(ref mut x) => x.resolve_declaration_types(&resolver),
+            ItemContainer::Union(ref mut x) => x.resolve_declaration_types(&resolver),
+            ItemContainer::Enum(ref mut x) => x.resolve_declaration_types(&resolver),
+            ItemContainer::Typedef(ref mut x) => x.resolve_declaration_types(&resolver),
+        }
+    }
+    for constant in &mut loaded.constants {
+        constant.resolve_declaration_types(&resolver);
+    }
+    for global in &mut loaded.globals {
+        global.resolve_declaration_types(&resolver);
+    }
+    for function in &mut loaded.functions {
+        function.resolve_declaration_types(&resolver);
+    }
+}
+
+fn get<'a>(value: &'a Value, key: &str) -> Result<&'a Value, String> {
+    value.get(key).ok_or_else(|| format!("missing `{}`", key))
+}
+
+fn string(value: &Value, key: &str) -> Result<String, String> {
+    get(value, key)?
+        .as_str()
+        .map(str::to_owned)
+        .ok_or_else(|| format!("`{}` isn't a string", key))
+}
+
+fn opt_string(value: &Value, key: &str) -> Result<Option<String>, String> {
+    match value.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(..) => string(value, key).map(Some),
+    }
+}
+
+fn boolean(value: &Value, key: &str) -> Result<bool, String> {
+    get(value, key)?
+        .as_bool()
+        .ok_or_else(|| format!("`{}` isn't a bool", key))
+}
+
+fn integer(value: &Value, key: &str) -> Result<u64, String> {
+    get(value, key)?
+        .as_u64()
+        .ok_or_else(|| format!("`{}` isn't an integer", key))
+}
+
+fn list<T>(
+    value: &Value,
+    key: &str,
+    load: impl Fn(&Value) -> Result<T, String>,
+) -> Result<Vec<T>, String> {
+    get(value, key)?
+        .as_array()
+        .ok_or_else(|| format!("`{}` isn't an array", key))?
+        .iter()
+        .map(load)
+        .collect()
+}
+
+fn load_item(value: &Value) -> Result<ItemContainer, String> {
+    Ok(match &*string(value, "kind")? {
+        "constant" => ItemContainer::Constant(load_constant(value)?),
+        "static" => ItemContainer::Static(load_global(value)?),
+        "opaque" => ItemContainer::OpaqueItem(load_opaque(value)?),
+        "struct" => ItemContainer::Struct(load_structure(value)?),
+        "union" => ItemContainer::Union(load_union(value)?),
+        "enum" => ItemContainer::Enum(load_enumeration(value)?),
+        "typedef" => ItemContainer::Typedef(load_typedef(value)?),
+        kind => return Err(format!("unknown item kind `{}`", kind)),
+    })
+}
+
+/// The `cfg` of `value`.
+fn load_cfg(value: &Value) -> Result<Option<Cfg>, String> {
+    match get(value, "cfg")? {
+        Value::Null => Ok(None),
+        cfg => load_cfg_value(cfg).map(Some),
+    }
+}
+
+fn load_cfg_value(value: &Value) -> Result<Cfg, String> {
+    Ok(match &*string(value, "kind")? {
+        "boolean" => Cfg::Boolean(string(value, "name")?),
+        "named" => Cfg::Named(string(value, "name")?, string(value, "value")?),
+        "any" => Cfg::Any(list(value, "cfgs", load_cfg_value)?),
+        "all" => Cfg::All(list(value, "cfgs", load_cfg_value)?),
+        "not" => Cfg::Not(Box::new(load_cfg_value(get(value, "cfg")?)?)),
+        kind => return Err(format!("unknown cfg kind `{}`", kind)),
+    })
+}
+
+/// The `annotations` and `must_use` of `value`.
+fn load_annotations(value: &Value) -> Result<AnnotationSet, String> {
+    let mut annotations = HashMap::new();
+    if let Some(map) = value.get("annotations").and_then(Value::as_object) {
+        for (name, value) in map {
+            let value = match *value {
+                Value::Array(ref list) => AnnotationValue::List(
+                    list.iter()
+                        .map(|x| x.as_str().unwrap_or_default().to_owned())
+                        .collect(),
+                ),
+                Value::String(ref atom) => AnnotationValue::Atom(Some(atom.clone())),
+                Value::Bool(b) => AnnotationValue::Bool(b),
+                _ => AnnotationValue::Atom(None),
+            };
+            annotations.insert(name.clone(), value);
+        }
+    }
+    let mut set = AnnotationSet::new();
+    set.merge(&annotations);
+    set.must_use = value
+        .get("must_use")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    Ok(set)
+}
+
+fn load_documentation(value: &Value) -> Result<Documentation, String> {
+    let doc_comment = match value.get("documentation") {
+        None | Some(Value::Null) => vec![],
+        Some(..) => list(value, "documentation", |x| {
+            x.as_str()
+                .map(str::to_owned)
+                .ok_or_else(|| "`documentation` isn't a list of strings".to_owned())
+        })?,
+    };
+    Ok(Documentation { doc_comment })
+}
+
+fn load_generic_params(value: &Value) -> Result<GenericParams, String> {
+    let params = list(value, "generic_params", |x| {
+        x.as_str()
+            .map(GenericParam::new_type_param)
+            .ok_or_else(|| "`generic_params` isn't a list of strings".to_owned())
+    })?;
+    Ok(GenericParams(params))
+}
+
+/// An array length or const generic, which is a number or the name of a
+/// constant.
+fn load_const_expr(value: String) -> ConstExpr {
+    if value.starts_with(|c: char| c.is_ascii_digit()) {
+        ConstExpr::Value(value)
+    } else {
+        ConstExpr::Name(value)
+    }
+}
+
+fn load_abi(value: &Value) -> Result<Abi, String> {
+    let name = string(value, "abi")?;
+    Abi::maybe(&name).ok_or_else(|| format!("unknown abi `{}`", name))
+}
+
+fn load_primitive(name: &str) -> Result<PrimitiveType, String> {
+    PrimitiveType::maybe(name).ok_or_else(|| format!("unknown primitive type `{}`", name))
+}
+
+fn load_ty(value: &Value) -> Result<Type, String> {
+    Ok(match &*string(value, "kind")? {
+        "pointer" => Type::Ptr {
+            ty: Box::new(load_ty(get(value, "pointee")?)?),
+            is_const: boolean(value, "is_const")?,
+            is_nullable: boolean(value, "is_nullable")?,
+            is_ref: boolean(value, "is_ref")?,
+        },
+        "path" => {
+            let generics = list(value, "generics", |x| {
+                match x.get("kind").and_then(Value::as_str) {
+                    Some("const") => {
+                        Ok(GenericArgument::Const(load_const_expr(string(x, "value")?)))
+                    }
+                    _ => load_ty(x).map(GenericArgument::Type),
+                }
+            })?;
+            let path = GenericPath::new(Path::new(string(value, "name")?), generics);
+            Type::Path(path.with_export_name(string(value, "export_name")?))
+        }
+        "primitive" => Type::Primitive(load_primitive(&string(value, "name")?)?),
+        "array" => Type::Array(
+            Box::new(load_ty(get(value, "element")?)?),
+            load_const_expr(string(value, "length")?),
+        ),
+        "function_pointer" => Type::FuncPtr {
+            ret: Box::new(load_ty(get(value, "return")?)?),
+            args: list(value, "args", |x| {
+                Ok((opt_string(x, "name")?, load_ty(get(x, "type")?)?))
+            })?,
+            abi: load_abi(value)?,
+            is_nullable: boolean(value, "is_nullable")?,
+            never_return: boolean(value, "never_return")?,
+        },
+        "atomic" => Type::Atomic(Box::new(load_ty(get(value, "type")?)?)),
+        kind => return Err(format!("unknown type kind `{}`", kind)),
+    })
+}
+
+/// The operators `Literal` is written with, which it keeps as `&'static str`.
+const OPERATORS: &[&str] = &[
+    "+", "-", "*", "/", "%", "&&", "||", "^", "&", "|", "<<", ">>", "==", "<", "<=", "!=", ">=",
+    ">", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<=", ">>=", "~", "!",
+];
+
+fn load_operator(value: &Value) -> Result<&'static str, String> {
+    let op = string(value, "op")?;
+    OPERATORS
+        .iter()
+        .find(|x| **x == op)
+        .copied()
+        .ok_or_else(|| format!("unknown operator `{}`", op))
+}
+
+fn load_literal(value: &Value) -> Result<Literal, String> {
+    Ok(match &*string(value, "kind")? {
+        "expr" => Literal::Expr(string(value, "value")?),
+        "path" => Literal::Path {
+            associated_to: opt_string(value, "associated_to")?
+                .map(|export_name| (Path::new(export_name.clone()), export_name)),
+            name: string(value, "name")?,
+        },
+        "unary_op" => Literal::PostfixUnaryOp {
+            op: load_operator(value)?,
+            value: Box::new(load_literal(get(value, "value")?)?),
+        },
+        "binary_op" => Literal::BinOp {
+            left: Box::new(load_literal(get(value, "left")?)?),
+            op: load_operator(value)?,
+            right: Box::new(load_literal(get(value, "right")?)?),
+        },
+        "field_access" => Literal::FieldAccess {
+            base: Box::new(load_literal(get(value, "base")?)?),
+            field: string(value, "field")?,
+        },
+        "struct" => Literal::Struct {
+            path: Path::new(string(value, "name")?),
+            export_name: string(value, "export_name")?,
+            fields: get(value, "fields")?
+                .as_object()
+                .ok_or_else(|| "`fields` isn't an object".to_owned())?
+                .iter()
+                .map(|(name, value)| Ok((name.clone(), load_literal(value)?)))
+                .collect::<Result<_, String>>()?,
+        },
+        "cast" => Literal::Cast {
+            ty: load_ty(get(value, "type")?)?,
+            value: Box::new(load_literal(get(value, "value")?)?),
+        },
+        kind => return Err(format!("unknown literal kind `{}`", kind)),
+    })
+}
+
+fn load_field(value: &Value) -> Result<Field, String> {
+    let name = string(value, "name")?;
+    Ok(Field {
+        rust_name: name.clone(),
+        name,
+        ty: load_ty(get(value, "type")?)?,
+        cfg: load_cfg(value)?,
+        annotations: load_annotations(value)?,
+        documentation: load_documentation(value)?,
+        flexible_array: false,
+    })
+}
+
+fn load_alignment(value: &Value) -> Result<Option<ReprAlign>, String> {
+    Ok(match *get(value, "alignment")? {
+        Value::Null => None,
+        ref alignment => match &*string(alignment, "kind")? {
+            "packed" if alignment.get("value").is_some() => {
+                Some(ReprAlign::PackedN(integer(alignment, "value")?))
+            }
+            "packed" => Some(ReprAlign::Packed),
+            "align" => Some(ReprAlign::Align(integer(alignment, "value")?)),
+            kind => return Err(format!("unknown alignment kind `{}`", kind)),
+        },
+    })
+}
+
+fn load_constant(value: &Value) -> Result<Constant, String> {
+    let mut c = Constant::new(
+        Path::new(string(value, "name")?),
+        load_ty(get(value, "type")?)?,
+        load_literal(get(value, "value")?)?,
+        load_cfg(value)?,
+        load_annotations(value)?,
+        load_documentation(value)?,
+        opt_string(value, "associated_to")?.map(Path::new),
+    );
+    c.export_name = string(value, "export_name")?;
+    Ok(c)
+}
+
+fn load_global(value: &Value) -> Result<Static, String> {
+    let mut s = Static::new(
+        Path::new(string(value, "name")?),
+        load_ty(get(value, "type")?)?,
+        boolean(value, "mutable")?,
+        load_cfg(value)?,
+        load_annotations(value)?,
+        load_documentation(value)?,
+    );
+    s.export_name = string(value, "export_name")?;
+    Ok(s)
+}
+
+fn load_opaque(value: &Value) -> Result<OpaqueItem, String> {
+    let mut o = OpaqueItem::new(
+        Path::new(string(value, "name")?),
+        load_generic_params(value)?,
+        load_cfg(value)?,
+        load_annotations(value)?,
+        load_documentation(value)?,
+    );
+    o.export_name = string(value, "export_name")?;
+    Ok(o)
+}
+
+fn load_structure(value: &Value) -> Result<Struct, String> {
+    let mut s = Struct::new(
+        Path::new(string(value, "name")?),
+        load_generic_params(value)?,
+        list(value, "fields", load_field)?,
+        false,
+        false,
+        load_alignment(value)?,
+        boolean(value, "is_transparent")?,
+        load_cfg(value)?,
+        load_annotations(value)?,
+        load_documentation(value)?,
+    );
+    s.export_name = string(value, "export_name")?;
+    s.associated_constants = list(value, "associated_constants", load_constant)?;
+    Ok(s)
+}
+
+fn load_union(value: &Value) -> Result<Union, String> {
+    let mut u = Union::new(
+        Path::new(string(value, "name")?),
+        load_generic_params(value)?,
+        list(value, "fields", load_field)?,
+        load_alignment(value)?,
+        false,
+        load_cfg(value)?,
+        load_annotations(value)?,
+        load_documentation(value)?,
+    );
+    u.export_name = string(value, "export_name")?;
+    Ok(u)
+}
+
+fn load_variant(value: &Value, inline_tag_field: bool) -> Result<EnumVariant, String> {
+    let body = match *get(value, "body")? {
+        Value::Null => VariantBody::Empty(load_annotations(value)?),
+        ref body => {
+            let mut s = load_structure(get(body, "struct")?)?;
+            s.has_tag_field = inline_tag_field;
+            s.is_enum_variant_body = true;
+            let inline = boolean(body, "inline")?;
+            VariantBody::Body {
+                name: string(body, "name")?,
+                body: s,
+                inline,
+                inline_casts: inline,
+            }
+        }
+    };
+    let discriminant = match *get(value, "discriminant")? {
+        Value::Null => None,
+        ref discriminant => Some(load_literal(discriminant)?),
+    };
+    let mut v = EnumVariant::new(
+        string(value, "name")?,
+        discriminant,
+        body,
+        load_cfg(value)?,
+        load_documentation(value)?,
+    );
+    v.export_name = string(value, "export_name")?;
+    Ok(v)
+}
+
+fn load_enumeration(value: &Value) -> Result<Enum, String> {
+    let repr_value = get(value, "repr")?;
+    let style = match &*string(repr_value, "style")? {
+        "rust" => ReprStyle::Rust,
+        "c" => ReprStyle::C,
+        "transparent" => ReprStyle::Transparent,
+        style => return Err(format!("unknown repr style `{}`", style)),
+    };
+    let ty = match opt_string(repr_value, "type")? {
+        None => None,
+        Some(name) => Some(
+            ReprType::from_primitive(&load_primitive(&name)?)
+                .ok_or_else(|| format!("invalid repr type `{}`", name))?,
+        ),
+    };
+    let repr = Repr {
+        style,
+        ty,
+        align: load_alignment(repr_value)?,
+    };
+
+    let inline_tag_field = Enum::inline_tag_field(&repr);
+    let variants = list(value, "variants", |x| load_variant(x, inline_tag_field))?;
+    let has_data = variants
+        .iter()
+        .any(|x| matches!(x.body, VariantBody::Body { .. }));
+    let tag = if has_data {
+        Some(string(value, "tag")?)
+    } else {
+        None
+    };
+
+    let mut e = Enum::new(
+        Path::new(string(value, "name")?),
+        load_generic_params(value)?,
+        repr,
+        variants,
+        tag,
+        load_cfg(value)?,
+        load_annotations(value)?,
+        load_documentation(value)?,
+    );
+    e.export_name = string(value, "export_name")?;
+    Ok(e)
+}
+
+fn load_typedef(value: &Value) -> Result<Typedef, String> {
+    let mut t = Typedef::new(
+        Path::new(string(value, "name")?),
+        load_generic_params(value)?,
+        load_ty(get(value, "aliased")?)?,
+        load_cfg(value)?,
+        load_annotations(value)?,
+        load_documentation(value)?,
+    );
+    t.export_name = string(value, "export_name")?;
+    Ok(t)
+}
+
+fn load_function(value: &Value) -> Result<Function, String> {
+    Ok(Function {
+        path: Path::new(string(value, "name")?),
+        self_type_path: opt_string(value, "self_type")?.map(Path::new),
+        ret: load_ty(get(value, "return")?)?,
+        args: list(value, "args", |x| {
+            Ok(FunctionArgument {
+                name: opt_string(x, "name")?,
+                ty: load_ty(get(x, "type")?)?,
+                array_length: opt_string(x, "array_length")?,
+            })
+        })?,
+        abi: load_abi(value)?,
+        extern_decl: false,
+        cfg: load_cfg(value)?,
+        annotations: load_annotations(value)?,
+        documentation: load_documentation(value)?,
+        never_return: boolean(value, "never_return")?,
+    })
+}
@@ -0,0 +1,2299 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::borrow::Cow;
+
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{Config, DocumentationLength, DocumentationStyle, Language, Layout};
+use crate::bindgen::ir::{
+    Abi, Condition, ConditionWrite, Constant, Documentation, Enum, EnumVariant, Field, Function,
+    GenericParamType, GenericParams, IntKind, Item, Literal, OpaqueItem, Path, PrimitiveType,
+    ReprAlign, Static, Struct, ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::layout;
+use crate::bindgen::rename::IdentifierType;
+use crate::bindgen::writer::{ListType, Source, SourceWriter};
+use crate::bindgen::Bindings;
+
+/// The type of a pointer to `f`, with or without the names of the arguments.
+fn function_pointer(f: &Function, arg_names: bool) -> Type {
+    Type::FuncPtr {
+        ret: Box::new(f.ret.clone()),
+        args: f
+            .args
+            .iter()
+            .map(|arg| (arg.name.clone().filter(|_| arg_names), arg.ty.clone()))
+            .collect(),
+        abi: f.abi,
+        is_nullable: true,
+        never_return: f.never_return,
+    }
+}
+
+/// The backend for C and C++, which it tells apart with `config.language`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CLikeLanguageBackend;
+
+#[derive(PartialEq, Eq)]
+enum NamespaceOperation {
+    Open,
+    Close,
+}
+
+// TODO: Maybe add support to more std associated constants.
+pub(super) fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
+    if name != "MAX" && name != "MIN" {
+        return None;
+    }
+
+    let prim = PrimitiveType::maybe(associated_to.name())?;
+    let prefix = match prim {
+        PrimitiveType::Integer {
+            kind,
+            signed,
+            zeroable: _,
+        } => match kind {
+            IntKind::B8 => {
+                if signed {
+                    "INT8"
+                } else {
+                    "UINT8"
+                }
+            }
+            IntKind::B16 => {
+                if signed {
+                    "INT16"
+                } else {
+                    "UINT16"
+                }
+            }
+            IntKind::B32 => {
+                if signed {
+                    "INT32"
+                } else {
+                    "UINT32"
+                }
+            }
+            IntKind::B64 => {
+                if signed {
+                    "INT64"
+                } else {
+                    "UINT64"
+                }
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(format!("{}_{}", prefix, name))
+}
+
+/// Writes a condition as a C preprocessor expression, which is also how the
+/// languages without conditional compilation keep it around as a comment.
+pub(super) fn write_condition_expr(out: &mut SourceWriter, condition: &Condition) {
+    match *condition {
+        Condition::Define(ref define) => {
+            out.write("defined(");
+            write!(out, "{}", define);
+            out.write(")");
+        }
+        Condition::Any(ref conditions) => {
+            out.write("(");
+            for (i, condition) in conditions.iter().enumerate() {
+                if i != 0 {
+                    out.write(" || ");
+                }
+                write_condition_expr(out, condition);
+            }
+            out.write(")");
+        }
+        Condition::All(ref conditions) => {
+            out.write("(");
+            for (i, condition) in conditions.iter().enumerate() {
+                if i != 0 {
+                    out.write(" && ");
+                }
+                write_condition_expr(out, condition);
+            }
+            out.write(")");
+        }
+        Condition::Not(ref condition) => {
+            out.write("!");
+            write_condition_expr(out, condition);
+        }
+    }
+}
+
+/// Writes an enum variant, followed by a comma.
+pub(super) fn write_enum_variant(config: &Config, out: &mut SourceWriter, v: &EnumVariant) {
+    let condition = v.cfg.to_condition(config);
+    condition.write_before(config, out);
+    v.documentation.write(config, out);
+    write!(out, "{}", v.export_name);
+    if let Some(discriminant) = &v.discriminant {
+        out.write(" = ");
+        discriminant.write(config, out);
+    }
+    out.write(",");
+    condition.write_after(config, out);
+}
+
+impl CLikeLanguageBackend {
+    fn all_namespaces<'a>(&self, config: &'a Config) -> Vec<&'a str> {
+        if config.language != Language::Cxx && !config.cpp_compatible_c() {
+            return vec![];
+        }
+        let mut ret = vec![];
+        if let Some(ref namespace) = config.namespace {
+            ret.push(&**namespace);
+        }
+        if let Some(ref namespaces) = config.namespaces {
+            for namespace in namespaces {
+                ret.push(&**namespace);
+            }
+        }
+        ret
+    }
+
+    fn open_close_namespaces(
+        &self,
+        config: &Config,
+        op: NamespaceOperation,
+        out: &mut SourceWriter,
+    ) {
+        let mut namespaces = self.all_namespaces(config);
+        if namespaces.is_empty() {
+            return;
+        }
+
+        if op == NamespaceOperation::Close {
+            namespaces.reverse();
+        }
+
+        if config.cpp_compatible_c() {
+            out.new_line_if_not_start();
+            out.write("#ifdef __cplusplus");
+        }
+
+        for namespace in namespaces {
+            out.new_line();
+            match op {
+                NamespaceOperation::Open => write!(out, "namespace {} {{", namespace),
+                NamespaceOperation::Close => write!(out, "}} // namespace {}", namespace),
+            }
+        }
+
+        out.new_line();
+        if config.cpp_compatible_c() {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+    }
+
+    /// Writes the `template<...>` line of generic C++ items.
+    fn write_generic_params(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        params: &GenericParams,
+        with_default: bool,
+    ) {
+        if params.is_empty() || config.language != Language::Cxx {
+            return;
+        }
+
+        out.write("template<");
+        for (i, item) in params.iter().enumerate() {
+            if i != 0 {
+                out.write(", ");
+            }
+            match *item.ty() {
+                GenericParamType::Type => {
+                    write!(out, "typename {}", item.name());
+                    if with_default {
+                        write!(out, " = void");
+                    }
+                }
+                GenericParamType::Const(ref ty) => {
+                    cdecl::write_field(out, ty, item.name().name(), config);
+                    if with_default {
+                        write!(out, " = 0");
+                    }
+                }
+            }
+        }
+        out.write(">");
+        out.new_line();
+    }
+
+    /// Writes static assertions checking that the C compiler lays out the
+    /// item at `path` the way rustc does, if enabled and if its layout is
+    /// known. `keyword` is what refers to the item when there's no typedef for
+    /// it, if anything.
+    fn write_layout_asserts(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        path: &Path,
+        name: &str,
+        keyword: Option<&str>,
+    ) {
+        if !config.layout.static_asserts {
+            return;
+        }
+        let layout = match out.bindings().layout(path) {
+            Some(layout) => layout,
+            None => return,
+        };
+
+        let ty = layout::c_type_name(config, name, keyword);
+        out.new_line();
+        write!(
+            out,
+            "static_assert(sizeof({}) == {}, \"unexpected size of {}\");",
+            ty, layout.size, name
+        );
+        out.new_line();
+        write!(
+            out,
+            "static_assert(alignof({}) == {}, \"unexpected alignment of {}\");",
+            ty, layout.align, name
+        );
+        for (field, offset) in &layout.field_offsets {
+            out.new_line();
+            write!(
+                out,
+                "static_assert(offsetof({}, {}) == {}, \"unexpected offset of {}::{}\");",
+                ty, field, offset, name, field
+            );
+        }
+    }
+
+    /// Writes a `#[repr(packed)]`, `#[repr(packed(n))]` or `#[repr(align(n))]`
+    /// annotation, if configured.
+    fn write_alignment(&self, config: &Config, out: &mut SourceWriter, align: Option<ReprAlign>) {
+        match align {
+            Some(ReprAlign::Packed) => {
+                if let Some(ref anno) = config.layout.packed {
+                    write!(out, " {}", anno);
+                }
+            }
+            // Without an annotation, see `write_pack_push`.
+            Some(ReprAlign::PackedN(n)) => {
+                if let Some(ref anno) = config.layout.packed_n {
+                    write!(out, " {}({})", anno, n);
+                }
+            }
+            Some(ReprAlign::Align(n)) => {
+                if let Some(ref anno) = config.layout.aligned_n {
+                    write!(out, " {}({})", anno, n);
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Starts the `#pragma pack(n)` of a `#[repr(packed(n))]` type, unless
+    /// `layout.packed_n` is configured. Unlike `__attribute__((packed,
+    /// aligned(n)))`, it caps the alignment of every field at `n` the way rustc
+    /// does, and gcc, clang and MSVC all have it.
+    fn write_pack_push(&self, config: &Config, out: &mut SourceWriter, align: Option<ReprAlign>) {
+        if config.layout.packed_n.is_some() {
+            return;
+        }
+        if let Some(ReprAlign::PackedN(n)) = align {
+            write!(out, "#pragma pack(push, {})", n);
+            out.new_line();
+        }
+    }
+
+    /// Ends what `write_pack_push` started.
+    fn write_pack_pop(&self, config: &Config, out: &mut SourceWriter, align: Option<ReprAlign>) {
+        if config.layout.packed_n.is_some() {
+            return;
+        }
+        if let Some(ReprAlign::PackedN(..)) = align {
+            out.new_line();
+            out.write("#pragma pack(pop)");
+        }
+    }
+
+    fn write_struct_derives(&self, config: &Config, out: &mut SourceWriter, s: &Struct) {
+        let mut wrote_start_newline = false;
+
+        if config.structure.derive_constructor(&s.annotations) && !s.fields.is_empty() {
+            if !wrote_start_newline {
+                wrote_start_newline = true;
+                out.new_line();
+            }
+
+            out.new_line();
+
+            let arg_renamer = |name: &str| {
+                config
+                    .function
+                    .rename_args
+                    .apply(name, IdentifierType::FunctionArg)
+                    .into_owned()
+            };
+            // A flexible array member can't be passed or initialized.
+            let fields = || s.fields.iter().filter(|field| !field.flexible_array);
+            write!(out, "{}(", s.export_name());
+            let vec: Vec<_> = fields()
+                .map(|field| {
+                    Field::from_name_and_type(
+                        // const-ref args to constructor
+                        format!("const& {}", arg_renamer(&field.name)),
+                        field.ty.clone(),
+                    )
+                })
+                .collect();
+            out.write_vertical_source_list(&vec[..], ListType::Join(","));
+            write!(out, ")");
+            out.new_line();
+            write!(out, "  : ");
+            let vec: Vec<_> = fields()
+                .map(|field| format!("{}({})", field.name, arg_renamer(&field.name)))
+                .collect();
+            out.write_vertical_source_list(&vec[..], ListType::Join(","));
+            out.new_line();
+            write!(out, "{{}}");
+            out.new_line();
+        }
+
+        let other = config
+            .function
+            .rename_args
+            .apply("other", IdentifierType::FunctionArg);
+
+        if s.annotations
+            .bool("internal-derive-bitflags")
+            .unwrap_or(false)
+        {
+            if !wrote_start_newline {
+                wrote_start_newline = true;
+                out.new_line();
+            }
+            let constexpr_prefix = if config.constant.allow_constexpr {
+                "constexpr "
+            } else {
+                ""
+            };
+
+            out.new_line();
+            write!(out, "{}explicit operator bool() const", constexpr_prefix);
+            out.open_brace();
+            write!(out, "return !!bits;");
+            out.close_brace(false);
+
+            out.new_line();
+            write!(
+                out,
+                "{}{} operator~() const",
+                constexpr_prefix,
+                s.export_name()
+            );
+            out.open_brace();
+            write!(
+                out,
+                "return {} {{ static_cast<decltype(bits)>(~bits) }};",
+                s.export_name()
+            );
+            out.close_brace(false);
+            self.emit_bitflags_binop(out, s, constexpr_prefix, '|', &other);
+            self.emit_bitflags_binop(out, s, constexpr_prefix, '&', &other);
+            self.emit_bitflags_binop(out, s, constexpr_prefix, '^', &other);
+        }
+
+        // Generate a serializer function that allows dumping this struct
+        // to an std::ostream. It's defined as a friend function inside the
+        // struct definition, and doesn't need the `inline` keyword even
+        // though it's implemented right in the generated header file.
+        if config.structure.derive_ostream(&s.annotations) {
+            if !wrote_start_newline {
+                wrote_start_newline = true;
+                out.new_line();
+            }
+
+            out.new_line();
+            let stream = config
+                .function
+                .rename_args
+                .apply("stream", IdentifierType::FunctionArg);
+            let instance = config
+                .function
+                .rename_args
+                .apply("instance", IdentifierType::FunctionArg);
+            write!(
+                out,
+                "friend std::ostream& operator<<(std::ostream& {}, const {}& {})",
+                stream,
+                s.export_name(),
+                instance,
+            );
+            out.open_brace();
+            write!(out, "return {} << \"{{ \"", stream);
+            let vec: Vec<_> = s
+                .fields
+                .iter()
+                .map(|x| format!(" << \"{}=\" << {}.{}", x.name, instance, x.name))
+                .collect();
+            out.write_vertical_source_list(&vec[..], ListType::Join(" << \", \""));
+            out.write(" << \" }\";");
+            out.close_brace(false);
+        }
+
+        let skip_fields = s.has_tag_field as usize;
+
+        macro_rules! emit_op {
+            ($op_name:expr, $op:expr, $conjuc:expr) => {{
+                if !wrote_start_newline {
+                    #[allow(unused_assignments)]
+                    {
+                        wrote_start_newline = true;
+                    }
+                    out.new_line();
+                }
+
+                out.new_line();
+
+                if let Some(Some(attrs)) = s.annotations.atom(concat!($op_name, "-attributes")) {
+                    write!(out, "{} ", attrs);
+                }
+
+                write!(
+                    out,
+                    "bool operator{}(const {}& {}) const",
+                    $op,
+                    s.export_name(),
+                    other
+                );
+                out.open_brace();
+                out.write("return ");
+                let vec: Vec<_> = s
+                    .fields
+                    .iter()
+                    .skip(skip_fields)
+                    .map(|field| format!("{} {} {}.{}", field.name, $op, other, field.name))
+                    .collect();
+                out.write_vertical_source_list(&vec[..], ListType::Join(&format!(" {}", $conjuc)));
+                out.write(";");
+                out.close_brace(false);
+            }};
+        }
+
+        if config.structure.derive_eq(&s.annotations) && s.can_derive_eq() {
+            emit_op!("eq", "==", "&&");
+        }
+        if config.structure.derive_neq(&s.annotations) && s.can_derive_eq() {
+            emit_op!("neq", "!=", "||");
+        }
+        if config.structure.derive_lt(&s.annotations)
+            && s.fields.len() == 1
+            && s.fields[0].ty.can_cmp_order()
+        {
+            emit_op!("lt", "<", "&&");
+        }
+        if config.structure.derive_lte(&s.annotations)
+            && s.fields.len() == 1
+            && s.fields[0].ty.can_cmp_order()
+        {
+            emit_op!("lte", "<=", "&&");
+        }
+        if config.structure.derive_gt(&s.annotations)
+            && s.fields.len() == 1
+            && s.fields[0].ty.can_cmp_order()
+        {
+            emit_op!("gt", ">", "&&");
+        }
+        if config.structure.derive_gte(&s.annotations)
+            && s.fields.len() == 1
+            && s.fields[0].ty.can_cmp_order()
+        {
+            emit_op!("gte", ">=", "&&");
+        }
+    }
+
+    fn emit_bitflags_binop(
+        &self,
+        out: &mut SourceWriter,
+        s: &Struct,
+        constexpr_prefix: &str,
+        operator: char,
+        other: &str,
+    ) {
+        out.new_line();
+        write!(
+            out,
+            "{}{} operator{}(const {}& {}) const",
+            constexpr_prefix,
+            s.export_name(),
+            operator,
+            s.export_name(),
+            other
+        );
+        out.open_brace();
+        write!(
+            out,
+            "return {} {{ static_cast<decltype(bits)>(this->bits {} {}.bits) }};",
+            s.export_name(),
+            operator,
+            other
+        );
+        out.close_brace(false);
+
+        out.new_line();
+        write!(
+            out,
+            "{}& operator{}=(const {}& {})",
+            s.export_name(),
+            operator,
+            s.export_name(),
+            other
+        );
+        out.open_brace();
+        write!(out, "*this = (*this {} {});", operator, other);
+        out.new_line();
+        write!(out, "return *this;");
+        out.close_brace(false);
+    }
+
+    /// Writes the declaration of an associated constant in the body of the
+    /// struct, its definition comes after the struct.
+    fn write_constant_declaration(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: &Struct,
+    ) {
+        debug_assert!(c.associated_to.is_some());
+        debug_assert!(config.language == Language::Cxx);
+        debug_assert!(!associated_to_struct.is_transparent);
+        debug_assert!(config.structure.associated_constants_in_body);
+        debug_assert!(config.constant.allow_static_const);
+
+        if let Type::Ptr { is_const: true, .. } = c.ty {
+            out.write("static ");
+        } else {
+            out.write("static const ");
+        }
+        c.ty.write(config, out);
+        write!(out, " {};", c.export_name())
+    }
+
+    /// Emit the tag enum and convenience methods for it.
+    /// For enums with data this is only a part of the output,
+    /// but for enums without data it's the whole output (modulo doc comments etc.).
+    fn write_tag_enum(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        e: &Enum,
+        size: Option<&str>,
+        has_data: bool,
+        tag_name: &str,
+    ) {
+        // Open the tag enum.
+        if config.language == Language::Cxx {
+            if config.enumeration.enum_class(&e.annotations) {
+                out.write("enum class");
+            } else {
+                out.write("enum");
+            }
+
+            if e.annotations.must_use(config) {
+                if let Some(ref anno) = config.enumeration.must_use {
+                    write!(out, " {}", anno)
+                }
+            }
+
+            write!(out, " {}", tag_name);
+            if let Some(prim) = size {
+                write!(out, " : {}", prim);
+            }
+        } else if let Some(prim) = size {
+            // If we need to specify size, then we have no choice but to create a typedef,
+            // so `config.style` is not respected.
+            write!(out, "enum {}", tag_name);
+
+            if config.cpp_compatible_c() {
+                out.new_line();
+                out.write("#ifdef __cplusplus");
+                out.new_line();
+                write!(out, "  : {}", prim);
+                out.new_line();
+                out.write("#endif // __cplusplus");
+                out.new_line();
+            }
+        } else {
+            if config.style.generate_typedef() {
+                out.write("typedef ");
+            }
+            out.write("enum");
+            if config.style.generate_tag() {
+                write!(out, " {}", tag_name);
+            }
+        }
+        out.open_brace();
+
+        // Emit enumerators for the tag enum.
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line()
+            }
+            write_enum_variant(config, out, variant);
+        }
+
+        // Close the tag enum.
+        if config.language != Language::Cxx && size.is_none() && config.style.generate_typedef() {
+            out.close_brace(false);
+            write!(out, " {};", tag_name);
+        } else {
+            out.close_brace(true);
+        }
+
+        // Emit typedef specifying the tag enum's size if necessary.
+        // In C++ enums can "inherit" from numeric types (`enum E: uint8_t { ... }`),
+        // but in C `typedef uint8_t E` is the only way to give a fixed size to `E`.
+        if let Some(prim) = size {
+            if config.cpp_compatible_c() {
+                out.new_line_if_not_start();
+                out.write("#ifndef __cplusplus");
+            }
+
+            if config.language != Language::Cxx {
+                out.new_line();
+                write!(out, "typedef {} {};", prim, tag_name);
+            }
+
+            if config.cpp_compatible_c() {
+                out.new_line_if_not_start();
+                out.write("#endif // __cplusplus");
+            }
+        }
+
+        // Emit convenience methods for the tag enum.
+        self.write_derived_functions_enum(config, out, e, has_data, tag_name);
+    }
+
+    /// The code here mirrors the beginning of `write_struct` and `write_union`.
+    fn open_struct_or_union(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        e: &Enum,
+        inline_tag_field: bool,
+    ) {
+        if config.language != Language::Cxx && config.style.generate_typedef() {
+            out.write("typedef ");
+        }
+
+        out.write(if inline_tag_field { "union" } else { "struct" });
+
+        if e.annotations.must_use(config) {
+            if let Some(ref anno) = config.structure.must_use {
+                write!(out, " {}", anno);
+            }
+        }
+
+        if config.language == Language::Cxx || config.style.generate_tag() {
+            write!(out, " {}", e.export_name());
+        }
+
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant.
+        if let Some(body) = config.export.pre_body(&e.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+    }
+
+    /// Emit struct definitions for variants having data.
+    fn write_variant_defs(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref body,
+                inline: false,
+                ..
+            } = variant.body
+            {
+                out.new_line();
+                out.new_line();
+                let condition = variant.cfg.to_condition(config);
+                condition.write_before(config, out);
+                self.write_struct(config, out, body);
+                condition.write_after(config, out);
+            }
+        }
+    }
+
+    /// Emit tag field that is separate from all variants.
+    /// For non-inline tag scenario this is *the* tag field, and it does not exist in the variants.
+    /// For the inline tag scenario this is just a convenience and another way
+    /// to refer to the same tag that exist in all the variants.
+    fn write_tag_field(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        size: Option<&str>,
+        inline_tag_field: bool,
+        tag_name: &str,
+    ) {
+        // C++ allows accessing only common initial sequence of union
+        // fields so we have to wrap the tag field into an anonymous struct.
+        let wrap_tag = inline_tag_field && config.language == Language::Cxx;
+
+        if wrap_tag {
+            out.write("struct");
+            out.open_brace();
+        }
+
+        if config.language != Language::Cxx && size.is_none() && !config.style.generate_typedef() {
+            out.write("enum ");
+        }
+
+        write!(out, "{} tag;", tag_name);
+
+        if wrap_tag {
+            out.close_brace(true);
+        }
+    }
+
+    /// Emit fields for all variants with data.
+    fn write_variant_fields(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        let mut first = true;
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                name, body, inline, ..
+            } = &variant.body
+            {
+                if !first {
+                    out.new_line();
+                }
+                first = false;
+                let condition = variant.cfg.to_condition(config);
+                condition.write_before(config, out);
+                if *inline {
+                    // Write definition of an inlined variant with data.
+                    out.write("struct");
+                    out.open_brace();
+                    out.write_vertical_source_list(&body.fields, ListType::Cap(";"));
+                    out.close_brace(true);
+                } else if config.style.generate_typedef() {
+                    write!(out, "{} {};", body.export_name(), name);
+                } else {
+                    write!(out, "struct {} {};", body.export_name(), name);
+                }
+                condition.write_after(config, out);
+            }
+        }
+    }
+
+    // Emit convenience methods for enums themselves.
+    fn write_derived_functions_enum(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        e: &Enum,
+        has_data: bool,
+        tag_name: &str,
+    ) {
+        if config.language != Language::Cxx {
+            return;
+        }
+
+        // Emit an ostream function if required.
+        if config.enumeration.derive_ostream(&e.annotations) {
+            // For enums without data, this emits the serializer function for the
+            // enum. For enums with data, this emits the serializer function for
+            // the tag enum. In the latter case we need a couple of minor changes
+            // due to the function living inside the top-level struct or enum.
+            let stream = config
+                .function
+                .rename_args
+                .apply("stream", IdentifierType::FunctionArg);
+            let instance = config
+                .function
+                .rename_args
+                .apply("instance", IdentifierType::FunctionArg);
+
+            out.new_line();
+            out.new_line();
+            // For enums without data, we mark the function inline because the
+            // header might get included into multiple compilation units that
+            // get linked together, and not marking it inline would result in
+            // multiply-defined symbol errors. For enums with data we don't have
+            // the same problem, but mark it as a friend function of the
+            // containing union/struct.
+            // Note also that for enums with data, the case labels for switch
+            // statements apparently need to be qualified to the top-level
+            // generated struct or union. This is why the generated case labels
+            // below use the A::B::C format for enums with data, with A being
+            // e.export_name(). Failure to have that qualification results
+            // in a surprising compilation failure for the generated header.
+            write!(
+                out,
+                "{} std::ostream& operator<<(std::ostream& {}, const {}& {})",
+                if has_data { "friend" } else { "inline" },
+                stream,
+                tag_name,
+                instance,
+            );
+
+            out.open_brace();
+            if has_data {
+                // C++ name resolution rules are weird.
+                write!(
+                    out,
+                    "using {} = {}::{};",
+                    tag_name,
+                    e.export_name(),
+                    tag_name
+                );
+                out.new_line();
+            }
+            write!(out, "switch ({})", instance);
+            out.open_brace();
+            let vec: Vec<_> = e
+                .variants
+                .iter()
+                .map(|x| {
+                    format!(
+                        "case {}::{}: {} << \"{}\"; break;",
+                        tag_name, x.export_name, stream, x.export_name
+                    )
+                })
+                .collect();
+            out.write_vertical_source_list(&vec[..], ListType::Join(""));
+            out.close_brace(false);
+            out.new_line();
+
+            write!(out, "return {};", stream);
+            out.close_brace(false);
+
+            if has_data {
+                // For enums with data, this emits the serializer function for
+                // the top-level union or struct.
+                out.new_line();
+                out.new_line();
+                write!(
+                    out,
+                    "friend std::ostream& operator<<(std::ostream& {}, const {}& {})",
+                    stream,
+                    e.export_name(),
+                    instance,
+                );
+
+                out.open_brace();
+
+                // C++ name resolution rules are weird.
+                write!(
+                    out,
+                    "using {} = {}::{};",
+                    tag_name,
+                    e.export_name(),
+                    tag_name
+                );
+                out.new_line();
+
+                write!(out, "switch ({}.tag)", instance);
+                out.open_brace();
+                let vec: Vec<_> = e
+                    .variants
+                    .iter()
+                    .map(|x| {
+                        let tag_str = format!("\"{}\"", x.export_name);
+                        if let VariantBody::Body {
+                            ref name, ref body, ..
+                        } = x.body
+                        {
+                            format!(
+                                "case {}::{}: {} << {}{}{}.{}; break;",
+                                tag_name,
+                                x.export_name,
+                                stream,
+                                if body.has_tag_field { "" } else { &tag_str },
+                                if body.has_tag_field { "" } else { " << " },
+                                instance,
+                                name,
+                            )
+                        } else {
+                            format!(
+                                "case {}::{}: {} << {}; break;",
+                                tag_name, x.export_name, stream, tag_str,
+                            )
+                        }
+                    })
+                    .collect();
+                out.write_vertical_source_list(&vec[..], ListType::Join(""));
+                out.close_brace(false);
+                out.new_line();
+
+                write!(out, "return {};", stream);
+                out.close_brace(false);
+            }
+        }
+    }
+
+    // Emit convenience methods for structs or unions produced for enums with data.
+    fn write_derived_functions_data(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        e: &Enum,
+        tag_name: &str,
+    ) {
+        if config.language != Language::Cxx {
+            return;
+        }
+
+        if config.enumeration.derive_helper_methods(&e.annotations) {
+            for variant in &e.variants {
+                out.new_line();
+                out.new_line();
+
+                let condition = variant.cfg.to_condition(config);
+                condition.write_before(config, out);
+
+                let arg_renamer = |name: &str| {
+                    config
+                        .function
+                        .rename_args
+                        .apply(name, IdentifierType::FunctionArg)
+                        .into_owned()
+                };
+
+                macro_rules! write_attrs {
+                    ($op:expr) => {{
+                        if let Some(Some(attrs)) =
+                            variant
+                                .body
+                                .annotations()
+                                .atom(concat!("variant-", $op, "-attributes"))
+                        {
+                            write!(out, "{} ", attrs);
+                        }
+                    }};
+                }
+
+                write_attrs!("constructor");
+                write!(out, "static {} {}(", e.export_name, variant.export_name);
+
+                if let VariantBody::Body { ref body, .. } = variant.body {
+                    let skip_fields = body.has_tag_field as usize;
+                    let vec: Vec<_> = body
+                        .fields
+                        .iter()
+                        .skip(skip_fields)
+                        .map(|field| {
+                            Field::from_name_and_type(
+                                // const-ref args to constructor
+                                arg_renamer(&field.name),
+                                Type::const_ref_to(&field.ty),
+                            )
+                        })
+                        .collect();
+                    out.write_vertical_source_list(&vec[..], ListType::Join(","));
+                }
+
+                write!(out, ")");
+                out.open_brace();
+
+                write!(out, "{} result;", e.export_name);
+
+                if let VariantBody::Body {
+                    name: ref variant_name,
+                    ref body,
+                    ..
+                } = variant.body
+                {
+                    let skip_fields = body.has_tag_field as usize;
+                    for field in body.fields.iter().skip(skip_fields) {
+                        out.new_line();
+                        match field.ty {
+                            Type::Array(ref ty, ref length) => {
+                                // arrays are not assignable in C++ so we
+                                // need to manually copy the elements
+                                write!(out, "for (int i = 0; i < {}; i++)", length.as_str());
+                                out.open_brace();
+                                write!(out, "::new (&result.{}.{}[i]) (", variant_name, field.name);
+                                ty.write(config, out);
+                                write!(out, ")({}[i]);", arg_renamer(&field.name));
+                                out.close_brace(false);
+                            }
+                            ref ty => {
+                                write!(out, "::new (&result.{}.{}) (", variant_name, field.name);
+                                ty.write(config, out);
+                                write!(out, ")({});", arg_renamer(&field.name));
+                            }
+                        }
+                    }
+                }
+
+                out.new_line();
+                write!(out, "result.tag = {}::{};", tag_name, variant.export_name);
+                out.new_line();
+                write!(out, "return result;");
+                out.close_brace(false);
+
+                out.new_line();
+                out.new_line();
+
+                write_attrs!("is");
+                // FIXME: create a config for method case
+                write!(out, "bool Is{}() const", variant.export_name);
+                out.open_brace();
+                write!(out, "return tag == {}::{};", tag_name, variant.export_name);
+                out.close_brace(false);
+
+                let assert_name = match config.enumeration.cast_assert_name {
+                    Some(ref n) => &**n,
+                    None => "assert",
+                };
+
+                let mut derive_casts = |const_casts: bool| {
+                    let (member_name, body, inline_casts) = match variant.body {
+                        VariantBody::Body {
+                            ref name,
+                            ref body,
+                            inline_casts,
+                            ..
+                        } => (name, body, inline_casts),
+                        VariantBody::Empty(..) => return,
+                    };
+
+                    let skip_fields = body.has_tag_field as usize;
+                    let field_count = body.fields.len() - skip_fields;
+                    if field_count == 0 {
+                        return;
+                    }
+
+                    out.new_line();
+                    out.new_line();
+
+                    if const_casts {
+                        write_attrs!("const-cast");
+                    } else {
+                        write_attrs!("mut-cast");
+                    }
+                    if inline_casts {
+                        let field = body.fields.last().unwrap();
+                        let return_type = field.ty.clone();
+                        let return_type = Type::Ptr {
+                            ty: Box::new(return_type),
+                            is_const: const_casts,
+                            is_ref: true,
+                            is_nullable: false,
+                        };
+                        return_type.write(config, out);
+                    } else if const_casts {
+                        write!(out, "const {}&", body.export_name());
+                    } else {
+                        write!(out, "{}&", body.export_name());
+                    }
+
+                    write!(out, " As{}()", variant.export_name);
+                    if const_casts {
+                        write!(out, " const");
+                    }
+                    out.open_brace();
+                    write!(out, "{}(Is{}());", assert_name, variant.export_name);
+                    out.new_line();
+                    write!(out, "return {}", member_name);
+                    if inline_casts {
+                        write!(out, "._0");
+                    }
+                    write!(out, ";");
+                    out.close_brace(false);
+                };
+
+                if config.enumeration.derive_const_casts(&e.annotations) {
+                    derive_casts(true)
+                }
+
+                if config.enumeration.derive_mut_casts(&e.annotations) {
+                    derive_casts(false)
+                }
+
+                condition.write_after(config, out);
+            }
+        }
+
+        let other = config
+            .function
+            .rename_args
+            .apply("other", IdentifierType::FunctionArg);
+
+        macro_rules! write_attrs {
+            ($op:expr) => {{
+                if let Some(Some(attrs)) = e.annotations.atom(concat!($op, "-attributes")) {
+                    write!(out, "{} ", attrs);
+                }
+            }};
+        }
+
+        if e.can_derive_eq() && config.structure.derive_eq(&e.annotations) {
+            out.new_line();
+            out.new_line();
+            write_attrs!("eq");
+            write!(
+                out,
+                "bool operator==(const {}& {}) const",
+                e.export_name, other
+            );
+            out.open_brace();
+            write!(out, "if (tag != {}.tag)", other);
+            out.open_brace();
+            write!(out, "return false;");
+            out.close_brace(false);
+            out.new_line();
+            write!(out, "switch (tag)");
+            out.open_brace();
+            let mut exhaustive = true;
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    name: ref variant_name,
+                    ..
+                } = variant.body
+                {
+                    let condition = variant.cfg.to_condition(config);
+                    condition.write_before(config, out);
+                    write!(
+                        out,
+                        "case {}::{}: return {} == {}.{};",
+                        e.tag.as_ref().unwrap(),
+                        variant.export_name,
+                        variant_name,
+                        other,
+                        variant_name
+                    );
+                    condition.write_after(config, out);
+                    out.new_line();
+                } else {
+                    exhaustive = false;
+                }
+            }
+            if !exhaustive {
+                write!(out, "default: break;");
+            }
+            out.close_brace(false);
+
+            out.new_line();
+            write!(out, "return true;");
+
+            out.close_brace(false);
+
+            if config.structure.derive_neq(&e.annotations) {
+                out.new_line();
+                out.new_line();
+                write_attrs!("neq");
+                write!(
+                    out,
+                    "bool operator!=(const {}& {}) const",
+                    e.export_name, other
+                );
+                out.open_brace();
+                write!(out, "return !(*this == {});", other);
+                out.close_brace(false);
+            }
+        }
+
+        if config
+            .enumeration
+            .private_default_tagged_enum_constructor(&e.annotations)
+        {
+            out.new_line();
+            out.new_line();
+            write!(out, "private:");
+            out.new_line();
+            write!(out, "{}()", e.export_name);
+            out.open_brace();
+            out.close_brace(false);
+            out.new_line();
+            write!(out, "public:");
+            out.new_line();
+        }
+
+        if config
+            .enumeration
+            .derive_tagged_enum_destructor(&e.annotations)
+        {
+            out.new_line();
+            out.new_line();
+            write_attrs!("destructor");
+            write!(out, "~{}()", e.export_name);
+            out.open_brace();
+            write!(out, "switch (tag)");
+            out.open_brace();
+            let mut exhaustive = true;
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref name, ref body, ..
+                } = variant.body
+                {
+                    let condition = variant.cfg.to_condition(config);
+                    condition.write_before(config, out);
+                    write!(
+                        out,
+                        "case {}::{}: {}.~{}(); break;",
+                        e.tag.as_ref().unwrap(),
+                        variant.export_name,
+                        name,
+                        body.export_name(),
+                    );
+                    condition.write_after(config, out);
+                    out.new_line();
+                } else {
+                    exhaustive = false;
+                }
+            }
+            if !exhaustive {
+                write!(out, "default: break;");
+            }
+            out.close_brace(false);
+            out.close_brace(false);
+        }
+
+        if config
+            .enumeration
+            .derive_tagged_enum_copy_constructor(&e.annotations)
+        {
+            out.new_line();
+            out.new_line();
+            write_attrs!("copy-constructor");
+            write!(out, "{}(const {}& {})", e.export_name, e.export_name, other);
+            out.new_line();
+            write!(out, " : tag({}.tag)", other);
+            out.open_brace();
+            write!(out, "switch (tag)");
+            out.open_brace();
+            let mut exhaustive = true;
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref name, ref body, ..
+                } = variant.body
+                {
+                    let condition = variant.cfg.to_condition(config);
+                    condition.write_before(config, out);
+                    write!(
+                        out,
+                        "case {}::{}: ::new (&{}) ({})({}.{}); break;",
+                        e.tag.as_ref().unwrap(),
+                        variant.export_name,
+                        name,
+                        body.export_name(),
+                        other,
+                        name,
+                    );
+                    condition.write_after(config, out);
+                    out.new_line();
+                } else {
+                    exhaustive = false;
+                }
+            }
+            if !exhaustive {
+                write!(out, "default: break;");
+            }
+            out.close_brace(false);
+            out.close_brace(false);
+
+            if config
+                .enumeration
+                .derive_tagged_enum_copy_assignment(&e.annotations)
+            {
+                out.new_line();
+                write_attrs!("copy-assignment");
+                write!(
+                    out,
+                    "{}& operator=(const {}& {})",
+                    e.export_name, e.export_name, other
+                );
+                out.open_brace();
+                write!(out, "if (this != &{})", other);
+                out.open_brace();
+                write!(out, "this->~{}();", e.export_name);
+                out.new_line();
+                write!(out, "new (this) {}({});", e.export_name, other);
+                out.close_brace(false);
+                out.new_line();
+                write!(out, "return *this;");
+                out.close_brace(false);
+            }
+        }
+    }
+
+    fn write_function_horizontal(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        let prefix = config.function.prefix(&f.annotations);
+        let postfix = config.function.postfix(&f.annotations);
+
+        let condition = f.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        f.documentation.write(config, out);
+
+        if f.extern_decl {
+            out.write("extern ");
+        } else {
+            if let Some(ref prefix) = prefix {
+                write!(out, "{} ", prefix);
+            }
+            if f.annotations.must_use(config) {
+                if let Some(ref anno) = config.function.must_use {
+                    write!(out, "{} ", anno);
+                }
+            }
+        }
+        cdecl::write_func(out, f, Layout::Horizontal, config);
+
+        if !f.extern_decl {
+            if let Some(ref postfix) = postfix {
+                write!(out, " {}", postfix);
+            }
+        }
+
+        if let Some(ref swift_name_macro) = config.function.swift_name_macro {
+            if let Some(swift_name) = f.swift_name(config) {
+                write!(out, " {}({})", swift_name_macro, swift_name);
+            }
+        }
+
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_function_vertical(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        let prefix = config.function.prefix(&f.annotations);
+        let postfix = config.function.postfix(&f.annotations);
+
+        let condition = f.cfg.to_condition(config);
+
+        condition.write_before(config, out);
+
+        f.documentation.write(config, out);
+
+        if f.extern_decl {
+            out.write("extern ");
+        } else {
+            if let Some(ref prefix) = prefix {
+                write!(out, "{}", prefix);
+                out.new_line();
+            }
+            if f.annotations.must_use(config) {
+                if let Some(ref anno) = config.function.must_use {
+                    write!(out, "{}", anno);
+                    out.new_line();
+                }
+            }
+        }
+        cdecl::write_func(out, f, Layout::Vertical, config);
+        if !f.extern_decl {
+            if let Some(ref postfix) = postfix {
+                out.new_line();
+                write!(out, "{}", postfix);
+            }
+        }
+
+        if let Some(ref swift_name_macro) = config.function.swift_name_macro {
+            if let Some(swift_name) = f.swift_name(config) {
+                write!(out, " {}({})", swift_name_macro, swift_name);
+            }
+        }
+
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_declarations(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+        if bindings.functions().is_empty() && bindings.globals().is_empty() {
+            return;
+        }
+
+        if config.cpp_compatible_c() {
+            out.new_line_if_not_start();
+            out.write("#ifdef __cplusplus");
+        }
+
+        if config.language == Language::Cxx {
+            if let Some(ref using_namespaces) = config.using_namespaces {
+                for namespace in using_namespaces {
+                    out.new_line();
+                    write!(out, "using namespace {};", namespace);
+                }
+                out.new_line();
+            }
+        }
+
+        if config.language == Language::Cxx || config.cpp_compatible_c() {
+            out.new_line();
+            out.write("extern \"C\" {");
+            out.new_line();
+        }
+
+        if config.cpp_compatible_c() {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+
+        for global in bindings.globals() {
+            out.new_line_if_not_start();
+            self.write_static(config, out, global);
+            out.new_line();
+        }
+
+        for function in bindings.functions() {
+            out.new_line_if_not_start();
+            self.write_function(config, out, function);
+            out.new_line();
+        }
+
+        if config.cpp_compatible_c() {
+            out.new_line();
+            out.write("#ifdef __cplusplus");
+        }
+
+        if config.language == Language::Cxx || config.cpp_compatible_c() {
+            out.new_line();
+            out.write("} // extern \"C\"");
+            out.new_line();
+        }
+
+        if config.cpp_compatible_c() {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+    }
+
+    /// Writes the table of `[loader]`, with a function pointer for each
+    /// function, and the loader filling it in from a library opened with
+    /// `dlopen`.
+    fn write_loader(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+        let (table, loader) = match (&config.loader.table, config.loader.function()) {
+            (Some(table), Some(loader)) => (table, loader),
+            _ => return,
+        };
+        let functions: Vec<_> = bindings
+            .loader_functions()
+            .iter()
+            .filter(|f| !f.extern_decl)
+            .collect();
+        if functions.is_empty() {
+            return;
+        }
+        let null = if config.language == Language::Cxx {
+            "nullptr"
+        } else {
+            "NULL"
+        };
+
+        out.new_line_if_not_start();
+        if config.language == Language::C {
+            write!(out, "typedef struct {}", table);
+        } else {
+            write!(out, "struct {}", table);
+        }
+        self.open_brace(config, out);
+        for (i, f) in functions.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            let condition = f.cfg.to_condition(config);
+            condition.write_before(config, out);
+            if let Some(ref prefix) = config.function.prefix(&f.annotations) {
+                write!(out, "{} ", prefix);
+            }
+            cdecl::write_field(out, &function_pointer(f, true), f.path().name(), config);
+            if let Some(ref postfix) = config.function.postfix(&f.annotations) {
+                write!(out, " {}", postfix);
+            }
+            out.write(";");
+            condition.write_after(config, out);
+        }
+        if config.language == Language::C {
+            self.close_brace(config, out, false);
+            write!(out, " {};", table);
+        } else {
+            self.close_brace(config, out, true);
+        }
+        out.new_line();
+
+        out.new_line();
+        write!(
+            out,
+            "static inline void *{}(const char *path, {} *table)",
+            loader, table
+        );
+        self.open_brace(config, out);
+        out.write("void *handle = dlopen(path, RTLD_NOW | RTLD_LOCAL);");
+        out.new_line();
+        out.write("if (!handle)");
+        self.open_brace(config, out);
+        write!(out, "return {};", null);
+        self.close_brace(config, out, false);
+        for f in &functions {
+            let name = f.path().name();
+            let condition = f.cfg.to_condition(config);
+            out.new_line();
+            condition.write_before(config, out);
+            write!(out, "table->{} = (", name);
+            cdecl::write_type(out, &function_pointer(f, false), config);
+            write!(
+                out,
+                "){}(handle, \"{}\");",
+                config.loader.symbol_lookup(),
+                name
+            );
+            out.new_line();
+            write!(out, "if (!table->{})", name);
+            self.open_brace(config, out);
+            out.write("dlclose(handle);");
+            out.new_line();
+            write!(out, "return {};", null);
+            self.close_brace(config, out, false);
+            condition.write_after(config, out);
+        }
+        out.new_line();
+        out.write("return handle;");
+        self.close_brace(config, out, false);
+        out.new_line();
+    }
+}
+
+impl LanguageBackend for CLikeLanguageBackend {
+    fn write_headers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+
+        if let Some(ref f) = config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(f) = config.include_guard() {
+            out.new_line_if_not_start();
+            write!(out, "#ifndef {}", f);
+            out.new_line();
+            write!(out, "#define {}", f);
+            out.new_line();
+        }
+        if config.pragma_once {
+            out.new_line_if_not_start();
+            write!(out, "#pragma once");
+            out.new_line();
+        }
+        if config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "/* Generated with cbindgen:{} */",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if config.no_includes
+            && config.sys_includes().is_empty()
+            && config.includes().is_empty()
+            && config.after_includes.is_none()
+        {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !config.no_includes {
+            if config.language == Language::Cxx {
+                out.write("#include <cstdarg>");
+                out.new_line();
+                if config.usize_is_size_t || config.layout.static_asserts {
+                    out.write("#include <cstddef>");
+                    out.new_line();
+                }
+                out.write("#include <cstdint>");
+                out.new_line();
+                out.write("#include <cstdlib>");
+                out.new_line();
+                out.write("#include <ostream>");
+                out.new_line();
+                out.write("#include <new>");
+                out.new_line();
+                if bindings.uses_atomics() {
+                    out.write("#include <atomic>");
+                    out.new_line();
+                }
+                if config.loader.table.is_some() {
+                    out.write("#include <dlfcn.h>");
+                    out.new_line();
+                }
+                if config.enumeration.cast_assert_name.is_none()
+                    && (config.enumeration.derive_mut_casts
+                        || config.enumeration.derive_const_casts)
+                {
+                    out.write("#include <cassert>");
+                    out.new_line();
+                }
+            } else {
+                if config.layout.static_asserts {
+                    out.write("#include <assert.h>");
+                    out.new_line();
+                    out.write("#include <stdalign.h>");
+                    out.new_line();
+                }
+                out.write("#include <stdarg.h>");
+                out.new_line();
+                out.write("#include <stdbool.h>");
+                out.new_line();
+                if config.usize_is_size_t || config.layout.static_asserts {
+                    out.write("#include <stddef.h>");
+                    out.new_line();
+                }
+                out.write("#include <stdint.h>");
+                out.new_line();
+                out.write("#include <stdlib.h>");
+                out.new_line();
+                if config.cpp_compatible_c() && bindings.uses_atomics() {
+                    // C++ has no `_Atomic` before C++23's <stdatomic.h>.
+                    out.write("#if defined(__cplusplus) && !defined(_Atomic)");
+                    out.new_line();
+                    out.write("#include <atomic>");
+                    out.new_line();
+                    out.write("#define _Atomic(T) std::atomic<T>");
+                    out.new_line();
+                    out.write("#endif");
+                    out.new_line();
+                }
+                if config.loader.table.is_some() {
+                    out.write("#include <dlfcn.h>");
+                    out.new_line();
+                }
+            }
+        }
+
+        for include in config.sys_includes() {
+            write!(out, "#include <{}>", include);
+            out.new_line();
+        }
+
+        for include in config.includes() {
+            write!(out, "#include \"{}\"", include);
+            out.new_line();
+        }
+
+        if let Some(ref line) = config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.open_close_namespaces(&bindings.config, NamespaceOperation::Open, out);
+    }
+
+    fn close_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.open_close_namespaces(&bindings.config, NamespaceOperation::Close, out);
+    }
+
+    fn write_footers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        if let Some(f) = bindings.config.include_guard() {
+            out.new_line_if_not_start();
+            if bindings.config.language == Language::Cxx {
+                write!(out, "#endif // {}", f);
+            } else {
+                write!(out, "#endif /* {} */", f);
+            }
+            out.new_line();
+        }
+    }
+
+    fn write_globals_and_functions(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.write_declarations(bindings, out);
+        self.write_loader(bindings, out);
+    }
+
+    fn write_enum(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        let size = e.repr.ty.map(|ty| ty.to_primitive().to_repr_c(config));
+        let has_data = e.tag.is_some();
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        let tag_name = e.tag_name();
+
+        let condition = e.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        e.documentation.write(config, out);
+        self.write_generic_params(config, out, &e.generic_params, false);
+
+        // If the enum has data, we need to emit a struct or union for the data
+        // and enum for the tag. C++ supports nested type definitions, so we open
+        // the struct or union here and define the tag enum inside it (*).
+        if has_data && config.language == Language::Cxx {
+            self.open_struct_or_union(config, out, e, inline_tag_field);
+        }
+
+        // Emit the tag enum and everything related to it.
+        self.write_tag_enum(config, out, e, size, has_data, tag_name);
+
+        // If the enum has data, we need to emit structs for the variants and gather them together.
+        if has_data {
+            self.write_variant_defs(config, out, e);
+            out.new_line();
+            out.new_line();
+
+            // Open the struct or union for the data (**), gathering all the variants with data
+            // together, unless it's C++, then we have already opened that struct/union at (*) and
+            // are currently inside it.
+            if config.language != Language::Cxx {
+                self.open_struct_or_union(config, out, e, inline_tag_field);
+            }
+
+            // Emit tag field that is separate from all variants.
+            self.write_tag_field(config, out, size, inline_tag_field, tag_name);
+            out.new_line();
+
+            // Open union of all variants with data, only in the non-inline tag scenario.
+            if !inline_tag_field {
+                out.write("union");
+                out.open_brace();
+            }
+
+            // Emit fields for all variants with data.
+            self.write_variant_fields(config, out, e);
+
+            // Close union of all variants with data, only in the non-inline tag scenario.
+            if !inline_tag_field {
+                out.close_brace(true);
+            }
+
+            // Emit convenience methods for the struct or enum for the data.
+            self.write_derived_functions_data(config, out, e, tag_name);
+
+            // Emit the post_body section, if relevant.
+            if let Some(body) = config.export.post_body(&e.path) {
+                out.new_line();
+                out.write_raw_block(body);
+            }
+
+            // Close the struct or union opened either at (*) or at (**).
+            if config.language != Language::Cxx && config.style.generate_typedef() {
+                out.close_brace(false);
+                write!(out, " {};", e.export_name);
+            } else {
+                out.close_brace(true);
+            }
+
+            let keyword = if inline_tag_field { "union" } else { "struct" };
+            self.write_layout_asserts(config, out, &e.path, e.export_name(), Some(keyword));
+        } else {
+            // Enums with a size are always typedef'd in C.
+            let keyword = if size.is_none() { Some("enum") } else { None };
+            self.write_layout_asserts(config, out, &e.path, tag_name, keyword);
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_struct(&self, config: &Config, out: &mut SourceWriter, s: &Struct) {
+        if s.is_transparent {
+            self.write_type_def(config, out, &s.as_typedef());
+            for constant in &s.associated_constants {
+                out.new_line();
+                constant.write(config, out, Some(s));
+            }
+            return;
+        }
+
+        let condition = s.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_pack_push(config, out, s.alignment);
+
+        s.documentation.write(config, out);
+
+        if !s.is_enum_variant_body {
+            self.write_generic_params(config, out, &s.generic_params, false);
+        }
+
+        // The following results in
+        // C++ or C with Tag as style:
+        //   struct Name {
+        // C with Type only style:
+        //   typedef struct {
+        // C with Both as style:
+        //   typedef struct Name {
+        if config.language != Language::Cxx && config.style.generate_typedef() {
+            out.write("typedef ");
+        }
+
+        out.write("struct");
+
+        self.write_alignment(config, out, s.alignment);
+
+        if s.annotations.must_use(config) {
+            if let Some(ref anno) = config.structure.must_use {
+                write!(out, " {}", anno);
+            }
+        }
+
+        if config.language == Language::Cxx || config.style.generate_tag() {
+            write!(out, " {}", s.export_name());
+        }
+
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = config.export.pre_body(&s.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        out.write_vertical_source_list(&s.fields, ListType::Cap(";"));
+
+        if config.language == Language::Cxx {
+            self.write_struct_derives(config, out, s);
+        }
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = config.export.post_body(&s.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        if config.language == Language::Cxx
+            && config.structure.associated_constants_in_body
+            && config.constant.allow_static_const
+        {
+            for constant in &s.associated_constants {
+                out.new_line();
+                self.write_constant_declaration(config, out, constant, s);
+            }
+        }
+
+        if config.language != Language::Cxx && config.style.generate_typedef() {
+            out.close_brace(false);
+            write!(out, " {};", s.export_name());
+        } else {
+            out.close_brace(true);
+        }
+
+        self.write_pack_pop(config, out, s.alignment);
+
+        self.write_layout_asserts(config, out, &s.path, s.export_name(), Some("struct"));
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(config, out, Some(s));
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_union(&self, config: &Config, out: &mut SourceWriter, u: &Union) {
+        let condition = u.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_pack_push(config, out, u.alignment);
+
+        u.documentation.write(config, out);
+
+        self.write_generic_params(config, out, &u.generic_params, false);
+
+        // The following results in
+        // C++ or C with Tag as style:
+        //   union Name {
+        // C with Type only style:
+        //   typedef union {
+        // C with Both as style:
+        //   typedef union Name {
+        if config.language != Language::Cxx && config.style.generate_typedef() {
+            out.write("typedef ");
+        }
+
+        out.write("union");
+
+        self.write_alignment(config, out, u.alignment);
+
+        if config.language == Language::Cxx || config.style.generate_tag() {
+            write!(out, " {}", u.export_name);
+        }
+
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = config.export.pre_body(&u.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        out.write_vertical_source_list(&u.fields, ListType::Cap(";"));
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = config.export.post_body(&u.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        if config.language != Language::Cxx && config.style.generate_typedef() {
+            out.close_brace(false);
+            write!(out, " {};", u.export_name);
+        } else {
+            out.close_brace(true);
+        }
+
+        self.write_pack_pop(config, out, u.alignment);
+
+        self.write_layout_asserts(config, out, &u.path, &u.export_name, Some("union"));
+
+        condition.write_after(config, out);
+    }
+
+    fn write_opaque_item(&self, config: &Config, out: &mut SourceWriter, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        o.documentation.write(config, out);
+
+        self.write_generic_params(config, out, &o.generic_params, true);
+
+        if config.language != Language::Cxx && config.style.generate_typedef() {
+            write!(
+                out,
+                "typedef struct {} {};",
+                o.export_name(),
+                o.export_name()
+            );
+        } else {
+            write!(out, "struct {};", o.export_name());
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_type_def(&self, config: &Config, out: &mut SourceWriter, t: &Typedef) {
+        let condition = t.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        t.documentation.write(config, out);
+
+        self.write_generic_params(config, out, &t.generic_params, false);
+
+        if config.language == Language::Cxx {
+            write!(out, "using {} = ", t.export_name());
+            t.aliased.write(config, out);
+        } else {
+            out.write("typedef ");
+            Field::from_name_and_type(t.export_name().to_owned(), t.aliased.clone())
+                .write(config, out);
+        }
+
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_static(&self, config: &Config, out: &mut SourceWriter, s: &Static) {
+        s.documentation.write(config, out);
+
+        out.write("extern ");
+        if let Type::Ptr { is_const: true, .. } = s.ty {
+        } else if !s.mutable {
+            out.write("const ");
+        }
+        cdecl::write_field(out, &s.ty, &s.export_name, config);
+        out.write(";");
+    }
+
+    fn write_function(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        match config.function.args {
+            Layout::Horizontal => self.write_function_horizontal(config, out, f),
+            Layout::Vertical => self.write_function_vertical(config, out, f),
+            Layout::Auto => {
+                if !out.try_write(
+                    |out| self.write_function_horizontal(config, out, f),
+                    config.line_length,
+                ) {
+                    self.write_function_vertical(config, out, f)
+                }
+            }
+        }
+    }
+
+    fn write_constant(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        if !c.can_write(out.bindings(), associated_to_struct) {
+            return;
+        }
+
+        let associated_to_transparent = associated_to_struct.map_or(false, |s| s.is_transparent);
+
+        let in_body = associated_to_struct.is_some()
+            && config.language == Language::Cxx
+            && config.structure.associated_constants_in_body
+            && config.constant.allow_static_const
+            && !associated_to_transparent;
+
+        let condition = c.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        let name = if in_body {
+            Cow::Owned(format!(
+                "{}::{}",
+                associated_to_struct.unwrap().export_name(),
+                c.export_name(),
+            ))
+        } else {
+            c.prefixed_name(config, associated_to_struct)
+        };
+
+        let value = c.written_value(out.bindings());
+
+        c.documentation.write(config, out);
+
+        let allow_constexpr = config.constant.allow_constexpr && c.value.can_be_constexpr();
+        if config.language == Language::Cxx
+            && (config.constant.allow_static_const || allow_constexpr)
+        {
+            if allow_constexpr {
+                out.write("constexpr ")
+            }
+
+            if config.constant.allow_static_const {
+                out.write(if in_body { "inline " } else { "static " });
+            }
+
+            if let Type::Ptr { is_const: true, .. } = c.ty {
+                // Nothing.
+            } else {
+                out.write("const ");
+            }
+
+            c.ty.write(config, out);
+            write!(out, " {} = ", name);
+            value.write(config, out);
+            write!(out, ";");
+        } else {
+            write!(out, "#define {} ", name);
+            value.write(config, out);
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_field(&self, config: &Config, out: &mut SourceWriter, f: &Field) {
+        let condition = f.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        f.documentation.write(config, out);
+        // ISO C++ has no flexible array members, so it keeps the `[0]` of gcc.
+        if f.flexible_array && config.language != Language::Cxx {
+            cdecl::write_flexible_array_field(out, &f.ty, &f.name, config);
+        } else {
+            cdecl::write_field(out, &f.ty, &f.name, config);
+        }
+        if let Some(bitfield) = f.annotations.atom("bitfield") {
+            write!(out, ": {}", bitfield.unwrap_or_default());
+        }
+
+        condition.write_after(config, out);
+        // FIXME(#634): `write_vertical_source_list` should support
+        // configuring list elements natively. For now we print a newline
+        // here to avoid printing `#endif;` with semicolon.
+        if condition.is_some() {
+            out.new_line();
+        }
+    }
+
+    fn write_type(&self, config: &Config, out: &mut SourceWriter, t: &Type) {
+        cdecl::write_type(out, t, config);
+    }
+
+    fn write_literal(&self, config: &Config, out: &mut SourceWriter, l: &Literal) {
+        match l {
+            Literal::Expr(v) => write!(out, "{}", v),
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(known) = self.known_assoc_constant(path, name) {
+                        return write!(out, "{}", known);
+                    }
+                    let path_separator = if config.language == Language::Cxx
+                        && config.structure.associated_constants_in_body
+                    {
+                        "::"
+                    } else {
+                        "_"
+                    };
+                    write!(out, "{}{}", export_name, path_separator)
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                base.write(config, out);
+                write!(out, ").{}", field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                value.write(config, out);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                write!(out, "(");
+                left.write(config, out);
+                write!(out, " {} ", op);
+                right.write(config, out);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                out.write("(");
+                ty.write(config, out);
+                out.write(")");
+                value.write(config, out);
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                if config.language == Language::Cxx {
+                    write!(out, "{}", export_name);
+                } else {
+                    write!(out, "({})", export_name);
+                }
+
+                out.write("{ ");
+                let mut is_first_field = true;
+                // In C++, same order as defined is required.
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        } else {
+                            is_first_field = false;
+                        }
+                        if config.language == Language::Cxx {
+                            write!(out, "/* .{} = */ ", ordered_key);
+                        } else {
+                            write!(out, ".{} = ", ordered_key);
+                        }
+                        lit.write(config, out);
+                    }
+                }
+                out.write(" }");
+            }
+        }
+    }
+
+    fn write_documentation(&self, config: &Config, out: &mut SourceWriter, d: &Documentation) {
+        if d.doc_comment.is_empty() || !config.documentation {
+            return;
+        }
+
+        let end = match config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        let style = match config.documentation_style {
+            DocumentationStyle::Auto if config.language == Language::Cxx => DocumentationStyle::Cxx,
+            DocumentationStyle::Auto => DocumentationStyle::Doxy,
+            other => other,
+        };
+
+        // Following these documents for style conventions:
+        // https://en.wikibooks.org/wiki/C++_Programming/Code/Style_Conventions/Comments
+        // https://www.cs.cmu.edu/~410/doc/doxygen.html
+        match style {
+            DocumentationStyle::C => {
+                out.write("/*");
+                out.new_line();
+            }
+
+            DocumentationStyle::Doxy => {
+                out.write("/**");
+                out.new_line();
+            }
+
+            _ => (),
+        }
+
+        for line in &d.doc_comment[..end] {
+            match style {
+                DocumentationStyle::C => out.write(""),
+                DocumentationStyle::Doxy => out.write(" *"),
+                DocumentationStyle::C99 => out.write("//"),
+                DocumentationStyle::Cxx => out.write("///"),
+                DocumentationStyle::Auto => unreachable!(), // Auto case should always be covered
+            }
+
+            write!(out, "{}", line);
+            out.new_line();
+        }
+
+        match style {
+            DocumentationStyle::C => {
+                out.write(" */");
+                out.new_line();
+            }
+
+            DocumentationStyle::Doxy => {
+                out.write(" */");
+                out.new_line();
+            }
+
+            _ => (),
+        }
+    }
+
+    fn check_calling_convention(&self, config: &Config, abi: Abi, _: bool, _: bool) {
+        if config.function.abi_macro(abi).is_none() {
+            warn!(
+                "There's no `[fn.abi_macros]` entry for `extern \"{}\"`, so functions and \
+                 function pointers using it are declared as `extern \"C\"` ones.",
+                abi.to_repr_rust()
+            );
+        }
+    }
+
+    fn known_assoc_constant(&self, associated_to: &Path, name: &str) -> Option<String> {
+        to_known_assoc_constant(associated_to, name)
+    }
+
+    fn write_condition_before(&self, _config: &Config, out: &mut SourceWriter, c: &Condition) {
+        out.push_set_spaces(0);
+        out.write("#if ");
+        write_condition_expr(out, c);
+        out.pop_set_spaces();
+        out.new_line();
+    }
+
+    fn write_condition_after(&self, _config: &Config, out: &mut SourceWriter, _c: &Condition) {
+        out.new_line();
+        out.push_set_spaces(0);
+        out.write("#endif");
+        out.pop_set_spaces();
+    }
+}
@@ -0,0 +1,736 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::borrow::Cow;
+
+use crate::bindgen::config::{Config, DocumentationLength};
+use crate::bindgen::csdecl;
+use crate::bindgen::ir::{
+    Abi, Condition, ConditionWrite, Constant, Documentation, Enum, Field, Function, GenericPath,
+    IntKind, Item, ItemContainer, Literal, OpaqueItem, Path, PrimitiveType, ReprAlign, Static,
+    Struct, ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::clike::write_enum_variant;
+use crate::bindgen::language_backend::{exported_items, LanguageBackend};
+use crate::bindgen::writer::{Source, SourceWriter};
+use crate::bindgen::Bindings;
+
+/// The backend for C# P/Invoke declarations.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CSharpLanguageBackend;
+
+/// Literals are stored with C syntax, C# does have suffixes for 64 bits
+/// integers, just not the same ones as C.
+fn to_csharp_literal(v: &str) -> Cow<'_, str> {
+    if let Some(code) = v
+        .strip_prefix(r"U'\U")
+        .and_then(|code| code.strip_suffix('\''))
+    {
+        // C#'s `char` is UTF-16, so this is used as a number instead.
+        return Cow::Owned(format!("0x{}", code));
+    }
+    if let Some(code) = v
+        .strip_prefix(r"'\u{")
+        .and_then(|code| code.strip_suffix("}'"))
+    {
+        return Cow::Owned(format!(r"'\u{:0>4}'", code));
+    }
+    if let Some(digits) = v.strip_suffix("ull") {
+        return Cow::Owned(format!("{}UL", digits));
+    }
+    if let Some(digits) = v.strip_suffix("ll") {
+        return Cow::Owned(format!("{}L", digits));
+    }
+    Cow::Borrowed(v)
+}
+
+/// Writes a condition as a C# preprocessor expression, where the symbols are
+/// used as is rather than with `defined(...)`.
+fn write_condition_expr(out: &mut SourceWriter, condition: &Condition) {
+    match *condition {
+        Condition::Define(ref define) => write!(out, "{}", define),
+        Condition::Any(ref conditions) => {
+            out.write("(");
+            for (i, condition) in conditions.iter().enumerate() {
+                if i != 0 {
+                    out.write(" || ");
+                }
+                write_condition_expr(out, condition);
+            }
+            out.write(")");
+        }
+        Condition::All(ref conditions) => {
+            out.write("(");
+            for (i, condition) in conditions.iter().enumerate() {
+                if i != 0 {
+                    out.write(" && ");
+                }
+                write_condition_expr(out, condition);
+            }
+            out.write(")");
+        }
+        Condition::Not(ref condition) => {
+            out.write("!");
+            write_condition_expr(out, condition);
+        }
+    }
+}
+
+impl CSharpLanguageBackend {
+    fn namespaces<'a>(&self, config: &'a Config) -> Vec<&'a str> {
+        let mut ret = vec![];
+        if let Some(ref namespace) = config.namespace {
+            ret.push(&**namespace);
+        }
+        if let Some(ref namespaces) = config.namespaces {
+            for namespace in namespaces {
+                ret.push(&**namespace);
+            }
+        }
+        ret
+    }
+
+    /// Writes the fields of a C# struct, each with a `[FieldOffset(0)]` if
+    /// it's `explicit`, that is a union.
+    fn write_fields(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        fields: &[Field],
+        explicit: bool,
+    ) {
+        for (i, field) in fields.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+
+            let condition = field.cfg.to_condition(config);
+            condition.write_before(config, out);
+
+            field.documentation.write(config, out);
+            if explicit {
+                out.write("[FieldOffset(0)] ");
+            }
+            if field.annotations.atom("bitfield").is_some() {
+                warn!(
+                    "Bitfield `{}` can't be represented in C#, emitting the whole field.",
+                    field.name
+                );
+            }
+            csdecl::write_field(out, &field.ty, &field.name, config);
+
+            condition.write_after(config, out);
+        }
+    }
+}
+
+impl LanguageBackend for CSharpLanguageBackend {
+    fn write_headers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+
+        if let Some(ref f) = config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if config.no_includes && config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !config.no_includes {
+            out.write("using System;");
+            out.new_line();
+            if config.csharp.library_import {
+                out.write("using System.Runtime.CompilerServices;");
+                out.new_line();
+            }
+            out.write("using System.Runtime.InteropServices;");
+            out.new_line();
+        }
+
+        if let Some(ref line) = config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    /// Everything is declared in a static class, since C# doesn't allow
+    /// constants and functions outside of one.
+    fn open_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+        let namespaces = self.namespaces(config);
+
+        out.new_line_if_not_start();
+        if !namespaces.is_empty() {
+            write!(out, "namespace {}", namespaces.join("."));
+            out.open_brace();
+        }
+        out.write("public static ");
+        if config.csharp.use_unsafe {
+            out.write("unsafe ");
+        }
+        write!(out, "partial class {}", config.csharp.class());
+        out.open_brace();
+
+        // Functions without a library to load them from are looked up in the
+        // executable itself.
+        let library = config.csharp.library.as_deref().unwrap_or("__Internal");
+        write!(out, "const string __DllName = \"{}\";", library);
+        out.new_line();
+
+        if !bindings.globals().is_empty() {
+            out.new_line();
+            write!(
+                out,
+                "static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof({}).Assembly, null), name);",
+                config.csharp.class()
+            );
+            out.new_line();
+        }
+    }
+
+    fn close_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        // Every item already ends with a new line, so this doesn't use
+        // `close_brace`, which would add another one.
+        out.pop_tab();
+        out.write("}");
+        if !self.namespaces(&bindings.config).is_empty() {
+            out.new_line();
+            out.pop_tab();
+            out.write("}");
+        }
+        out.new_line();
+    }
+
+    fn write_footers(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn write_items(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        for item in exported_items(bindings) {
+            // C# has no type aliases, they're replaced by what they alias
+            // wherever they're used instead.
+            match *item {
+                ItemContainer::Typedef(..) => continue,
+                ItemContainer::Struct(ref x)
+                    if x.is_transparent && x.associated_constants.is_empty() =>
+                {
+                    continue
+                }
+                _ => {}
+            }
+
+            out.new_line_if_not_start();
+            self.write_item(&bindings.config, out, item);
+            out.new_line();
+        }
+    }
+
+    fn write_globals_and_functions(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        for global in bindings.globals() {
+            out.new_line_if_not_start();
+            self.write_static(&bindings.config, out, global);
+            out.new_line();
+        }
+
+        // Functions with calling conventions C# can't import are left out,
+        // the library warns about them.
+        let functions = bindings
+            .functions()
+            .iter()
+            .filter(|f| csdecl::supports_function(f.abi, &bindings.config));
+        for function in functions {
+            out.new_line_if_not_start();
+            self.write_function(&bindings.config, out, function);
+            out.new_line();
+        }
+    }
+
+    /// C# has no unions, so these are structs with an explicit layout instead,
+    /// and the variants are all defined as separate structs.
+    fn write_enum(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        let condition = e.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        e.documentation.write(config, out);
+
+        write!(out, "public enum {}", e.tag_name());
+        if let Some(ty) = e.repr.ty {
+            let prim = match ty.to_primitive() {
+                PrimitiveType::Integer {
+                    kind: IntKind::Size,
+                    signed,
+                    zeroable,
+                } => {
+                    warn!(
+                        "C# enums can't have a pointer-sized type, assuming `{}` is 64 bits.",
+                        e.tag_name()
+                    );
+                    PrimitiveType::Integer {
+                        kind: IntKind::B64,
+                        signed,
+                        zeroable,
+                    }
+                }
+                prim => prim,
+            };
+            write!(out, " : {}", prim.to_repr_csharp());
+        }
+        out.open_brace();
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write_enum_variant(config, out, variant);
+        }
+        out.close_brace(false);
+
+        if e.tag.is_none() {
+            condition.write_after(config, out);
+            return;
+        }
+
+        // Emit struct definitions for variants having data.
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref body,
+                inline: false,
+                ..
+            } = variant.body
+            {
+                out.new_line();
+                out.new_line();
+                let condition = variant.cfg.to_condition(config);
+                condition.write_before(config, out);
+                self.write_struct(config, out, body);
+                condition.write_after(config, out);
+            }
+        }
+        out.new_line();
+        out.new_line();
+
+        let mut fields = vec![];
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref name, ref body, ..
+            } = variant.body
+            {
+                let mut field = Field::from_name_and_type(
+                    name.clone(),
+                    Type::Path(GenericPath::new(Path::new(body.export_name()), vec![])),
+                );
+                field.cfg = variant.cfg.clone();
+                fields.push(field);
+            }
+        }
+        let tag = Field::from_name_and_type(
+            "tag".to_owned(),
+            Type::Path(GenericPath::new(Path::new(e.tag_name()), vec![])),
+        );
+
+        if Enum::inline_tag_field(&e.repr) {
+            fields.insert(0, tag);
+            out.write("[StructLayout(LayoutKind.Explicit)]");
+            out.new_line();
+            write!(out, "public struct {}", e.export_name());
+            out.open_brace();
+            self.write_fields(config, out, &fields, true);
+        } else {
+            // The union of the variants is nested, as it has no name in C.
+            out.write("[StructLayout(LayoutKind.Sequential)]");
+            out.new_line();
+            write!(out, "public struct {}", e.export_name());
+            out.open_brace();
+            let body = Field::from_name_and_type(
+                "body".to_owned(),
+                Type::Path(GenericPath::new(Path::new("Body"), vec![])),
+            );
+            self.write_fields(config, out, &[tag, body], false);
+            out.new_line();
+            out.new_line();
+            out.write("[StructLayout(LayoutKind.Explicit)]");
+            out.new_line();
+            out.write("public struct Body");
+            out.open_brace();
+            self.write_fields(config, out, &fields, true);
+            out.close_brace(false);
+        }
+        out.close_brace(false);
+
+        condition.write_after(config, out);
+    }
+
+    fn write_struct(&self, config: &Config, out: &mut SourceWriter, s: &Struct) {
+        // C# has no type aliases, so there's only the constants of transparent
+        // structs to write.
+        if s.is_transparent {
+            for (i, constant) in s.associated_constants.iter().enumerate() {
+                if i != 0 {
+                    out.new_line();
+                }
+                constant.write(config, out, Some(s));
+            }
+            return;
+        }
+
+        let condition = s.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        s.documentation.write(config, out);
+
+        match s.alignment {
+            Some(ReprAlign::Packed) => {
+                out.write("[StructLayout(LayoutKind.Sequential, Pack = 1)]");
+            }
+            Some(ReprAlign::PackedN(n)) => {
+                write!(out, "[StructLayout(LayoutKind.Sequential, Pack = {})]", n);
+            }
+            Some(ReprAlign::Align(..)) => {
+                warn!(
+                    "Alignment of `{}` can't be represented in C#, ignoring it.",
+                    s.export_name
+                );
+                out.write("[StructLayout(LayoutKind.Sequential)]");
+            }
+            None => out.write("[StructLayout(LayoutKind.Sequential)]"),
+        }
+        out.new_line();
+        write!(out, "public struct {}", s.export_name());
+        out.open_brace();
+
+        if let Some(body) = config.export.pre_body(&s.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(config, out, &s.fields, false);
+
+        if let Some(body) = config.export.post_body(&s.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        out.close_brace(false);
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(config, out, Some(s));
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_union(&self, config: &Config, out: &mut SourceWriter, u: &Union) {
+        let condition = u.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        u.documentation.write(config, out);
+
+        match u.alignment {
+            Some(ReprAlign::Packed) => out.write("[StructLayout(LayoutKind.Explicit, Pack = 1)]"),
+            Some(ReprAlign::PackedN(n)) => {
+                write!(out, "[StructLayout(LayoutKind.Explicit, Pack = {})]", n)
+            }
+            _ => out.write("[StructLayout(LayoutKind.Explicit)]"),
+        }
+        out.new_line();
+        write!(out, "public struct {}", u.export_name);
+        out.open_brace();
+        self.write_fields(config, out, &u.fields, true);
+        out.close_brace(false);
+
+        condition.write_after(config, out);
+    }
+
+    fn write_opaque_item(&self, config: &Config, out: &mut SourceWriter, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        o.documentation.write(config, out);
+
+        write!(out, "public struct {} {{ }}", o.export_name());
+
+        condition.write_after(config, out);
+    }
+
+    /// C# has no type aliases, uses of this are replaced by the aliased type
+    /// instead, see `Bindings::aliased_type`.
+    fn write_type_def(&self, _config: &Config, _out: &mut SourceWriter, _t: &Typedef) {}
+
+    /// P/Invoke can only import functions, so this gives the address of the
+    /// static instead.
+    fn write_static(&self, config: &Config, out: &mut SourceWriter, s: &Static) {
+        s.documentation.write(config, out);
+
+        let ptr = Type::Ptr {
+            ty: Box::new(s.ty.clone()),
+            is_const: !s.mutable,
+            is_nullable: false,
+            is_ref: false,
+        };
+        out.write("public static ");
+        ptr.write(config, out);
+        write!(out, " {} => ", s.export_name);
+        if config.csharp.use_unsafe {
+            out.write("(");
+            ptr.write(config, out);
+            out.write(")");
+        }
+        write!(out, "__GetExport(\"{}\");", s.export_name);
+    }
+
+    fn write_function(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        let condition = f.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        f.documentation.write(config, out);
+
+        // The prefix / postfix attributes are for C compilers.
+        csdecl::write_func(out, f, config.function.args.clone(), config);
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_constant(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        if !c.can_write(out.bindings(), associated_to_struct) {
+            return;
+        }
+
+        let condition = c.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        let name = c.prefixed_name(config, associated_to_struct);
+        let value = c.written_value(out.bindings());
+
+        c.documentation.write(config, out);
+
+        // Only built-in types can be `const`.
+        let ty = csdecl::resolve(out, &c.ty);
+        let is_const = match ty {
+            Type::Primitive(PrimitiveType::Integer {
+                kind: IntKind::Long | IntKind::B128,
+                ..
+            })
+            | Type::Primitive(PrimitiveType::Float16)
+            | Type::Primitive(PrimitiveType::VaList) => false,
+            Type::Primitive(..) => true,
+            _ => false,
+        };
+        out.write(if is_const {
+            "public const "
+        } else {
+            "public static readonly "
+        });
+        c.ty.write(config, out);
+        write!(out, " {} = ", name);
+        // Float literals are `double`s.
+        match ty {
+            Type::Primitive(PrimitiveType::Float) => out.write("(float)"),
+            Type::Primitive(PrimitiveType::Float16) => out.write("(Half)"),
+            _ => {}
+        }
+        // Unlike in C, the variants of an enum are only reachable through it.
+        match *value {
+            Literal::Path {
+                associated_to: None,
+                name: ref variant,
+            } => match out.bindings().enum_variant(&ty, variant) {
+                Some((e, variant)) => write!(out, "{}.{}", e, variant),
+                None => value.write(config, out),
+            },
+            _ => value.write(config, out),
+        }
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_field(&self, config: &Config, out: &mut SourceWriter, f: &Field) {
+        self.write_fields(config, out, std::slice::from_ref(f), false);
+    }
+
+    fn write_type(&self, config: &Config, out: &mut SourceWriter, t: &Type) {
+        csdecl::write_type(out, t, config);
+    }
+
+    fn write_literal(&self, config: &Config, out: &mut SourceWriter, l: &Literal) {
+        match l {
+            Literal::Expr(v) => write!(out, "{}", to_csharp_literal(v)),
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(known) = self.known_assoc_constant(path, name) {
+                        return write!(out, "{}", known);
+                    }
+                    write!(out, "{}_", export_name)
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                base.write(config, out);
+                write!(out, ").{}", field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                value.write(config, out);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                write!(out, "(");
+                left.write(config, out);
+                write!(out, " {} ", op);
+                right.write(config, out);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                // Rust's `as` truncates, which is an error in a C# constant
+                // expression unless explicitly allowed.
+                out.write("unchecked((");
+                ty.write(config, out);
+                out.write(")");
+                value.write(config, out);
+                out.write(")");
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                write!(out, "new {} ", export_name);
+
+                out.write("{ ");
+                let mut is_first_field = true;
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        } else {
+                            is_first_field = false;
+                        }
+                        write!(out, "{} = ", ordered_key);
+                        lit.write(config, out);
+                    }
+                }
+                out.write(" }");
+            }
+        }
+    }
+
+    /// C# doc comments are XML.
+    fn write_documentation(&self, config: &Config, out: &mut SourceWriter, d: &Documentation) {
+        if d.doc_comment.is_empty() || !config.documentation {
+            return;
+        }
+
+        let end = match config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        out.write("/// <summary>");
+        out.new_line();
+        for line in &d.doc_comment[..end] {
+            let line = line
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            write!(out, "///{}", line);
+            out.new_line();
+        }
+        out.write("/// </summary>");
+        out.new_line();
+    }
+
+    fn write_condition_before(&self, _config: &Config, out: &mut SourceWriter, c: &Condition) {
+        out.push_set_spaces(0);
+        out.write("#if ");
+        write_condition_expr(out, c);
+        out.pop_set_spaces();
+        out.new_line();
+    }
+
+    fn write_condition_after(&self, _config: &Config, out: &mut SourceWriter, _c: &Condition) {
+        out.new_line();
+        out.push_set_spaces(0);
+        out.write("#endif");
+        out.pop_set_spaces();
+    }
+
+    /// There's no quad float in .NET.
+    fn supports_primitive(&self, primitive: &PrimitiveType) -> bool {
+        *primitive != PrimitiveType::Float128
+    }
+
+    fn check_calling_convention(
+        &self,
+        config: &Config,
+        abi: Abi,
+        in_functions: bool,
+        in_pointers: bool,
+    ) {
+        if in_functions && !csdecl::supports_function(abi, config) {
+            warn!(
+                "C# bindings can't import `extern \"{}\"` functions, so they're left out.",
+                abi.to_repr_rust()
+            );
+        }
+        if in_pointers && !csdecl::supports_pointer(abi) {
+            warn!(
+                "C# bindings can't express `extern \"{}\"` function pointers, so they're \
+                 written as opaque pointers.",
+                abi.to_repr_rust()
+            );
+        }
+    }
+
+    fn known_assoc_constant(&self, associated_to: &Path, name: &str) -> Option<String> {
+        if name != "MAX" && name != "MIN" {
+            return None;
+        }
+
+        match PrimitiveType::maybe(associated_to.name())? {
+            prim @ PrimitiveType::Integer {
+                kind: IntKind::B8 | IntKind::B16 | IntKind::B32 | IntKind::B64,
+                ..
+            } => Some(format!(
+                "{}.{}Value",
+                prim.to_repr_csharp(),
+                if name == "MAX" { "Max" } else { "Min" }
+            )),
+            _ => None,
+        }
+    }
+}
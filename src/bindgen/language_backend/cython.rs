@@ -0,0 +1,607 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{Config, DocumentationLength};
+use crate::bindgen::ir::{
+    Abi, Condition, ConditionWrite, Constant, Documentation, Enum, EnumVariant, Field, Function,
+    IntKind, Item, Literal, OpaqueItem, Path, PrimitiveType, ReprAlign, Static, Struct,
+    ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::clike::{to_known_assoc_constant, CLikeLanguageBackend};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::writer::{ListType, Source, SourceWriter};
+use crate::bindgen::Bindings;
+
+/// The backend for Cython `.pxd` declarations of a C header.
+///
+/// Cython extern declarations don't manage layouts, those are defined entirely
+/// by the corresponding C code, so this leaves out whatever Cython doesn't
+/// support but doesn't change how the items are used, like bitfield sizes or
+/// conditional fields.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CythonLanguageBackend;
+
+fn write_condition_expr(out: &mut SourceWriter, condition: &Condition) {
+    match *condition {
+        Condition::Define(ref define) => write!(out, "{}", define),
+        Condition::Any(ref conditions) => {
+            out.write("(");
+            for (i, condition) in conditions.iter().enumerate() {
+                if i != 0 {
+                    out.write(" or ");
+                }
+                write_condition_expr(out, condition);
+            }
+            out.write(")");
+        }
+        Condition::All(ref conditions) => {
+            out.write("(");
+            for (i, condition) in conditions.iter().enumerate() {
+                if i != 0 {
+                    out.write(" and ");
+                }
+                write_condition_expr(out, condition);
+            }
+            out.write(")");
+        }
+        Condition::Not(ref condition) => {
+            out.write("not ");
+            write_condition_expr(out, condition);
+        }
+    }
+}
+
+/// Cython has no 128-bit integers, nor half or quad floats, so those are
+/// declared as an extern type with the C spelling, which Cython converts like
+/// the approximation.
+fn approximation(primitive: &PrimitiveType) -> Option<&'static str> {
+    match *primitive {
+        PrimitiveType::Integer {
+            kind: IntKind::B128,
+            signed,
+            ..
+        } => Some(if signed {
+            "long long"
+        } else {
+            "unsigned long long"
+        }),
+        PrimitiveType::Float16 => Some("float"),
+        PrimitiveType::Float128 => Some("long double"),
+        _ => None,
+    }
+}
+
+impl CythonLanguageBackend {
+    fn write_enum_variant(&self, config: &Config, out: &mut SourceWriter, v: &EnumVariant) {
+        // Cython doesn't support conditional enum variants.
+        v.documentation.write(config, out);
+        write!(out, "{}", v.export_name);
+        if let Some(discriminant) = &v.discriminant {
+            // For extern Cython declarations the enumerator value is ignored,
+            // but still useful as documentation, so we write it as a comment.
+            out.write(" # = ");
+            discriminant.write(config, out);
+        }
+        out.write(",");
+    }
+
+    /// The code here mirrors the beginning of `write_struct` and `write_union`.
+    fn open_struct_or_union(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        e: &Enum,
+        inline_tag_field: bool,
+    ) {
+        out.write(config.style.cython_def());
+        out.write(if inline_tag_field { "union" } else { "struct" });
+        write!(out, " {}", e.export_name());
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant.
+        if let Some(body) = config.export.pre_body(&e.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+    }
+
+    /// Emit fields for all variants with data.
+    ///
+    /// Cython doesn't support unnamed structs, so the fields of inline
+    /// variants go in the enum itself instead, without the per-variant tags,
+    /// the main `tag` is enough.
+    fn write_variant_fields(
+        &self,
+        _config: &Config,
+        out: &mut SourceWriter,
+        e: &Enum,
+        inline_tag_field: bool,
+    ) {
+        let mut first = true;
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                name, body, inline, ..
+            } = &variant.body
+            {
+                if !first {
+                    out.new_line();
+                }
+                first = false;
+                if *inline {
+                    let start_field = usize::from(inline_tag_field);
+                    out.write_vertical_source_list(&body.fields[start_field..], ListType::Cap(";"));
+                } else {
+                    write!(out, "{} {};", body.export_name(), name);
+                }
+            }
+        }
+    }
+}
+
+impl LanguageBackend for CythonLanguageBackend {
+    fn write_headers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+
+        if let Some(ref f) = config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "/* Generated with cbindgen:{} */",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if config.no_includes
+            && config.cython.cimports.is_empty()
+            && config.after_includes.is_none()
+        {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !config.no_includes {
+            out.write("from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t");
+            out.new_line();
+            out.write("from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t");
+            out.new_line();
+            out.write("cdef extern from *");
+            out.open_brace();
+            out.write("ctypedef bint bool");
+            out.new_line();
+            out.write("ctypedef struct va_list");
+            out.new_line();
+            for primitive in bindings.primitives() {
+                if let Some(approximation) = approximation(&primitive) {
+                    write!(
+                        out,
+                        "ctypedef {} {} \"{}\"",
+                        approximation,
+                        primitive.to_repr_cython(config),
+                        primitive.to_repr_c(config)
+                    );
+                    out.new_line();
+                }
+            }
+            out.close_brace(false);
+        }
+
+        for (module, names) in &config.cython.cimports {
+            write!(out, "from {} cimport {}", module, names.join(", "));
+            out.new_line();
+        }
+
+        if let Some(ref line) = config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        out.new_line();
+        let header = bindings.config.cython.header.as_deref().unwrap_or("*");
+        write!(out, "cdef extern from {}", header);
+        out.open_brace();
+    }
+
+    fn close_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        if bindings.globals().is_empty()
+            && bindings.constants().is_empty()
+            && bindings.items().is_empty()
+            && bindings.functions().is_empty()
+        {
+            out.write("pass");
+        }
+        out.close_brace(false);
+    }
+
+    fn write_footers(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn write_enum(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        let size = e.repr.ty.map(|ty| ty.to_primitive().to_repr_c(config));
+        let has_data = e.tag.is_some();
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        let tag_name = e.tag_name();
+
+        let condition = e.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        e.documentation.write(config, out);
+
+        // Emit the tag enum.
+        if size.is_some() {
+            // If we need to specify size, then we have no choice but to create a typedef,
+            // so `config.style` is not respected.
+            write!(out, "cdef enum");
+        } else {
+            write!(out, "{}enum {}", config.style.cython_def(), tag_name);
+        }
+        out.open_brace();
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line()
+            }
+            self.write_enum_variant(config, out, variant);
+        }
+        out.close_brace(true);
+
+        if let Some(prim) = size {
+            out.new_line();
+            write!(out, "ctypedef {} {};", prim, tag_name);
+        }
+
+        // If the enum has data, we need to emit structs for the variants and gather them together.
+        if has_data {
+            // Emit struct definitions for variants having data.
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref body,
+                    inline: false,
+                    ..
+                } = variant.body
+                {
+                    out.new_line();
+                    out.new_line();
+                    self.write_struct(config, out, body);
+                }
+            }
+            out.new_line();
+            out.new_line();
+
+            self.open_struct_or_union(config, out, e, inline_tag_field);
+
+            write!(out, "{} tag;", tag_name);
+            out.new_line();
+
+            // Cython doesn't support unnamed unions, but as it doesn't manage
+            // layouts either, the fields of all variants can go right here.
+            self.write_variant_fields(config, out, e, inline_tag_field);
+
+            // Emit the post_body section, if relevant.
+            if let Some(body) = config.export.post_body(&e.path) {
+                out.new_line();
+                out.write_raw_block(body);
+            }
+
+            out.close_brace(true);
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_struct(&self, config: &Config, out: &mut SourceWriter, s: &Struct) {
+        if s.is_transparent {
+            self.write_type_def(config, out, &s.as_typedef());
+            for constant in &s.associated_constants {
+                out.new_line();
+                constant.write(config, out, Some(s));
+            }
+            return;
+        }
+
+        let condition = s.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        s.documentation.write(config, out);
+
+        out.write(config.style.cython_def());
+
+        // This `packed` is only for documentation, and missing `aligned(n)` is
+        // also not a problem.
+        match s.alignment {
+            Some(ReprAlign::Packed) => out.write("packed "),
+            Some(ReprAlign::PackedN(n)) => warn!(
+                "Packing of `{}` to {} bytes can't be represented in Cython, ignoring it.",
+                s.export_name, n
+            ),
+            _ => {}
+        }
+
+        write!(out, "struct {}", s.export_name());
+
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = config.export.pre_body(&s.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        out.write_vertical_source_list(&s.fields, ListType::Cap(";"));
+        if s.fields.is_empty() {
+            out.write("pass");
+        }
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = config.export.post_body(&s.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        out.close_brace(true);
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(config, out, Some(s));
+        }
+
+        condition.write_after(config, out);
+    }
+
+    fn write_union(&self, config: &Config, out: &mut SourceWriter, u: &Union) {
+        let condition = u.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        u.documentation.write(config, out);
+
+        // Unlike structs, unions can't be `packed`.
+        if let Some(ReprAlign::PackedN(n)) = u.alignment {
+            warn!(
+                "Packing of `{}` to {} bytes can't be represented in Cython, ignoring it.",
+                u.export_name, n
+            );
+        }
+        write!(out, "{}union {}", config.style.cython_def(), u.export_name);
+
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = config.export.pre_body(&u.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        out.write_vertical_source_list(&u.fields, ListType::Cap(";"));
+        if u.fields.is_empty() {
+            out.write("pass");
+        }
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = config.export.post_body(&u.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        out.close_brace(true);
+
+        condition.write_after(config, out);
+    }
+
+    fn write_opaque_item(&self, config: &Config, out: &mut SourceWriter, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        o.documentation.write(config, out);
+
+        write!(
+            out,
+            "{}struct {}",
+            config.style.cython_def(),
+            o.export_name()
+        );
+        out.open_brace();
+        out.write("pass");
+        out.close_brace(false);
+
+        condition.write_after(config, out);
+    }
+
+    fn write_type_def(&self, config: &Config, out: &mut SourceWriter, t: &Typedef) {
+        let condition = t.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        t.documentation.write(config, out);
+
+        out.write("ctypedef ");
+        Field::from_name_and_type(t.export_name().to_owned(), t.aliased.clone()).write(config, out);
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_static(&self, config: &Config, out: &mut SourceWriter, s: &Static) {
+        CLikeLanguageBackend.write_static(config, out, s);
+    }
+
+    fn write_function(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        CLikeLanguageBackend.write_function(config, out, f);
+    }
+
+    fn write_constant(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        if !c.can_write(out.bindings(), associated_to_struct) {
+            return;
+        }
+
+        let condition = c.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        let name = c.prefixed_name(config, associated_to_struct);
+        let value = c.written_value(out.bindings());
+
+        c.documentation.write(config, out);
+
+        out.write("const ");
+        c.ty.write(config, out);
+        // For extern Cython declarations the initializer is ignored,
+        // but still useful as documentation, so we write it as a comment.
+        write!(out, " {} # = ", name);
+        value.write(config, out);
+
+        condition.write_after(config, out);
+    }
+
+    fn write_field(&self, config: &Config, out: &mut SourceWriter, f: &Field) {
+        // Cython doesn't support conditional fields, and bitfield sizes.
+        f.documentation.write(config, out);
+        if f.flexible_array {
+            cdecl::write_flexible_array_field(out, &f.ty, &f.name, config);
+        } else {
+            cdecl::write_field(out, &f.ty, &f.name, config);
+        }
+    }
+
+    fn write_type(&self, config: &Config, out: &mut SourceWriter, t: &Type) {
+        cdecl::write_type(out, t, config);
+    }
+
+    fn write_literal(&self, config: &Config, out: &mut SourceWriter, l: &Literal) {
+        match l {
+            Literal::Expr(v) => match &**v {
+                "true" => write!(out, "True"),
+                "false" => write!(out, "False"),
+                v => write!(out, "{}", v),
+            },
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(known) = self.known_assoc_constant(path, name) {
+                        return write!(out, "{}", known);
+                    }
+                    write!(out, "{}_", export_name)
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                base.write(config, out);
+                write!(out, ").{}", field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                value.write(config, out);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                write!(out, "(");
+                left.write(config, out);
+                write!(out, " {} ", op);
+                right.write(config, out);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                out.write("<");
+                ty.write(config, out);
+                out.write(">");
+                value.write(config, out);
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                write!(out, "<{}>", export_name);
+
+                out.write("{ ");
+                let mut is_first_field = true;
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        } else {
+                            is_first_field = false;
+                        }
+                        lit.write(config, out);
+                    }
+                }
+                out.write(" }");
+            }
+        }
+    }
+
+    fn write_documentation(&self, config: &Config, out: &mut SourceWriter, d: &Documentation) {
+        if d.doc_comment.is_empty() || !config.documentation {
+            return;
+        }
+
+        let end = match config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // Cython uses Python-style comments, so `documentation_style` is not relevant.
+        for line in &d.doc_comment[..end] {
+            write!(out, "#{}", line);
+            out.new_line();
+        }
+    }
+
+    fn write_condition_before(&self, _config: &Config, out: &mut SourceWriter, c: &Condition) {
+        out.write("IF ");
+        write_condition_expr(out, c);
+        out.open_brace();
+    }
+
+    fn write_condition_after(&self, _config: &Config, out: &mut SourceWriter, _c: &Condition) {
+        out.close_brace(false);
+    }
+
+    fn check_calling_convention(&self, _config: &Config, abi: Abi, _: bool, _: bool) {
+        warn!(
+            "Cython bindings can't express `extern \"{}\"`, so functions and function \
+             pointers using it are declared as `extern \"C\"` ones.",
+            abi.to_repr_rust()
+        );
+    }
+
+    fn known_assoc_constant(&self, associated_to: &Path, name: &str) -> Option<String> {
+        to_known_assoc_constant(associated_to, name)
+    }
+
+    fn open_brace(&self, _config: &Config, out: &mut SourceWriter) {
+        out.write(":");
+        out.new_line();
+        out.push_tab();
+    }
+
+    fn close_brace(&self, _config: &Config, out: &mut SourceWriter, _semicolon: bool) {
+        out.pop_tab();
+    }
+}
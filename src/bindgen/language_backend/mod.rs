@@ -0,0 +1,238 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::bindgen::config::{Braces, Config, Language};
+pub use crate::bindgen::ir::{
+    Abi, Condition, Constant, Documentation, Enum, Field, Function, ItemContainer, Literal,
+    OpaqueItem, Path, PrimitiveType, Static, Struct, Type, Typedef, Union,
+};
+use crate::bindgen::writer::SourceWriter;
+use crate::bindgen::Bindings;
+
+mod clike;
+mod csharp;
+mod cython;
+mod python;
+mod zig;
+
+pub use self::clike::CLikeLanguageBackend;
+pub use self::csharp::CSharpLanguageBackend;
+pub use self::cython::CythonLanguageBackend;
+pub use self::python::PythonLanguageBackend;
+pub use self::zig::ZigLanguageBackend;
+
+/// Writes bindings in some language.
+///
+/// `Bindings::write` hands everything over to `write_bindings`, whose default
+/// implementation calls the other methods in turn. The IR items write
+/// themselves by dispatching to the backend of the bindings being written, so
+/// overriding e.g. `write_type` also changes the types written within the
+/// items of a backend this one delegates to.
+///
+/// Renaming and escaping of reserved words happen before the bindings are
+/// written, and still follow `config.language`.
+///
+/// This is unstable: the items handed to a backend are cbindgen's own IR,
+/// which may change in any release.
+pub trait LanguageBackend: fmt::Debug + Send + Sync {
+    /// Writes everything before the items, like the header and includes.
+    fn write_headers(&self, bindings: &Bindings, out: &mut SourceWriter);
+    /// Opens whatever the items are declared in, like C++ namespaces.
+    fn open_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter);
+    /// Closes what `open_namespaces` opened.
+    fn close_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter);
+    /// Writes everything after the items, except for the trailer.
+    fn write_footers(&self, bindings: &Bindings, out: &mut SourceWriter);
+
+    fn write_enum(&self, config: &Config, out: &mut SourceWriter, e: &Enum);
+    fn write_struct(&self, config: &Config, out: &mut SourceWriter, s: &Struct);
+    fn write_union(&self, config: &Config, out: &mut SourceWriter, u: &Union);
+    fn write_opaque_item(&self, config: &Config, out: &mut SourceWriter, o: &OpaqueItem);
+    fn write_type_def(&self, config: &Config, out: &mut SourceWriter, t: &Typedef);
+    fn write_static(&self, config: &Config, out: &mut SourceWriter, s: &Static);
+    fn write_function(&self, config: &Config, out: &mut SourceWriter, f: &Function);
+    /// Writes a constant, `associated_to_struct` is the struct it's written
+    /// after, if it's an associated constant.
+    fn write_constant(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: Option<&Struct>,
+    );
+
+    fn write_field(&self, config: &Config, out: &mut SourceWriter, f: &Field);
+    fn write_type(&self, config: &Config, out: &mut SourceWriter, t: &Type);
+    fn write_literal(&self, config: &Config, out: &mut SourceWriter, l: &Literal);
+    fn write_documentation(&self, config: &Config, out: &mut SourceWriter, d: &Documentation);
+    /// Writes what comes before an item or field only generated under
+    /// `condition`.
+    fn write_condition_before(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        condition: &Condition,
+    );
+    fn write_condition_after(&self, config: &Config, out: &mut SourceWriter, condition: &Condition);
+
+    /// Whether the bindings can express items, fields and variants only
+    /// generated under some condition. If not, the items which are or have
+    /// such fields or variants are left out, along with everything using them.
+    fn writes_conditions(&self) -> bool {
+        true
+    }
+
+    /// Whether the bindings have a type for `primitive` with the same ABI as
+    /// in C. If not, the items, functions and globals using it are left out,
+    /// along with everything using them.
+    fn supports_primitive(&self, _primitive: &PrimitiveType) -> bool {
+        true
+    }
+
+    /// Warns if the bindings can't express the calling convention `abi`, other
+    /// than `extern "C"`, which some functions use if `in_functions`, and
+    /// some function pointers if `in_pointers`. Does nothing by default.
+    fn check_calling_convention(
+        &self,
+        _config: &Config,
+        _abi: Abi,
+        _in_functions: bool,
+        _in_pointers: bool,
+    ) {
+    }
+
+    /// The expression standing for a constant associated to a primitive
+    /// type, like `u32::MAX`. Constants referring to those are only written
+    /// if this knows about them.
+    fn known_assoc_constant(&self, _associated_to: &Path, _name: &str) -> Option<String> {
+        None
+    }
+
+    fn open_brace(&self, config: &Config, out: &mut SourceWriter) {
+        match config.braces {
+            Braces::SameLine => {
+                out.write(" {");
+                out.push_tab();
+                out.new_line();
+            }
+            Braces::NextLine => {
+                out.new_line();
+                out.write("{");
+                out.push_tab();
+                out.new_line();
+            }
+        }
+    }
+
+    fn close_brace(&self, _config: &Config, out: &mut SourceWriter, semicolon: bool) {
+        out.pop_tab();
+        out.new_line();
+        if semicolon {
+            out.write("};");
+        } else {
+            out.write("}");
+        }
+    }
+
+    fn write_bindings(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.write_headers(bindings, out);
+        self.open_namespaces(bindings, out);
+        self.write_primitive_constants(bindings, out);
+        self.write_items(bindings, out);
+        self.write_non_primitive_constants(bindings, out);
+        self.write_globals_and_functions(bindings, out);
+        self.close_namespaces(bindings, out);
+        self.write_footers(bindings, out);
+
+        if let Some(ref f) = bindings.config.trailer {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            if !f.ends_with('\n') {
+                out.new_line();
+            }
+        }
+    }
+
+    /// Writes the constants that only use primitive types, which can go
+    /// before the items.
+    fn write_primitive_constants(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        for constant in bindings.constants() {
+            if constant.uses_only_primitive_types() {
+                out.new_line_if_not_start();
+                self.write_constant(&bindings.config, out, constant, None);
+                out.new_line();
+            }
+        }
+    }
+
+    fn write_items(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        for item in exported_items(bindings) {
+            out.new_line_if_not_start();
+            self.write_item(&bindings.config, out, item);
+            out.new_line();
+        }
+    }
+
+    fn write_item(&self, config: &Config, out: &mut SourceWriter, item: &ItemContainer) {
+        match *item {
+            ItemContainer::Constant(..) => unreachable!(),
+            ItemContainer::Static(..) => unreachable!(),
+            ItemContainer::Enum(ref x) => self.write_enum(config, out, x),
+            ItemContainer::Struct(ref x) => self.write_struct(config, out, x),
+            ItemContainer::Union(ref x) => self.write_union(config, out, x),
+            ItemContainer::OpaqueItem(ref x) => self.write_opaque_item(config, out, x),
+            ItemContainer::Typedef(ref x) => self.write_type_def(config, out, x),
+        }
+    }
+
+    /// Writes the constants using the items, which go after those.
+    fn write_non_primitive_constants(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        for constant in bindings.constants() {
+            if !constant.uses_only_primitive_types() {
+                out.new_line_if_not_start();
+                self.write_constant(&bindings.config, out, constant, None);
+                out.new_line();
+            }
+        }
+    }
+
+    fn write_globals_and_functions(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        for global in bindings.globals() {
+            out.new_line_if_not_start();
+            self.write_static(&bindings.config, out, global);
+            out.new_line();
+        }
+
+        for function in bindings.functions() {
+            out.new_line_if_not_start();
+            self.write_function(&bindings.config, out, function);
+            out.new_line();
+        }
+    }
+}
+
+/// The items to write, that is all but the `no-export` ones.
+pub(super) fn exported_items(bindings: &Bindings) -> impl Iterator<Item = &ItemContainer> {
+    bindings.items().iter().filter(|item| {
+        !item
+            .deref()
+            .annotations()
+            .bool("no-export")
+            .unwrap_or(false)
+    })
+}
+
+/// The built-in backend for `language`.
+pub(crate) fn for_language(language: Language) -> Arc<dyn LanguageBackend> {
+    match language {
+        Language::C | Language::Cxx => Arc::new(CLikeLanguageBackend),
+        Language::Cython => Arc::new(CythonLanguageBackend),
+        Language::Zig => Arc::new(ZigLanguageBackend),
+        Language::Python => Arc::new(PythonLanguageBackend),
+        Language::CSharp => Arc::new(CSharpLanguageBackend),
+    }
+}
@@ -0,0 +1,625 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::borrow::Cow;
+
+use crate::bindgen::config::{Config, DocumentationLength};
+use crate::bindgen::ir::{
+    Abi, Condition, Constant, Documentation, Enum, Field, Function, GenericPath, IntKind, Item,
+    ItemContainer, Literal, OpaqueItem, Path, PrimitiveType, ReprAlign, Static, Struct, Type,
+    Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::{exported_items, LanguageBackend};
+use crate::bindgen::pydecl;
+use crate::bindgen::writer::{Source, SourceWriter};
+use crate::bindgen::Bindings;
+
+/// The backend for Python modules declaring the bindings with ctypes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PythonLanguageBackend;
+
+/// Literals are stored with C syntax, this strips the integer suffixes, and
+/// char literals become their code point, as Python has no such thing.
+fn to_python_literal(v: &str) -> Cow<'_, str> {
+    match v {
+        "true" => return Cow::Borrowed("True"),
+        "false" => return Cow::Borrowed("False"),
+        _ => {}
+    }
+    if v.ends_with('\'') {
+        return Cow::Owned(format!("ord({})", v.trim_start_matches('U')));
+    }
+    if v.starts_with(|c: char| c.is_ascii_digit()) && !v.contains('.') {
+        return Cow::Borrowed(v.trim_end_matches(|c| c == 'u' || c == 'l'));
+    }
+    Cow::Borrowed(v)
+}
+
+impl PythonLanguageBackend {
+    /// ctypes only learns about the layout of a structure once its `_fields_`
+    /// are assigned, so all the classes are declared upfront, which allows
+    /// pointing to them from anywhere. Their fields are filled in by the items.
+    fn write_declarations(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        for item in exported_items(bindings) {
+            let (name, base) = match *item {
+                ItemContainer::Struct(ref x) if !x.is_transparent => (&x.export_name, "Structure"),
+                ItemContainer::Union(ref x) => (&x.export_name, "Union"),
+                ItemContainer::Enum(ref x) if x.tag.is_some() => {
+                    if Enum::inline_tag_field(&x.repr) {
+                        (&x.export_name, "Union")
+                    } else {
+                        (&x.export_name, "Structure")
+                    }
+                }
+                _ => continue,
+            };
+
+            out.new_line_if_not_start();
+            write!(out, "class {}(ctypes.{})", name, base);
+            out.open_brace();
+            out.write("pass");
+            out.close_brace(false);
+            out.new_line();
+        }
+    }
+
+    /// Writes the layout of a ctypes `Structure` or `Union`, that is its
+    /// `_fields_`, preceded by `_pack_` or `_align_` if needed.
+    ///
+    /// `owner` is the class to assign these to, or `None` within its body.
+    fn write_layout(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        fields: &[Field],
+        alignment: Option<ReprAlign>,
+        owner: Option<&str>,
+    ) {
+        let prefix = owner.map_or_else(String::new, |owner| format!("{}.", owner));
+        match alignment {
+            Some(ReprAlign::Packed) => {
+                write!(out, "{}_pack_ = 1", prefix);
+                out.new_line();
+            }
+            Some(ReprAlign::PackedN(n)) => {
+                write!(out, "{}_pack_ = {}", prefix, n);
+                out.new_line();
+            }
+            // Only honored since Python 3.13.
+            Some(ReprAlign::Align(n)) => {
+                write!(out, "{}_align_ = {}", prefix, n);
+                out.new_line();
+            }
+            None => {}
+        }
+        write!(out, "{}_fields_ = ", prefix);
+        if fields.is_empty() {
+            out.write("[]");
+            return;
+        }
+        out.write("[");
+        out.push_tab();
+        for field in fields {
+            out.new_line();
+            field.write(config, out);
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("]");
+    }
+
+    fn write_anonymous(&self, out: &mut SourceWriter, anonymous: &[String], owner: Option<&str>) {
+        if anonymous.is_empty() {
+            return;
+        }
+        if let Some(owner) = owner {
+            write!(out, "{}.", owner);
+        }
+        out.write("_anonymous_ = [");
+        for (i, name) in anonymous.iter().enumerate() {
+            if i != 0 {
+                out.write(", ");
+            }
+            write!(out, "\"{}\"", name);
+        }
+        out.write("]");
+        out.new_line();
+    }
+}
+
+impl LanguageBackend for PythonLanguageBackend {
+    fn write_headers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+
+        if let Some(ref f) = config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "# Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if config.no_includes && config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !config.no_includes {
+            out.write("import ctypes");
+            out.new_line();
+            out.write("import enum");
+            out.new_line();
+        }
+
+        if let Some(ref line) = config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn close_namespaces(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn write_footers(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn write_items(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.write_declarations(bindings, out);
+
+        for item in exported_items(bindings) {
+            out.new_line_if_not_start();
+            self.write_item(&bindings.config, out, item);
+            out.new_line();
+        }
+    }
+
+    /// Python has no way to declare functions up front, so the `argtypes` and
+    /// `restype` are set on the library when it's loaded instead.
+    fn write_globals_and_functions(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        if bindings.functions().is_empty() && bindings.globals().is_empty() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+        out.write("def load(path)");
+        out.open_brace();
+        out.write("lib = ctypes.CDLL(path)");
+        out.new_line();
+
+        for global in bindings.globals() {
+            out.new_line_if_not_start();
+            self.write_static(&bindings.config, out, global);
+            out.new_line();
+        }
+
+        // Functions with calling conventions `ctypes` can't call are left
+        // out, the library warns about them.
+        let functions = bindings
+            .functions()
+            .iter()
+            .filter(|f| pydecl::function_type(f.abi).is_some());
+        for function in functions {
+            out.new_line_if_not_start();
+            self.write_function(&bindings.config, out, function);
+            out.new_line();
+        }
+
+        out.new_line();
+        out.write("return lib");
+        out.close_brace(false);
+        out.new_line();
+    }
+
+    /// Python has no anonymous structs or unions, so these become classes of
+    /// their own, and are added to `_anonymous_` to keep their fields accessible
+    /// in the same way as in C.
+    fn write_enum(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        e.documentation.write(config, out);
+
+        write!(out, "class {}(enum.IntEnum)", e.tag_name());
+        out.open_brace();
+        if e.variants.is_empty() {
+            out.write("pass");
+        }
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            variant.documentation.write(config, out);
+            write!(out, "{} = ", variant.export_name);
+            match variant.discriminant {
+                Some(ref discriminant) => discriminant.write(config, out),
+                // Like in C, the first variant is zero and each next one is
+                // one more than the previous.
+                None if i == 0 => out.write("0"),
+                None => out.write("enum.auto()"),
+            }
+        }
+        out.close_brace(false);
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        let mut fields = vec![Field::from_name_and_type(
+            "tag".to_owned(),
+            Type::Path(GenericPath::new(Path::new(e.tag_name()), vec![])),
+        )];
+        let mut anonymous = vec![];
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref name,
+                ref body,
+                inline,
+                ..
+            } = variant.body
+            {
+                let field = match body.fields[..] {
+                    [ref field] if inline => field.clone(),
+                    _ => {
+                        let (field_name, class_name) = if inline {
+                            let class_name = format!("_{}_{}", e.export_name, name);
+                            (format!("_{}", name), class_name)
+                        } else {
+                            (name.clone(), body.export_name().to_owned())
+                        };
+
+                        out.new_line();
+                        out.new_line();
+                        write!(out, "class {}(ctypes.Structure)", class_name);
+                        out.open_brace();
+                        self.write_layout(config, out, &body.fields, None, None);
+                        out.close_brace(false);
+
+                        if inline {
+                            anonymous.push(field_name.clone());
+                        }
+                        Field::from_name_and_type(
+                            field_name,
+                            Type::Path(GenericPath::new(Path::new(class_name), vec![])),
+                        )
+                    }
+                };
+                fields.push(field);
+            }
+        }
+
+        // With a separate tag, the variants are in a union next to it.
+        if !Enum::inline_tag_field(&e.repr) {
+            let union_name = format!("_{}_Body", e.export_name);
+            let union_fields = fields.split_off(1);
+
+            out.new_line();
+            out.new_line();
+            write!(out, "class {}(ctypes.Union)", union_name);
+            out.open_brace();
+            self.write_anonymous(out, &anonymous, None);
+            self.write_layout(config, out, &union_fields, None, None);
+            out.close_brace(false);
+
+            anonymous = vec!["_body".to_owned()];
+            fields.push(Field::from_name_and_type(
+                "_body".to_owned(),
+                Type::Path(GenericPath::new(Path::new(union_name), vec![])),
+            ));
+        }
+
+        // The class itself is declared by `write_declarations`.
+        out.new_line();
+        out.new_line();
+        self.write_anonymous(out, &anonymous, Some(e.export_name()));
+        self.write_layout(config, out, &fields, None, Some(e.export_name()));
+    }
+
+    /// The class itself is declared by `write_declarations`, this fills in
+    /// its fields.
+    fn write_struct(&self, config: &Config, out: &mut SourceWriter, s: &Struct) {
+        if s.is_transparent {
+            self.write_type_def(config, out, &s.as_typedef());
+            for constant in &s.associated_constants {
+                out.new_line();
+                constant.write(config, out, Some(s));
+            }
+            return;
+        }
+
+        s.documentation.write(config, out);
+
+        self.write_layout(config, out, &s.fields, s.alignment, Some(s.export_name()));
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(config, out, Some(s));
+        }
+    }
+
+    /// Like structs, this only fills in the fields of the class.
+    fn write_union(&self, config: &Config, out: &mut SourceWriter, u: &Union) {
+        u.documentation.write(config, out);
+
+        self.write_layout(config, out, &u.fields, u.alignment, Some(&u.export_name));
+    }
+
+    fn write_opaque_item(&self, config: &Config, out: &mut SourceWriter, o: &OpaqueItem) {
+        o.documentation.write(config, out);
+
+        write!(out, "class {}(ctypes.Structure)", o.export_name());
+        out.open_brace();
+        out.write("pass");
+        out.close_brace(false);
+    }
+
+    fn write_type_def(&self, config: &Config, out: &mut SourceWriter, t: &Typedef) {
+        t.documentation.write(config, out);
+
+        write!(out, "{} = ", t.export_name());
+        t.aliased.write(config, out);
+    }
+
+    fn write_static(&self, config: &Config, out: &mut SourceWriter, s: &Static) {
+        s.documentation.write(config, out);
+
+        write!(out, "lib.{} = ", s.export_name);
+        if let Type::Array(..) = s.ty {
+            out.write("(");
+            s.ty.write(config, out);
+            out.write(")");
+        } else {
+            s.ty.write(config, out);
+        }
+        write!(out, ".in_dll(lib, \"{}\")", s.export_name);
+    }
+
+    fn write_function(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        f.documentation.write(config, out);
+
+        // There are no prototypes in Python, this declares the
+        // `argtypes` and `restype` of the function instead.
+        pydecl::write_func(out, f, config.function.args.clone(), config);
+    }
+
+    fn write_constant(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        if !c.can_write(out.bindings(), associated_to_struct) {
+            return;
+        }
+
+        let name = c.prefixed_name(config, associated_to_struct);
+        let value = c.written_value(out.bindings());
+
+        c.documentation.write(config, out);
+
+        write!(out, "{} = ", name);
+        // Unlike in C, the variants of an enum are only reachable through it.
+        match *value {
+            Literal::Path {
+                associated_to: None,
+                name: ref variant,
+            } => match out.bindings().enum_variant(&c.ty, variant) {
+                Some((e, variant)) => write!(out, "{}.{}", e, variant),
+                None => value.write(config, out),
+            },
+            _ => value.write(config, out),
+        }
+    }
+
+    fn write_field(&self, config: &Config, out: &mut SourceWriter, f: &Field) {
+        f.documentation.write(config, out);
+        // ctypes fields are `(name, type)` or `(name, type, bits)` tuples.
+        write!(out, "(\"{}\", ", f.name);
+        f.ty.write(config, out);
+        if let Some(bitfield) = f.annotations.atom("bitfield") {
+            write!(out, ", {}", bitfield.unwrap_or_default());
+        }
+        out.write("),");
+    }
+
+    fn write_type(&self, config: &Config, out: &mut SourceWriter, t: &Type) {
+        pydecl::write_type(out, t, config);
+    }
+
+    fn write_literal(&self, config: &Config, out: &mut SourceWriter, l: &Literal) {
+        match l {
+            Literal::Expr(v) => write!(out, "{}", to_python_literal(v)),
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(known) = self.known_assoc_constant(path, name) {
+                        return write!(out, "{}", known);
+                    }
+                    write!(out, "{}_", export_name)
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                base.write(config, out);
+                write!(out, ").{}", field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                value.write(config, out);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let op = match *op {
+                    "&&" => "and",
+                    "||" => "or",
+                    // C divides integers without a remainder.
+                    "/" => "//",
+                    op => op,
+                };
+                write!(out, "(");
+                left.write(config, out);
+                write!(out, " {} ", op);
+                right.write(config, out);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                // Going through ctypes takes care of truncating the value, but
+                // it doesn't convert floats to integers on its own.
+                let to_int = !matches!(
+                    *ty,
+                    Type::Ptr { .. }
+                        | Type::Primitive(PrimitiveType::Float | PrimitiveType::Double)
+                );
+                if let Type::Ptr { .. } = *ty {
+                    out.write("ctypes.c_void_p");
+                } else {
+                    ty.write(config, out);
+                }
+                out.write(if to_int { "(int(" } else { "(" });
+                value.write(config, out);
+                out.write(if to_int { ")).value" } else { ").value" });
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                write!(out, "{}(", export_name);
+                let mut is_first_field = true;
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        } else {
+                            is_first_field = false;
+                        }
+                        write!(out, "{}=", ordered_key);
+                        lit.write(config, out);
+                    }
+                }
+                out.write(")");
+            }
+        }
+    }
+
+    fn write_documentation(&self, config: &Config, out: &mut SourceWriter, d: &Documentation) {
+        if d.doc_comment.is_empty() || !config.documentation {
+            return;
+        }
+
+        let end = match config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        for line in &d.doc_comment[..end] {
+            write!(out, "#{}", line);
+            out.new_line();
+        }
+    }
+
+    fn write_condition_before(&self, _config: &Config, _out: &mut SourceWriter, _c: &Condition) {
+        // Python has no conditional compilation, and conditional items are
+        // left out, see `writes_conditions`.
+    }
+
+    fn write_condition_after(&self, _config: &Config, _out: &mut SourceWriter, _c: &Condition) {}
+
+    fn writes_conditions(&self) -> bool {
+        false
+    }
+
+    /// ctypes has no 128-bit integers, nor half or quad floats, and an array
+    /// of the same size is passed differently.
+    fn supports_primitive(&self, primitive: &PrimitiveType) -> bool {
+        !matches!(
+            *primitive,
+            PrimitiveType::Float16
+                | PrimitiveType::Float128
+                | PrimitiveType::Integer {
+                    kind: IntKind::B128,
+                    ..
+                }
+        )
+    }
+
+    fn check_calling_convention(
+        &self,
+        _config: &Config,
+        abi: Abi,
+        in_functions: bool,
+        in_pointers: bool,
+    ) {
+        if pydecl::function_type(abi).is_some() {
+            return;
+        }
+        if in_functions {
+            warn!(
+                "Python bindings can't import `extern \"{}\"` functions, so they're left out.",
+                abi.to_repr_rust()
+            );
+        }
+        if in_pointers {
+            warn!(
+                "Python bindings can't express `extern \"{}\"` function pointers, so they're \
+                 written as opaque pointers.",
+                abi.to_repr_rust()
+            );
+        }
+    }
+
+    fn known_assoc_constant(&self, associated_to: &Path, name: &str) -> Option<String> {
+        if name != "MAX" && name != "MIN" {
+            return None;
+        }
+
+        let (bits, signed) = match PrimitiveType::maybe(associated_to.name())? {
+            PrimitiveType::Integer { kind, signed, .. } => match kind {
+                IntKind::B8 => (8, signed),
+                IntKind::B16 => (16, signed),
+                IntKind::B32 => (32, signed),
+                IntKind::B64 => (64, signed),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let value = match (name, signed) {
+            ("MAX", true) => (1i128 << (bits - 1)) - 1,
+            ("MIN", true) => -(1i128 << (bits - 1)),
+            ("MAX", false) => (1i128 << bits) - 1,
+            _ => 0,
+        };
+        Some(value.to_string())
+    }
+
+    fn open_brace(&self, _config: &Config, out: &mut SourceWriter) {
+        out.write(":");
+        out.new_line();
+        out.push_tab();
+    }
+
+    fn close_brace(&self, _config: &Config, out: &mut SourceWriter, _semicolon: bool) {
+        out.pop_tab();
+    }
+}
@@ -0,0 +1,508 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::borrow::Cow;
+
+use crate::bindgen::config::{Config, DocumentationLength};
+use crate::bindgen::ir::{
+    Condition, Constant, Documentation, Enum, Field, Function, Item, Literal, OpaqueItem, Path,
+    PrimitiveType, ReprAlign, Static, Struct, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::clike::write_enum_variant;
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::writer::{Source, SourceWriter};
+use crate::bindgen::zigdecl;
+use crate::bindgen::Bindings;
+
+/// The backend for Zig.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ZigLanguageBackend;
+
+/// Literals are stored with C syntax, this strips the integer suffixes and
+/// rewrites `U'\U0001F600'` style char literals, which Zig doesn't understand.
+fn to_zig_literal(v: &str) -> Cow<'_, str> {
+    if let Some(code) = v
+        .strip_prefix(r"U'\U")
+        .and_then(|code| code.strip_suffix('\''))
+    {
+        return Cow::Owned(format!(r"'\u{{{}}}'", code.trim_start_matches('0')));
+    }
+    if v.starts_with(|c: char| c.is_ascii_digit()) && !v.contains('.') {
+        return Cow::Borrowed(v.trim_end_matches(|c| c == 'u' || c == 'l'));
+    }
+    Cow::Borrowed(v)
+}
+
+impl ZigLanguageBackend {
+    /// Writes the fields of a Zig `extern struct` or `extern union`.
+    ///
+    /// Zig has no way to pack or align those as a whole, so this is expressed
+    /// through `align(...)` on the fields instead.
+    fn write_fields(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        fields: &[Field],
+        alignment: Option<ReprAlign>,
+    ) {
+        for (i, field) in fields.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            let align = match alignment {
+                // The alignment of an aggregate is the largest alignment of
+                // its fields, so it's enough to over-align the first one.
+                Some(ReprAlign::Align(..)) if i != 0 => None,
+                align => align,
+            };
+            self.write_aligned_field(config, out, field, align);
+            out.write(",");
+        }
+    }
+
+    fn write_aligned_field(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        f: &Field,
+        align: Option<ReprAlign>,
+    ) {
+        f.documentation.write(config, out);
+        zigdecl::write_field(out, &f.ty, &f.name, config);
+        match align {
+            Some(ReprAlign::Packed) => out.write(" align(1)"),
+            Some(ReprAlign::PackedN(n)) => {
+                write!(out, " align(@min({}, @alignOf(", n);
+                f.ty.write(config, out);
+                out.write(")))");
+            }
+            // `align(n)` on a field can also lower its alignment, unlike `#[repr(align(n))]`.
+            Some(ReprAlign::Align(n)) => {
+                write!(out, " align(@max({}, @alignOf(", n);
+                f.ty.write(config, out);
+                out.write(")))");
+            }
+            None => {}
+        }
+        // Zig `extern struct`s can't have bitfields at all.
+        if f.annotations.atom("bitfield").is_some() {
+            warn!(
+                "Bitfield `{}` can't be represented in Zig, emitting the whole field.",
+                f.name
+            );
+        }
+    }
+
+    /// Emit fields for all variants with data.
+    fn write_variant_fields(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        let mut first = true;
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                name, body, inline, ..
+            } = &variant.body
+            {
+                if !first {
+                    out.new_line();
+                }
+                first = false;
+                if *inline {
+                    // Zig doesn't have anonymous fields, so the struct needs a name, unless
+                    // there's a single field which can just go in the union directly.
+                    if let [ref field] = body.fields[..] {
+                        field.write(config, out);
+                    } else {
+                        write!(out, "{}: extern struct", name);
+                        out.open_brace();
+                        self.write_fields(config, out, &body.fields, None);
+                        out.close_brace(false);
+                    }
+                    out.write(",");
+                } else {
+                    write!(out, "{}: {},", name, body.export_name());
+                }
+            }
+        }
+    }
+}
+
+impl LanguageBackend for ZigLanguageBackend {
+    fn write_headers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        let config = &bindings.config;
+
+        if let Some(ref f) = config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+
+        if config.no_includes && config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !config.no_includes {
+            out.write("const std = @import(\"std\");");
+            out.new_line();
+        }
+
+        if let Some(ref line) = config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn close_namespaces(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn write_footers(&self, _bindings: &Bindings, _out: &mut SourceWriter) {}
+
+    fn write_enum(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        let size = e.repr.ty.map(|ty| ty.to_primitive().to_repr_zig());
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        let tag_name = e.tag_name();
+
+        e.documentation.write(config, out);
+
+        // C leaves the size of an enum without a `#[repr(prim)]` up to the
+        // implementation, but in practice it's always an `int`.
+        write!(
+            out,
+            "pub const {} = enum({})",
+            tag_name,
+            size.unwrap_or("c_int")
+        );
+        out.open_brace();
+        for (i, variant) in e.variants.iter().enumerate() {
+            if i != 0 {
+                out.new_line()
+            }
+            write_enum_variant(config, out, variant);
+        }
+        out.close_brace(true);
+
+        // If the enum has data, we need to emit structs for the variants and gather them together.
+        if e.tag.is_some() {
+            // Emit struct definitions for variants having data.
+            for variant in &e.variants {
+                if let VariantBody::Body {
+                    ref body,
+                    inline: false,
+                    ..
+                } = variant.body
+                {
+                    out.new_line();
+                    out.new_line();
+                    self.write_struct(config, out, body);
+                }
+            }
+            out.new_line();
+            out.new_line();
+
+            write!(out, "pub const {} = extern ", e.export_name());
+            out.write(if inline_tag_field { "union" } else { "struct" });
+            out.open_brace();
+
+            // Emit the pre_body section, if relevant.
+            if let Some(body) = config.export.pre_body(&e.path) {
+                out.write_raw_block(body);
+                out.new_line();
+            }
+
+            write!(out, "tag: {},", tag_name);
+            out.new_line();
+
+            // Zig doesn't have anonymous fields, so the union needs a name.
+            if !inline_tag_field {
+                out.write("body: extern union");
+                out.open_brace();
+            }
+
+            self.write_variant_fields(config, out, e);
+
+            if !inline_tag_field {
+                out.close_brace(false);
+                out.write(",");
+            }
+
+            // Emit the post_body section, if relevant.
+            if let Some(body) = config.export.post_body(&e.path) {
+                out.new_line();
+                out.write_raw_block(body);
+            }
+
+            out.close_brace(true);
+        }
+    }
+
+    fn write_struct(&self, config: &Config, out: &mut SourceWriter, s: &Struct) {
+        if s.is_transparent {
+            self.write_type_def(config, out, &s.as_typedef());
+            for constant in &s.associated_constants {
+                out.new_line();
+                constant.write(config, out, Some(s));
+            }
+            return;
+        }
+
+        s.documentation.write(config, out);
+
+        write!(out, "pub const {} = extern struct", s.export_name());
+
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = config.export.pre_body(&s.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(config, out, &s.fields, s.alignment);
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = config.export.post_body(&s.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        out.close_brace(true);
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(config, out, Some(s));
+        }
+    }
+
+    fn write_union(&self, config: &Config, out: &mut SourceWriter, u: &Union) {
+        u.documentation.write(config, out);
+
+        write!(out, "pub const {} = extern union", u.export_name);
+
+        out.open_brace();
+
+        // Emit the pre_body section, if relevant
+        if let Some(body) = config.export.pre_body(&u.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(config, out, &u.fields, u.alignment);
+
+        // Emit the post_body section, if relevant
+        if let Some(body) = config.export.post_body(&u.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+
+        out.close_brace(true);
+    }
+
+    fn write_opaque_item(&self, config: &Config, out: &mut SourceWriter, o: &OpaqueItem) {
+        o.documentation.write(config, out);
+
+        write!(out, "pub const {} = opaque {{}};", o.export_name());
+    }
+
+    fn write_type_def(&self, config: &Config, out: &mut SourceWriter, t: &Typedef) {
+        t.documentation.write(config, out);
+
+        write!(out, "pub const {} = ", t.export_name());
+        t.aliased.write(config, out);
+        out.write(";");
+    }
+
+    fn write_static(&self, config: &Config, out: &mut SourceWriter, s: &Static) {
+        s.documentation.write(config, out);
+
+        out.write(if s.mutable {
+            "pub extern var "
+        } else {
+            "pub extern const "
+        });
+        zigdecl::write_field(out, &s.ty, &s.export_name, config);
+        out.write(";");
+    }
+
+    fn write_function(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        f.documentation.write(config, out);
+
+        // Zig has no use for the prefix / postfix attributes, and `extern`
+        // functions use the C calling convention unless told otherwise.
+        out.write("pub extern fn ");
+        zigdecl::write_func(out, f, config.function.args.clone(), config);
+        out.write(";");
+    }
+
+    fn write_constant(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        if !c.can_write(out.bindings(), associated_to_struct) {
+            return;
+        }
+
+        let name = c.prefixed_name(config, associated_to_struct);
+        let value = c.written_value(out.bindings());
+
+        c.documentation.write(config, out);
+
+        write!(out, "pub const {}: ", name);
+        c.ty.write(config, out);
+        out.write(" = ");
+        value.write(config, out);
+        out.write(";");
+    }
+
+    fn write_field(&self, config: &Config, out: &mut SourceWriter, f: &Field) {
+        self.write_aligned_field(config, out, f, None);
+    }
+
+    fn write_type(&self, config: &Config, out: &mut SourceWriter, t: &Type) {
+        zigdecl::write_type(out, t, config);
+    }
+
+    fn write_literal(&self, config: &Config, out: &mut SourceWriter, l: &Literal) {
+        match l {
+            Literal::Expr(v) => write!(out, "{}", to_zig_literal(v)),
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(known) = self.known_assoc_constant(path, name) {
+                        return write!(out, "{}", known);
+                    }
+                    write!(out, "{}_", export_name)
+                }
+                write!(out, "{}", name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                write!(out, "(");
+                base.write(config, out);
+                write!(out, ").{}", field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{}", op);
+                value.write(config, out);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let op = match *op {
+                    "&&" => "and",
+                    "||" => "or",
+                    op => op,
+                };
+                write!(out, "(");
+                left.write(config, out);
+                write!(out, " {} ", op);
+                right.write(config, out);
+                write!(out, ")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                out.write("@as(");
+                ty.write(config, out);
+                out.write(", ");
+                value.write(config, out);
+                out.write(")");
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                write!(out, "{}", export_name);
+
+                out.write("{ ");
+                let mut is_first_field = true;
+                let ordered_fields = out.bindings().struct_field_names(path);
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            write!(out, ", ");
+                        } else {
+                            is_first_field = false;
+                        }
+                        write!(out, ".{} = ", ordered_key);
+                        lit.write(config, out);
+                    }
+                }
+                out.write(" }");
+            }
+        }
+    }
+
+    fn write_documentation(&self, config: &Config, out: &mut SourceWriter, d: &Documentation) {
+        if d.doc_comment.is_empty() || !config.documentation {
+            return;
+        }
+
+        let end = match config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // Zig doc comments are always `///`, other styles would just be regular comments.
+        for line in &d.doc_comment[..end] {
+            write!(out, "///{}", line);
+            out.new_line();
+        }
+    }
+
+    fn write_condition_before(&self, _config: &Config, _out: &mut SourceWriter, _c: &Condition) {
+        // Zig has no conditional compilation of declarations, and conditional
+        // items are left out, see `writes_conditions`.
+    }
+
+    fn write_condition_after(&self, _config: &Config, _out: &mut SourceWriter, _c: &Condition) {}
+
+    fn writes_conditions(&self) -> bool {
+        false
+    }
+
+    fn known_assoc_constant(&self, associated_to: &Path, name: &str) -> Option<String> {
+        if name != "MAX" && name != "MIN" {
+            return None;
+        }
+
+        match PrimitiveType::maybe(associated_to.name())? {
+            prim @ PrimitiveType::Integer { .. } => Some(format!(
+                "std.math.{}Int({})",
+                name.to_lowercase(),
+                prim.to_repr_zig()
+            )),
+            _ => None,
+        }
+    }
+
+    fn open_brace(&self, _config: &Config, out: &mut SourceWriter) {
+        // `zig fmt` always keeps the brace on the same line.
+        out.write(" {");
+        out.push_tab();
+        out.new_line();
+    }
+}
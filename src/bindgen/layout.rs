@@ -0,0 +1,507 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::borrow::Cow;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use crate::bindgen::config::{Config, Language, LayoutConfig};
+use crate::bindgen::ir::{
+    ConstExpr, Enum, Field, IntKind, ItemContainer, Path, PrimitiveType, ReprAlign, Struct, Type,
+    Union, VariantBody,
+};
+use crate::bindgen::Bindings;
+
+/// The size and alignment of a type, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TypeLayout {
+    size: u64,
+    align: u64,
+}
+
+/// The layout of a struct, union, enum or typedef, as rustc lays it out for
+/// the target described by `[layout]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemLayout {
+    pub size: u64,
+    pub align: u64,
+    /// The offsets of the fields of a struct or union, in declaration order.
+    /// This is empty for enums and typedefs.
+    pub field_offsets: Vec<(String, u64)>,
+}
+
+impl ItemLayout {
+    fn type_layout(&self) -> TypeLayout {
+        TypeLayout {
+            size: self.size,
+            align: self.align,
+        }
+    }
+}
+
+fn align_up(offset: u64, align: u64) -> u64 {
+    (offset + align - 1) / align * align
+}
+
+/// Computes the layouts of `items`, and of the bodies of the variants of the
+/// enums among them, by path.
+///
+/// Items whose layout can't be known statically are left out. That's the case
+/// of items depending on opaque items, on a cfg, on bitfields or on `VaList`,
+/// and of zero-sized items, which C and C++ don't agree on the size of.
+pub(crate) fn compute_layouts(
+    config: &LayoutConfig,
+    items: &[ItemContainer],
+) -> HashMap<Path, ItemLayout> {
+    let mut by_path = HashMap::new();
+    for item in items {
+        // Several items can share a path when they're under different cfgs,
+        // there's no telling which one a type refers to then.
+        by_path
+            .entry(item.deref().path())
+            .and_modify(|x| *x = None)
+            .or_insert(Some(item));
+    }
+
+    let mut computer = LayoutComputer {
+        config,
+        items: by_path,
+        layouts: HashMap::new(),
+        in_progress: HashSet::new(),
+    };
+    for item in items {
+        computer.item(item.deref().path());
+        if let ItemContainer::Enum(ref e) = *item {
+            for variant in &e.variants {
+                if let VariantBody::Body { ref body, .. } = variant.body {
+                    let layout = computer.body_layout(e, body).filter(|x| x.size != 0);
+                    computer.layouts.insert(body.path.clone(), layout);
+                }
+            }
+        }
+    }
+
+    computer
+        .layouts
+        .into_iter()
+        .filter_map(|(path, layout)| Some((path, layout?)))
+        .collect()
+}
+
+struct LayoutComputer<'a> {
+    config: &'a LayoutConfig,
+    items: HashMap<&'a Path, Option<&'a ItemContainer>>,
+    layouts: HashMap<Path, Option<ItemLayout>>,
+    /// The items whose layout is being computed, so that a type containing
+    /// itself doesn't overflow the stack.
+    in_progress: HashSet<Path>,
+}
+
+impl<'a> LayoutComputer<'a> {
+    fn pointer_size(&self) -> u64 {
+        self.config.pointer_width / 8
+    }
+
+    fn item(&mut self, path: &Path) -> Option<ItemLayout> {
+        if let Some(layout) = self.layouts.get(path) {
+            return layout.clone();
+        }
+        let item = (*self.items.get(path)?)?;
+        if !self.in_progress.insert(path.clone()) {
+            return None;
+        }
+
+        let layout = match *item {
+            ItemContainer::Struct(ref s) => self.struct_layout(s),
+            ItemContainer::Union(ref u) => self.union_layout(u),
+            ItemContainer::Enum(ref e) => self.enum_layout(e),
+            ItemContainer::Typedef(ref t) => self.ty(&t.aliased).map(|layout| ItemLayout {
+                size: layout.size,
+                align: layout.align,
+                field_offsets: vec![],
+            }),
+            ItemContainer::OpaqueItem(..)
+            | ItemContainer::Constant(..)
+            | ItemContainer::Static(..) => None,
+        }
+        .filter(|layout| layout.size != 0);
+
+        self.in_progress.remove(path);
+        self.layouts.insert(path.clone(), layout.clone());
+        layout
+    }
+
+    fn ty(&mut self, ty: &Type) -> Option<TypeLayout> {
+        match *ty {
+            Type::Ptr { .. } | Type::FuncPtr { .. } => Some(TypeLayout {
+                size: self.pointer_size(),
+                align: self.pointer_size(),
+            }),
+            Type::Primitive(ref prim) => self.primitive(prim),
+            Type::Array(ref ty, ConstExpr::Value(ref len)) => {
+                let elem = self.ty(ty)?;
+                Some(TypeLayout {
+                    size: elem.size * len.parse::<u64>().ok()?,
+                    align: elem.align,
+                })
+            }
+            Type::Array(_, ConstExpr::Name(..)) => None,
+            // Unlike `u64` on some 32-bit targets, atomics are always aligned
+            // to their size.
+            Type::Atomic(ref ty) => self.ty(ty).map(|layout| TypeLayout {
+                size: layout.size,
+                align: layout.size,
+            }),
+            Type::Path(ref generic) => self.item(generic.path()).map(|x| x.type_layout()),
+        }
+    }
+
+    fn primitive(&self, prim: &PrimitiveType) -> Option<TypeLayout> {
+        let size = match *prim {
+            PrimitiveType::Void | PrimitiveType::VaList => return None,
+            PrimitiveType::Bool
+            | PrimitiveType::Char
+            | PrimitiveType::SChar
+            | PrimitiveType::UChar => 1,
+            PrimitiveType::Char32 | PrimitiveType::Float => 4,
+            PrimitiveType::Float16 => 2,
+            PrimitiveType::Double => 8,
+            PrimitiveType::Float128 => 16,
+            PrimitiveType::PtrDiffT => self.pointer_size(),
+            PrimitiveType::Integer { kind, .. } => match kind {
+                IntKind::Short => 2,
+                IntKind::Int => 4,
+                IntKind::Long => self.config.long_width() / 8,
+                IntKind::LongLong => 8,
+                IntKind::SizeT | IntKind::Size => self.pointer_size(),
+                IntKind::B8 => 1,
+                IntKind::B16 => 2,
+                IntKind::B32 => 4,
+                IntKind::B64 => 8,
+                IntKind::B128 => 16,
+            },
+        };
+        let align = if size == 8 {
+            self.config.align_64
+        } else {
+            size
+        };
+        Some(TypeLayout { size, align })
+    }
+
+    /// Lays out `fields` one after the other, or all at offset 0 for a union.
+    fn fields_layout(
+        &mut self,
+        fields: &[Field],
+        alignment: Option<ReprAlign>,
+        is_union: bool,
+        tag: Option<TypeLayout>,
+    ) -> Option<ItemLayout> {
+        // The most the fields can be aligned to.
+        let max_field_align = match alignment {
+            Some(ReprAlign::Packed) => 1,
+            Some(ReprAlign::PackedN(n)) => n,
+            _ => u64::MAX,
+        };
+        let mut size = 0;
+        let mut align = 1;
+        let mut field_offsets = Vec::with_capacity(fields.len());
+        for (i, field) in fields.iter().enumerate() {
+            if field.cfg.is_some() || field.annotations.atom("bitfield").is_some() {
+                return None;
+            }
+            let layout = match tag {
+                Some(tag) if i == 0 => tag,
+                _ => self.ty(&field.ty)?,
+            };
+            let field_align = cmp::min(layout.align, max_field_align);
+            let offset = if is_union {
+                0
+            } else {
+                align_up(size, field_align)
+            };
+            field_offsets.push((field.name.clone(), offset));
+            size = cmp::max(size, offset + layout.size);
+            align = cmp::max(align, field_align);
+        }
+        if let Some(ReprAlign::Align(n)) = alignment {
+            align = cmp::max(align, n);
+        }
+        Some(ItemLayout {
+            size: align_up(size, align),
+            align,
+            field_offsets,
+        })
+    }
+
+    fn struct_layout(&mut self, s: &Struct) -> Option<ItemLayout> {
+        if !s.generic_params.is_empty() {
+            return None;
+        }
+        self.fields_layout(&s.fields, s.alignment, false, None)
+    }
+
+    /// The tag field of the body of a variant refers to the tag enum, which
+    /// isn't an item on its own.
+    fn body_layout(&mut self, e: &Enum, body: &Struct) -> Option<ItemLayout> {
+        if !body.has_tag_field {
+            return self.struct_layout(body);
+        }
+        if !body.generic_params.is_empty() {
+            return None;
+        }
+        let tag = self.tag_layout(e)?;
+        self.fields_layout(&body.fields, body.alignment, false, Some(tag))
+    }
+
+    fn tag_layout(&self, e: &Enum) -> Option<TypeLayout> {
+        // C leaves the size of an enum without a `#[repr(prim)]` up to the
+        // implementation, but in practice it's always an `int`.
+        self.primitive(&e.repr.ty.map_or(
+            PrimitiveType::Integer {
+                zeroable: true,
+                signed: true,
+                kind: IntKind::Int,
+            },
+            |ty| ty.to_primitive(),
+        ))
+    }
+
+    fn union_layout(&mut self, u: &Union) -> Option<ItemLayout> {
+        if !u.generic_params.is_empty() {
+            return None;
+        }
+        self.fields_layout(&u.fields, u.alignment, true, None)
+    }
+
+    /// Enums with data are laid out as described in RFC 2195: a struct of the
+    /// tag and of a union of the variants for `repr(C)`, a union of the
+    /// variants, all starting with the tag, for `repr(prim)` only.
+    fn enum_layout(&mut self, e: &Enum) -> Option<ItemLayout> {
+        if !e.generic_params.is_empty() {
+            return None;
+        }
+
+        let tag = self.tag_layout(e)?;
+        if e.tag.is_none() {
+            return Some(ItemLayout {
+                size: tag.size,
+                align: tag.align,
+                field_offsets: vec![],
+            });
+        }
+
+        let mut variants = TypeLayout { size: 0, align: 1 };
+        for variant in &e.variants {
+            if variant.cfg.is_some() {
+                return None;
+            }
+            if let VariantBody::Body { ref body, .. } = variant.body {
+                let body = self.body_layout(e, body)?;
+                variants.size = cmp::max(variants.size, body.size);
+                variants.align = cmp::max(variants.align, body.align);
+            }
+        }
+
+        let mut align = cmp::max(tag.align, variants.align);
+        let size = if Enum::inline_tag_field(&e.repr) {
+            cmp::max(tag.size, variants.size)
+        } else {
+            align_up(tag.size, variants.align) + variants.size
+        };
+        if let Some(ReprAlign::Align(n)) = e.repr.align {
+            align = cmp::max(align, n);
+        }
+        Some(ItemLayout {
+            size: align_up(size, align),
+            align,
+            field_offsets: vec![],
+        })
+    }
+}
+
+/// How C and C++ refer to the type `name`, `keyword` being what it's declared
+/// with when there's no typedef for it, if anything.
+pub(crate) fn c_type_name<'a>(
+    config: &Config,
+    name: &'a str,
+    keyword: Option<&str>,
+) -> Cow<'a, str> {
+    match keyword {
+        Some(keyword) if config.language != Language::Cxx && !config.style.generate_typedef() => {
+            Cow::Owned(format!("{} {}", keyword, name))
+        }
+        _ => Cow::Borrowed(name),
+    }
+}
+
+/// An item checked by the layout checks.
+struct CheckedItem<'a> {
+    rust_name: &'a str,
+    c_name: String,
+    layout: &'a ItemLayout,
+    /// The Rust and C names of the fields, along with their offsets.
+    fields: Vec<(&'a str, &'a str, u64)>,
+}
+
+/// The items of `bindings` whose layout is known and which can be named both
+/// in Rust and C. Items under a cfg and instantiations of generic items are
+/// left out.
+fn checked_items(bindings: &Bindings) -> Vec<CheckedItem<'_>> {
+    let config = &bindings.config;
+    let namespace = if config.language == Language::Cxx {
+        let mut namespaces: Vec<&str> = config.namespace.iter().map(|x| &**x).collect();
+        namespaces.extend(config.namespaces.iter().flatten().map(|x| &**x));
+        namespaces.iter().map(|x| format!("{}::", x)).collect()
+    } else {
+        String::new()
+    };
+
+    let mut checked = vec![];
+    for item in bindings.items() {
+        let item_ref = item.deref();
+        if item_ref.cfg().is_some() || bindings.is_monomorph(item_ref.path()) {
+            continue;
+        }
+        let layout = match bindings.layout(item_ref.path()) {
+            Some(layout) => layout,
+            None => continue,
+        };
+        let (keyword, fields): (_, &[Field]) = match *item {
+            ItemContainer::Struct(ref s) if s.is_transparent => (None, &[]),
+            ItemContainer::Struct(ref s) => (Some("struct"), &s.fields),
+            ItemContainer::Union(ref u) => (Some("union"), &u.fields),
+            ItemContainer::Enum(ref e) if e.tag.is_some() => {
+                let inline_tag_field = Enum::inline_tag_field(&e.repr);
+                (Some(if inline_tag_field { "union" } else { "struct" }), &[])
+            }
+            // Enums with a size are always typedef'd in C.
+            ItemContainer::Enum(ref e) if e.repr.ty.is_some() => (None, &[]),
+            ItemContainer::Enum(..) => (Some("enum"), &[]),
+            ItemContainer::Typedef(..) => (None, &[]),
+            ItemContainer::OpaqueItem(..)
+            | ItemContainer::Constant(..)
+            | ItemContainer::Static(..) => continue,
+        };
+        checked.push(CheckedItem {
+            rust_name: item_ref.path().name(),
+            c_name: format!(
+                "{}{}",
+                namespace,
+                c_type_name(config, item_ref.export_name(), keyword)
+            ),
+            layout,
+            fields: fields
+                .iter()
+                .zip(&layout.field_offsets)
+                .map(|(field, &(ref name, offset))| (&*field.rust_name, &**name, offset))
+                .collect(),
+        });
+    }
+    checked
+}
+
+/// Writes Rust code asserting at compile time that rustc lays out the types of
+/// `bindings` the way the header expects, and a `print_layouts` function
+/// printing their layouts the way `write_c_checks` does.
+pub(crate) fn write_rust_checks(bindings: &Bindings, out: &mut dyn Write) {
+    let items = checked_items(bindings);
+
+    writeln!(out, "// Layout checks of the types exported to C.").unwrap();
+    writeln!(
+        out,
+        "// This is meant to be included where the types and their fields are visible."
+    )
+    .unwrap();
+    for item in &items {
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "const _: () = assert!(core::mem::size_of::<{}>() == {});",
+            item.rust_name, item.layout.size
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "const _: () = assert!(core::mem::align_of::<{}>() == {});",
+            item.rust_name, item.layout.align
+        )
+        .unwrap();
+        for &(field, _, offset) in &item.fields {
+            writeln!(
+                out,
+                "const _: () = assert!(core::mem::offset_of!({}, {}) == {});",
+                item.rust_name, field, offset
+            )
+            .unwrap();
+        }
+    }
+
+    writeln!(out).unwrap();
+    writeln!(out, "/// Prints the layouts the way the C checks do.").unwrap();
+    writeln!(out, "pub fn print_layouts() {{").unwrap();
+    for item in &items {
+        writeln!(
+            out,
+            "    println!(\"size {} {{}}\", core::mem::size_of::<{}>());",
+            item.c_name, item.rust_name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    println!(\"align {} {{}}\", core::mem::align_of::<{}>());",
+            item.c_name, item.rust_name
+        )
+        .unwrap();
+        for &(rust_field, c_field, _) in &item.fields {
+            writeln!(
+                out,
+                "    println!(\"offset {}.{} {{}}\", core::mem::offset_of!({}, {}));",
+                item.c_name, c_field, item.rust_name, rust_field
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "}}").unwrap();
+}
+
+/// Writes a C or C++ translation unit including `header` and printing the
+/// layouts of its types the way `print_layouts` does.
+pub(crate) fn write_c_checks(bindings: &Bindings, header: &str, out: &mut dyn Write) {
+    let items = checked_items(bindings);
+
+    if bindings.config.language != Language::Cxx {
+        writeln!(out, "#include <stdalign.h>").unwrap();
+    }
+    writeln!(out, "#include <stddef.h>").unwrap();
+    writeln!(out, "#include <stdio.h>").unwrap();
+    writeln!(out, "#include \"{}\"", header).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "int main(void) {{").unwrap();
+    for item in &items {
+        writeln!(
+            out,
+            "  printf(\"size {} %zu\\n\", sizeof({}));",
+            item.c_name, item.c_name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "  printf(\"align {} %zu\\n\", alignof({}));",
+            item.c_name, item.c_name
+        )
+        .unwrap();
+        for &(_, field, _) in &item.fields {
+            writeln!(
+                out,
+                "  printf(\"offset {}.{} %zu\\n\", offsetof({}, {}));",
+                item.c_name, field, item.c_name, field
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "  return 0;").unwrap();
+    writeln!(out, "}}").unwrap();
+}
@@ -2,17 +2,24 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::mem;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::bindgen::bindings::Bindings;
 use crate::bindgen::config::{Config, Language, SortKey};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::error::Error;
-use crate::bindgen::ir::{Constant, Enum, Function, Item, ItemContainer, ItemMap};
-use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union};
+use crate::bindgen::ir::VariantBody;
+use crate::bindgen::ir::{Abi, Cfg, Constant, Enum, Field, Function, Item, ItemContainer, ItemMap};
+use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, ToCondition, Type, Typedef, Union};
+use crate::bindgen::language_backend::LanguageBackend;
 use crate::bindgen::monomorph::Monomorphs;
+use crate::bindgen::pattern::{self, NamePattern};
+use crate::bindgen::split::HeaderSplit;
 use crate::bindgen::ItemType;
 
 #[derive(Debug, Clone)]
@@ -27,6 +34,9 @@ pub struct Library {
     typedefs: ItemMap<Typedef>,
     functions: Vec<Function>,
     source_files: Vec<PathBuf>,
+    /// The Rust module each item and function was defined in, by path.
+    modules: HashMap<Path, String>,
+    language_backend: Arc<dyn LanguageBackend>,
 }
 
 impl Library {
@@ -42,6 +52,8 @@ impl Library {
         typedefs: ItemMap<Typedef>,
         functions: Vec<Function>,
         source_files: Vec<PathBuf>,
+        modules: HashMap<Path, String>,
+        language_backend: Arc<dyn LanguageBackend>,
     ) -> Library {
         Library {
             config,
@@ -54,6 +66,8 @@ impl Library {
             typedefs,
             functions,
             source_files,
+            modules,
+            language_backend,
         }
     }
 
@@ -66,9 +80,12 @@ impl Library {
             SortKey::None => { /* keep input order */ }
         }
 
-        if self.config.language != Language::Cxx {
-            self.instantiate_monomorphs();
-        }
+        let monomorph_paths = if self.config.language != Language::Cxx {
+            self.instantiate_monomorphs()
+        } else {
+            HashSet::new()
+        };
+        self.check_export_patterns();
         self.remove_excluded();
         if self.config.language == Language::C {
             self.resolve_declaration_types();
@@ -87,8 +104,7 @@ impl Library {
         self.constants.for_all_items(|constant| {
             constant.add_dependencies(&self, &mut dependencies);
         });
-        for name in &self.config.export.include {
-            let path = Path::new(name.clone());
+        for path in self.included_paths() {
             if let Some(items) = self.get_items(&path) {
                 if dependencies.items.insert(path) {
                     for item in &items {
@@ -103,8 +119,8 @@ impl Library {
 
         dependencies.sort();
 
-        let items = dependencies.order;
-        let constants = if self.config.export.should_generate(ItemType::Constants) {
+        let mut items = dependencies.order;
+        let mut constants = if self.config.export.should_generate(ItemType::Constants) {
             let mut constants = self.constants.to_vec();
             match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
                 SortKey::Name => constants.sort_by(|x, y| x.path.cmp(&y.path)),
@@ -115,7 +131,7 @@ impl Library {
             vec![]
         };
 
-        let globals = if self.config.export.should_generate(ItemType::Globals) {
+        let mut globals = if self.config.export.should_generate(ItemType::Globals) {
             let mut globals = self.globals.to_vec();
             match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
                 SortKey::Name => globals.sort_by(|x, y| x.path.cmp(&y.path)),
@@ -125,12 +141,30 @@ impl Library {
         } else {
             vec![]
         };
-        let functions = if self.config.export.should_generate(ItemType::Functions) {
-            self.functions
+        let mut functions = if self.config.export.should_generate(ItemType::Functions) {
+            mem::take(&mut self.functions)
         } else {
             vec![]
         };
 
+        self.remove_unsupported(&mut items, &mut constants, &mut globals, &mut functions);
+
+        self.check_calling_conventions(&items, &globals, &functions);
+        self.check_flexible_arrays(&items);
+
+        let split = if self.config.split.is_enabled(self.config.language) {
+            Some(HeaderSplit::new(
+                &self,
+                &self.modules,
+                &items,
+                &constants,
+                &globals,
+                &functions,
+            )?)
+        } else {
+            None
+        };
+
         Ok(Bindings::new(
             self.config,
             self.structs,
@@ -139,8 +173,11 @@ impl Library {
             globals,
             items,
             functions,
+            monomorph_paths,
+            split,
             self.source_files,
             false,
+            self.language_backend,
         ))
     }
 
@@ -168,28 +205,368 @@ impl Library {
         &self.config
     }
 
+    /// The paths of the items in `[export] include`, in the order of the
+    /// config, and for patterns in the order of the items.
+    fn included_paths(&self) -> Vec<Path> {
+        let mut paths = vec![];
+        for name in &self.config.export.include {
+            if !pattern::is_pattern(name) {
+                paths.push(Path::new(name.clone()));
+                continue;
+            }
+            let pattern = match NamePattern::new(name) {
+                Ok(pattern) => pattern,
+                Err(_) => continue,
+            };
+            let mut add = |path: &Path| {
+                if pattern.matches(path.name()) {
+                    paths.push(path.clone());
+                }
+            };
+            self.enums.for_all_items(|x| add(x.path()));
+            self.structs.for_all_items(|x| add(x.path()));
+            self.unions.for_all_items(|x| add(x.path()));
+            self.opaque_items.for_all_items(|x| add(x.path()));
+            self.typedefs.for_all_items(|x| add(x.path()));
+        }
+        paths
+    }
+
+    /// Leaves out what the language backend can't express, along with the
+    /// items, constants, globals and functions using it: items only generated
+    /// under some `[defines]` condition, or with fields or variants which are,
+    /// if it can't write conditions, and anything using primitives it has no
+    /// type for.
+    fn remove_unsupported(
+        &self,
+        items: &mut Vec<ItemContainer>,
+        constants: &mut Vec<Constant>,
+        globals: &mut Vec<Static>,
+        functions: &mut Vec<Function>,
+    ) {
+        let config = &self.config;
+        let backend = &self.language_backend;
+        let unsupported = |name: &dyn fmt::Display, cfg: Option<&Cfg>, types: &[&Type]| {
+            match cfg {
+                Some(cfg) if !backend.writes_conditions() && cfg.to_condition(config).is_some() => {
+                    warn!(
+                        "Leaving out `{}`, as these bindings can't express `#[cfg({})]`.",
+                        name, cfg
+                    );
+                    return true;
+                }
+                _ => {}
+            }
+            let mut primitives = BTreeSet::new();
+            for ty in types {
+                ty.add_primitives(&mut primitives);
+            }
+            match primitives.iter().find(|p| !backend.supports_primitive(p)) {
+                Some(primitive) => {
+                    warn!(
+                        "Leaving out `{}`, as these bindings have no type for `{}`.",
+                        name,
+                        primitive.to_repr_rust()
+                    );
+                    true
+                }
+                None => false,
+            }
+        };
+
+        let mut removed = BTreeSet::new();
+        for container in items.iter() {
+            let item = container.deref();
+            if unsupported(&item.export_name(), item.cfg(), &container.types()) {
+                removed.insert(item.path().clone());
+                continue;
+            }
+            if backend.writes_conditions() {
+                continue;
+            }
+            if let Some(part) = conditional_part(config, container) {
+                warn!(
+                    "Leaving out `{}`, as these bindings can't express that its {} is \
+                     conditional.",
+                    item.export_name(),
+                    part
+                );
+                removed.insert(item.path().clone());
+            }
+        }
+
+        // The dependencies of an item include those of the items it uses, so
+        // a single pass finds everything using the removed items.
+        let uses_removed = |name: &str, dependencies: Dependencies| {
+            if removed.is_empty() {
+                return false;
+            }
+            let used = dependencies
+                .items
+                .iter()
+                .filter(|path| removed.contains(path));
+            match used.min() {
+                Some(used) => {
+                    warn!("Leaving out `{}`, which uses `{}`.", name, used);
+                    true
+                }
+                None => false,
+            }
+        };
+        let mut dependents = HashSet::new();
+        for item in items.iter() {
+            let item = item.deref();
+            if removed.is_empty() || removed.contains(item.path()) {
+                continue;
+            }
+            let mut dependencies = Dependencies::new();
+            item.add_dependencies(self, &mut dependencies);
+            if uses_removed(item.export_name(), dependencies) {
+                dependents.insert(item.path().clone());
+            }
+        }
+
+        items.retain(|item| {
+            let path = item.deref().path();
+            !removed.contains(path) && !dependents.contains(path)
+        });
+        for item in items.iter_mut() {
+            if let ItemContainer::Struct(ref mut x) = *item {
+                let name = &x.export_name;
+                x.associated_constants.retain(|constant| {
+                    let name = format!("{}::{}", name, constant.path.name());
+                    !unsupported(&name, constant.cfg.as_ref(), &[&constant.ty])
+                });
+            }
+        }
+        constants.retain(|constant| {
+            let name = constant.export_name();
+            if unsupported(&name, constant.cfg.as_ref(), &[&constant.ty]) {
+                return false;
+            }
+            let mut dependencies = Dependencies::new();
+            constant.add_dependencies(self, &mut dependencies);
+            !uses_removed(name, dependencies)
+        });
+        globals.retain(|global| {
+            let name = &global.export_name;
+            if unsupported(name, global.cfg.as_ref(), &[&global.ty]) {
+                return false;
+            }
+            let mut dependencies = Dependencies::new();
+            global.add_dependencies(self, &mut dependencies);
+            !uses_removed(name, dependencies)
+        });
+        functions.retain(|function| {
+            let name = function.path.name();
+            let types: Vec<&Type> = Some(&function.ret)
+                .into_iter()
+                .chain(function.args.iter().map(|arg| &arg.ty))
+                .collect();
+            if unsupported(&name, function.cfg.as_ref(), &types) {
+                return false;
+            }
+            let mut dependencies = Dependencies::new();
+            function.add_dependencies(self, &mut dependencies);
+            !uses_removed(name, dependencies)
+        });
+    }
+
+    /// Warns about the calling conventions the bindings use but can't express.
+    fn check_calling_conventions(
+        &self,
+        items: &[ItemContainer],
+        globals: &[Static],
+        functions: &[Function],
+    ) {
+        let function_abis: BTreeSet<Abi> = functions.iter().map(|f| f.abi).collect();
+        let mut pointer_abis = BTreeSet::new();
+        for function in functions {
+            function.ret.add_abis(&mut pointer_abis);
+            for arg in &function.args {
+                arg.ty.add_abis(&mut pointer_abis);
+            }
+        }
+        for global in globals {
+            global.ty.add_abis(&mut pointer_abis);
+        }
+        for item in items {
+            for ty in item.types() {
+                ty.add_abis(&mut pointer_abis);
+            }
+        }
+
+        let abis = function_abis.union(&pointer_abis).copied();
+        for abi in abis.filter(|abi| !matches!(abi, Abi::C | Abi::Cdecl)) {
+            self.language_backend.check_calling_convention(
+                &self.config,
+                abi,
+                function_abis.contains(&abi),
+                pointer_abis.contains(&abi),
+            );
+        }
+    }
+
+    /// Warns about the structs with a flexible array member which other items
+    /// embed by value, which C doesn't allow. C++ keeps the `[0]` of gcc
+    /// instead, and the other languages have no flexible array members.
+    fn check_flexible_arrays(&self, items: &[ItemContainer]) {
+        if !matches!(self.config.language, Language::C | Language::Cython) {
+            return;
+        }
+        let flexible: HashSet<&str> = items
+            .iter()
+            .filter_map(|item| match *item {
+                ItemContainer::Struct(ref s) if s.fields.iter().any(|f| f.flexible_array) => {
+                    Some(s.export_name())
+                }
+                _ => None,
+            })
+            .collect();
+        if flexible.is_empty() {
+            return;
+        }
+        for item in items {
+            if !matches!(
+                *item,
+                ItemContainer::Struct(..) | ItemContainer::Union(..) | ItemContainer::Enum(..)
+            ) {
+                continue;
+            }
+            for mut ty in item.types() {
+                while let Type::Array(ref element, _) = *ty {
+                    ty = element;
+                }
+                if let Type::Path(ref path) = *ty {
+                    if flexible.contains(path.export_name()) {
+                        warn!(
+                            "`{}` has a flexible array member, so it can't be a field of `{}`.",
+                            path.export_name(),
+                            item.deref().export_name()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Warns about the patterns of `[export]` which are invalid or match no
+    /// item.
+    fn check_export_patterns(&self) {
+        let export = &self.config.export;
+        let patterns = export
+            .include
+            .iter()
+            .map(|pattern| ("include", pattern))
+            .chain(export.exclude.iter().map(|pattern| ("exclude", pattern)))
+            .chain(export.rename.keys().map(|pattern| ("rename", pattern)));
+
+        let mut names: Vec<String> = self
+            .functions
+            .iter()
+            .map(|x| x.path().name().to_owned())
+            .collect();
+        let mut add = |path: &Path| names.push(path.name().to_owned());
+        self.constants.for_all_items(|x| add(x.path()));
+        self.globals.for_all_items(|x| add(x.path()));
+        self.enums.for_all_items(|x| add(x.path()));
+        self.structs.for_all_items(|x| add(x.path()));
+        self.unions.for_all_items(|x| add(x.path()));
+        self.opaque_items.for_all_items(|x| add(x.path()));
+        self.typedefs.for_all_items(|x| add(x.path()));
+
+        for (key, pattern) in patterns {
+            if !pattern::is_pattern(pattern) {
+                continue;
+            }
+            match NamePattern::new(pattern) {
+                Ok(compiled) => {
+                    if !names.iter().any(|name| compiled.matches(name)) {
+                        warn!(
+                            "Pattern `{}` in `[export] {}` doesn't match any item.",
+                            pattern, key
+                        );
+                    }
+                }
+                Err(message) => warn!(
+                    "Invalid pattern `{}` in `[export] {}`: {}.",
+                    pattern, key, message
+                ),
+            }
+        }
+    }
+
     fn remove_excluded(&mut self) {
         let config = &self.config;
         // FIXME: interpret `config.export.exclude` as `Path`s.
         self.functions
-            .retain(|x| !config.export.exclude.iter().any(|y| y == x.path().name()));
+            .retain(|x| !config.export.excludes(x.path().name()));
         self.enums
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+            .filter(|x| config.export.excludes(x.path().name()));
         self.structs
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+            .filter(|x| config.export.excludes(x.path().name()));
         self.unions
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+            .filter(|x| config.export.excludes(x.path().name()));
         self.opaque_items
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+            .filter(|x| config.export.excludes(x.path().name()));
         self.typedefs
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+            .filter(|x| config.export.excludes(x.path().name()));
         self.globals
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+            .filter(|x| config.export.excludes(x.path().name()));
         self.constants
-            .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+            .filter(|x| config.export.excludes(x.path().name()));
+    }
+
+    /// Merges `[export.annotations]` into the annotations of the items, and
+    /// of the fields of structs and unions.
+    fn merge_config_annotations(&mut self) {
+        let annotations = &self.config.export.annotations;
+        if annotations.is_empty() {
+            return;
+        }
+
+        macro_rules! merge {
+            ($field:ident) => {
+                self.$field.for_all_items_mut(|x| {
+                    if let Some(extra) = annotations.get(x.path().name()) {
+                        x.annotations_mut().merge(extra);
+                    }
+                });
+            };
+        }
+        macro_rules! merge_fields {
+            ($field:ident) => {
+                self.$field.for_all_items_mut(|x| {
+                    for field in &mut x.fields {
+                        let key = format!("{}::{}", x.path.name(), field.rust_name);
+                        if let Some(extra) = annotations.get(&key) {
+                            field.annotations.merge(extra);
+                        }
+                    }
+                });
+            };
+        }
+
+        merge!(constants);
+        merge!(globals);
+        merge!(enums);
+        merge!(structs);
+        merge!(unions);
+        merge!(opaque_items);
+        merge!(typedefs);
+        merge_fields!(structs);
+        merge_fields!(unions);
+        for function in &mut self.functions {
+            if let Some(extra) = annotations.get(function.path.name()) {
+                function.annotations.merge(extra);
+            }
+        }
     }
 
     fn transfer_annotations(&mut self) {
+        // Merged first so that those of typedefs are transferred too.
+        self.merge_config_annotations();
+
         let mut annotations = HashMap::new();
 
         self.typedefs.for_all_items_mut(|x| {
@@ -386,9 +763,11 @@ impl Library {
         }
     }
 
-    fn instantiate_monomorphs(&mut self) {
+    /// Returns the paths of the monomorphs.
+    fn instantiate_monomorphs(&mut self) -> HashSet<Path> {
         // Collect a list of monomorphs
         let mut monomorphs = Monomorphs::default();
+        let mut paths = HashSet::new();
 
         self.structs.for_all_items(|x| {
             x.add_monomorphs(self, &mut monomorphs);
@@ -408,27 +787,39 @@ impl Library {
 
         // Insert the monomorphs into self
         for monomorph in monomorphs.drain_structs() {
+            paths.insert(monomorph.path.clone());
             self.structs.try_insert(monomorph);
         }
         for monomorph in monomorphs.drain_unions() {
+            paths.insert(monomorph.path.clone());
             self.unions.try_insert(monomorph);
         }
         for monomorph in monomorphs.drain_opaques() {
+            paths.insert(monomorph.path.clone());
             self.opaque_items.try_insert(monomorph);
         }
         for monomorph in monomorphs.drain_typedefs() {
+            paths.insert(monomorph.path.clone());
             self.typedefs.try_insert(monomorph);
         }
         for monomorph in monomorphs.drain_enums() {
+            paths.insert(monomorph.path.clone());
             self.enums.try_insert(monomorph);
         }
 
+        // Monomorphs are defined along with their generic item.
+        for (generic, monomorph) in monomorphs.generic_paths() {
+            if let Some(module) = self.modules.get(generic).cloned() {
+                self.modules.insert(monomorph.clone(), module);
+            }
+        }
+
         // Remove structs and opaque items that are generic
-        self.opaque_items.filter(|x| x.generic_params.len() > 0);
-        self.structs.filter(|x| x.generic_params.len() > 0);
-        self.unions.filter(|x| x.generic_params.len() > 0);
-        self.enums.filter(|x| x.generic_params.len() > 0);
-        self.typedefs.filter(|x| x.generic_params.len() > 0);
+        self.opaque_items.filter(|x| !x.generic_params.is_empty());
+        self.structs.filter(|x| !x.generic_params.is_empty());
+        self.unions.filter(|x| !x.generic_params.is_empty());
+        self.enums.filter(|x| !x.generic_params.is_empty());
+        self.typedefs.filter(|x| !x.generic_params.is_empty());
 
         // Mangle the paths that remain
         self.unions
@@ -442,5 +833,31 @@ impl Library {
         for x in &mut self.functions {
             x.mangle_paths(&monomorphs);
         }
+
+        paths
+    }
+}
+
+/// The first field or variant of `item` which is only generated under some
+/// `[defines]` condition, if any.
+fn conditional_part(config: &Config, item: &ItemContainer) -> Option<String> {
+    let is_conditional = |cfg: &Option<Cfg>| cfg.to_condition(config).is_some();
+    let field = |fields: &[Field]| {
+        let field = fields.iter().find(|field| is_conditional(&field.cfg))?;
+        Some(format!("field `{}`", field.name))
+    };
+    match *item {
+        ItemContainer::Struct(ref x) => field(&x.fields),
+        ItemContainer::Union(ref x) => field(&x.fields),
+        ItemContainer::Enum(ref x) => x.variants.iter().find_map(|variant| {
+            if is_conditional(&variant.cfg) {
+                return Some(format!("variant `{}`", variant.export_name));
+            }
+            match variant.body {
+                VariantBody::Body { ref body, .. } => field(&body.fields),
+                VariantBody::Empty(..) => None,
+            }
+        }),
+        _ => None,
     }
 }
@@ -128,6 +128,12 @@ impl<'a> Mangler<'a> {
                     self.push(Separator::EndFn);
                 }
             }
+            Type::Atomic(ref ty) => {
+                // Mangled like the `Atomic<T>` it would be if it was generic.
+                let generics = vec![GenericArgument::Type((**ty).clone())];
+                let path = Type::Path(GenericPath::new(Path::new("Atomic"), generics));
+                self.append_mangled_type(&path, last);
+            }
             Type::Array(..) => {
                 unimplemented!(
                     "Unable to mangle generic parameter {:?} for '{}'",
@@ -190,6 +196,19 @@ fn generics() {
         Path::new("Foo_f32")
     );
 
+    // Foo<u128, f16> => Foo_u128__f16
+    assert_eq!(
+        mangle_path(
+            &Path::new("Foo"),
+            &[
+                GenericArgument::Type(Type::Primitive(PrimitiveType::maybe("u128").unwrap())),
+                GenericArgument::Type(Type::Primitive(PrimitiveType::Float16)),
+            ],
+            &MangleConfig::default(),
+        ),
+        Path::new("Foo_u128__f16")
+    );
+
     // Foo<Bar<f32>> => Foo_Bar_f32
     assert_eq!(
         mangle_path(
@@ -4,20 +4,23 @@
 
 /// A helper macro for deriving deserialize for an enum to be used in toml-rs.
 /// This macro works be relying on an existing FromStr implementation for the
-/// desired type.
+/// desired type. The listed spellings are the ones the config schema offers,
+/// they should match the ones FromStr accepts.
 macro_rules! deserialize_enum_str {
-    ($name:ident) => {
+    ($name:ident, [$($variant:expr),* $(,)?]) => {
         impl<'de> ::serde::Deserialize<'de> for $name {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: ::serde::Deserializer<'de>,
             {
+                const VARIANTS: &[&str] = &[$($variant),*];
+
                 struct Visitor;
                 impl<'de> ::serde::de::Visitor<'de> for Visitor {
                     type Value = $name;
 
                     fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                        f.write_str("$name")
+                        f.write_str(stringify!($name))
                     }
 
                     fn visit_str<E>(self, v: &str) -> Result<$name, E>
@@ -29,8 +32,18 @@ macro_rules! deserialize_enum_str {
                             Err(m) => Err(E::custom(m)),
                         }
                     }
+
+                    fn visit_enum<A>(self, data: A) -> Result<$name, A::Error>
+                    where
+                        A: ::serde::de::EnumAccess<'de>,
+                    {
+                        use ::serde::de::{Error, VariantAccess};
+                        let (v, variant) = data.variant::<String>()?;
+                        variant.unit_variant()?;
+                        v.parse::<$name>().map_err(A::Error::custom)
+                    }
                 }
-                deserializer.deserialize_str(Visitor)
+                deserializer.deserialize_enum(stringify!($name), VARIANTS, Visitor)
             }
         }
     };
@@ -42,18 +55,32 @@ mod builder;
 mod cargo;
 mod cdecl;
 mod config;
+mod csdecl;
 mod declarationtyperesolver;
 mod dependencies;
+mod diff;
 mod error;
-mod ir;
+// Only public for the types `language_backend` re-exports, the IR isn't a
+// stable API.
+#[doc(hidden)]
+pub mod ir;
+mod json;
+pub mod language_backend;
+mod layout;
 mod library;
 mod mangle;
 mod monomorph;
 mod parser;
+mod pattern;
+mod pydecl;
 mod rename;
 mod reserved;
+mod schema;
+mod split;
+mod symbols;
 mod utilities;
 mod writer;
+mod zigdecl;
 
 #[allow(unused)]
 pub(crate) use self::cargo::*;
@@ -62,4 +89,10 @@ pub use self::bindings::Bindings;
 pub use self::builder::Builder;
 pub use self::config::Profile; // disambiguate with cargo::Profile
 pub use self::config::*;
+#[allow(unused)]
+pub use self::diff::{AbiChange, AbiChangeKind, AbiDiff};
 pub use self::error::Error;
+#[allow(unused)]
+pub use self::layout::ItemLayout;
+#[allow(unused)]
+pub use self::writer::{ListType, Source, SourceWriter};
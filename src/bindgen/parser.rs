@@ -6,19 +6,23 @@ use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::Read;
+use std::mem;
 use std::path::{Path as FilePath, PathBuf as FilePathBuf};
 
 use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
 
 use crate::bindgen::bitflags;
 use crate::bindgen::cargo::{Cargo, PackageRef};
 use crate::bindgen::config::{Config, ParseConfig};
 use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, Constant, Documentation, Enum, Function, GenericParam, GenericParams,
-    ItemMap, OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
+    AnnotationSet, Cfg, Constant, Documentation, Enum, Function, GenericArgument, GenericParam,
+    GenericParams, GenericPath, ItemContainer, ItemMap, OpaqueItem, Path, Static, Struct, Type,
+    Typedef, Union,
 };
-use crate::bindgen::utilities::{SynAbiHelpers, SynAttributeHelpers, SynItemFnHelpers};
+use crate::bindgen::pattern::{self, NamePattern};
+use crate::bindgen::utilities::{self, SynAbiHelpers, SynAttributeHelpers, SynItemHelpers};
 
 const STD_CRATES: &[&str] = &[
     "std",
@@ -45,9 +49,11 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         config: &config,
         lib: None,
         parsed_crates: HashSet::new(),
+        dependency_names: HashSet::new(),
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        mod_stack: Vec::new(),
         out: Parse::new(),
     };
 
@@ -56,7 +62,7 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         version: None,
     };
 
-    context.parse_mod(&pkg_ref, src_file, 0)?;
+    context.parse_mod(&pkg_ref, src_file, /* is_mod_rs = */ true)?;
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
     Ok(context.out)
 }
@@ -72,18 +78,48 @@ pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
         config,
         lib: Some(lib),
         parsed_crates: HashSet::new(),
+        dependency_names: HashSet::new(),
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        mod_stack: Vec::new(),
         out: Parse::new(),
     };
 
     let binding_crate = context.lib.as_ref().unwrap().binding_crate_ref();
     context.parse_crate(&binding_crate)?;
+    context.check_crate_patterns();
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
     Ok(context.out)
 }
 
+/// Collects the `Name as Alias`es of a `use` tree, leaving out `as _` and
+/// modules.
+fn collect_use_renames<'a>(tree: &'a syn::UseTree, out: &mut Vec<&'a syn::UseRename>) {
+    match *tree {
+        syn::UseTree::Path(ref path) => collect_use_renames(&path.tree, out),
+        syn::UseTree::Group(ref group) => {
+            for tree in &group.items {
+                collect_use_renames(tree, out);
+            }
+        }
+        syn::UseTree::Rename(ref rename) => {
+            if rename.ident != "self" && rename.rename != "_" && rename.ident != rename.rename {
+                out.push(rename);
+            }
+        }
+        syn::UseTree::Name(..) | syn::UseTree::Glob(..) => {}
+    }
+}
+
+/// Whether a macro invocation is `include!`.
+fn is_include(mac: &syn::Macro) -> bool {
+    mac.path
+        .segments
+        .last()
+        .map_or(false, |segment| segment.ident == "include")
+}
+
 #[derive(Debug, Clone)]
 struct Parser<'a> {
     binding_crate_name: String,
@@ -91,16 +127,21 @@ struct Parser<'a> {
     config: &'a Config,
 
     parsed_crates: HashSet<String>,
+    /// The names of the dependencies seen, parsed or not.
+    dependency_names: HashSet<String>,
     cache_src: HashMap<FilePathBuf, Vec<syn::Item>>,
     cache_expanded_crate: HashMap<String, Vec<syn::Item>>,
 
     cfg_stack: Vec<Cfg>,
+    /// The names of the modules being parsed inside the current crate.
+    mod_stack: Vec<String>,
 
     out: Parse,
 }
 
 impl<'a> Parser<'a> {
-    fn should_parse_dependency(&self, pkg_name: &str) -> bool {
+    fn should_parse_dependency(&mut self, pkg_name: &str) -> bool {
+        self.dependency_names.insert(pkg_name.to_owned());
         if self.parsed_crates.contains(pkg_name) {
             return false;
         }
@@ -123,7 +164,10 @@ impl<'a> Parser<'a> {
 
         // If we have a whitelist, check it
         if let Some(ref include) = self.config.parse.include {
-            if !include.iter().any(|name| name == pkg_name) {
+            if !include
+                .iter()
+                .any(|pattern| pattern::name_matches(pattern, pkg_name))
+            {
                 debug!("Excluding crate {}", pkg_name);
                 return false;
             }
@@ -136,7 +180,45 @@ impl<'a> Parser<'a> {
                 .parse
                 .exclude
                 .iter()
-                .any(|name| name == pkg_name)
+                .any(|pattern| pattern::name_matches(pattern, pkg_name))
+    }
+
+    /// Warns about the patterns of `[parse] include` and `exclude` which are
+    /// invalid or match no dependency.
+    fn check_crate_patterns(&self) {
+        if !self.config.parse.parse_deps {
+            return;
+        }
+        let parse = &self.config.parse;
+        let patterns = parse
+            .include
+            .iter()
+            .flatten()
+            .map(|pattern| ("include", pattern))
+            .chain(parse.exclude.iter().map(|pattern| ("exclude", pattern)));
+        for (key, pattern) in patterns {
+            if !pattern::is_pattern(pattern) {
+                continue;
+            }
+            match NamePattern::new(pattern) {
+                Ok(compiled) => {
+                    if !self
+                        .dependency_names
+                        .iter()
+                        .any(|name| compiled.matches(name))
+                    {
+                        warn!(
+                            "Pattern `{}` in `[parse] {}` doesn't match any crate.",
+                            pattern, key
+                        );
+                    }
+                }
+                Err(message) => warn!(
+                    "Invalid pattern `{}` in `[parse] {}`: {}.",
+                    pattern, key, message
+                ),
+            }
+        }
     }
 
     fn parse_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
@@ -154,7 +236,9 @@ impl<'a> Parser<'a> {
             let crate_src = self.lib.as_ref().unwrap().find_crate_src(pkg);
 
             match crate_src {
-                Some(crate_src) => self.parse_mod(pkg, crate_src.as_path(), 0)?,
+                Some(crate_src) => {
+                    self.parse_mod(pkg, crate_src.as_path(), /* is_mod_rs = */ true)?
+                }
                 None => {
                     // This should be an error, but is common enough to just elicit a warning
                     warn!(
@@ -203,7 +287,7 @@ impl<'a> Parser<'a> {
                         self.config.parse.expand.profile,
                     )
                     .map_err(|x| Error::CargoExpand(pkg.name.clone(), x))?;
-                let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
+                let i = utilities::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
                     crate_name: pkg.name.clone(),
                     src_path: "".to_owned(),
                     error: x,
@@ -215,48 +299,57 @@ impl<'a> Parser<'a> {
         };
 
         self.process_mod(
-            pkg, None, None, &mod_items, 0, /* is_mod_rs = */ true,
-            /* is_inline = */ false,
+            pkg, None, None, &mod_items, /* is_inline = */ false,
+            /* is_in_mod_rs = */ true,
         )
     }
 
-    fn parse_mod(
-        &mut self,
-        pkg: &PackageRef,
-        mod_path: &FilePath,
-        depth: usize,
-    ) -> Result<(), Error> {
-        let mod_items = match self.cache_src.entry(mod_path.to_path_buf()) {
+    /// Reads and parses the source file at `path`, or returns its items if it
+    /// was already parsed.
+    fn parse_file(&mut self, pkg: &PackageRef, path: &FilePath) -> Result<Vec<syn::Item>, Error> {
+        Ok(match self.cache_src.entry(path.to_path_buf()) {
             Entry::Vacant(vacant_entry) => {
                 let mut s = String::new();
-                let mut f = File::open(mod_path).map_err(|_| Error::ParseCannotOpenFile {
+                let mut f = File::open(path).map_err(|_| Error::ParseCannotOpenFile {
                     crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_str().unwrap().to_owned(),
+                    src_path: path.to_str().unwrap().to_owned(),
                 })?;
 
                 f.read_to_string(&mut s)
                     .map_err(|_| Error::ParseCannotOpenFile {
                         crate_name: pkg.name.clone(),
-                        src_path: mod_path.to_str().unwrap().to_owned(),
+                        src_path: path.to_str().unwrap().to_owned(),
                     })?;
 
-                let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
+                let i = utilities::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
                     crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_string_lossy().into(),
+                    src_path: path.to_string_lossy().into(),
                     error: x,
                 })?;
 
                 vacant_entry.insert(i.items).clone()
             }
             Entry::Occupied(occupied_entry) => occupied_entry.get().clone(),
-        };
+        })
+    }
+
+    /// `is_mod_rs` is whether the submodules of this file are looked up next
+    /// to it, which is the case for crate roots, `mod.rs` files and files
+    /// loaded through `#[path]`.
+    fn parse_mod(
+        &mut self,
+        pkg: &PackageRef,
+        mod_path: &FilePath,
+        is_mod_rs: bool,
+    ) -> Result<(), Error> {
+        let mod_items = self.parse_file(pkg, mod_path)?;
 
         // Compute module directory according to Rust 2018 rules
         let submod_dir_2018;
 
         let mod_dir = mod_path.parent().unwrap();
+        let mod_items = self.expand_includes(pkg, mod_dir, mod_items)?;
 
-        let is_mod_rs = depth == 0 || mod_path.ends_with("mod.rs");
         let submod_dir = if is_mod_rs {
             mod_dir
         } else {
@@ -272,12 +365,85 @@ impl<'a> Parser<'a> {
             Some(mod_dir),
             Some(submod_dir),
             &mod_items,
-            depth,
             /* is_inline = */ false,
             is_mod_rs,
         )
     }
 
+    /// Replaces the `include!`s in `items`, also those of inline modules, with
+    /// the items of the files they include. `dir` is the directory of the file
+    /// the items come from, which relative paths are resolved against.
+    fn expand_includes(
+        &mut self,
+        pkg: &PackageRef,
+        dir: &FilePath,
+        items: Vec<syn::Item>,
+    ) -> Result<Vec<syn::Item>, Error> {
+        let mut out = Vec::with_capacity(items.len());
+        for item in items {
+            match item {
+                syn::Item::Macro(ref item) if item.ident.is_none() && is_include(&item.mac) => {
+                    let path = item.mac.parse_body().ok();
+                    let path = match path.and_then(|x| self.eval_str(pkg, &x)) {
+                        Some(path) => dir.join(path),
+                        None => {
+                            warn!(
+                                "Parsing crate `{}`: can't resolve the path of `include!({})`.",
+                                pkg.name, item.mac.tokens
+                            );
+                            continue;
+                        }
+                    };
+                    let included = self.parse_file(pkg, &path)?;
+                    out.extend(self.expand_includes(pkg, path.parent().unwrap(), included)?);
+                }
+                syn::Item::Mod(mut item) => {
+                    if let Some((_, ref mut content)) = item.content {
+                        *content = self.expand_includes(pkg, dir, std::mem::take(content))?;
+                    }
+                    out.push(syn::Item::Mod(item));
+                }
+                item => out.push(item),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Evaluates a string built from literals, `concat!` and `env!`, like the
+    /// argument of `include!(concat!(env!("OUT_DIR"), "/ffi.rs"))`.
+    fn eval_str(&self, pkg: &PackageRef, expr: &syn::Expr) -> Option<String> {
+        let mac = match *expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(ref lit),
+                ..
+            }) => return Some(lit.value()),
+            syn::Expr::Macro(ref expr) => &expr.mac,
+            _ => return None,
+        };
+        let args = mac
+            .parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+            .ok()?;
+        if mac.path.is_ident("concat") {
+            args.iter().map(|arg| self.eval_str(pkg, arg)).collect()
+        } else if mac.path.is_ident("env") {
+            let name = self.eval_str(pkg, args.first()?)?;
+            match self.config.parse.out_dir {
+                // A relative `out_dir` is relative to the crate directory, as
+                // the `OUT_DIR` of cargo is absolute.
+                Some(ref out_dir) if name == "OUT_DIR" => {
+                    let crate_dir = self.lib.as_ref().and_then(|lib| lib.find_crate_dir(pkg));
+                    Some(match crate_dir {
+                        Some(crate_dir) => crate_dir.join(out_dir).to_string_lossy().into_owned(),
+                        None => out_dir.clone(),
+                    })
+                }
+                _ => std::env::var(name).ok(),
+            }
+        } else {
+            None
+        }
+    }
+
     /// `mod_dir` is the path to the current directory of the module. It may be
     /// `None` for pre-expanded modules.
     ///
@@ -290,16 +456,20 @@ impl<'a> Parser<'a> {
         mod_dir: Option<&FilePath>,
         submod_dir: Option<&FilePath>,
         items: &[syn::Item],
-        depth: usize,
         is_inline: bool,
         is_in_mod_rs: bool,
     ) -> Result<(), Error> {
         debug_assert_eq!(mod_dir.is_some(), submod_dir.is_some());
         // We process the items first then the nested modules.
+        let module = std::iter::once(pkg.name.replace('-', "_"))
+            .chain(self.mod_stack.iter().cloned())
+            .collect::<Vec<_>>()
+            .join("::");
         let nested_modules = self.out.load_syn_crate_mod(
             self.config,
             &self.binding_crate_name,
             &pkg.name,
+            &module,
             Cfg::join(&self.cfg_stack).as_ref(),
             items,
         );
@@ -310,77 +480,108 @@ impl<'a> Parser<'a> {
             if let Some(ref cfg) = cfg {
                 self.cfg_stack.push(cfg.clone());
             }
+            self.mod_stack.push(next_mod_name.clone());
+
+            // The `#[path]`s of the module, along with the predicates of the
+            // `#[cfg_attr]`s they're in, as platform modules often are.
+            let paths = utilities::expand_attrs(&item.attrs)
+                .into_iter()
+                .filter_map(|(cfg, meta)| match meta {
+                    syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }) if path.is_ident("path") => Some((cfg, lit.value())),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
 
             if let Some((_, ref inline_items)) = item.content {
-                // TODO(emilio): This should use #[path] attribute if present,
-                // rather than next_mod_name.
-                let next_submod_dir = submod_dir.map(|dir| dir.join(&next_mod_name));
-                let next_mod_dir = mod_dir.map(|dir| dir.join(&next_mod_name));
+                // The `#[path]` of an inline module is the name of its
+                // directory.
+                let next_dir_name = paths.last().map_or(&next_mod_name, |(_, path)| path);
+                let next_submod_dir = submod_dir.map(|dir| dir.join(next_dir_name));
+                let next_mod_dir = mod_dir.map(|dir| dir.join(next_dir_name));
                 self.process_mod(
                     pkg,
                     next_mod_dir.as_deref(),
                     next_submod_dir.as_deref(),
                     inline_items,
-                    depth,
                     /* is_inline = */ true,
                     is_in_mod_rs,
                 )?;
             } else if let Some(mod_dir) = mod_dir {
                 let submod_dir = submod_dir.unwrap();
-                let next_mod_path1 = submod_dir.join(next_mod_name.clone() + ".rs");
-                let next_mod_path2 = submod_dir.join(next_mod_name.clone()).join("mod.rs");
 
-                if next_mod_path1.exists() {
-                    self.parse_mod(pkg, next_mod_path1.as_path(), depth + 1)?;
-                } else if next_mod_path2.exists() {
-                    self.parse_mod(pkg, next_mod_path2.as_path(), depth + 1)?;
+                // https://doc.rust-lang.org/reference/items/modules.html#the-path-attribute
+                //
+                //     For path attributes on modules not inside inline module blocks, the file path
+                //     is relative to the directory the source file is located.
+                //
+                //     For path attributes inside inline module blocks, the relative location of the
+                //     file path depends on the kind of source file the path attribute is located
+                //     in.  "mod-rs" source files are root modules (such as lib.rs or main.rs) and
+                //     modules with files named mod.rs. "non-mod-rs" source files are all other
+                //     module files.
+                //
+                //     Paths for path attributes inside inline module blocks in a mod-rs file are
+                //     relative to the directory of the mod-rs file including the inline module
+                //     components as directories. For non-mod-rs files, it is the same except the
+                //     path starts with a directory with the name of the non-mod-rs module.
+                //
+                let base = if is_inline && !is_in_mod_rs {
+                    submod_dir
                 } else {
-                    // Last chance to find a module path
-                    let mut path_attr_found = false;
-                    for attr in &item.attrs {
-                        if let Ok(syn::Meta::NameValue(syn::MetaNameValue { path, lit, .. })) =
-                            attr.parse_meta()
-                        {
-                            match lit {
-                                syn::Lit::Str(ref path_lit) if path.is_ident("path") => {
-                                    path_attr_found = true;
-                                    // https://doc.rust-lang.org/reference/items/modules.html#the-path-attribute
-                                    //
-                                    //     For path attributes on modules not inside inline module blocks, the file path
-                                    //     is relative to the directory the source file is located.
-                                    //
-                                    //     For path attributes inside inline module blocks, the relative location of the
-                                    //     file path depends on the kind of source file the path attribute is located
-                                    //     in.  "mod-rs" source files are root modules (such as lib.rs or main.rs) and
-                                    //     modules with files named mod.rs. "non-mod-rs" source files are all other
-                                    //     module files.
-                                    //
-                                    //     Paths for path attributes inside inline module blocks in a mod-rs file are
-                                    //     relative to the directory of the mod-rs file including the inline module
-                                    //     components as directories. For non-mod-rs files, it is the same except the
-                                    //     path starts with a directory with the name of the non-mod-rs module.
-                                    //
-                                    let base = if is_inline && !is_in_mod_rs {
-                                        submod_dir
-                                    } else {
-                                        mod_dir
-                                    };
-                                    self.parse_mod(pkg, &base.join(path_lit.value()), depth + 1)?;
-                                    break;
-                                }
-                                _ => (),
-                            }
-                        }
+                    mod_dir
+                };
+                for (path_cfg, path) in &paths {
+                    if let Some(ref path_cfg) = path_cfg {
+                        self.cfg_stack.push(path_cfg.clone());
                     }
+                    // Files loaded through `#[path]` are always mod-rs files.
+                    self.parse_mod(pkg, &base.join(path), /* is_mod_rs = */ true)?;
+                    if path_cfg.is_some() {
+                        self.cfg_stack.pop();
+                    }
+                }
 
-                    // This should be an error, but it's common enough to
-                    // just elicit a warning
-                    if !path_attr_found {
+                // Unless a `#[path]` always applies, the module is in the
+                // default location when none of them does.
+                if paths.iter().all(|(path_cfg, _)| path_cfg.is_some()) {
+                    let default_cfg = if paths.is_empty() {
+                        None
+                    } else {
+                        Some(Cfg::Not(Box::new(Cfg::Any(
+                            paths.iter().filter_map(|(x, _)| x.clone()).collect(),
+                        ))))
+                    };
+                    if let Some(ref default_cfg) = default_cfg {
+                        self.cfg_stack.push(default_cfg.clone());
+                    }
+
+                    let next_mod_path1 = submod_dir.join(next_mod_name.clone() + ".rs");
+                    let next_mod_path2 = submod_dir.join(next_mod_name.clone()).join("mod.rs");
+
+                    if next_mod_path1.exists() {
+                        self.parse_mod(
+                            pkg,
+                            next_mod_path1.as_path(),
+                            /* is_mod_rs = */ false,
+                        )?;
+                    } else if next_mod_path2.exists() {
+                        self.parse_mod(pkg, next_mod_path2.as_path(), /* is_mod_rs = */ true)?;
+                    } else if paths.is_empty() {
+                        // This should be an error, but it's common enough to
+                        // just elicit a warning
                         warn!(
                             "Parsing crate `{}`: can't find mod {}`.",
                             pkg.name, next_mod_name
                         );
                     }
+
+                    if default_cfg.is_some() {
+                        self.cfg_stack.pop();
+                    }
                 }
             } else {
                 warn!(
@@ -389,6 +590,7 @@ impl<'a> Parser<'a> {
                 );
             }
 
+            self.mod_stack.pop();
             if cfg.is_some() {
                 self.cfg_stack.pop();
             }
@@ -409,6 +611,11 @@ pub struct Parse {
     pub typedefs: ItemMap<Typedef>,
     pub functions: Vec<Function>,
     pub source_files: Vec<FilePathBuf>,
+    /// The Rust module each item and function was defined in, like
+    /// `my_crate::ffi`, by path.
+    pub modules: HashMap<Path, String>,
+    /// The aliases the renamed `use`s were loaded as.
+    renamed_uses: Vec<Path>,
 }
 
 impl Parse {
@@ -423,6 +630,8 @@ impl Parse {
             typedefs: ItemMap::default(),
             functions: Vec::new(),
             source_files: Vec::new(),
+            modules: HashMap::new(),
+            renamed_uses: Vec::new(),
         }
     }
 
@@ -471,6 +680,60 @@ impl Parse {
         self.typedefs.extend_with(&other.typedefs);
         self.functions.extend_from_slice(&other.functions);
         self.source_files.extend_from_slice(&other.source_files);
+        for (path, module) in &other.modules {
+            self.modules
+                .entry(path.clone())
+                .or_insert_with(|| module.clone());
+        }
+        self.renamed_uses.extend_from_slice(&other.renamed_uses);
+    }
+
+    /// Makes the aliases of the renamed `use`s of generic items generic too,
+    /// so that `Alias<T>` stands for `Item<T>`. This needs all the items, so
+    /// it's done once everything is parsed.
+    pub fn resolve_renamed_uses(&mut self) {
+        for alias in mem::take(&mut self.renamed_uses) {
+            let mut target = None;
+            self.typedefs
+                .for_items(&alias, |x| target = x.aliased.get_root_path());
+            let target = match target {
+                Some(target) => target,
+                None => continue,
+            };
+            let items = self
+                .structs
+                .get_items(&target)
+                .or_else(|| self.enums.get_items(&target))
+                .or_else(|| self.unions.get_items(&target))
+                .or_else(|| self.opaque_items.get_items(&target))
+                .or_else(|| self.typedefs.get_items(&target));
+            let generic_params = match items.as_deref() {
+                Some([ItemContainer::Struct(x), ..]) => &x.generic_params,
+                Some([ItemContainer::Enum(x), ..]) => &x.generic_params,
+                Some([ItemContainer::Union(x), ..]) => &x.generic_params,
+                Some([ItemContainer::OpaqueItem(x), ..]) => &x.generic_params,
+                Some([ItemContainer::Typedef(x), ..]) => &x.generic_params,
+                _ => continue,
+            };
+            if generic_params.is_empty() {
+                continue;
+            }
+
+            // Like when parsed, the names of const parameters are paths too.
+            let generic_values = generic_params
+                .iter()
+                .map(|param| {
+                    GenericArgument::Type(Type::Path(GenericPath::new(
+                        param.name().clone(),
+                        vec![],
+                    )))
+                })
+                .collect::<Vec<_>>();
+            self.typedefs.for_items_mut(&alias, |x| {
+                x.generic_params = generic_params.clone();
+                x.aliased = Type::Path(GenericPath::new(target.clone(), generic_values.clone()));
+            });
+        }
     }
 
     fn load_syn_crate_mod<'a>(
@@ -478,6 +741,7 @@ impl Parse {
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        module: &str,
         mod_cfg: Option<&Cfg>,
         items: &'a [syn::Item],
     ) -> Vec<&'a syn::ItemMod> {
@@ -488,6 +752,20 @@ impl Parse {
             if item.should_skip_parsing() {
                 continue;
             }
+            let functions = self.functions.len();
+            let name = match item {
+                syn::Item::Const(ref item) => Some(&item.ident),
+                syn::Item::Static(ref item) => Some(&item.ident),
+                syn::Item::Struct(ref item) => Some(&item.ident),
+                syn::Item::Union(ref item) => Some(&item.ident),
+                syn::Item::Enum(ref item) => Some(&item.ident),
+                syn::Item::Type(ref item) => Some(&item.ident),
+                _ => None,
+            };
+            let mut paths: Vec<_> = name
+                .map(|name| Path::new(name.unraw().to_string()))
+                .into_iter()
+                .collect();
             match item {
                 syn::Item::ForeignMod(ref item) => {
                     self.load_syn_foreign_mod(
@@ -547,13 +825,23 @@ impl Parse {
                     }
                 }
                 syn::Item::Macro(ref item) => {
-                    self.load_builtin_macro(config, crate_name, mod_cfg, item)
+                    paths.extend(self.load_builtin_macro(config, crate_name, mod_cfg, item))
                 }
                 syn::Item::Mod(ref item) => {
                     nested_modules.push(item);
                 }
+                syn::Item::Use(ref item) => {
+                    paths.extend(self.load_syn_use(crate_name, mod_cfg, item));
+                }
                 _ => {}
             }
+
+            paths.extend(self.functions[functions..].iter().map(|f| f.path.clone()));
+            for path in paths {
+                self.modules
+                    .entry(path)
+                    .or_insert_with(|| module.to_owned());
+            }
         }
 
         for item_impl in impls_with_assoc_consts {
@@ -590,10 +878,13 @@ impl Parse {
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemForeignMod,
     ) {
-        if !item.abi.is_c() {
-            info!("Skip {} - (extern block must be extern C).", crate_name);
-            return;
-        }
+        let abi = match item.abi.abi() {
+            Some(abi) => abi,
+            None => {
+                info!("Skip {} - (extern block must be extern C).", crate_name);
+                return;
+            }
+        };
 
         for foreign_item in &item.items {
             if let syn::ForeignItem::Fn(ref function) = *foreign_item {
@@ -608,7 +899,15 @@ impl Parse {
                     return;
                 }
                 let path = Path::new(function.sig.ident.unraw().to_string());
-                match Function::load(path, None, &function.sig, true, &function.attrs, mod_cfg) {
+                match Function::load(
+                    path,
+                    None,
+                    &function.sig,
+                    abi,
+                    true,
+                    &function.attrs,
+                    mod_cfg,
+                ) {
                     Ok(func) => {
                         info!("Take {}::{}.", crate_name, &function.sig.ident);
 
@@ -677,7 +976,7 @@ impl Parse {
         binding_crate_name: &str,
         crate_name: &str,
         mod_cfg: Option<&Cfg>,
-        named_symbol: &dyn SynItemFnHelpers,
+        named_symbol: &dyn SynItemHelpers,
         self_type: Option<&Path>,
         sig: &syn::Signature,
         vis: &syn::Visibility,
@@ -704,14 +1003,14 @@ impl Parse {
             items.join("::")
         };
 
-        let is_extern_c = sig.abi.is_omitted() || sig.abi.is_c();
+        let abi = sig.abi.abi();
         let exported_name = named_symbol.exported_name();
 
         if let syn::Visibility::Public(_) = vis {
-            match (is_extern_c, exported_name) {
-                (true, Some(exported_name)) => {
+            match (abi, exported_name) {
+                (Some(abi), Some(exported_name)) => {
                     let path = Path::new(exported_name);
-                    match Function::load(path, self_type, sig, false, attrs, mod_cfg) {
+                    match Function::load(path, self_type, sig, abi, false, attrs, mod_cfg) {
                         Ok(func) => {
                             info!("Take {}.", loggable_item_name());
                             self.functions.push(func);
@@ -721,38 +1020,38 @@ impl Parse {
                         }
                     }
                 }
-                (true, None) => {
+                (Some(_), None) => {
                     warn!(
                         "Skipping {} - (not `no_mangle`, and has no `export_name` attribute)",
                         loggable_item_name()
                     );
                 }
-                (false, Some(_exported_name)) => {
+                (None, Some(_exported_name)) => {
                     warn!("Skipping {} - (not `extern \"C\"`", loggable_item_name());
                 }
-                (false, None) => {}
+                (None, None) => {}
             }
         } else {
-            match (is_extern_c, exported_name) {
-                (true, Some(..)) => {
+            match (abi, exported_name) {
+                (Some(_), Some(..)) => {
                     warn!(
                         "Skipping {} - (not `pub` but is `extern \"C\"` and `no_mangle`)",
                         loggable_item_name()
                     );
                 }
-                (true, None) => {
+                (Some(_), None) => {
                     warn!(
                         "Skipping {} - (not `pub` but is `extern \"C\"`)",
                         loggable_item_name()
                     );
                 }
-                (false, Some(..)) => {
+                (None, Some(..)) => {
                     warn!(
                         "Skipping {} - (not `pub` but is `no_mangle`)",
                         loggable_item_name()
                     );
                 }
-                (false, None) => {}
+                (None, None) => {}
             }
         }
     }
@@ -892,9 +1191,11 @@ impl Parse {
             return;
         }
 
+        let exported_name = item.exported_name();
+
         if let syn::Visibility::Public(_) = item.vis {
-            if item.is_no_mangle() {
-                match Static::load(item, mod_cfg) {
+            if let Some(ref exported_name) = exported_name {
+                match Static::load(Path::new(exported_name.clone()), item, mod_cfg) {
                     Ok(constant) => {
                         info!("Take {}::{}.", crate_name, &item.ident);
 
@@ -912,8 +1213,11 @@ impl Parse {
         } else {
             warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
         }
-        if !item.is_no_mangle() {
-            warn!("Skip {}::{} - (not `no_mangle`).", crate_name, &item.ident);
+        if exported_name.is_none() {
+            warn!(
+                "Skip {}::{} - (not `no_mangle`, and has no `export_name` attribute).",
+                crate_name, &item.ident
+            );
         }
     }
 
@@ -926,9 +1230,11 @@ impl Parse {
         item: &syn::ItemStruct,
     ) {
         match Struct::load(&config.layout, item, mod_cfg) {
-            Ok(st) => {
+            Ok(structs) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
-                self.structs.try_insert(st);
+                for st in structs {
+                    self.structs.try_insert(st);
+                }
             }
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
@@ -949,10 +1255,12 @@ impl Parse {
         item: &syn::ItemUnion,
     ) {
         match Union::load(&config.layout, item, mod_cfg) {
-            Ok(st) => {
+            Ok(unions) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
 
-                self.unions.try_insert(st);
+                for st in unions {
+                    self.unions.try_insert(st);
+                }
             }
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
@@ -973,9 +1281,11 @@ impl Parse {
         item: &syn::ItemEnum,
     ) {
         match Enum::load(item, mod_cfg, config) {
-            Ok(en) => {
+            Ok(enums) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
-                self.enums.try_insert(en);
+                for en in enums {
+                    self.enums.try_insert(en);
+                }
             }
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
@@ -1005,27 +1315,59 @@ impl Parse {
         }
     }
 
+    /// Loads the renamed imports of a public `use` declaration, like `pub use
+    /// inner::Handle as MyHandle;`, as aliases of the item they import.
+    fn load_syn_use(
+        &mut self,
+        crate_name: &str,
+        mod_cfg: Option<&Cfg>,
+        item: &syn::ItemUse,
+    ) -> Vec<Path> {
+        if let syn::Visibility::Inherited = item.vis {
+            return Vec::new();
+        }
+
+        let mut renames = Vec::new();
+        collect_use_renames(&item.tree, &mut renames);
+
+        let attrs = &item.attrs;
+        renames
+            .into_iter()
+            .map(|rename| {
+                let (ident, alias) = (&rename.ident, &rename.rename);
+                let ty: syn::ItemType = parse_quote! {
+                    #(#attrs)*
+                    pub type #alias = #ident;
+                };
+                self.load_syn_ty(crate_name, mod_cfg, &ty);
+                let path = Path::new(alias.unraw().to_string());
+                self.renamed_uses.push(path.clone());
+                path
+            })
+            .collect()
+    }
+
     fn load_builtin_macro(
         &mut self,
         config: &Config,
         crate_name: &str,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemMacro,
-    ) {
+    ) -> Option<Path> {
         let name = match item.mac.path.segments.last() {
             Some(n) => n.ident.unraw().to_string(),
-            None => return,
+            None => return None,
         };
 
         if name != "bitflags" || !config.macro_expansion.bitflags {
-            return;
+            return None;
         }
 
         let bitflags = match bitflags::parse(item.mac.tokens.clone()) {
             Ok(b) => b,
             Err(e) => {
                 warn!("Failed to parse bitflags invocation: {:?}", e);
-                return;
+                return None;
             }
         };
 
@@ -1035,5 +1377,6 @@ impl Parse {
         // fine to just do it here instead of deferring it like we do with the
         // other calls to this function.
         self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, &impl_);
+        Some(Path::new(struct_.ident.unraw().to_string()))
     }
 }
@@ -0,0 +1,189 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Patterns of the names of items and crates in the config.
+//!
+//! A pattern starting with `^` is a regular expression, in the syntax of the
+//! `regex` crate, one with a `*` or a `?` is a glob, and anything else is an
+//! exact name. In renames, `$1`, `$2`... stand for the text matched by the
+//! groups of a regular expression, or by the `*`s and `?`s of a glob.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+thread_local! {
+    /// The regular expressions compiled so far, by pattern, as the same
+    /// patterns are matched against every item and crate.
+    static COMPILED: RefCell<HashMap<String, Result<Regex, String>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Whether `pattern` is a glob or a regular expression rather than an exact
+/// name.
+pub(crate) fn is_pattern(pattern: &str) -> bool {
+    pattern.starts_with('^') || pattern.contains(&['*', '?'][..])
+}
+
+/// Whether `name` matches `pattern`. Invalid patterns match nothing.
+pub(crate) fn name_matches(pattern: &str, name: &str) -> bool {
+    if !is_pattern(pattern) {
+        return pattern == name;
+    }
+    NamePattern::new(pattern).map_or(false, |pattern| pattern.matches(name))
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum NamePattern {
+    Exact(String),
+    Regex(Regex),
+}
+
+impl NamePattern {
+    pub(crate) fn new(pattern: &str) -> Result<NamePattern, String> {
+        if pattern.starts_with('^') {
+            NamePattern::regex(pattern)
+        } else {
+            Ok(NamePattern::glob(pattern))
+        }
+    }
+
+    /// A glob, in which `*` and `?` are groups, or an exact name.
+    pub(crate) fn glob(pattern: &str) -> NamePattern {
+        if !pattern.contains(&['*', '?'][..]) {
+            return NamePattern::Exact(pattern.to_owned());
+        }
+        let mut regex = String::from("^");
+        for c in pattern.chars() {
+            match c {
+                '*' => regex.push_str("(.*)"),
+                '?' => regex.push_str("(.)"),
+                c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+            }
+        }
+        regex.push('$');
+        NamePattern::regex(&regex).expect("globs are escaped into valid regular expressions")
+    }
+
+    /// A regular expression, which is anchored at the start of the name even
+    /// in the other branches of a top-level `|`.
+    fn regex(pattern: &str) -> Result<NamePattern, String> {
+        COMPILED.with(|compiled| {
+            compiled
+                .borrow_mut()
+                .entry(pattern.to_owned())
+                .or_insert_with(|| {
+                    Regex::new(&format!("^(?:{})", pattern)).map_err(|e| e.to_string())
+                })
+                .clone()
+                .map(NamePattern::Regex)
+        })
+    }
+
+    pub(crate) fn matches(&self, name: &str) -> bool {
+        match *self {
+            NamePattern::Exact(ref exact) => exact == name,
+            NamePattern::Regex(ref regex) => regex.is_match(name),
+        }
+    }
+
+    /// Replaces the part of `name` the pattern matches, if any, with
+    /// `replacement`, expanding `$n` to the text of the n-th group and `$$` to
+    /// `$`.
+    pub(crate) fn replace(&self, name: &str, replacement: &str) -> Option<String> {
+        match *self {
+            NamePattern::Exact(ref exact) => {
+                if exact == name {
+                    Some(replacement.to_owned())
+                } else {
+                    None
+                }
+            }
+            NamePattern::Regex(ref regex) => {
+                let captures = regex.captures(name)?;
+                let mut out = expand_replacement(replacement, &captures);
+                out.push_str(&name[captures.get(0)?.end()..]);
+                Some(out)
+            }
+        }
+    }
+}
+
+/// Unlike `Captures::expand`, `$1_t` is the first group followed by `_t`
+/// rather than a group named `1_t`.
+fn expand_replacement(replacement: &str, captures: &regex::Captures) -> String {
+    let mut out = String::new();
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'$') {
+            chars.next();
+            out.push('$');
+            continue;
+        }
+        let mut index = String::new();
+        while let Some(&digit) = chars.peek() {
+            if !digit.is_ascii_digit() {
+                break;
+            }
+            index.push(digit);
+            chars.next();
+        }
+        match index.parse::<usize>() {
+            Ok(index) => {
+                if let Some(group) = captures.get(index) {
+                    out.push_str(group.as_str());
+                }
+            }
+            Err(_) => out.push('$'),
+        }
+    }
+    out
+}
+
+#[test]
+fn patterns() {
+    fn rename(pattern: &str, name: &str, replacement: &str) -> Option<String> {
+        NamePattern::new(pattern)
+            .unwrap()
+            .replace(name, replacement)
+    }
+
+    assert!(name_matches("Foo", "Foo"));
+    assert!(!name_matches("Foo", "FooBar"));
+    assert!(name_matches("*Internal", "FooInternal"));
+    assert!(!name_matches("*Internal", "FooInternals"));
+    assert!(name_matches("Debug?", "Debug1"));
+    assert!(name_matches("^Ffi", "FfiFoo"));
+    assert!(!name_matches("^Ffi$", "FfiFoo"));
+    assert!(name_matches("^(Foo|Bar)[0-9]+$", "Bar42"));
+    assert!(!name_matches("^(Foo|Bar)[0-9]+$", "Bar"));
+    assert!(name_matches("^\\w{2,3}$", "a_1"));
+    assert!(!name_matches("^[^_]+$", "a_1"));
+    assert!(!name_matches("^(", "("));
+    assert!(!name_matches("^a|b", "xb"));
+    assert!(name_matches("a.b*", "a.bc"));
+    assert!(!name_matches("a.b*", "axbc"));
+
+    assert_eq!(
+        rename("^Ffi(.*)$", "FfiPoint", "mylib_$1"),
+        Some("mylib_Point".to_owned())
+    );
+    assert_eq!(rename("^Ffi", "FfiPoint", "Ml"), Some("MlPoint".to_owned()));
+    assert_eq!(
+        rename("^(.*?)(s*)$", "Bass", "$2$1"),
+        Some("ssBa".to_owned())
+    );
+    assert_eq!(rename("*_t", "size_t", "$1$$"), Some("size$".to_owned()));
+    assert_eq!(rename("Debug*", "Release", "$1"), None);
+    assert_eq!(rename("*Raw", "FooRaw", "$1_t"), Some("Foo_t".to_owned()));
+
+    assert!(NamePattern::new("^a{2,1}").is_err());
+    assert!(NamePattern::new("^a|*").is_err());
+    assert!(NamePattern::new("^a)").is_err());
+}
@@ -0,0 +1,166 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindgen::config::{Config, Layout};
+use crate::bindgen::ir::{Abi, Function, PrimitiveType, Type};
+use crate::bindgen::writer::SourceWriter;
+
+// This code is for translating Rust types into Python `ctypes` type
+// expressions.
+// https://docs.python.org/3/library/ctypes.html#fundamental-data-types
+
+/// The `ctypes` factory of function pointer types of `abi`, if it has one.
+pub fn function_type(abi: Abi) -> Option<&'static str> {
+    match abi {
+        Abi::C | Abi::Cdecl => Some("ctypes.CFUNCTYPE"),
+        Abi::Stdcall => Some("ctypes.WINFUNCTYPE"),
+        // `WINFUNCTYPE` only exists on Windows, which is the only place where
+        // `system` isn't `C`.
+        Abi::System => Some("getattr(ctypes, \"WINFUNCTYPE\", ctypes.CFUNCTYPE)"),
+        _ => None,
+    }
+}
+
+fn write_ptr(out: &mut SourceWriter, pointee: &Type, is_const: bool, config: &Config) {
+    match *pointee {
+        Type::Primitive(PrimitiveType::Void) => out.write("ctypes.c_void_p"),
+        // `c_char_p` converts from and to `bytes` automatically, which is only
+        // safe if the callee doesn't keep or modify the string.
+        Type::Primitive(PrimitiveType::Char) if is_const => out.write("ctypes.c_char_p"),
+        _ => {
+            out.write("ctypes.POINTER(");
+            write_type(out, pointee, config);
+            out.write(")");
+        }
+    }
+}
+
+fn write_ret(out: &mut SourceWriter, ret: &Type, never_return: bool, config: &Config) {
+    if never_return {
+        out.write("None");
+    } else {
+        write_type(out, ret, config);
+    }
+}
+
+pub fn write_type(out: &mut SourceWriter, t: &Type, config: &Config) {
+    match *t {
+        Type::Path(ref generic) => {
+            // Enums without data are `IntEnum`s, which ctypes doesn't know
+            // about, so use the underlying integer type instead.
+            match out.bindings().enum_repr(generic.export_name()) {
+                Some(prim) => out.write(prim.to_repr_python()),
+                None => write!(out, "{}", generic.export_name()),
+            }
+        }
+        Type::Primitive(ref p) => out.write(p.to_repr_python()),
+        Type::Ptr {
+            ref ty, is_const, ..
+        } => write_ptr(out, ty, is_const, config),
+        // Atomics are only kept for C and C++, the layout is the same.
+        Type::Atomic(ref ty) => write_type(out, ty, config),
+        Type::Array(ref ty, ref len) => {
+            if let Type::Array(..) = **ty {
+                out.write("(");
+                write_type(out, ty, config);
+                out.write(")");
+            } else {
+                write_type(out, ty, config);
+            }
+            write!(out, " * {}", len.as_str());
+        }
+        Type::FuncPtr {
+            ref ret,
+            ref args,
+            abi,
+            never_return,
+            ..
+        } => match function_type(abi) {
+            Some(function_type) => {
+                write!(out, "{}(", function_type);
+                write_prototype(
+                    out,
+                    ret,
+                    never_return,
+                    args.iter().map(|(_, ty)| ty),
+                    config,
+                );
+                out.write(")");
+            }
+            // The library warns about these.
+            None => out.write("ctypes.c_void_p"),
+        },
+    }
+}
+
+fn write_prototype<'a>(
+    out: &mut SourceWriter,
+    ret: &Type,
+    never_return: bool,
+    args: impl Iterator<Item = &'a Type>,
+    config: &Config,
+) {
+    write_ret(out, ret, never_return, config);
+    for ty in args {
+        out.write(", ");
+        write_type(out, ty, config);
+    }
+}
+
+/// Writes the `argtypes` and `restype` declarations of `f`, given a `lib`
+/// variable holding the loaded `ctypes.CDLL`.
+///
+/// A `CDLL` calls everything as `C` functions, so functions with other
+/// calling conventions are replaced by a function pointer of the right type
+/// instead.
+pub fn write_func(out: &mut SourceWriter, f: &Function, layout: Layout, config: &Config) {
+    let name = f.path().name();
+
+    if !matches!(f.abi, Abi::C | Abi::Cdecl) {
+        if let Some(function_type) = function_type(f.abi) {
+            write!(out, "lib.{} = {}(", name, function_type);
+            let args = f.args.iter().map(|arg| &arg.ty);
+            write_prototype(out, &f.ret, f.never_return, args, config);
+            write!(out, ")((\"{}\", lib))", name);
+            return;
+        }
+    }
+
+    fn write_vertical(out: &mut SourceWriter, f: &Function, config: &Config) {
+        out.push_tab();
+        for arg in &f.args {
+            out.new_line();
+            write_type(out, &arg.ty, config);
+            out.write(",");
+        }
+        out.pop_tab();
+        out.new_line();
+    }
+
+    fn write_horizontal(out: &mut SourceWriter, f: &Function, config: &Config) {
+        for (i, arg) in f.args.iter().enumerate() {
+            if i != 0 {
+                out.write(", ");
+            }
+            write_type(out, &arg.ty, config);
+        }
+    }
+
+    write!(out, "lib.{}.argtypes = [", name);
+    match layout {
+        _ if f.args.is_empty() => {}
+        Layout::Vertical => write_vertical(out, f, config),
+        Layout::Horizontal => write_horizontal(out, f, config),
+        Layout::Auto => {
+            if !out.try_write(|out| write_horizontal(out, f, config), config.line_length) {
+                write_vertical(out, f, config)
+            }
+        }
+    }
+    out.write("]");
+    out.new_line();
+
+    write!(out, "lib.{}.restype = ", name);
+    write_ret(out, &f.ret, f.never_return, config);
+}
@@ -142,4 +142,32 @@ impl FromStr for RenameRule {
     }
 }
 
-deserialize_enum_str!(RenameRule);
+deserialize_enum_str!(
+    RenameRule,
+    [
+        "none",
+        "None",
+        "mGeckoCase",
+        "GeckoCase",
+        "gecko_case",
+        "lowercase",
+        "LowerCase",
+        "lower_case",
+        "UPPERCASE",
+        "UpperCase",
+        "upper_case",
+        "PascalCase",
+        "pascal_case",
+        "camelCase",
+        "CamelCase",
+        "camel_case",
+        "snake_case",
+        "SnakeCase",
+        "SCREAMING_SNAKE_CASE",
+        "ScreamingSnakeCase",
+        "screaming_snake_case",
+        "QUALIFIED_SCREAMING_SNAKE_CASE",
+        "QualifiedScreamingSnakeCase",
+        "qualified_screaming_snake_case"
+    ]
+);
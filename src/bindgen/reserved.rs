@@ -2,6 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use crate::bindgen::config::Language;
+
 /// Taken from `https://en.cppreference.com/w/cpp/keyword`
 /// Some experimental keywords were filtered out and the resulting list was
 /// sorted using a rust program.
@@ -81,11 +83,213 @@ const RESERVED_KEYWORDS: &[&str] = &[
     "while",
 ];
 
-pub fn escape(rust_identifier: &mut String) {
-    if RESERVED_KEYWORDS
-        .binary_search(&rust_identifier.as_ref())
-        .is_ok()
-    {
+/// Taken from `https://ziglang.org/documentation/master/#Keyword-Reference`
+/// and `https://ziglang.org/documentation/master/#Primitive-Types`, since
+/// primitive type names can't be shadowed either. Sorted.
+const ZIG_RESERVED_KEYWORDS: &[&str] = &[
+    "addrspace",
+    "align",
+    "allowzero",
+    "and",
+    "anyerror",
+    "anyframe",
+    "anyopaque",
+    "anytype",
+    "asm",
+    "async",
+    "await",
+    "bool",
+    "break",
+    "c_char",
+    "c_int",
+    "c_long",
+    "c_longdouble",
+    "c_longlong",
+    "c_short",
+    "c_uint",
+    "c_ulong",
+    "c_ulonglong",
+    "c_ushort",
+    "callconv",
+    "catch",
+    "comptime",
+    "comptime_float",
+    "comptime_int",
+    "const",
+    "continue",
+    "defer",
+    "else",
+    "enum",
+    "errdefer",
+    "error",
+    "export",
+    "extern",
+    "f128",
+    "f16",
+    "f32",
+    "f64",
+    "f80",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "inline",
+    "isize",
+    "linksection",
+    "noalias",
+    "noinline",
+    "noreturn",
+    "nosuspend",
+    "null",
+    "opaque",
+    "or",
+    "orelse",
+    "packed",
+    "pub",
+    "resume",
+    "return",
+    "struct",
+    "suspend",
+    "switch",
+    "test",
+    "threadlocal",
+    "true",
+    "try",
+    "type",
+    "undefined",
+    "union",
+    "unreachable",
+    "usingnamespace",
+    "usize",
+    "var",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Taken from `keyword.kwlist` in Python 3. Sorted.
+const PYTHON_RESERVED_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Taken from the C# language reference, without the contextual keywords. Sorted.
+const CSHARP_RESERVED_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Arbitrary bit-width integers (`u7`, `i48`, ...) are primitive types in Zig.
+fn is_zig_integer_type(identifier: &str) -> bool {
+    let mut chars = identifier.chars();
+    matches!(chars.next(), Some('i') | Some('u'))
+        && !chars.as_str().is_empty()
+        && chars.all(|c| c.is_ascii_digit())
+}
+
+pub fn escape(rust_identifier: &mut String, language: Language) {
+    let reserved = match language {
+        Language::Cxx | Language::C | Language::Cython => RESERVED_KEYWORDS
+            .binary_search(&rust_identifier.as_ref())
+            .is_ok(),
+        Language::Zig => {
+            ZIG_RESERVED_KEYWORDS
+                .binary_search(&rust_identifier.as_ref())
+                .is_ok()
+                || is_zig_integer_type(rust_identifier)
+        }
+        Language::Python => PYTHON_RESERVED_KEYWORDS
+            .binary_search(&rust_identifier.as_ref())
+            .is_ok(),
+        Language::CSharp => {
+            // C# has verbatim identifiers for this.
+            if CSHARP_RESERVED_KEYWORDS
+                .binary_search(&rust_identifier.as_ref())
+                .is_ok()
+            {
+                rust_identifier.insert(0, '@');
+            }
+            return;
+        }
+    };
+    if reserved {
         rust_identifier.push('_');
     }
 }
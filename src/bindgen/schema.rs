@@ -0,0 +1,428 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The shape of the config, found by probing the `Deserialize` impls of its
+//! types, so that it can't get out of sync with them. It's used to report
+//! unknown keys with their path and the key likely meant, and to emit a JSON
+//! Schema for editors.
+
+use serde::de::value::Error;
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+/// The path segment of the elements of arrays.
+const ELEMENT: &str = "[]";
+/// The path segment of the values of tables with arbitrary keys.
+const ENTRY: &str = "{}";
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Schema {
+    Any,
+    Bool,
+    Integer,
+    Number,
+    String,
+    Enum(&'static [&'static str]),
+    Array(Box<Schema>),
+    /// A table with arbitrary keys.
+    Map(Box<Schema>),
+    /// A table with the given keys only.
+    Object(Vec<(&'static str, Schema)>),
+}
+
+/// The shape of a value, with its nested values left out.
+enum Shape {
+    Leaf(Schema),
+    Array,
+    Map,
+    Object(&'static [&'static str]),
+}
+
+impl Schema {
+    pub(crate) fn of<T: for<'de> Deserialize<'de>>() -> Schema {
+        Schema::at::<T>(&mut vec![])
+    }
+
+    fn at<T: for<'de> Deserialize<'de>>(path: &mut Vec<&'static str>) -> Schema {
+        let mut shape = None;
+        let _ = T::deserialize(Probe {
+            path,
+            out: &mut shape,
+        });
+
+        let mut nested = |segment| {
+            path.push(segment);
+            let schema = Schema::at::<T>(path);
+            path.pop();
+            schema
+        };
+        match shape {
+            None => Schema::Any,
+            Some(Shape::Leaf(schema)) => schema,
+            Some(Shape::Array) => Schema::Array(Box::new(nested(ELEMENT))),
+            Some(Shape::Map) => Schema::Map(Box::new(nested(ENTRY))),
+            Some(Shape::Object(fields)) => {
+                Schema::Object(fields.iter().map(|&field| (field, nested(field))).collect())
+            }
+        }
+    }
+
+    /// Checks that the tables of `value` only have known keys. Type errors are
+    /// left to serde.
+    pub(crate) fn check_keys(&self, value: &toml::Value) -> Result<(), String> {
+        self.check_keys_at(value, "")
+    }
+
+    fn check_keys_at(&self, value: &toml::Value, path: &str) -> Result<(), String> {
+        let join = |key: &str| {
+            if path.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{}.{}", path, key)
+            }
+        };
+        match (self, value) {
+            (Schema::Object(fields), toml::Value::Table(table)) => {
+                for (key, value) in table {
+                    match fields.iter().find(|&&(field, _)| field == key) {
+                        Some((_, schema)) => schema.check_keys_at(value, &join(key))?,
+                        None => {
+                            let mut message = format!("unknown key `{}`", join(key));
+                            let names = fields.iter().map(|&(field, _)| field);
+                            match closest_name(key, names) {
+                                Some(name) => {
+                                    message.push_str(&format!(", did you mean `{}`?", name))
+                                }
+                                None => message.push('.'),
+                            }
+                            return Err(message);
+                        }
+                    }
+                }
+                Ok(())
+            }
+            (Schema::Map(schema), toml::Value::Table(table)) => {
+                for (key, value) in table {
+                    schema.check_keys_at(value, &join(key))?;
+                }
+                Ok(())
+            }
+            (Schema::Array(schema), toml::Value::Array(values)) => {
+                for (i, value) in values.iter().enumerate() {
+                    schema.check_keys_at(value, &format!("{}[{}]", path, i))?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        match *self {
+            Schema::Any => json!({}),
+            Schema::Bool => json!({ "type": "boolean" }),
+            Schema::Integer => json!({ "type": "integer" }),
+            Schema::Number => json!({ "type": "number" }),
+            Schema::String => json!({ "type": "string" }),
+            Schema::Enum(variants) => json!({ "enum": variants }),
+            Schema::Array(ref schema) => json!({ "type": "array", "items": schema.to_json() }),
+            Schema::Map(ref schema) => {
+                json!({ "type": "object", "additionalProperties": schema.to_json() })
+            }
+            Schema::Object(ref fields) => {
+                let properties: Map<String, Value> = fields
+                    .iter()
+                    .map(|(field, schema)| (field.to_string(), schema.to_json()))
+                    .collect();
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "additionalProperties": false,
+                })
+            }
+        }
+    }
+}
+
+/// The name among `names` closest to `name`, if it's close enough to be a
+/// typo of it.
+fn closest_name<'a>(name: &str, names: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = std::cmp::max(1, name.chars().count() / 3);
+    names
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between `a` and `b`, where swapping two adjacent
+/// characters also counts as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // The distances between the prefixes of `b` and the prefixes of `a` one
+    // and two characters shorter than the current one.
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()]
+}
+
+/// A deserializer which follows `path` through the value being deserialized,
+/// and records the shape of the value at its end. It then fails, as it has
+/// no actual value to give.
+struct Probe<'a> {
+    path: &'a [&'static str],
+    out: &'a mut Option<Shape>,
+}
+
+impl<'a> Probe<'a> {
+    fn record<T>(self, shape: Shape) -> Result<T, Error> {
+        *self.out = Some(shape);
+        Err(de::Error::custom("probed"))
+    }
+
+    fn leaf<T>(self, schema: Schema) -> Result<T, Error> {
+        self.record(Shape::Leaf(schema))
+    }
+}
+
+macro_rules! probe_leaf {
+    ($($method:ident => $schema:ident,)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+                self.leaf(Schema::$schema)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for Probe<'a> {
+    type Error = Error;
+
+    probe_leaf! {
+        deserialize_any => Any,
+        deserialize_bool => Bool,
+        deserialize_i8 => Integer,
+        deserialize_i16 => Integer,
+        deserialize_i32 => Integer,
+        deserialize_i64 => Integer,
+        deserialize_u8 => Integer,
+        deserialize_u16 => Integer,
+        deserialize_u32 => Integer,
+        deserialize_u64 => Integer,
+        deserialize_f32 => Number,
+        deserialize_f64 => Number,
+        deserialize_char => String,
+        deserialize_str => String,
+        deserialize_string => String,
+        deserialize_identifier => String,
+        deserialize_bytes => Any,
+        deserialize_byte_buf => Any,
+        deserialize_unit => Any,
+        deserialize_ignored_any => Any,
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: V,
+    ) -> Result<V::Value, Error> {
+        self.leaf(Schema::Any)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.path.split_first() {
+            Some((&ELEMENT, path)) => visitor.visit_seq(ProbeAccess {
+                key: Some(ELEMENT),
+                path,
+                out: self.out,
+            }),
+            _ => self.record(Shape::Array),
+        }
+    }
+
+    fn deserialize_tuple<V: de::Visitor<'de>>(
+        self,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.path.split_first() {
+            Some((&ENTRY, path)) => visitor.visit_map(ProbeAccess {
+                key: Some(ENTRY),
+                path,
+                out: self.out,
+            }),
+            _ => self.record(Shape::Map),
+        }
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.path.split_first() {
+            Some((&field, path)) => visitor.visit_map(ProbeAccess {
+                key: Some(field),
+                path,
+                out: self.out,
+            }),
+            None => self.record(Shape::Object(fields)),
+        }
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Error> {
+        self.leaf(Schema::Enum(variants))
+    }
+}
+
+/// A table with the single key `key`, or an array with a single element,
+/// whose value is probed.
+struct ProbeAccess<'a> {
+    key: Option<&'static str>,
+    path: &'a [&'static str],
+    out: &'a mut Option<Shape>,
+}
+
+impl<'a> ProbeAccess<'a> {
+    fn probe(&mut self) -> Probe<'_> {
+        Probe {
+            path: self.path,
+            out: &mut *self.out,
+        }
+    }
+}
+
+impl<'de, 'a> MapAccess<'de> for ProbeAccess<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.key.take() {
+            Some(key) => seed.deserialize(key.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(self.probe())
+    }
+}
+
+impl<'de, 'a> SeqAccess<'de> for ProbeAccess<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.key.take() {
+            Some(_) => seed.deserialize(self.probe()).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[test]
+fn unknown_keys() {
+    use crate::bindgen::Config;
+
+    let schema = Schema::of::<Config>();
+    let check = |text: &str| schema.check_keys(&toml::from_str(text).unwrap());
+
+    assert_eq!(
+        check("language = \"C\"\n[enum]\nprefix_with_name = true\n[export.rename]\nFoo = \"Bar\""),
+        Ok(())
+    );
+    assert_eq!(
+        check("[enum]\nprefix_with_nmae = true"),
+        Err("unknown key `enum.prefix_with_nmae`, did you mean `prefix_with_name`?".to_owned())
+    );
+    assert_eq!(
+        check("[[split.headers]]\nname = \"a\"\nitem = []"),
+        Err("unknown key `split.headers[0].item`, did you mean `items`?".to_owned())
+    );
+    assert_eq!(
+        check("[fn]\nzzzzzz = 1"),
+        Err("unknown key `fn.zzzzzz`.".to_owned())
+    );
+}
+
+#[test]
+fn string_enums() {
+    use crate::bindgen::Config;
+
+    let schema = Schema::of::<Config>().to_json();
+    let values = |path: &[&str]| {
+        let mut schema = &schema;
+        for key in path {
+            schema = &schema["properties"][key];
+        }
+        schema["enum"].clone()
+    };
+
+    assert!(values(&["language"])
+        .as_array()
+        .unwrap()
+        .contains(&json!("C")));
+    assert!(values(&["style"])
+        .as_array()
+        .unwrap()
+        .contains(&json!("both")));
+    assert!(values(&["sort_by"])
+        .as_array()
+        .unwrap()
+        .contains(&json!("Name")));
+    assert!(values(&["enum", "rename_variants"])
+        .as_array()
+        .unwrap()
+        .contains(&json!("SCREAMING_SNAKE_CASE")));
+}
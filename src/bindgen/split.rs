@@ -0,0 +1,223 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::bindgen::config::Language;
+use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::error::Error;
+use crate::bindgen::ir::{
+    AnnotationSet, Constant, Documentation, Function, GenericParams, Item, ItemContainer,
+    OpaqueItem, Path, Static,
+};
+use crate::bindgen::library::Library;
+
+/// The index of the main header, the headers of `[split]` following it.
+pub(crate) const MAIN: usize = 0;
+
+/// The header of `[split]` each item goes to, the headers each header
+/// includes for the items it depends on, and the items of other headers it
+/// only points to, which it forward-declares instead.
+#[derive(Debug, Clone)]
+pub(crate) struct HeaderSplit {
+    headers: HashMap<Path, usize>,
+    includes: Vec<BTreeSet<usize>>,
+    forward_declarations: Vec<HashSet<Path>>,
+}
+
+impl HeaderSplit {
+    pub(crate) fn new(
+        library: &Library,
+        modules: &HashMap<Path, String>,
+        items: &[ItemContainer],
+        constants: &[Constant],
+        globals: &[Static],
+        functions: &[Function],
+    ) -> Result<HeaderSplit, Error> {
+        let config = &library.get_config().split;
+        let header_of = |path: &Path| {
+            let module = modules.get(path).map(String::as_str);
+            config
+                .headers
+                .iter()
+                .position(|header| header.matches(path, module))
+                .map_or(MAIN, |i| i + 1)
+        };
+
+        let mut headers = HashMap::new();
+        let paths = items
+            .iter()
+            .map(|item| item.deref().path())
+            .chain(constants.iter().map(|constant| constant.path()))
+            .chain(globals.iter().map(|global| global.path()))
+            .chain(functions.iter().map(|function| function.path()));
+        for path in paths {
+            headers.insert(path.clone(), header_of(path));
+        }
+
+        // Structs and unions can be declared without being defined, unless
+        // they're anonymous structs behind a typedef in C.
+        let lib_config = library.get_config();
+        let can_forward_declare = |path: &Path| {
+            (lib_config.language == Language::Cxx || lib_config.style.generate_tag())
+                && items.iter().any(|item| match *item {
+                    ItemContainer::Struct(ref s) => s.path == *path && !s.is_transparent,
+                    ItemContainer::Union(ref u) => u.path == *path,
+                    ItemContainer::OpaqueItem(ref o) => o.path == *path,
+                    _ => false,
+                })
+        };
+
+        let mut includes = vec![BTreeSet::new(); config.headers.len() + 1];
+        let mut forward_declarations = vec![HashSet::new(); config.headers.len() + 1];
+        let mut add_dependencies = |path: &Path, dependencies: Dependencies| {
+            let header = headers[path];
+            let mut include = |dependencies: &Dependencies| {
+                for dependency in &dependencies.order {
+                    match headers.get(dependency.deref().path()) {
+                        Some(&other) if other != header => {
+                            includes[header].insert(other);
+                        }
+                        _ => {}
+                    }
+                }
+            };
+            include(&dependencies);
+            for pointee in &dependencies.pointees {
+                match headers.get(pointee) {
+                    Some(&other) if other != header => {
+                        if can_forward_declare(pointee) {
+                            forward_declarations[header].insert(pointee.clone());
+                        } else if let Some(pointee_items) = library.get_items(pointee) {
+                            let mut pointee_dependencies = Dependencies::new();
+                            for item in &pointee_items {
+                                item.deref()
+                                    .add_dependencies(library, &mut pointee_dependencies);
+                            }
+                            pointee_dependencies.order.extend(pointee_items);
+                            include(&pointee_dependencies);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        };
+        for item in items {
+            let mut dependencies = Dependencies::by_value();
+            item.deref().add_dependencies(library, &mut dependencies);
+            add_dependencies(item.deref().path(), dependencies);
+        }
+        for constant in constants {
+            let mut dependencies = Dependencies::by_value();
+            constant.add_dependencies(library, &mut dependencies);
+            add_dependencies(constant.path(), dependencies);
+        }
+        for global in globals {
+            let mut dependencies = Dependencies::by_value();
+            global.add_dependencies(library, &mut dependencies);
+            add_dependencies(global.path(), dependencies);
+        }
+        for function in functions {
+            let mut dependencies = Dependencies::by_value();
+            function.add_dependencies(library, &mut dependencies);
+            add_dependencies(function.path(), dependencies);
+        }
+
+        let split = HeaderSplit {
+            headers,
+            includes,
+            forward_declarations,
+        };
+        if let Some(cycle) = split.find_cycle() {
+            let name = |header: usize| match header {
+                MAIN => "the main header".to_owned(),
+                _ => format!("`{}`", config.headers[header - 1].name),
+            };
+            return Err(Error::SplitHeaderCycle(
+                cycle.into_iter().map(name).collect(),
+            ));
+        }
+        Ok(split)
+    }
+
+    /// The number of headers, including the main one.
+    pub(crate) fn len(&self) -> usize {
+        self.includes.len()
+    }
+
+    /// Whether the item, constant, global or function at `path` goes to
+    /// `header`.
+    pub(crate) fn is_in(&self, path: &Path, header: usize) -> bool {
+        self.headers.get(path).map_or(MAIN, |&h| h) == header
+    }
+
+    /// The headers `header` has to include.
+    pub(crate) fn includes(&self, header: usize) -> impl Iterator<Item = usize> + '_ {
+        self.includes[header].iter().cloned()
+    }
+
+    /// The forward declarations `header` needs, for the structs and unions of
+    /// other headers among `items` it only points to.
+    pub(crate) fn forward_declarations(
+        &self,
+        header: usize,
+        items: &[ItemContainer],
+    ) -> Vec<ItemContainer> {
+        let declared = &self.forward_declarations[header];
+        items
+            .iter()
+            .filter(|item| declared.contains(item.deref().path()))
+            .map(|container| {
+                let generic_params = match *container {
+                    ItemContainer::Struct(ref s) => s.generic_params.clone(),
+                    ItemContainer::Union(ref u) => u.generic_params.clone(),
+                    ItemContainer::OpaqueItem(ref o) => o.generic_params.clone(),
+                    _ => GenericParams::default(),
+                };
+                let item = container.deref();
+                let mut declaration = OpaqueItem::new(
+                    item.path().clone(),
+                    generic_params,
+                    item.cfg().cloned(),
+                    AnnotationSet::new(),
+                    Documentation::none(),
+                );
+                declaration.export_name = item.export_name().to_owned();
+                ItemContainer::OpaqueItem(declaration)
+            })
+            .collect()
+    }
+
+    /// Some headers including each other in a loop, starting and ending with
+    /// the same one, if any.
+    fn find_cycle(&self) -> Option<Vec<usize>> {
+        fn visit(
+            split: &HeaderSplit,
+            header: usize,
+            stack: &mut Vec<usize>,
+            done: &mut [bool],
+        ) -> Option<Vec<usize>> {
+            if let Some(start) = stack.iter().position(|&h| h == header) {
+                let mut cycle = stack[start..].to_vec();
+                cycle.push(header);
+                return Some(cycle);
+            }
+            if done[header] {
+                return None;
+            }
+            stack.push(header);
+            for include in split.includes(header) {
+                if let Some(cycle) = visit(split, include, stack, done) {
+                    return Some(cycle);
+                }
+            }
+            stack.pop();
+            done[header] = true;
+            None
+        }
+
+        let mut done = vec![false; self.len()];
+        (0..self.len()).find_map(|header| visit(self, header, &mut Vec::new(), &mut done))
+    }
+}
@@ -0,0 +1,103 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::Write;
+
+use crate::bindgen::ir::{Cfg, Item};
+use crate::bindgen::Bindings;
+
+/// A symbol of a function or global the bindings declare.
+struct Symbol<'a> {
+    name: &'a str,
+    cfg: Option<&'a Cfg>,
+    is_data: bool,
+}
+
+/// Whether the library exports `symbol`, as far as `[symbols]` tells.
+fn is_exported(bindings: &Bindings, symbol: &Symbol) -> bool {
+    let cfg = match symbol.cfg {
+        Some(cfg) => cfg,
+        None => return true,
+    };
+    match bindings.config.symbols.cfgs {
+        Some(ref cfgs) => cfg.holds(cfgs),
+        None => {
+            warn!(
+                "Listing symbol `{}`, which is only exported under `cfg({})`. Set `cfgs` in \
+                 `[symbols]` to only list it when it is.",
+                symbol.name, cfg
+            );
+            true
+        }
+    }
+}
+
+/// The symbols of the functions and then of the globals, leaving out the
+/// functions declared in `extern "C"` blocks, which the library imports, and
+/// the ones under a cfg which doesn't hold.
+fn symbols(bindings: &Bindings) -> Vec<Symbol<'_>> {
+    let functions = bindings
+        .functions()
+        .iter()
+        .filter(|f| !f.extern_decl)
+        .map(|f| Symbol {
+            name: f.path().name(),
+            cfg: f.cfg.as_ref(),
+            is_data: false,
+        });
+    let globals = bindings.globals().iter().map(|g| Symbol {
+        name: g.path().name(),
+        cfg: g.cfg(),
+        is_data: true,
+    });
+    functions
+        .chain(globals)
+        .filter(|symbol| is_exported(bindings, symbol))
+        .collect()
+}
+
+/// Writes a GNU ld version script exporting the symbols, in the version node
+/// of `[symbols]` if any, and hiding everything else.
+pub(crate) fn write_version_script<F: Write>(bindings: &Bindings, out: &mut F) {
+    match bindings.config.symbols.version_node {
+        Some(ref node) => writeln!(out, "{} {{", node).unwrap(),
+        None => writeln!(out, "{{").unwrap(),
+    }
+    writeln!(out, "  global:").unwrap();
+    for symbol in symbols(bindings) {
+        if let Some(cfg) = symbol.cfg {
+            writeln!(out, "    /* cfg({}) */", cfg).unwrap();
+        }
+        writeln!(out, "    {};", symbol.name).unwrap();
+    }
+    writeln!(out, "  local:").unwrap();
+    writeln!(out, "    *;").unwrap();
+    writeln!(out, "}};").unwrap();
+}
+
+/// Writes a Windows module-definition file exporting the symbols, with the
+/// library name of `[symbols]` if any.
+pub(crate) fn write_def_file<F: Write>(bindings: &Bindings, out: &mut F) {
+    if let Some(ref library) = bindings.config.symbols.library {
+        writeln!(out, "LIBRARY {}", library).unwrap();
+    }
+    writeln!(out, "EXPORTS").unwrap();
+    for symbol in symbols(bindings) {
+        if let Some(cfg) = symbol.cfg {
+            writeln!(out, "    ; cfg({})", cfg).unwrap();
+        }
+        if symbol.is_data {
+            writeln!(out, "    {} DATA", symbol.name).unwrap();
+        } else {
+            writeln!(out, "    {}", symbol.name).unwrap();
+        }
+    }
+}
+
+/// Writes the symbols one per line.
+pub(crate) fn write_symbol_list<F: Write>(bindings: &Bindings, out: &mut F) {
+    for symbol in symbols(bindings) {
+        writeln!(out, "{}", symbol.name).unwrap();
+    }
+}
@@ -4,8 +4,11 @@
 
 #![allow(clippy::redundant_closure_call)]
 
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
 use syn::ext::IdentExt;
 
+use crate::bindgen::ir::{Abi, Cfg};
+
 pub trait IterHelpers: Iterator {
     fn try_skip_map<F, T, E>(&mut self, f: F) -> Result<Vec<T>, E>
     where
@@ -30,11 +33,11 @@ where
     }
 }
 
-pub trait SynItemFnHelpers: SynAttributeHelpers {
+pub trait SynItemHelpers: SynAttributeHelpers {
     fn exported_name(&self) -> Option<String>;
 }
 
-impl SynItemFnHelpers for syn::ItemFn {
+impl SynItemHelpers for syn::ItemFn {
     fn exported_name(&self) -> Option<String> {
         self.attrs
             .attr_name_value_lookup("export_name")
@@ -48,7 +51,7 @@ impl SynItemFnHelpers for syn::ItemFn {
     }
 }
 
-impl SynItemFnHelpers for syn::ImplItemMethod {
+impl SynItemHelpers for syn::ImplItemMethod {
     fn exported_name(&self) -> Option<String> {
         self.attrs
             .attr_name_value_lookup("export_name")
@@ -62,6 +65,112 @@ impl SynItemFnHelpers for syn::ImplItemMethod {
     }
 }
 
+impl SynItemHelpers for syn::ItemStatic {
+    fn exported_name(&self) -> Option<String> {
+        self.attrs
+            .attr_name_value_lookup("export_name")
+            .or_else(|| {
+                if self.is_no_mangle() {
+                    Some(self.ident.unraw().to_string())
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+/// Parses a source file like `syn::parse_file`, but also accepting the unsafe
+/// attributes of Rust 2024, like `#[unsafe(no_mangle)]`, which syn doesn't
+/// parse. Their `unsafe` is turned into `r#unsafe` for that.
+pub fn parse_file(src: &str) -> syn::Result<syn::File> {
+    syn::parse_file(src).or_else(|err| {
+        let tokens: TokenStream = src.parse().map_err(|_| err.clone())?;
+        syn::parse2(raw_unsafe_attrs(tokens)).map_err(|_| err)
+    })
+}
+
+fn raw_unsafe_attrs(tokens: TokenStream) -> TokenStream {
+    let mut in_attr = false;
+    tokens
+        .into_iter()
+        .map(|token| {
+            let token = match token {
+                TokenTree::Group(group) => {
+                    let mut stream = raw_unsafe_attrs(group.stream()).into_iter();
+                    let mut tokens: Vec<_> = stream.by_ref().take(2).collect();
+                    if let [TokenTree::Ident(ref mut ident), TokenTree::Group(ref args)] =
+                        tokens[..]
+                    {
+                        if in_attr
+                            && group.delimiter() == Delimiter::Bracket
+                            && args.delimiter() == Delimiter::Parenthesis
+                            && ident == "unsafe"
+                        {
+                            *ident = Ident::new_raw("unsafe", ident.span());
+                        }
+                    }
+                    let mut raw = Group::new(
+                        group.delimiter(),
+                        tokens.into_iter().chain(stream).collect(),
+                    );
+                    raw.set_span(group.span());
+                    TokenTree::Group(raw)
+                }
+                token => token,
+            };
+            // An attribute is a bracketed group after `#` or `#!`.
+            in_attr = match token {
+                TokenTree::Punct(ref punct) => {
+                    punct.as_char() == '#' || (in_attr && punct.as_char() == '!')
+                }
+                _ => false,
+            };
+            token
+        })
+        .collect()
+}
+
+/// Parses attributes, taking the attributes out of the `unsafe(...)` of the
+/// unsafe attributes of Rust 2024, like `#[unsafe(no_mangle)]`, and out of
+/// `#[cfg_attr(...)]`. Those of a `cfg_attr` come with its predicate, along
+/// with those of the `cfg_attr`s around it.
+pub fn expand_attrs(attrs: &[syn::Attribute]) -> Vec<(Option<Cfg>, syn::Meta)> {
+    let mut out = Vec::new();
+    for attr in attrs {
+        if let Ok(meta) = attr.parse_meta() {
+            expand_meta(meta, None, &mut out);
+        }
+    }
+    out
+}
+
+fn expand_meta(meta: syn::Meta, cfg: Option<&Cfg>, out: &mut Vec<(Option<Cfg>, syn::Meta)>) {
+    let list = match meta {
+        syn::Meta::List(list) => list,
+        meta => return out.push((cfg.cloned(), meta)),
+    };
+    let is_unsafe = list
+        .path
+        .get_ident()
+        .map_or(false, |x| x.unraw() == "unsafe");
+    if is_unsafe && list.nested.len() == 1 {
+        if let Some(syn::NestedMeta::Meta(meta)) = list.nested.into_iter().next() {
+            expand_meta(meta, cfg, out);
+        }
+    } else if list.path.is_ident("cfg_attr") && list.nested.len() >= 2 {
+        let mut nested = list.nested.into_iter();
+        let predicate = Cfg::load_single(&nested.next().unwrap());
+        let cfg = Cfg::append(cfg, predicate);
+        for meta in nested {
+            if let syn::NestedMeta::Meta(meta) = meta {
+                expand_meta(meta, cfg.as_ref(), out);
+            }
+        }
+    } else {
+        out.push((cfg.cloned(), syn::Meta::List(list)));
+    }
+}
+
 /// Returns whether this attribute causes us to skip at item. This basically
 /// checks for `#[cfg(test)]`, `#[test]`, `/// cbindgen::ignore` and
 /// variations thereof.
@@ -100,20 +209,18 @@ pub trait SynAttributeHelpers {
     /// Returns the list of attributes for an item.
     fn attrs(&self) -> &[syn::Attribute];
 
-    /// Searches for attributes like `#[test]`.
+    /// Searches for attributes like `#[test]`, also in `unsafe(...)` and
+    /// `cfg_attr(...)`.
     /// Example:
     /// - `item.has_attr_word("test")` => `#[test]`
     fn has_attr_word(&self, name: &str) -> bool {
-        self.attrs()
-            .iter()
-            .filter_map(|x| x.parse_meta().ok())
-            .any(|attr| {
-                if let syn::Meta::Path(ref path) = attr {
-                    path.is_ident(name)
-                } else {
-                    false
-                }
-            })
+        expand_attrs(self.attrs()).into_iter().any(|(_, attr)| {
+            if let syn::Meta::Path(ref path) = attr {
+                path.is_ident(name)
+            } else {
+                false
+            }
+        })
     }
 
     fn is_no_mangle(&self) -> bool {
@@ -136,10 +243,9 @@ pub trait SynAttributeHelpers {
     }
 
     fn attr_name_value_lookup(&self, name: &str) -> Option<String> {
-        self.attrs()
-            .iter()
-            .filter_map(|attr| {
-                let attr = attr.parse_meta().ok()?;
+        expand_attrs(self.attrs())
+            .into_iter()
+            .filter_map(|(_, attr)| {
                 if let syn::Meta::NameValue(syn::MetaNameValue {
                     path,
                     lit: syn::Lit::Str(lit),
@@ -241,39 +347,23 @@ impl_syn_item_helper!(syn::ItemTraitAlias);
 
 /// Helper function for accessing Abi information
 pub trait SynAbiHelpers {
-    fn is_c(&self) -> bool;
-    fn is_omitted(&self) -> bool;
+    /// The calling convention, if it's one that can be called from C.
+    fn abi(&self) -> Option<Abi>;
 }
 
 impl SynAbiHelpers for Option<syn::Abi> {
-    fn is_c(&self) -> bool {
-        if let Some(ref abi) = *self {
-            if let Some(ref lit_string) = abi.name {
-                return lit_string.value() == "C";
-            }
-        }
-        false
-    }
-    fn is_omitted(&self) -> bool {
-        if let Some(ref abi) = *self {
-            abi.name.is_none()
-        } else {
-            false
-        }
+    fn abi(&self) -> Option<Abi> {
+        self.as_ref().and_then(|abi| abi.abi())
     }
 }
 
 impl SynAbiHelpers for syn::Abi {
-    fn is_c(&self) -> bool {
-        if let Some(ref lit_string) = self.name {
-            lit_string.value() == "C"
-        } else {
-            false
+    fn abi(&self) -> Option<Abi> {
+        match self.name {
+            Some(ref lit_string) => Abi::maybe(&lit_string.value()),
+            None => Some(Abi::C),
         }
     }
-    fn is_omitted(&self) -> bool {
-        self.name.is_none()
-    }
 }
 
 impl SynAttributeHelpers for [syn::Attribute] {
@@ -6,7 +6,7 @@ use std::cmp;
 use std::io;
 use std::io::Write;
 
-use crate::bindgen::config::{Braces, Config, Language};
+use crate::bindgen::config::Config;
 use crate::bindgen::Bindings;
 
 /// A type of way to format a list.
@@ -18,9 +18,9 @@ pub enum ListType<'a> {
 }
 
 /// A utility wrapper to write unbuffered data and correctly adjust positions.
-struct InnerWriter<'a, 'b: 'a, F: 'a + Write>(&'a mut SourceWriter<'b, F>);
+struct InnerWriter<'a, 'b: 'a>(&'a mut SourceWriter<'b>);
 
-impl<'a, 'b, F: Write> Write for InnerWriter<'a, 'b, F> {
+impl<'a, 'b> Write for InnerWriter<'a, 'b> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         let writer = &mut self.0;
 
@@ -44,8 +44,8 @@ impl<'a, 'b, F: Write> Write for InnerWriter<'a, 'b, F> {
 }
 
 /// A utility writer for generating code easier.
-pub struct SourceWriter<'a, F: Write> {
-    out: F,
+pub struct SourceWriter<'a> {
+    out: &'a mut dyn Write,
     bindings: &'a Bindings,
     spaces: Vec<usize>,
     line_started: bool,
@@ -54,10 +54,8 @@ pub struct SourceWriter<'a, F: Write> {
     max_line_length: usize,
 }
 
-pub type MeasureWriter<'a> = SourceWriter<'a, &'a mut Vec<u8>>;
-
-impl<'a, F: Write> SourceWriter<'a, F> {
-    pub fn new(out: F, bindings: &'a Bindings) -> Self {
+impl<'a> SourceWriter<'a> {
+    pub fn new(out: &'a mut dyn Write, bindings: &'a Bindings) -> Self {
         SourceWriter {
             out,
             bindings,
@@ -69,7 +67,7 @@ impl<'a, F: Write> SourceWriter<'a, F> {
         }
     }
 
-    pub fn bindings(&self) -> &Bindings {
+    pub fn bindings(&self) -> &'a Bindings {
         self.bindings
     }
 
@@ -77,7 +75,7 @@ impl<'a, F: Write> SourceWriter<'a, F> {
     /// written.
     pub fn try_write<T>(&mut self, func: T, max_line_length: usize) -> bool
     where
-        T: Fn(&mut MeasureWriter),
+        T: Fn(&mut SourceWriter),
     {
         if self.line_length > max_line_length {
             return false;
@@ -157,41 +155,15 @@ impl<'a, F: Write> SourceWriter<'a, F> {
     }
 
     pub fn open_brace(&mut self) {
-        match self.bindings.config.language {
-            Language::Cxx | Language::C => match self.bindings.config.braces {
-                Braces::SameLine => {
-                    self.write(" {");
-                    self.push_tab();
-                    self.new_line();
-                }
-                Braces::NextLine => {
-                    self.new_line();
-                    self.write("{");
-                    self.push_tab();
-                    self.new_line();
-                }
-            },
-            Language::Cython => {
-                self.write(":");
-                self.new_line();
-                self.push_tab();
-            }
-        }
+        let bindings = self.bindings;
+        bindings.backend().open_brace(&bindings.config, self);
     }
 
     pub fn close_brace(&mut self, semicolon: bool) {
-        self.pop_tab();
-        match self.bindings.config.language {
-            Language::Cxx | Language::C => {
-                self.new_line();
-                if semicolon {
-                    self.write("};");
-                } else {
-                    self.write("}");
-                }
-            }
-            Language::Cython => {}
-        }
+        let bindings = self.bindings;
+        bindings
+            .backend()
+            .close_brace(&bindings.config, self, semicolon);
     }
 
     pub fn write(&mut self, text: &'static str) {
@@ -254,5 +226,5 @@ impl<'a, F: Write> SourceWriter<'a, F> {
 }
 
 pub trait Source {
-    fn write<F: Write>(&self, config: &Config, _: &mut SourceWriter<F>);
+    fn write(&self, config: &Config, _: &mut SourceWriter);
 }
@@ -0,0 +1,208 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindgen::config::{Config, Layout};
+use crate::bindgen::ir::{Abi, ConstExpr, Function, PrimitiveType, Type};
+use crate::bindgen::writer::SourceWriter;
+
+// This code is for translating Rust types into Zig declarations.
+// Unlike C, Zig types read left to right, so there's no need for the
+// declarator juggling done in `cdecl`.
+// https://ziglang.org/documentation/master/#C-Type-Primitives
+
+/// The `std.builtin.CallingConvention` matching `abi`. Zig has no equivalent
+/// of `system` or `efiapi`, which are the C convention on most targets.
+fn callconv(abi: Abi) -> &'static str {
+    match abi {
+        Abi::C | Abi::Cdecl | Abi::System | Abi::Efiapi => ".C",
+        Abi::Stdcall => ".Stdcall",
+        Abi::Fastcall => ".Fastcall",
+        Abi::Vectorcall => ".Vectorcall",
+        Abi::Thiscall => ".Thiscall",
+        Abi::Aapcs => ".AAPCS",
+        Abi::Win64 => ".Win64",
+        Abi::Sysv64 => ".SysV",
+    }
+}
+
+fn write_pointee(out: &mut SourceWriter, t: &Type, config: &Config) {
+    // `*void` is a pointer to a zero-sized type in Zig, `anyopaque` is what
+    // corresponds to C's `void`.
+    if let Type::Primitive(PrimitiveType::Void) = *t {
+        out.write("anyopaque");
+    } else {
+        write_type(out, t, config);
+    }
+}
+
+fn write_ptr(
+    out: &mut SourceWriter,
+    pointee: &Type,
+    is_const: bool,
+    is_nullable: bool,
+    config: &Config,
+) {
+    if is_nullable {
+        out.write("?");
+    }
+    out.write("*");
+    if is_const {
+        out.write("const ");
+    }
+    write_pointee(out, pointee, config);
+}
+
+fn write_func_ptr_args(out: &mut SourceWriter, args: &[(Option<String>, Type)], config: &Config) {
+    for (i, (_, ty)) in args.iter().enumerate() {
+        if i != 0 {
+            out.write(", ");
+        }
+        write_type(out, ty, config);
+    }
+}
+
+fn write_ret(out: &mut SourceWriter, ret: &Type, never_return: bool, config: &Config) {
+    if never_return {
+        out.write("noreturn");
+    } else {
+        write_type(out, ret, config);
+    }
+}
+
+pub fn write_type(out: &mut SourceWriter, t: &Type, config: &Config) {
+    match *t {
+        Type::Path(ref generic) => {
+            // Generics only survive for C++, everything else is monomorphized
+            // by the time we get here.
+            write!(out, "{}", generic.export_name());
+        }
+        Type::Primitive(ref p) => {
+            write!(out, "{}", p.to_repr_zig());
+        }
+        Type::Ptr {
+            ref ty,
+            is_const,
+            is_nullable,
+            is_ref: _,
+        } => write_ptr(out, ty, is_const, is_nullable, config),
+        // Atomics are only kept for C and C++, the layout is the same.
+        Type::Atomic(ref ty) => write_type(out, ty, config),
+        Type::Array(ref ty, ref len) => {
+            write!(out, "[{}]", len.as_str());
+            write_type(out, ty, config);
+        }
+        Type::FuncPtr {
+            ref ret,
+            ref args,
+            abi,
+            is_nullable,
+            never_return,
+        } => {
+            if is_nullable {
+                out.write("?");
+            }
+            out.write("*const fn (");
+            write_func_ptr_args(out, args, config);
+            write!(out, ") callconv({}) ", callconv(abi));
+            write_ret(out, ret, never_return, config);
+        }
+    }
+}
+
+pub fn write_field(out: &mut SourceWriter, t: &Type, ident: &str, config: &Config) {
+    write!(out, "{}: ", ident);
+    write_type(out, t, config);
+}
+
+fn write_func_arg(
+    out: &mut SourceWriter,
+    name: Option<&str>,
+    t: &Type,
+    array_length: Option<&str>,
+    config: &Config,
+) {
+    write!(out, "{}: ", name.unwrap_or("_"));
+    match (array_length, t) {
+        (
+            Some(length),
+            Type::Ptr {
+                ty,
+                is_const,
+                is_nullable,
+                ..
+            },
+        ) => {
+            // `T arg[]` is just a pointer to an unknown number of items.
+            if length.is_empty() {
+                if *is_nullable {
+                    out.write("?");
+                }
+                out.write("[*]");
+                if *is_const {
+                    out.write("const ");
+                }
+                write_pointee(out, ty, config);
+            } else {
+                let array = Type::Array(ty.clone(), ConstExpr::Value(length.to_owned()));
+                write_ptr(out, &array, *is_const, *is_nullable, config);
+            }
+        }
+        _ => write_type(out, t, config),
+    }
+}
+
+pub fn write_func(out: &mut SourceWriter, f: &Function, layout: Layout, config: &Config) {
+    write!(out, "{}(", f.path().name());
+
+    fn write_vertical(out: &mut SourceWriter, f: &Function, config: &Config) {
+        out.push_tab();
+        for arg in &f.args {
+            out.new_line();
+            write_func_arg(
+                out,
+                arg.name.as_deref(),
+                &arg.ty,
+                arg.array_length.as_deref(),
+                config,
+            );
+            out.write(",");
+        }
+        out.pop_tab();
+        out.new_line();
+    }
+
+    fn write_horizontal(out: &mut SourceWriter, f: &Function, config: &Config) {
+        for (i, arg) in f.args.iter().enumerate() {
+            if i != 0 {
+                out.write(", ");
+            }
+            write_func_arg(
+                out,
+                arg.name.as_deref(),
+                &arg.ty,
+                arg.array_length.as_deref(),
+                config,
+            );
+        }
+    }
+
+    // Zig's formatter puts every argument on its own line, with a trailing
+    // comma, rather than aligning them with the opening parenthesis.
+    match layout {
+        _ if f.args.is_empty() => {}
+        Layout::Vertical => write_vertical(out, f, config),
+        Layout::Horizontal => write_horizontal(out, f, config),
+        Layout::Auto => {
+            if !out.try_write(|out| write_horizontal(out, f, config), config.line_length) {
+                write_vertical(out, f, config)
+            }
+        }
+    }
+    out.write(") ");
+    let callconv = callconv(f.abi);
+    if callconv != ".C" {
+        write!(out, "callconv({}) ", callconv);
+    }
+    write_ret(out, &f.ret, f.never_return, config);
+}
@@ -3,7 +3,8 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::env;
-use std::io;
+use std::fs::File;
+use std::io::{self, BufReader};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
@@ -78,23 +79,27 @@ fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     }
 }
 
+/// The config for bindings loaded from the file `input`.
+fn file_config(input: &Path, matches: &ArgMatches) -> Config {
+    // Load any config specified or search in the input directory
+    let mut config = match matches.value_of("config") {
+        Some(c) => Config::from_file(c).unwrap(),
+        None => Config::from_root_or_default(
+            input
+                .parent()
+                .expect("All files should have a parent directory"),
+        ),
+    };
+
+    apply_config_overrides(&mut config, matches);
+    config
+}
+
 fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error> {
     // If a file is specified then we load it as a single source
     if !input.is_dir() {
-        // Load any config specified or search in the input directory
-        let mut config = match matches.value_of("config") {
-            Some(c) => Config::from_file(c).unwrap(),
-            None => Config::from_root_or_default(
-                input
-                    .parent()
-                    .expect("All files should have a parent directory"),
-            ),
-        };
-
-        apply_config_overrides(&mut config, matches);
-
         return Builder::new()
-            .with_config(config)
+            .with_config(file_config(input, matches))
             .with_src(input)
             .generate();
     }
@@ -133,6 +138,36 @@ fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error>
         .generate()
 }
 
+fn load_bindings_or_exit(input: &Path, matches: &ArgMatches) -> Bindings {
+    match load_bindings(input, matches) {
+        Ok(bindings) => bindings,
+        Err(msg) => {
+            error!("{}", msg);
+            error!("Couldn't generate bindings for {}.", input.display());
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Loads a version to compare with `diff`, which can also be the JSON written
+/// with `--format json`.
+fn load_diff_input_or_exit(input: &Path, matches: &ArgMatches) -> Bindings {
+    if input.extension().map_or(true, |x| x != "json") {
+        return load_bindings_or_exit(input, matches);
+    }
+    let bindings = File::open(input)
+        .map_err(|e| Error::InvalidJson(e.to_string()))
+        .and_then(|file| Bindings::from_json(file_config(input, matches), BufReader::new(file)));
+    match bindings {
+        Ok(bindings) => bindings,
+        Err(msg) => {
+            error!("{}", msg);
+            error!("Couldn't load bindings from {}.", input.display());
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() {
     let matches = Command::new("cbindgen")
         .version(bindgen::VERSION)
@@ -155,13 +190,32 @@ fn main() {
                 .value_name("PATH")
                 .help("Specify path to a `cbindgen.toml` config to use"),
         )
+        .arg(
+            Arg::new("print-config-schema")
+                .long("print-config-schema")
+                .help("Print a JSON Schema of `cbindgen.toml` for editors to validate and complete it, and exit"),
+        )
         .arg(
             Arg::new("lang")
                 .short('l')
                 .long("lang")
                 .value_name("LANGUAGE")
                 .help("Specify the language to output bindings in")
-                .possible_values(["c++", "C++", "c", "C", "cython", "Cython"]),
+                .possible_values([
+                    "c++", "C++", "c", "C", "cython", "Cython", "zig", "Zig", "python", "Python",
+                    "csharp", "CSharp", "C#",
+                ]),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .help(
+                    "Specify whether to output bindings as source code or as a JSON \
+                    description of the items that would be generated",
+                )
+                .possible_values(["source", "json"])
+                .default_value("source"),
         )
         .arg(
             Arg::new("cpp-compat")
@@ -280,6 +334,77 @@ fn main() {
                     This option is ignored if `--out` is missing."
                 )
         )
+        .arg(
+            Arg::new("rust-layout-checks")
+                .value_name("PATH")
+                .long("rust-layout-checks")
+                .takes_value(true)
+                .required(false)
+                .help("Generate a Rust file at the given path asserting at compile time \
+                    that rustc lays out the exported types the way the bindings expect, \
+                    and printing their layouts like the program of `--c-layout-checks`."
+                )
+        )
+        .arg(
+            Arg::new("c-layout-checks")
+                .value_name("PATH")
+                .long("c-layout-checks")
+                .takes_value(true)
+                .required(false)
+                .help("Generate a C or C++ program at the given path printing the layouts \
+                    of the exported types, for comparison with the Rust side. It includes \
+                    the bindings by the file name given to `--out`, which is required, or by \
+                    the name of the umbrella header of `[split]`."
+                )
+        )
+        .arg(
+            Arg::new("version-script")
+                .value_name("PATH")
+                .long("version-script")
+                .takes_value(true)
+                .required(false)
+                .help("Generate a GNU ld version script at the given path exporting \
+                    exactly the functions and globals of the bindings."
+                )
+        )
+        .arg(
+            Arg::new("def-file")
+                .value_name("PATH")
+                .long("def-file")
+                .takes_value(true)
+                .required(false)
+                .help("Generate a Windows module-definition (.def) file at the given \
+                    path exporting the functions and globals of the bindings."
+                )
+        )
+        .arg(
+            Arg::new("symbol-list")
+                .value_name("PATH")
+                .long("symbol-list")
+                .takes_value(true)
+                .required(false)
+                .help("Generate a file at the given path listing the symbols of the \
+                    functions and globals of the bindings, one per line."
+                )
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("Compare the ABI of two versions of a crate, printing the changes and \
+                    exiting with status 2 if any of them would break code built against \
+                    the old version. The options given before `diff` apply to both.")
+                .arg(
+                    Arg::new("OLD")
+                        .help("The crate directory, source file or JSON dump of the old version.")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("NEW")
+                        .help("The crate directory, source file or JSON dump of the new version.")
+                        .required(true)
+                        .index(2),
+                )
+        )
         .get_matches();
 
     if !matches.is_present("out") && matches.is_present("verify") {
@@ -289,6 +414,13 @@ fn main() {
         std::process::exit(2);
     }
 
+    if !matches.is_present("out") && matches.is_present("c-layout-checks") {
+        error!(
+            "Cannot include bindings written to `stdout`, please specify a file to write them to."
+        );
+        std::process::exit(2);
+    }
+
     // Initialize logging
     if matches.is_present("quiet") {
         logging::ErrorLogger::init().unwrap();
@@ -300,25 +432,68 @@ fn main() {
         }
     }
 
+    if matches.is_present("print-config-schema") {
+        println!("{}", Config::json_schema());
+        return;
+    }
+
+    if let Some(diff_matches) = matches.subcommand_matches("diff") {
+        let old =
+            load_diff_input_or_exit(Path::new(diff_matches.value_of("OLD").unwrap()), &matches);
+        let new =
+            load_diff_input_or_exit(Path::new(diff_matches.value_of("NEW").unwrap()), &matches);
+        let diff = old.diff(&new);
+        print!("{}", diff);
+        if diff.is_breaking() {
+            std::process::exit(2);
+        }
+        return;
+    }
+
     // Find the input directory
     let input = match matches.value_of("INPUT") {
         Some(input) => PathBuf::from(input),
         None => env::current_dir().unwrap(),
     };
 
-    let bindings = match load_bindings(&input, &matches) {
-        Ok(bindings) => bindings,
-        Err(msg) => {
-            error!("{}", msg);
-            error!("Couldn't generate bindings for {}.", input.display());
-            std::process::exit(1);
+    let bindings = load_bindings_or_exit(&input, &matches);
+
+    let json = matches.value_of("format") == Some("json");
+
+    if let Some(checks) = matches.value_of("rust-layout-checks") {
+        if bindings.write_rust_layout_checks_to_file(checks) && matches.is_present("verify") {
+            error!("Layout checks changed: {}", checks);
+            std::process::exit(2);
         }
-    };
+    }
+
+    if let Some(script) = matches.value_of("version-script") {
+        if bindings.write_version_script_to_file(script) && matches.is_present("verify") {
+            error!("Version script changed: {}", script);
+            std::process::exit(2);
+        }
+    }
+    if let Some(def) = matches.value_of("def-file") {
+        if bindings.write_def_file_to_file(def) && matches.is_present("verify") {
+            error!("Module-definition file changed: {}", def);
+            std::process::exit(2);
+        }
+    }
+    if let Some(list) = matches.value_of("symbol-list") {
+        if bindings.write_symbol_list_to_file(list) && matches.is_present("verify") {
+            error!("Symbol list changed: {}", list);
+            std::process::exit(2);
+        }
+    }
 
     // Write the bindings file
     match matches.value_of("out") {
         Some(file) => {
-            let changed = bindings.write_to_file(file);
+            let changed = if json {
+                bindings.write_json_to_file(file)
+            } else {
+                bindings.write_to_file(file)
+            };
 
             if matches.is_present("verify") && changed {
                 error!("Bindings changed: {}", file);
@@ -327,9 +502,38 @@ fn main() {
             if let Some(depfile) = matches.value_of("depfile") {
                 bindings.generate_depfile(file, depfile)
             }
+            if let Some(checks) = matches.value_of("c-layout-checks") {
+                let split = &bindings.config.split;
+                // Include all the headers at once if they're split.
+                let header = match split.umbrella {
+                    Some(ref umbrella) if split.is_enabled(bindings.config.language) => {
+                        umbrella.clone()
+                    }
+                    _ => Path::new(file)
+                        .file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .into_owned(),
+                };
+                if bindings.write_c_layout_checks_to_file(checks, &header)
+                    && matches.is_present("verify")
+                {
+                    error!("Layout checks changed: {}", checks);
+                    std::process::exit(2);
+                }
+            }
         }
         _ => {
-            bindings.write(io::stdout());
+            if json {
+                bindings.write_json(io::stdout());
+            } else if bindings.config.split.is_enabled(bindings.config.language) {
+                error!(
+                    "Cannot write the headers of `[split]` to `stdout`, please specify a file to write them to."
+                );
+                std::process::exit(2);
+            } else {
+                bindings.write(io::stdout());
+            }
         }
     }
 }
@@ -0,0 +1,124 @@
+use cbindgen::*;
+
+use serde_json::Value;
+use std::path::PathBuf;
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.language = Language::C;
+    config
+}
+
+fn generate(name: &str) -> Bindings {
+    let test_dir = {
+        let mut this_file = PathBuf::from(file!());
+        this_file.pop();
+        this_file.push("diff");
+        this_file
+    };
+
+    Builder::new()
+        .with_config(config())
+        .with_src(test_dir.join(name))
+        .generate()
+        .expect("build should succeed")
+}
+
+#[test]
+fn diff_unchanged() {
+    let diff = generate("old.rs").diff(&generate("old.rs"));
+    assert_eq!(diff, AbiDiff::default());
+}
+
+fn to_json(bindings: &Bindings) -> Value {
+    let mut output = Vec::new();
+    bindings.write_json(&mut output);
+    serde_json::from_slice(&output).expect("output should be valid JSON")
+}
+
+fn from_json(value: &Value) -> Bindings {
+    let input = serde_json::to_vec(value).unwrap();
+    Bindings::from_json(config(), &input[..]).expect("JSON should load")
+}
+
+#[test]
+fn diff_compatible() {
+    let diff = generate("old.rs").diff(&generate("compatible.rs"));
+    assert!(!diff.is_breaking(), "{}", diff);
+    assert_eq!(
+        diff.to_string(),
+        "compatible: Mode: variant `Auto` added
+compatible: Status: variant `Busy` added
+compatible: plugin_stop: added
+"
+    );
+}
+
+#[test]
+fn diff_breaking() {
+    let diff = generate("old.rs").diff(&generate("breaking.rs"));
+    assert!(diff.is_breaking());
+    assert_eq!(
+        diff.to_string(),
+        "breaking: Mode: discriminant of variant `Off` changed from `0` to `1`
+breaking: Mode: discriminant of variant `On` changed from `1` to `2`
+compatible: Mode: variant `Auto` added
+breaking: Status: variant `Retry` removed
+breaking: Config: fields reordered from `flags, level, name` to `level, flags, name`
+breaking: Point: type of field `x` changed from `float` to `double`
+breaking: Point: type of field `y` changed from `float` to `double`
+breaking: Point: size changed from 8 to 16 bytes
+breaking: Point: alignment changed from 4 to 8 bytes
+breaking: Point: offset of field `y` changed from 4 to 8
+breaking: PLUGIN_VERSION: type changed from `uint32_t` to `uint64_t`
+breaking: plugin_start: calling convention changed from `C` to `stdcall`
+breaking: plugin_move: argument 1 of type `uint32_t` removed
+"
+    );
+}
+
+#[test]
+fn diff_json() {
+    let old = to_json(&generate("old.rs"));
+    assert_eq!(
+        from_json(&old).diff(&generate("old.rs")),
+        AbiDiff::default()
+    );
+    assert_eq!(
+        from_json(&old).diff(&generate("compatible.rs")),
+        generate("old.rs").diff(&generate("compatible.rs"))
+    );
+    assert_eq!(
+        from_json(&old).diff(&generate("breaking.rs")),
+        generate("old.rs").diff(&generate("breaking.rs"))
+    );
+}
+
+#[test]
+fn diff_json_evaluates_discriminants() {
+    for discriminant in ["0xA", "0XAu", "0o12", "012", "0b1010", "10ull"] {
+        let mut old = to_json(&generate("old.rs"));
+        let status = old["items"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|x| x["name"] == "Status")
+            .unwrap();
+        assert_eq!(status["variants"][1]["name"], "Failed");
+        status["variants"][1]["discriminant"]["value"] = Value::from(discriminant);
+        assert_eq!(
+            from_json(&old).diff(&generate("old.rs")),
+            AbiDiff::default(),
+            "{}",
+            discriminant
+        );
+    }
+}
+
+#[test]
+fn diff_json_rejects_other_versions() {
+    let mut old = to_json(&generate("old.rs"));
+    old["version"] = Value::from(0);
+    let input = serde_json::to_vec(&old).unwrap();
+    assert!(Bindings::from_json(config(), &input[..]).is_err());
+}
@@ -0,0 +1,41 @@
+#[repr(C)]
+pub struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[repr(C)]
+pub struct Config {
+    level: u16,
+    flags: u32,
+    name: *const u8,
+}
+
+#[repr(u8)]
+pub enum Mode {
+    Auto,
+    Off,
+    On,
+}
+
+#[repr(C)]
+pub enum Status {
+    Ok = 0,
+    Failed = 10,
+}
+
+#[no_mangle]
+pub extern "stdcall" fn plugin_start(config: *const Config, mode: Mode) -> Status {
+    Status::Ok
+}
+
+pub type Callback = extern "C" fn(status: Status, data: *mut u8);
+
+#[no_mangle]
+pub extern "C" fn plugin_listen(callback: Callback) {}
+
+#[no_mangle]
+pub extern "C" fn plugin_move(point: Point) {}
+
+#[no_mangle]
+pub static PLUGIN_VERSION: u64 = 1;
@@ -0,0 +1,41 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Config {
+    flags: u32,
+    level: u16,
+    name: *const u8,
+}
+
+#[repr(u8)]
+pub enum Mode {
+    Off,
+    On,
+}
+
+#[repr(C)]
+pub enum Status {
+    Ok = 0,
+    Failed = 10,
+    Retry,
+}
+
+#[no_mangle]
+pub extern "C" fn plugin_start(config: *const Config, mode: Mode) -> Status {
+    Status::Ok
+}
+
+pub type Callback = extern "C" fn(status: Status, data: *mut u8);
+
+#[no_mangle]
+pub extern "C" fn plugin_listen(callback: Callback) {}
+
+#[no_mangle]
+pub extern "C" fn plugin_move(point: Point, steps: u32) {}
+
+#[no_mangle]
+pub static PLUGIN_VERSION: u32 = 1;
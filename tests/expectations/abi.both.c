@@ -0,0 +1,40 @@
+#if defined(_WIN32)
+#define MYLIB_STDCALL __stdcall
+#define MYLIB_FASTCALL __fastcall
+#define MYLIB_SYSTEM __stdcall
+#define MYLIB_EFIAPI
+#else
+#define MYLIB_STDCALL
+#define MYLIB_FASTCALL
+#define MYLIB_SYSTEM
+#define MYLIB_EFIAPI
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef int32_t (MYLIB_STDCALL *StdcallCallback)(int32_t a);
+
+typedef void (MYLIB_SYSTEM *SystemCallback)(void);
+
+typedef struct Callbacks {
+  void (MYLIB_FASTCALL *fastcall)(uint8_t a, uint8_t b);
+  void (*c_unwind)(void);
+} Callbacks;
+
+typedef void (*PlainCallback)(int32_t a);
+
+void MYLIB_STDCALL stdcall_fn(StdcallCallback callback);
+
+int32_t MYLIB_SYSTEM system_fn(SystemCallback callback);
+
+void c_unwind_fn(struct Callbacks callbacks);
+
+void MYLIB_EFIAPI efiapi_fn(PlainCallback callback);
+
+void (MYLIB_FASTCALL *sysv64_fn(void))(uint8_t a, uint8_t b);
+
+extern void MYLIB_SYSTEM imported(int32_t a);
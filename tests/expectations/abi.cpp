@@ -0,0 +1,45 @@
+#if defined(_WIN32)
+#define MYLIB_STDCALL __stdcall
+#define MYLIB_FASTCALL __fastcall
+#define MYLIB_SYSTEM __stdcall
+#define MYLIB_EFIAPI
+#else
+#define MYLIB_STDCALL
+#define MYLIB_FASTCALL
+#define MYLIB_SYSTEM
+#define MYLIB_EFIAPI
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using StdcallCallback = int32_t(MYLIB_STDCALL *)(int32_t a);
+
+using SystemCallback = void(MYLIB_SYSTEM *)();
+
+struct Callbacks {
+  void (MYLIB_FASTCALL *fastcall)(uint8_t a, uint8_t b);
+  void (*c_unwind)();
+};
+
+using PlainCallback = void(*)(int32_t a);
+
+extern "C" {
+
+void MYLIB_STDCALL stdcall_fn(StdcallCallback callback);
+
+int32_t MYLIB_SYSTEM system_fn(SystemCallback callback);
+
+void c_unwind_fn(Callbacks callbacks);
+
+void MYLIB_EFIAPI efiapi_fn(PlainCallback callback);
+
+void (MYLIB_FASTCALL *sysv64_fn())(uint8_t a, uint8_t b);
+
+extern void MYLIB_SYSTEM imported(int32_t a);
+
+} // extern "C"
@@ -0,0 +1,37 @@
+#if defined(_WIN32)
+#define MYLIB_STDCALL __stdcall
+#define MYLIB_FASTCALL __fastcall
+#define MYLIB_SYSTEM __stdcall
+#define MYLIB_EFIAPI
+#else
+#define MYLIB_STDCALL
+#define MYLIB_FASTCALL
+#define MYLIB_SYSTEM
+#define MYLIB_EFIAPI
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Callbacks {
+    public IntPtr fastcall;
+    public IntPtr c_unwind;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.StdCall)]
+  public static extern void stdcall_fn(IntPtr callback);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Winapi)]
+  public static extern int system_fn(IntPtr callback);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void c_unwind_fn(Callbacks callbacks);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Winapi)]
+  public static extern void imported(int a);
+}
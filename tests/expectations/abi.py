@@ -0,0 +1,43 @@
+#if defined(_WIN32)
+#define MYLIB_STDCALL __stdcall
+#define MYLIB_FASTCALL __fastcall
+#define MYLIB_SYSTEM __stdcall
+#define MYLIB_EFIAPI
+#else
+#define MYLIB_STDCALL
+#define MYLIB_FASTCALL
+#define MYLIB_SYSTEM
+#define MYLIB_EFIAPI
+#endif
+
+
+import ctypes
+import enum
+
+class Callbacks(ctypes.Structure):
+  pass
+
+StdcallCallback = ctypes.WINFUNCTYPE(ctypes.c_int32, ctypes.c_int32)
+
+SystemCallback = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)(None)
+
+Callbacks._fields_ = [
+  ("fastcall", ctypes.c_void_p),
+  ("c_unwind", ctypes.CFUNCTYPE(None)),
+]
+
+PlainCallback = ctypes.CFUNCTYPE(None, ctypes.c_int32)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.stdcall_fn = ctypes.WINFUNCTYPE(None, StdcallCallback)(("stdcall_fn", lib))
+
+  lib.system_fn = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)(ctypes.c_int32, SystemCallback)(("system_fn", lib))
+
+  lib.c_unwind_fn.argtypes = [Callbacks]
+  lib.c_unwind_fn.restype = None
+
+  lib.imported = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)(None, ctypes.c_int32)(("imported", lib))
+
+  return lib
@@ -0,0 +1,42 @@
+#if defined(_WIN32)
+#define MYLIB_STDCALL __stdcall
+#define MYLIB_FASTCALL __fastcall
+#define MYLIB_SYSTEM __stdcall
+#define MYLIB_EFIAPI
+#else
+#define MYLIB_STDCALL
+#define MYLIB_FASTCALL
+#define MYLIB_SYSTEM
+#define MYLIB_EFIAPI
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef int32_t (*StdcallCallback)(int32_t a);
+
+  ctypedef void (*SystemCallback)();
+
+  cdef struct Callbacks:
+    void (*fastcall)(uint8_t a, uint8_t b);
+    void (*c_unwind)();
+
+  ctypedef void (*PlainCallback)(int32_t a);
+
+  void stdcall_fn(StdcallCallback callback);
+
+  int32_t system_fn(SystemCallback callback);
+
+  void c_unwind_fn(Callbacks callbacks);
+
+  void efiapi_fn(PlainCallback callback);
+
+  void (*sysv64_fn())(uint8_t a, uint8_t b);
+
+  extern void imported(int32_t a);
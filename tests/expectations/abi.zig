@@ -0,0 +1,37 @@
+#if defined(_WIN32)
+#define MYLIB_STDCALL __stdcall
+#define MYLIB_FASTCALL __fastcall
+#define MYLIB_SYSTEM __stdcall
+#define MYLIB_EFIAPI
+#else
+#define MYLIB_STDCALL
+#define MYLIB_FASTCALL
+#define MYLIB_SYSTEM
+#define MYLIB_EFIAPI
+#endif
+
+
+const std = @import("std");
+
+pub const StdcallCallback = ?*const fn (i32) callconv(.Stdcall) i32;
+
+pub const SystemCallback = *const fn () callconv(.C) void;
+
+pub const Callbacks = extern struct {
+  fastcall: *const fn (u8, u8) callconv(.Fastcall) void,
+  c_unwind: ?*const fn () callconv(.C) void,
+};
+
+pub const PlainCallback = *const fn (i32) callconv(.C) void;
+
+pub extern fn stdcall_fn(callback: StdcallCallback) callconv(.Stdcall) void;
+
+pub extern fn system_fn(callback: SystemCallback) i32;
+
+pub extern fn c_unwind_fn(callbacks: Callbacks) void;
+
+pub extern fn efiapi_fn(callback: PlainCallback) void;
+
+pub extern fn sysv64_fn() callconv(.SysV) *const fn (u8, u8) callconv(.Fastcall) void;
+
+pub extern fn imported(a: i32) void;
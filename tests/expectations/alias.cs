@@ -0,0 +1,34 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum Status : uint {
+    Ok,
+    Err,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Dep {
+    public int a;
+    public float b;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_i32 {
+    public int a;
+    public int b;
+    public Dep c;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_f64 {
+    public double a;
+    public double b;
+    public Dep c;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo_i32 x, Foo_f64 y, int z, Status w);
+}
@@ -0,0 +1,48 @@
+import ctypes
+import enum
+
+class Dep(ctypes.Structure):
+  pass
+
+class Foo_i32(ctypes.Structure):
+  pass
+
+class Foo_f64(ctypes.Structure):
+  pass
+
+class Status(enum.IntEnum):
+  Ok = 0
+  Err = enum.auto()
+
+Dep._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_float),
+]
+
+Foo_i32._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_int32),
+  ("c", Dep),
+]
+
+IntFoo = Foo_i32
+
+Foo_f64._fields_ = [
+  ("a", ctypes.c_double),
+  ("b", ctypes.c_double),
+  ("c", Dep),
+]
+
+DoubleFoo = Foo_f64
+
+Unit = ctypes.c_int32
+
+SpecialStatus = ctypes.c_uint32
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [IntFoo, DoubleFoo, Unit, SpecialStatus]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,80 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum C : uint {
+    X = 2,
+    Y,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A {
+    public int m0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B {
+    public int x;
+    public float y;
+  }
+
+  public enum F_Tag : byte {
+    Foo,
+    Bar,
+    Baz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Body {
+    public F_Tag tag;
+    public short _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar_Body {
+    public F_Tag tag;
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct F {
+    [FieldOffset(0)] public F_Tag tag;
+    [FieldOffset(0)] public Foo_Body foo;
+    [FieldOffset(0)] public Bar_Body bar;
+  }
+
+  public enum H_Tag : byte {
+    Hello,
+    There,
+    Everyone,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Hello_Body {
+    public short _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct There_Body {
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct H {
+    public H_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public Hello_Body hello;
+      [FieldOffset(0)] public There_Body there;
+    }
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A x, B y, C z, F f, H h);
+}
@@ -0,0 +1,83 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  pass
+
+class B(ctypes.Structure):
+  pass
+
+class F(ctypes.Union):
+  pass
+
+class H(ctypes.Structure):
+  pass
+
+class C(enum.IntEnum):
+  X = 2
+  Y = enum.auto()
+
+A._fields_ = [
+  ("m0", ctypes.c_int32),
+]
+
+B._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+class F_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = enum.auto()
+  Baz = enum.auto()
+
+class _F_foo(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+F._anonymous_ = ["_foo"]
+F._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_foo", _F_foo),
+  ("bar", Bar_Body),
+]
+
+class H_Tag(enum.IntEnum):
+  Hello = 0
+  There = enum.auto()
+  Everyone = enum.auto()
+
+class There_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _H_Body(ctypes.Union):
+  _fields_ = [
+    ("hello", ctypes.c_int16),
+    ("there", There_Body),
+  ]
+
+H._anonymous_ = ["_body"]
+H._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _H_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, B, ctypes.c_uint32, F, H]
+  lib.root.restype = None
+
+  return lib
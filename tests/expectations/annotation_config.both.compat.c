@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Mode_Fast,
+  Mode_Safe,
+  Mode_Count,
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Flags {
+  uint8_t bits: 4;
+  uint32_t mode;
+} Flags;
+
+typedef struct Overridden {
+  int32_t x;
+} Overridden;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Point p, struct Flags f, Mode m, struct Overridden o);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Mode_Fast,
+  Mode_Safe,
+  Mode_Count,
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  uint8_t bits: 4;
+  uint32_t mode;
+} Flags;
+
+typedef struct {
+  int32_t x;
+} Overridden;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Point p, Flags f, Mode m, Overridden o);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,36 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Mode : uint8_t {
+  Mode_Fast,
+  Mode_Safe,
+  Mode_Count,
+};
+
+struct Point {
+  float x;
+  float y;
+
+  bool operator==(const Point& other) const {
+    return x == other.x &&
+           y == other.y;
+  }
+};
+
+struct Flags {
+  uint8_t bits: 4;
+  uint32_t mode;
+};
+
+struct Overridden {
+  int32_t x;
+};
+
+extern "C" {
+
+void root(Point p, Flags f, Mode m, Overridden o);
+
+} // extern "C"
@@ -0,0 +1,32 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum Mode : byte {
+    Mode_Fast,
+    Mode_Safe,
+    Mode_Count,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Point {
+    public float x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Flags {
+    public byte bits;
+    public uint mode;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Overridden {
+    public int x;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Point p, Flags f, Mode m, Overridden o);
+}
@@ -0,0 +1,38 @@
+import ctypes
+import enum
+
+class Point(ctypes.Structure):
+  pass
+
+class Flags(ctypes.Structure):
+  pass
+
+class Overridden(ctypes.Structure):
+  pass
+
+class Mode(enum.IntEnum):
+  Mode_Fast = 0
+  Mode_Safe = enum.auto()
+  Mode_Count = enum.auto()
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+Flags._fields_ = [
+  ("bits", ctypes.c_uint8, 4),
+  ("mode", ctypes.c_uint32),
+]
+
+Overridden._fields_ = [
+  ("x", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Point, Flags, ctypes.c_uint8, Overridden]
+  lib.root.restype = None
+
+  return lib
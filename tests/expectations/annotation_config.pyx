@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Mode_Fast,
+    Mode_Safe,
+    Mode_Count,
+  ctypedef uint8_t Mode;
+
+  ctypedef struct Point:
+    float x;
+    float y;
+
+  ctypedef struct Flags:
+    uint8_t bits;
+    uint32_t mode;
+
+  ctypedef struct Overridden:
+    int32_t x;
+
+  void root(Point p, Flags f, Mode m, Overridden o);
@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Mode {
+  Mode_Fast,
+  Mode_Safe,
+  Mode_Count,
+};
+typedef uint8_t Mode;
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Flags {
+  uint8_t bits: 4;
+  uint32_t mode;
+};
+
+struct Overridden {
+  int32_t x;
+};
+
+void root(struct Point p, struct Flags f, Mode m, struct Overridden o);
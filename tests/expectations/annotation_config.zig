@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const Mode = enum(u8) {
+  Mode_Fast,
+  Mode_Safe,
+  Mode_Count,
+};
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Flags = extern struct {
+  bits: u8,
+  mode: u32,
+};
+
+pub const Overridden = extern struct {
+  x: i32,
+};
+
+pub extern fn root(p: Point, f: Flags, m: Mode, o: Overridden) void;
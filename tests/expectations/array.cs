@@ -0,0 +1,29 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum Foo_Tag {
+    A,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A_Body {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 20)] public float[] _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+    public Foo_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public A_Body a;
+    }
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo a);
+}
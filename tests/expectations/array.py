@@ -0,0 +1,27 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+class Foo_Tag(enum.IntEnum):
+  A = 0
+
+class _Foo_Body(ctypes.Union):
+  _fields_ = [
+    ("a", ctypes.c_float * 20),
+  ]
+
+Foo._anonymous_ = ["_body"]
+Foo._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _Foo_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub const Foo_Tag = enum(c_int) {
+  A,
+};
+
+pub const Foo = extern struct {
+  tag: Foo_Tag,
+  body: extern union {
+    a: [20]f32,
+  },
+};
+
+pub extern fn root(a: Foo) void;
@@ -0,0 +1,97 @@
+#define MY_ASSERT(...) do { } while (0)
+#define MY_ATTRS __attribute((noinline))
+
+
+import ctypes
+import enum
+
+class H(ctypes.Structure):
+  pass
+
+class J(ctypes.Structure):
+  pass
+
+class K(ctypes.Union):
+  pass
+
+class I(ctypes.Structure):
+  pass
+
+class H_Tag(enum.IntEnum):
+  H_Foo = 0
+  H_Bar = enum.auto()
+  H_Baz = enum.auto()
+
+class H_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _H_Body(ctypes.Union):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+    ("bar", H_Bar_Body),
+  ]
+
+H._anonymous_ = ["_body"]
+H._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _H_Body),
+]
+
+class J_Tag(enum.IntEnum):
+  J_Foo = 0
+  J_Bar = enum.auto()
+  J_Baz = enum.auto()
+
+class J_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _J_Body(ctypes.Union):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+    ("bar", J_Bar_Body),
+  ]
+
+J._anonymous_ = ["_body"]
+J._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _J_Body),
+]
+
+class K_Tag(enum.IntEnum):
+  K_Foo = 0
+  K_Bar = enum.auto()
+  K_Baz = enum.auto()
+
+class _K_foo(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class K_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+K._anonymous_ = ["_foo"]
+K._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_foo", _K_foo),
+  ("bar", K_Bar_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [H, I, J, K]
+  lib.foo.restype = None
+
+  return lib
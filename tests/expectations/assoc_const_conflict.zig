@@ -0,0 +1,3 @@
+const std = @import("std");
+
+pub const Foo_FOO: u32 = 42;
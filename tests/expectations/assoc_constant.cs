@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+
+  }
+  public const int Foo_GA = 10;
+  public const float Foo_ZO = (float)3.14;
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo x);
+}
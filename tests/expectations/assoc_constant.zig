@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const Foo = extern struct {
+
+};
+pub const Foo_GA: i32 = 10;
+pub const Foo_ZO: f32 = 3.14;
+
+pub extern fn root(x: Foo) void;
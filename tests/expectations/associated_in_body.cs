@@ -0,0 +1,65 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  /// <summary>
+  /// Constants shared by multiple CSS Box Alignment properties
+  ///
+  /// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+  /// </summary>
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleAlignFlags {
+    public byte bits;
+  }
+  /// <summary>
+  /// 'auto'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_AUTO = new StyleAlignFlags { bits = unchecked((byte)0) };
+  /// <summary>
+  /// 'normal'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_NORMAL = new StyleAlignFlags { bits = unchecked((byte)1) };
+  /// <summary>
+  /// 'start'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_START = new StyleAlignFlags { bits = unchecked((byte)(1 << 1)) };
+  /// <summary>
+  /// 'end'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_END = new StyleAlignFlags { bits = unchecked((byte)(1 << 2)) };
+  public static readonly StyleAlignFlags StyleAlignFlags_ALIAS = new StyleAlignFlags { bits = unchecked((byte)(StyleAlignFlags_END).bits) };
+  /// <summary>
+  /// 'flex-start'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_FLEX_START = new StyleAlignFlags { bits = unchecked((byte)(1 << 3)) };
+  public static readonly StyleAlignFlags StyleAlignFlags_MIXED = new StyleAlignFlags { bits = unchecked((byte)(((1 << 4) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits)) };
+  public static readonly StyleAlignFlags StyleAlignFlags_MIXED_SELF = new StyleAlignFlags { bits = unchecked((byte)(((1 << 5) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits)) };
+
+  /// <summary>
+  /// An arbitrary identifier for a native (OS compositor) surface
+  /// </summary>
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleNativeSurfaceId {
+    public ulong _0;
+  }
+  /// <summary>
+  /// A special id for the native surface that is used for debug / profiler overlays.
+  /// </summary>
+  public static readonly StyleNativeSurfaceId StyleNativeSurfaceId_DEBUG_OVERLAY = new StyleNativeSurfaceId { _0 = ulong.MaxValue };
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleNativeTileId {
+    public StyleNativeSurfaceId surface_id;
+    public int x;
+    public int y;
+  }
+  /// <summary>
+  /// A special id for the native surface that is used for debug / profiler overlays.
+  /// </summary>
+  public static readonly StyleNativeTileId StyleNativeTileId_DEBUG_OVERLAY = new StyleNativeTileId { surface_id = StyleNativeSurfaceId_DEBUG_OVERLAY, x = 0, y = 0 };
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(StyleAlignFlags flags, StyleNativeTileId tile);
+}
@@ -0,0 +1,17 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uintptr_t head;
+  uintptr_t tail;
+  bool closed;
+  int64_t sequence;
+  uint8_t *data;
+  uint32_t *slots;
+} RingBuffer;
+
+void ring_buffer_push(const RingBuffer *buffer, uint32_t *counter);
+
+void ring_buffer_wait(bool *closed, uint8_t **data);
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <atomic>
+
+struct RingBuffer {
+  std::atomic<uintptr_t> head;
+  std::atomic<uintptr_t> tail;
+  std::atomic<bool> closed;
+  std::atomic<int64_t> sequence;
+  std::atomic<uint8_t*> data;
+  std::atomic<uint32_t> *slots;
+};
+
+extern "C" {
+
+void ring_buffer_push(const RingBuffer *buffer, std::atomic<uint32_t> *counter);
+
+void ring_buffer_wait(std::atomic<bool> *closed, std::atomic<uint8_t*> *data);
+
+} // extern "C"
@@ -0,0 +1,22 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct RingBuffer {
+    public nuint head;
+    public nuint tail;
+    [MarshalAs(UnmanagedType.U1)] public bool closed;
+    public long sequence;
+    public IntPtr data;
+    public IntPtr slots;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ring_buffer_push(IntPtr buffer, IntPtr counter);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ring_buffer_wait(IntPtr closed, IntPtr data);
+}
@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct RingBuffer:
+    uintptr_t head;
+    uintptr_t tail;
+    bool closed;
+    int64_t sequence;
+    uint8_t *data;
+    uint32_t *slots;
+
+  void ring_buffer_push(const RingBuffer *buffer, uint32_t *counter);
+
+  void ring_buffer_wait(bool *closed, uint8_t **data);
@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub const RingBuffer = extern struct {
+  head: usize,
+  tail: usize,
+  closed: bool,
+  sequence: i64,
+  data: ?*u8,
+  slots: ?*u32,
+};
+
+pub extern fn ring_buffer_push(buffer: *const RingBuffer, counter: *u32) void;
+
+pub extern fn ring_buffer_wait(closed: ?*bool, data: *?*u8) void;
@@ -0,0 +1,17 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct RingBuffer {
+  _Atomic(uintptr_t) head;
+  _Atomic(uintptr_t) tail;
+  _Atomic(bool) closed;
+  _Atomic(int64_t) sequence;
+  _Atomic(uint8_t*) data;
+  _Atomic(uint32_t) *slots;
+} RingBuffer;
+
+void ring_buffer_push(const struct RingBuffer *buffer, _Atomic(uint32_t) *counter);
+
+void ring_buffer_wait(_Atomic(bool) *closed, _Atomic(uint8_t*) *data);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if defined(__cplusplus) && !defined(_Atomic)
+#include <atomic>
+#define _Atomic(T) std::atomic<T>
+#endif
+
+typedef struct RingBuffer {
+  _Atomic(uintptr_t) head;
+  _Atomic(uintptr_t) tail;
+  _Atomic(bool) closed;
+  _Atomic(int64_t) sequence;
+  _Atomic(uint8_t*) data;
+  _Atomic(uint32_t) *slots;
+} RingBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void ring_buffer_push(const struct RingBuffer *buffer, _Atomic(uint32_t) *counter);
+
+void ring_buffer_wait(_Atomic(bool) *closed, _Atomic(uint8_t*) *data);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,28 @@
+import ctypes
+import enum
+
+class RingBuffer(ctypes.Structure):
+  pass
+
+RingBuffer._fields_ = [
+  ("head", ctypes.c_size_t),
+  ("tail", ctypes.c_size_t),
+  ("closed", ctypes.c_bool),
+  ("sequence", ctypes.c_int64),
+  ("data", ctypes.POINTER(ctypes.c_uint8)),
+  ("slots", ctypes.POINTER(ctypes.c_uint32)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.ring_buffer_push.argtypes = [ctypes.POINTER(RingBuffer), ctypes.POINTER(ctypes.c_uint32)]
+  lib.ring_buffer_push.restype = None
+
+  lib.ring_buffer_wait.argtypes = [
+    ctypes.POINTER(ctypes.c_bool),
+    ctypes.POINTER(ctypes.POINTER(ctypes.c_uint8)),
+  ]
+  lib.ring_buffer_wait.restype = None
+
+  return lib
@@ -0,0 +1,15 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "bitfield";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct HasBitfields {
+    public ulong foo;
+    public ulong bar;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr arg0);
+}
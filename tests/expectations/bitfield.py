@@ -0,0 +1,18 @@
+import ctypes
+import enum
+
+class HasBitfields(ctypes.Structure):
+  pass
+
+HasBitfields._fields_ = [
+  ("foo", ctypes.c_uint64, 8),
+  ("bar", ctypes.c_uint64, 56),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(HasBitfields)]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,8 @@
+const std = @import("std");
+
+pub const HasBitfields = extern struct {
+  foo: u64,
+  bar: u64,
+};
+
+pub extern fn root(_: *const HasBitfields) void;
@@ -0,0 +1,52 @@
+import ctypes
+import enum
+
+class AlignFlags(ctypes.Structure):
+  pass
+
+class DebugFlags(ctypes.Structure):
+  pass
+
+class LargeFlags(ctypes.Structure):
+  pass
+
+# Constants shared by multiple CSS Box Alignment properties
+#
+# These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+AlignFlags._fields_ = [
+  ("bits", ctypes.c_uint8),
+]
+# 'auto'
+AlignFlags_AUTO = AlignFlags(bits=ctypes.c_uint8(int(0)).value)
+# 'normal'
+AlignFlags_NORMAL = AlignFlags(bits=ctypes.c_uint8(int(1)).value)
+# 'start'
+AlignFlags_START = AlignFlags(bits=ctypes.c_uint8(int((1 << 1))).value)
+# 'end'
+AlignFlags_END = AlignFlags(bits=ctypes.c_uint8(int((1 << 2))).value)
+AlignFlags_ALIAS = AlignFlags(bits=ctypes.c_uint8(int((AlignFlags_END).bits)).value)
+# 'flex-start'
+AlignFlags_FLEX_START = AlignFlags(bits=ctypes.c_uint8(int((1 << 3))).value)
+AlignFlags_MIXED = AlignFlags(bits=ctypes.c_uint8(int((((1 << 4) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits))).value)
+AlignFlags_MIXED_SELF = AlignFlags(bits=ctypes.c_uint8(int((((1 << 5) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits))).value)
+
+DebugFlags._fields_ = [
+  ("bits", ctypes.c_uint32),
+]
+# Flag with the topmost bit set of the u32
+DebugFlags_BIGGEST_ALLOWED = DebugFlags(bits=ctypes.c_uint32(int((1 << 31))).value)
+
+LargeFlags._fields_ = [
+  ("bits", ctypes.c_uint64),
+]
+# Flag with a very large shift that usually would be narrowed.
+LargeFlags_LARGE_SHIFT = LargeFlags(bits=ctypes.c_uint64(int((1 << 44))).value)
+LargeFlags_INVERTED = LargeFlags(bits=ctypes.c_uint64(int(~(LargeFlags_LARGE_SHIFT).bits)).value)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [AlignFlags, DebugFlags, LargeFlags]
+  lib.root.restype = None
+
+  return lib
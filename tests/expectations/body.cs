@@ -0,0 +1,112 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum MyCLikeEnum {
+    Foo1,
+    Bar1,
+    Baz1,
+  }
+
+  public enum MyCLikeEnum_Prepended {
+    Foo1_Prepended,
+    Bar1_Prepended,
+    Baz1_Prepended,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyFancyStruct {
+    public int i;
+#ifdef __cplusplus
+    inline void foo();
+#endif
+  }
+
+  public enum MyFancyEnum_Tag {
+    Foo,
+    Bar,
+    Baz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar_Body {
+    public int _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Baz_Body {
+    public int _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyFancyEnum {
+    public MyFancyEnum_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public Bar_Body bar;
+      [FieldOffset(0)] public Baz_Body baz;
+    }
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct MyUnion {
+    [FieldOffset(0)] public float f;
+    [FieldOffset(0)] public uint u;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyFancyStruct_Prepended {
+#ifdef __cplusplus
+    inline void prepended_wohoo();
+#endif
+    public int i;
+  }
+
+  public enum MyFancyEnum_Prepended_Tag {
+    Foo_Prepended,
+    Bar_Prepended,
+    Baz_Prepended,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar_Prepended_Body {
+    public int _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Baz_Prepended_Body {
+    public int _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyFancyEnum_Prepended {
+    public MyFancyEnum_Prepended_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public Bar_Prepended_Body bar_prepended;
+      [FieldOffset(0)] public Baz_Prepended_Body baz_prepended;
+    }
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct MyUnion_Prepended {
+    [FieldOffset(0)] public float f;
+    [FieldOffset(0)] public uint u;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(MyFancyStruct s,
+                                 MyFancyEnum e,
+                                 MyCLikeEnum c,
+                                 MyUnion u,
+                                 MyFancyStruct_Prepended sp,
+                                 MyFancyEnum_Prepended ep,
+                                 MyCLikeEnum_Prepended cp,
+                                 MyUnion_Prepended up);
+}
@@ -0,0 +1,99 @@
+import ctypes
+import enum
+
+class MyFancyStruct(ctypes.Structure):
+  pass
+
+class MyFancyEnum(ctypes.Structure):
+  pass
+
+class MyUnion(ctypes.Union):
+  pass
+
+class MyFancyStruct_Prepended(ctypes.Structure):
+  pass
+
+class MyFancyEnum_Prepended(ctypes.Structure):
+  pass
+
+class MyUnion_Prepended(ctypes.Union):
+  pass
+
+class MyCLikeEnum(enum.IntEnum):
+  Foo1 = 0
+  Bar1 = enum.auto()
+  Baz1 = enum.auto()
+
+class MyCLikeEnum_Prepended(enum.IntEnum):
+  Foo1_Prepended = 0
+  Bar1_Prepended = enum.auto()
+  Baz1_Prepended = enum.auto()
+
+MyFancyStruct._fields_ = [
+  ("i", ctypes.c_int32),
+]
+
+class MyFancyEnum_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = enum.auto()
+  Baz = enum.auto()
+
+class _MyFancyEnum_Body(ctypes.Union):
+  _fields_ = [
+    ("bar", ctypes.c_int32),
+    ("baz", ctypes.c_int32),
+  ]
+
+MyFancyEnum._anonymous_ = ["_body"]
+MyFancyEnum._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _MyFancyEnum_Body),
+]
+
+MyUnion._fields_ = [
+  ("f", ctypes.c_float),
+  ("u", ctypes.c_uint32),
+]
+
+MyFancyStruct_Prepended._fields_ = [
+  ("i", ctypes.c_int32),
+]
+
+class MyFancyEnum_Prepended_Tag(enum.IntEnum):
+  Foo_Prepended = 0
+  Bar_Prepended = enum.auto()
+  Baz_Prepended = enum.auto()
+
+class _MyFancyEnum_Prepended_Body(ctypes.Union):
+  _fields_ = [
+    ("bar_prepended", ctypes.c_int32),
+    ("baz_prepended", ctypes.c_int32),
+  ]
+
+MyFancyEnum_Prepended._anonymous_ = ["_body"]
+MyFancyEnum_Prepended._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _MyFancyEnum_Prepended_Body),
+]
+
+MyUnion_Prepended._fields_ = [
+  ("f", ctypes.c_float),
+  ("u", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    MyFancyStruct,
+    MyFancyEnum,
+    ctypes.c_int,
+    MyUnion,
+    MyFancyStruct_Prepended,
+    MyFancyEnum_Prepended,
+    ctypes.c_int,
+    MyUnion_Prepended,
+  ]
+  lib.root.restype = None
+
+  return lib
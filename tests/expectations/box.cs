@@ -0,0 +1,36 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct NotReprC_____i32 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyStruct {
+    public IntPtr number;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, IntPtr with_box);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void drop_box(IntPtr x);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void drop_box_opt(IntPtr x);
+}
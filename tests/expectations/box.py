@@ -0,0 +1,42 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class MyStruct(ctypes.Structure):
+  pass
+
+class NotReprC_____i32(ctypes.Structure):
+  pass
+
+Foo = NotReprC_____i32
+
+MyStruct._fields_ = [
+  ("number", ctypes.POINTER(ctypes.c_int32)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(MyStruct)]
+  lib.root.restype = None
+
+  lib.drop_box.argtypes = [ctypes.POINTER(ctypes.c_int32)]
+  lib.drop_box.restype = None
+
+  lib.drop_box_opt.argtypes = [ctypes.POINTER(ctypes.c_int32)]
+  lib.drop_box_opt.restype = None
+
+  return lib
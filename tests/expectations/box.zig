@@ -0,0 +1,29 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const NotReprC_____i32 = opaque {};
+
+pub const Foo = NotReprC_____i32;
+
+pub const MyStruct = extern struct {
+  number: *i32,
+};
+
+pub extern fn root(a: *const Foo, with_box: *const MyStruct) void;
+
+pub extern fn drop_box(x: *i32) void;
+
+pub extern fn drop_box_opt(x: ?*i32) void;
@@ -0,0 +1,26 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern IntPtr O();
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a,
+                                 IntPtr b,
+                                 IntPtr c,
+                                 IntPtr d,
+                                 IntPtr e,
+                                 IntPtr f,
+                                 IntPtr g,
+                                 IntPtr h,
+                                 IntPtr i,
+                                 IntPtr j,
+                                 IntPtr k,
+                                 IntPtr l,
+                                 IntPtr m,
+                                 IntPtr n,
+                                 IntPtr p);
+}
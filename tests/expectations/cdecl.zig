@@ -0,0 +1,51 @@
+const std = @import("std");
+
+pub const A = *const fn () callconv(.C) void;
+
+pub const B = *const fn () callconv(.C) void;
+
+pub const C = *const fn (i32, i32) callconv(.C) bool;
+
+pub const D = *const fn (i32) callconv(.C) *const fn (f32) callconv(.C) bool;
+
+pub const E = *const fn () callconv(.C) ?*const [16]i32;
+
+pub const F = ?*const i32;
+
+pub const G = ?*const ?*const i32;
+
+pub const H = ?*const ?*i32;
+
+pub const I = ?*const [16]i32;
+
+pub const J = ?*const *const fn (f32) callconv(.C) f64;
+
+pub const K = [16]i32;
+
+pub const L = [16]?*const i32;
+
+pub const M = [16]*const fn (i32, i32) callconv(.C) bool;
+
+pub const N = [16]*const fn (i32, i32) callconv(.C) void;
+
+pub const P = *const fn (i32, bool, bool, i32) callconv(.C) void;
+
+pub extern fn O() *const fn () callconv(.C) void;
+
+pub extern fn root(
+  a: A,
+  b: B,
+  c: C,
+  d: D,
+  e: E,
+  f: F,
+  g: G,
+  h: H,
+  i: I,
+  j: J,
+  k: K,
+  l: L,
+  m: M,
+  n: N,
+  p: P,
+) void;
@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct NotReprC_RefCell_i32 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyStruct {
+    public int number;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, IntPtr with_cell);
+}
@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+class MyStruct(ctypes.Structure):
+  pass
+
+class NotReprC_RefCell_i32(ctypes.Structure):
+  pass
+
+Foo = NotReprC_RefCell_i32
+
+MyStruct._fields_ = [
+  ("number", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(MyStruct)]
+  lib.root.restype = None
+
+  return lib
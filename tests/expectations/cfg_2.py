@@ -0,0 +1,8 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+import ctypes
+import enum
@@ -0,0 +1,82 @@
+#if 0
+DEF FFI = 1
+DEF DEFINED = 1
+DEF SIMD = 1
+DEF WINDOWS = 0
+#endif
+
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(FFI)
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+#endif
+
+typedef struct Always {
+  uint32_t value;
+} Always;
+
+#if defined(SIMD)
+typedef struct CBINDGEN_ALIGNED(16) Vector {
+  float x;
+  float y;
+} Vector;
+#endif
+
+#if !defined(SIMD)
+typedef struct Vector {
+  float x;
+  float y;
+} Vector;
+#endif
+
+#if defined(WINDOWS)
+typedef struct CBINDGEN_PACKED Header {
+  uint8_t kind;
+  uint32_t length;
+} Header;
+#endif
+
+#if !defined(WINDOWS)
+typedef struct Header {
+  uint8_t kind;
+  uint32_t length;
+} Header;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(FFI)
+struct Point make_point(float x, float y);
+#endif
+
+#if defined(FFI)
+void renamed_always(struct Always a);
+#endif
+
+void unconditional(struct Always a);
+
+#if (!defined(DEFINED) || defined(FFI))
+void ffi_on_unix(void);
+#endif
+
+#if (defined(FFI) && defined(DEFINED))
+uint32_t unix_ffi_value(void);
+#endif
+
+void take_vector(struct Vector v, struct Header h);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,88 @@
+#if 0
+DEF FFI = 1
+DEF DEFINED = 1
+DEF SIMD = 1
+DEF WINDOWS = 0
+#endif
+
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+#if FFI
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Point {
+    public float x;
+    public float y;
+  }
+#endif
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Always {
+    public uint value;
+  }
+
+#if SIMD
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Vector {
+    public float x;
+    public float y;
+  }
+#endif
+
+#if !SIMD
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Vector {
+    public float x;
+    public float y;
+  }
+#endif
+
+#if WINDOWS
+  [StructLayout(LayoutKind.Sequential, Pack = 1)]
+  public struct Header {
+    public byte kind;
+    public uint length;
+  }
+#endif
+
+#if !WINDOWS
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Header {
+    public byte kind;
+    public uint length;
+  }
+#endif
+
+#if FFI
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Point make_point(float x, float y);
+#endif
+
+#if FFI
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void renamed_always(Always a);
+#endif
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void unconditional(Always a);
+
+#if (!DEFINED || FFI)
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ffi_on_unix();
+#endif
+
+#if (FFI && DEFINED)
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern uint unix_ffi_value();
+#endif
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void take_vector(Vector v, Header h);
+}
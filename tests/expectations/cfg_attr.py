@@ -0,0 +1,28 @@
+#if 0
+DEF FFI = 1
+DEF DEFINED = 1
+DEF SIMD = 1
+DEF WINDOWS = 0
+#endif
+
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+import ctypes
+import enum
+
+class Always(ctypes.Structure):
+  pass
+
+Always._fields_ = [
+  ("value", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.unconditional.argtypes = [Always]
+  lib.unconditional.restype = None
+
+  return lib
@@ -0,0 +1,62 @@
+#if 0
+DEF FFI = 1
+DEF DEFINED = 1
+DEF SIMD = 1
+DEF WINDOWS = 0
+#endif
+
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  IF FFI:
+    ctypedef struct Point:
+      float x;
+      float y;
+
+  ctypedef struct Always:
+    uint32_t value;
+
+  IF SIMD:
+    ctypedef struct Vector:
+      float x;
+      float y;
+
+  IF not SIMD:
+    ctypedef struct Vector:
+      float x;
+      float y;
+
+  IF WINDOWS:
+    ctypedef packed struct Header:
+      uint8_t kind;
+      uint32_t length;
+
+  IF not WINDOWS:
+    ctypedef struct Header:
+      uint8_t kind;
+      uint32_t length;
+
+  IF FFI:
+    Point make_point(float x, float y);
+
+  IF FFI:
+    void renamed_always(Always a);
+
+  void unconditional(Always a);
+
+  IF (not DEFINED or FFI):
+    void ffi_on_unix();
+
+  IF (FFI and DEFINED):
+    uint32_t unix_ffi_value();
+
+  void take_vector(Vector v, Header h);
@@ -0,0 +1,74 @@
+#if 0
+DEF FFI = 1
+DEF DEFINED = 1
+DEF SIMD = 1
+DEF WINDOWS = 0
+#endif
+
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(FFI)
+struct Point {
+  float x;
+  float y;
+};
+#endif
+
+struct Always {
+  uint32_t value;
+};
+
+#if defined(SIMD)
+struct CBINDGEN_ALIGNED(16) Vector {
+  float x;
+  float y;
+};
+#endif
+
+#if !defined(SIMD)
+struct Vector {
+  float x;
+  float y;
+};
+#endif
+
+#if defined(WINDOWS)
+struct CBINDGEN_PACKED Header {
+  uint8_t kind;
+  uint32_t length;
+};
+#endif
+
+#if !defined(WINDOWS)
+struct Header {
+  uint8_t kind;
+  uint32_t length;
+};
+#endif
+
+#if defined(FFI)
+struct Point make_point(float x, float y);
+#endif
+
+#if defined(FFI)
+void renamed_always(struct Always a);
+#endif
+
+void unconditional(struct Always a);
+
+#if (!defined(DEFINED) || defined(FFI))
+void ffi_on_unix(void);
+#endif
+
+#if (defined(FFI) && defined(DEFINED))
+uint32_t unix_ffi_value(void);
+#endif
+
+void take_vector(struct Vector v, struct Header h);
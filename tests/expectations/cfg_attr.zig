@@ -0,0 +1,18 @@
+#if 0
+DEF FFI = 1
+DEF DEFINED = 1
+DEF SIMD = 1
+DEF WINDOWS = 0
+#endif
+
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+const std = @import("std");
+
+pub const Always = extern struct {
+  value: u32,
+};
+
+pub extern fn unconditional(a: Always) void;
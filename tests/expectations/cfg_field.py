@@ -0,0 +1,2 @@
+import ctypes
+import enum
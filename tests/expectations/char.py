@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("a", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,7 @@
+const std = @import("std");
+
+pub const Foo = extern struct {
+  a: u32,
+};
+
+pub extern fn root(a: Foo) void;
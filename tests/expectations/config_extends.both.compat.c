@@ -0,0 +1,31 @@
+/* Shared by all the configs extending this one. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Base_Corner {
+  Base_Corner_TopLeft,
+  Base_Corner_BottomRight,
+} Base_Corner;
+
+typedef struct Base_Vec2 {
+  float x;
+  float y;
+} Base_Vec2;
+
+typedef struct Base_Box2 {
+  struct Base_Vec2 origin;
+  struct Base_Vec2 size;
+} Base_Box2;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Base_Vec2 corner(struct Base_Box2 rect, enum Base_Corner corner);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
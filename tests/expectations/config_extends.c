@@ -0,0 +1,23 @@
+/* Shared by all the configs extending this one. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Base_Corner_TopLeft,
+  Base_Corner_BottomRight,
+} Base_Corner;
+
+typedef struct {
+  float x;
+  float y;
+} Base_Vec2;
+
+typedef struct {
+  Base_Vec2 origin;
+  Base_Vec2 size;
+} Base_Box2;
+
+Base_Vec2 corner(Base_Box2 rect, Base_Corner corner);
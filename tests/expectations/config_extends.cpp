@@ -0,0 +1,28 @@
+/* Shared by all the configs extending this one. */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Base_Corner {
+  Base_Corner_TopLeft,
+  Base_Corner_BottomRight,
+};
+
+struct Base_Vec2 {
+  float x;
+  float y;
+};
+
+struct Base_Box2 {
+  Base_Vec2 origin;
+  Base_Vec2 size;
+};
+
+extern "C" {
+
+Base_Vec2 corner(Base_Box2 rect, Base_Corner corner);
+
+} // extern "C"
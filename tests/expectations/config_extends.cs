@@ -0,0 +1,28 @@
+/* Shared by all the configs extending this one. */
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum Base_Corner {
+    Base_Corner_TopLeft,
+    Base_Corner_BottomRight,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Base_Vec2 {
+    public float x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Base_Box2 {
+    public Base_Vec2 origin;
+    public Base_Vec2 size;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Base_Vec2 corner(Base_Box2 rect, Base_Corner corner);
+}
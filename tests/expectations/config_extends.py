@@ -0,0 +1,32 @@
+/* Shared by all the configs extending this one. */
+
+import ctypes
+import enum
+
+class Base_Vec2(ctypes.Structure):
+  pass
+
+class Base_Box2(ctypes.Structure):
+  pass
+
+class Base_Corner(enum.IntEnum):
+  Base_Corner_TopLeft = 0
+  Base_Corner_BottomRight = enum.auto()
+
+Base_Vec2._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+Base_Box2._fields_ = [
+  ("origin", Base_Vec2),
+  ("size", Base_Vec2),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.corner.argtypes = [Base_Box2, ctypes.c_int]
+  lib.corner.restype = Base_Vec2
+
+  return lib
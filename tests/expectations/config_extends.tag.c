@@ -0,0 +1,23 @@
+/* Shared by all the configs extending this one. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Base_Corner {
+  Base_Corner_TopLeft,
+  Base_Corner_BottomRight,
+};
+
+struct Base_Vec2 {
+  float x;
+  float y;
+};
+
+struct Base_Box2 {
+  struct Base_Vec2 origin;
+  struct Base_Vec2 size;
+};
+
+struct Base_Vec2 corner(struct Base_Box2 rect, enum Base_Corner corner);
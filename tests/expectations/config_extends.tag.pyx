@@ -0,0 +1,23 @@
+/* Shared by all the configs extending this one. */
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum Base_Corner:
+    Base_Corner_TopLeft,
+    Base_Corner_BottomRight,
+
+  cdef struct Base_Vec2:
+    float x;
+    float y;
+
+  cdef struct Base_Box2:
+    Base_Vec2 origin;
+    Base_Vec2 size;
+
+  Base_Vec2 corner(Base_Box2 rect, Base_Corner corner);
@@ -0,0 +1,20 @@
+/* Shared by all the configs extending this one. */
+
+const std = @import("std");
+
+pub const Base_Corner = enum(c_int) {
+  Base_Corner_TopLeft,
+  Base_Corner_BottomRight,
+};
+
+pub const Base_Vec2 = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Base_Box2 = extern struct {
+  origin: Base_Vec2,
+  size: Base_Vec2,
+};
+
+pub extern fn corner(rect: Base_Box2, corner: Base_Corner) Base_Vec2;
@@ -0,0 +1,8 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public const uint Foo_FOO = 42;
+}
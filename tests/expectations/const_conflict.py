@@ -0,0 +1,4 @@
+import ctypes
+import enum
+
+Foo_FOO = 42
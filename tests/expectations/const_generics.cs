@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public const nuint TITLE_SIZE = 80;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Book {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = TITLE_SIZE)] public sbyte[] title;
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 40)] public sbyte[] author;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a);
+}
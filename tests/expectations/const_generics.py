@@ -0,0 +1,24 @@
+import ctypes
+import enum
+
+TITLE_SIZE = 80
+
+class Book(ctypes.Structure):
+  pass
+
+CArrayString_TITLE_SIZE = ctypes.c_int8 * TITLE_SIZE
+
+CArrayString_40 = ctypes.c_int8 * 40
+
+Book._fields_ = [
+  ("title", CArrayString_TITLE_SIZE),
+  ("author", CArrayString_40),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Book)]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,15 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ArrayVec_____u8__100 {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 100)] public IntPtr[] xs;
+    public uint len;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int push(IntPtr v, IntPtr elem);
+}
@@ -0,0 +1,8 @@
+const std = @import("std");
+
+pub const ArrayVec_____u8__100 = extern struct {
+  xs: [100]?*u8,
+  len: u32,
+};
+
+pub extern fn push(v: ?*ArrayVec_____u8__100, elem: ?*u8) i32;
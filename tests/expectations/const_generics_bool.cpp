@@ -4,9 +4,6 @@
 #include <ostream>
 #include <new>
 
-template<typename T = void>
-struct MaybeUninit;
-
 using Str = const char*;
 
 template<typename K, typename V, bool IS_MAP>
@@ -14,8 +11,8 @@ struct HashTable {
   uintptr_t num_buckets;
   uintptr_t capacity;
   uint8_t *occupied;
-  MaybeUninit<K> *keys;
-  MaybeUninit<V> *vals;
+  K *keys;
+  V *vals;
 };
 
 using MySet = HashTable<Str, char, false>;
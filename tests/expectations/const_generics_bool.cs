@@ -0,0 +1,36 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct HashTable_Str__c_char__false {
+    public nuint num_buckets;
+    public nuint capacity;
+    public IntPtr occupied;
+    public IntPtr keys;
+    public IntPtr vals;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct HashTable_Str__u64__true {
+    public nuint num_buckets;
+    public nuint capacity;
+    public IntPtr occupied;
+    public IntPtr keys;
+    public IntPtr vals;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern IntPtr new_set();
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void set_for_each(IntPtr set, IntPtr callback);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern IntPtr new_map();
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void map_for_each(IntPtr map, IntPtr callback);
+}
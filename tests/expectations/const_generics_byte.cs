@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Parser_40__41 {
+    public IntPtr buf;
+    public nuint len;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Parser_123__125 {
+    public IntPtr buf;
+    public nuint len;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void init_parens_parser(IntPtr p, IntPtr buf, nuint len);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void destroy_parens_parser(IntPtr p);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void init_braces_parser(IntPtr p, IntPtr buf, nuint len);
+}
@@ -0,0 +1,40 @@
+import ctypes
+import enum
+
+class Parser_40__41(ctypes.Structure):
+  pass
+
+class Parser_123__125(ctypes.Structure):
+  pass
+
+Parser_40__41._fields_ = [
+  ("buf", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+]
+
+Parser_123__125._fields_ = [
+  ("buf", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.init_parens_parser.argtypes = [
+    ctypes.POINTER(Parser_40__41),
+    ctypes.POINTER(ctypes.c_uint8),
+    ctypes.c_size_t,
+  ]
+  lib.init_parens_parser.restype = None
+
+  lib.destroy_parens_parser.argtypes = [ctypes.POINTER(Parser_40__41)]
+  lib.destroy_parens_parser.restype = None
+
+  lib.init_braces_parser.argtypes = [
+    ctypes.POINTER(Parser_123__125),
+    ctypes.POINTER(ctypes.c_uint8),
+    ctypes.c_size_t,
+  ]
+  lib.init_braces_parser.restype = None
+
+  return lib
@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TakeUntil_0 {
+    public IntPtr start;
+    public nuint len;
+    public nuint point;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern TakeUntil_0 until_nul(IntPtr start, nuint len);
+}
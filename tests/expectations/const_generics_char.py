@@ -0,0 +1,19 @@
+import ctypes
+import enum
+
+class TakeUntil_0(ctypes.Structure):
+  pass
+
+TakeUntil_0._fields_ = [
+  ("start", ctypes.POINTER(ctypes.c_uint8)),
+  ("len", ctypes.c_size_t),
+  ("point", ctypes.c_size_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.until_nul.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+  lib.until_nul.restype = TakeUntil_0
+
+  return lib
@@ -0,0 +1,29 @@
+import ctypes
+import enum
+
+FONT_WEIGHT_FRACTION_BITS = 6
+
+class FixedPoint_FONT_WEIGHT_FRACTION_BITS(ctypes.Structure):
+  pass
+
+class FontWeight(ctypes.Structure):
+  pass
+
+FixedPoint_FONT_WEIGHT_FRACTION_BITS._fields_ = [
+  ("value", ctypes.c_uint16),
+]
+
+FontWeightFixedPoint = FixedPoint_FONT_WEIGHT_FRACTION_BITS
+
+FontWeight._fields_ = [
+  ("_0", FontWeightFixedPoint),
+]
+FontWeight_NORMAL = FontWeight(_0=FontWeightFixedPoint(value=(400 << FONT_WEIGHT_FRACTION_BITS)))
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [FontWeight]
+  lib.root.restype = None
+
+  return lib
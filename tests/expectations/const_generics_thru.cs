@@ -0,0 +1,32 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Inner_1 {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 1)] public byte[] bytes;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Outer_1 {
+    public Inner_1 inner;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Inner_2 {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 2)] public byte[] bytes;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Outer_2 {
+    public Inner_2 inner;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Outer_1 one();
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Outer_2 two();
+}
@@ -0,0 +1,41 @@
+import ctypes
+import enum
+
+class Inner_1(ctypes.Structure):
+  pass
+
+class Outer_1(ctypes.Structure):
+  pass
+
+class Inner_2(ctypes.Structure):
+  pass
+
+class Outer_2(ctypes.Structure):
+  pass
+
+Inner_1._fields_ = [
+  ("bytes", ctypes.c_uint8 * 1),
+]
+
+Outer_1._fields_ = [
+  ("inner", Inner_1),
+]
+
+Inner_2._fields_ = [
+  ("bytes", ctypes.c_uint8 * 2),
+]
+
+Outer_2._fields_ = [
+  ("inner", Inner_2),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.one.argtypes = []
+  lib.one.restype = Outer_1
+
+  lib.two.argtypes = []
+  lib.two.restype = Outer_2
+
+  return lib
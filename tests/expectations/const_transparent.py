@@ -0,0 +1,6 @@
+import ctypes
+import enum
+
+Transparent = ctypes.c_uint8
+
+FOO = 0
@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub const Transparent = u8;
+
+pub const FOO: Transparent = 0;
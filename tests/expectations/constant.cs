@@ -0,0 +1,57 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public const int FOO = 10;
+
+  public const uint DELIMITER = ':';
+
+  public const uint LEFTCURLY = '{';
+
+  public const uint QUOTE = '\'';
+
+  public const uint TAB = '\t';
+
+  public const uint NEWLINE = '\n';
+
+  public const uint HEART = 0x00002764;
+
+  public const uint EQUID = 0x00010083;
+
+  public const float ZOM = (float)3.14;
+
+  /// <summary>
+  /// A single-line doc comment.
+  /// </summary>
+  public const sbyte POS_ONE = 1;
+
+  /// <summary>
+  /// A
+  /// multi-line
+  /// doc
+  /// comment.
+  /// </summary>
+  public const sbyte NEG_ONE = -1;
+
+  public const long SHIFT = 3;
+
+  public const long XBOOL = 1;
+
+  public const long XFALSE = ((0 << SHIFT) | XBOOL);
+
+  public const long XTRUE = (1 << (SHIFT | XBOOL));
+
+  public const byte CAST = unchecked((byte)'A');
+
+  public const uint DOUBLE_CAST = unchecked((uint)unchecked((float)1));
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = FOO)] public int[] x;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo x);
+}
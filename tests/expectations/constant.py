@@ -0,0 +1,56 @@
+import ctypes
+import enum
+
+FOO = 10
+
+DELIMITER = ord(':')
+
+LEFTCURLY = ord('{')
+
+QUOTE = ord('\'')
+
+TAB = ord('\t')
+
+NEWLINE = ord('\n')
+
+HEART = ord('\U00002764')
+
+EQUID = ord('\U00010083')
+
+ZOM = 3.14
+
+# A single-line doc comment.
+POS_ONE = 1
+
+# A
+# multi-line
+# doc
+# comment.
+NEG_ONE = -1
+
+SHIFT = 3
+
+XBOOL = 1
+
+XFALSE = ((0 << SHIFT) | XBOOL)
+
+XTRUE = (1 << (SHIFT | XBOOL))
+
+CAST = ctypes.c_uint8(int(ord('A'))).value
+
+DOUBLE_CAST = ctypes.c_uint32(int(ctypes.c_float(1).value)).value
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.c_int32 * FOO),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
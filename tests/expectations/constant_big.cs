@@ -0,0 +1,14 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public const ulong UNSIGNED_NEEDS_ULL_SUFFIX = 9223372036854775808UL;
+
+  public const ulong UNSIGNED_DOESNT_NEED_ULL_SUFFIX = 8070450532247928832;
+
+  public const long SIGNED_NEEDS_ULL_SUFFIX = -9223372036854775808UL;
+
+  public const long SIGNED_DOESNT_NEED_ULL_SUFFIX = -9223372036854775807;
+}
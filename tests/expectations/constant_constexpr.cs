@@ -0,0 +1,22 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public const long CONSTANT_I64 = 216;
+
+  public const float CONSTANT_FLOAT32 = (float)312.292;
+
+  public const uint DELIMITER = ':';
+
+  public const uint LEFTCURLY = '{';
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+    public int x;
+  }
+  public const long Foo_CONSTANT_I64_BODY = 216;
+
+  public static readonly Foo SomeFoo = new Foo { x = 99 };
+}
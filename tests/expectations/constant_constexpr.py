@@ -0,0 +1,20 @@
+import ctypes
+import enum
+
+CONSTANT_I64 = 216
+
+CONSTANT_FLOAT32 = 312.292
+
+DELIMITER = ord(':')
+
+LEFTCURLY = ord('{')
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.c_int32),
+]
+Foo_CONSTANT_I64_BODY = 216
+
+SomeFoo = Foo(x=99)
@@ -0,0 +1,15 @@
+import ctypes
+import enum
+
+A = 0
+
+B = 0
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.C = ctypes.c_uint8.in_dll(lib, "C")
+
+  lib.D = ctypes.c_uint8.in_dll(lib, "D")
+
+  return lib
@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof(NativeMethods).Assembly, null), name);
+
+  public const byte B = 0;
+
+  public const byte A = 0;
+
+  public static IntPtr D => __GetExport("D");
+
+  public static IntPtr C => __GetExport("C");
+}
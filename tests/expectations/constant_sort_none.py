@@ -0,0 +1,15 @@
+import ctypes
+import enum
+
+B = 0
+
+A = 0
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.D = ctypes.c_uint8.in_dll(lib, "D")
+
+  lib.C = ctypes.c_uint8.in_dll(lib, "C")
+
+  return lib
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum E {
+    V,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct S {
+    public byte field;
+  }
+
+  public static readonly S C1 = new S { field = 0 };
+
+  public static readonly E C2 = E.V;
+
+  public const byte C3 = 0;
+}
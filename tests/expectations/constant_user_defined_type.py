@@ -0,0 +1,20 @@
+import ctypes
+import enum
+
+class S(ctypes.Structure):
+  pass
+
+class E(enum.IntEnum):
+  V = 0
+
+S._fields_ = [
+  ("field", ctypes.c_uint8),
+]
+
+A = ctypes.c_uint8
+
+C1 = S(field=0)
+
+C2 = E.V
+
+C3 = 0
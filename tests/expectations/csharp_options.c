@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef bool (*Callback)(bool value, uintptr_t len);
+
+typedef void (*StdcallCallback)(uint8_t value);
+
+typedef void (*SystemCallback)(void);
+
+typedef struct {
+  uint8_t data[16];
+  Point points[2][2];
+  Point *next;
+  Callback callback;
+  StdcallCallback on_fill;
+  SystemCallback on_done;
+} Buffer;
+
+extern uint32_t COUNTER;
+
+bool fill(Buffer *buffer, uint8_t value, Callback callback);
+
+void clear(Buffer *buffer);
+
+void done(Buffer *buffer);
@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace Bindings {
+#endif // __cplusplus
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef bool (*Callback)(bool value, uintptr_t len);
+
+typedef void (*StdcallCallback)(uint8_t value);
+
+typedef void (*SystemCallback)(void);
+
+typedef struct {
+  uint8_t data[16];
+  Point points[2][2];
+  Point *next;
+  Callback callback;
+  StdcallCallback on_fill;
+  SystemCallback on_done;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t COUNTER;
+
+bool fill(Buffer *buffer, uint8_t value, Callback callback);
+
+void clear(Buffer *buffer);
+
+void done(Buffer *buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace Bindings
+#endif // __cplusplus
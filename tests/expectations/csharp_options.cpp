@@ -0,0 +1,41 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace Bindings {
+
+struct Point {
+  float x;
+  float y;
+};
+
+using Callback = bool(*)(bool value, uintptr_t len);
+
+using StdcallCallback = void(*)(uint8_t value);
+
+using SystemCallback = void(*)();
+
+struct Buffer {
+  uint8_t data[16];
+  Point points[2][2];
+  Point *next;
+  Callback callback;
+  StdcallCallback on_fill;
+  SystemCallback on_done;
+};
+
+extern "C" {
+
+extern uint32_t COUNTER;
+
+bool fill(Buffer *buffer, uint8_t value, Callback callback);
+
+void clear(Buffer *buffer);
+
+void done(Buffer *buffer);
+
+} // extern "C"
+
+} // namespace Bindings
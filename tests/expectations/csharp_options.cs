@@ -0,0 +1,43 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace Bindings {
+  public static unsafe partial class Native {
+    const string __DllName = "my_library";
+
+    static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof(Native).Assembly, null), name);
+
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Point {
+      public float x;
+      public float y;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Buffer {
+      public fixed byte data[16];
+      public Point points_0, points_1, points_2, points_3;
+      public Point* next;
+      public delegate* unmanaged[Cdecl]<bool, nuint, bool> callback;
+      public delegate* unmanaged[Stdcall]<byte, void> on_fill;
+      public delegate* unmanaged<void> on_done;
+    }
+
+    public static uint* COUNTER => (uint*)__GetExport("COUNTER");
+
+    [LibraryImport(__DllName)]
+    [UnmanagedCallConv(CallConvs = new[] { typeof(CallConvCdecl) })]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool fill(Buffer* buffer,
+                                    byte value,
+                                    delegate* unmanaged[Cdecl]<bool, nuint, bool> callback);
+
+    [LibraryImport(__DllName)]
+    [UnmanagedCallConv(CallConvs = new[] { typeof(CallConvFastcall) })]
+    public static partial void clear(Buffer* buffer);
+
+    [LibraryImport(__DllName)]
+    public static partial void done(Buffer* buffer);
+  }
+}
@@ -0,0 +1,40 @@
+import ctypes
+import enum
+
+class Point(ctypes.Structure):
+  pass
+
+class Buffer(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+Callback = ctypes.CFUNCTYPE(ctypes.c_bool, ctypes.c_bool, ctypes.c_size_t)
+
+StdcallCallback = ctypes.WINFUNCTYPE(None, ctypes.c_uint8)
+
+SystemCallback = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)(None)
+
+Buffer._fields_ = [
+  ("data", ctypes.c_uint8 * 16),
+  ("points", (Point * 2) * 2),
+  ("next", ctypes.POINTER(Point)),
+  ("callback", Callback),
+  ("on_fill", StdcallCallback),
+  ("on_done", SystemCallback),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.COUNTER = ctypes.c_uint32.in_dll(lib, "COUNTER")
+
+  lib.fill.argtypes = [ctypes.POINTER(Buffer), ctypes.c_uint8, Callback]
+  lib.fill.restype = ctypes.c_bool
+
+  lib.done = getattr(ctypes, "WINFUNCTYPE", ctypes.CFUNCTYPE)(None, ctypes.POINTER(Buffer))(("done", lib))
+
+  return lib
@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+typedef bool (*Callback)(bool value, uintptr_t len);
+
+typedef void (*StdcallCallback)(uint8_t value);
+
+typedef void (*SystemCallback)(void);
+
+struct Buffer {
+  uint8_t data[16];
+  struct Point points[2][2];
+  struct Point *next;
+  Callback callback;
+  StdcallCallback on_fill;
+  SystemCallback on_done;
+};
+
+extern uint32_t COUNTER;
+
+bool fill(struct Buffer *buffer, uint8_t value, Callback callback);
+
+void clear(struct Buffer *buffer);
+
+void done(struct Buffer *buffer);
@@ -0,0 +1,33 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    float x;
+    float y;
+
+  ctypedef bool (*Callback)(bool value, uintptr_t len);
+
+  ctypedef void (*StdcallCallback)(uint8_t value);
+
+  ctypedef void (*SystemCallback)();
+
+  cdef struct Buffer:
+    uint8_t data[16];
+    Point points[2][2];
+    Point *next;
+    Callback callback;
+    StdcallCallback on_fill;
+    SystemCallback on_done;
+
+  extern uint32_t COUNTER;
+
+  bool fill(Buffer *buffer, uint8_t value, Callback callback);
+
+  void clear(Buffer *buffer);
+
+  void done(Buffer *buffer);
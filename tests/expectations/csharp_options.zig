@@ -0,0 +1,29 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Callback = *const fn (bool, usize) callconv(.C) bool;
+
+pub const StdcallCallback = *const fn (u8) callconv(.Stdcall) void;
+
+pub const SystemCallback = *const fn () callconv(.C) void;
+
+pub const Buffer = extern struct {
+  data: [16]u8,
+  points: [2][2]Point,
+  next: ?*Point,
+  callback: Callback,
+  on_fill: StdcallCallback,
+  on_done: SystemCallback,
+};
+
+pub extern var COUNTER: u32;
+
+pub extern fn fill(buffer: ?*Buffer, value: u8, callback: Callback) bool;
+
+pub extern fn clear(buffer: ?*Buffer) callconv(.Fastcall) void;
+
+pub extern fn done(buffer: ?*Buffer) void;
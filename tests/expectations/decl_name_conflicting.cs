@@ -0,0 +1,19 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum BindingType : uint {
+    Buffer = 0,
+    NotBuffer = 1,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct BindGroupLayoutEntry {
+    public BindingType ty;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(BindGroupLayoutEntry entry);
+}
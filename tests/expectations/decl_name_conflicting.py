@@ -0,0 +1,21 @@
+import ctypes
+import enum
+
+class BindGroupLayoutEntry(ctypes.Structure):
+  pass
+
+class BindingType(enum.IntEnum):
+  Buffer = 0
+  NotBuffer = 1
+
+BindGroupLayoutEntry._fields_ = [
+  ("ty", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [BindGroupLayoutEntry]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,116 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  pass
+
+class B(ctypes.Structure):
+  pass
+
+class D(ctypes.Structure):
+  pass
+
+class F(ctypes.Union):
+  pass
+
+class H(ctypes.Structure):
+  pass
+
+class I(ctypes.Structure):
+  pass
+
+class C(enum.IntEnum):
+  X = 2
+  Y = enum.auto()
+
+A._fields_ = [
+  ("_0", ctypes.c_int32),
+]
+
+B._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+D._fields_ = [
+  ("List", ctypes.c_uint8),
+  ("Of", ctypes.c_size_t),
+  ("Things", B),
+]
+
+class F_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = enum.auto()
+  Baz = enum.auto()
+
+class _F_foo(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+F._anonymous_ = ["_foo"]
+F._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_foo", _F_foo),
+  ("bar", Bar_Body),
+]
+
+class H_Tag(enum.IntEnum):
+  Hello = 0
+  There = enum.auto()
+  Everyone = enum.auto()
+
+class There_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _H_Body(ctypes.Union):
+  _fields_ = [
+    ("hello", ctypes.c_int16),
+    ("there", There_Body),
+  ]
+
+H._anonymous_ = ["_body"]
+H._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _H_Body),
+]
+
+class I_Tag(enum.IntEnum):
+  ThereAgain = 0
+  SomethingElse = enum.auto()
+
+class ThereAgain_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _I_Body(ctypes.Union):
+  _fields_ = [
+    ("there_again", ThereAgain_Body),
+  ]
+
+I._anonymous_ = ["_body"]
+I._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _I_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, B, ctypes.c_uint32, D, F, H, I]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,80 @@
+const std = @import("std");
+
+pub const C = enum(u32) {
+  X = 2,
+  Y,
+};
+
+pub const A = extern struct {
+  _0: i32,
+};
+
+pub const B = extern struct {
+  x: i32,
+  y: f32,
+};
+
+pub const D = extern struct {
+  List: u8,
+  Of: usize,
+  Things: B,
+};
+
+pub const F_Tag = enum(u8) {
+  Foo,
+  Bar,
+  Baz,
+};
+
+pub const Bar_Body = extern struct {
+  tag: F_Tag,
+  x: u8,
+  y: i16,
+};
+
+pub const F = extern union {
+  tag: F_Tag,
+  foo: extern struct {
+    foo_tag: F_Tag,
+    foo: i16,
+  },
+  bar: Bar_Body,
+};
+
+pub const H_Tag = enum(u8) {
+  Hello,
+  There,
+  Everyone,
+};
+
+pub const There_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const H = extern struct {
+  tag: H_Tag,
+  body: extern union {
+    hello: i16,
+    there: There_Body,
+  },
+};
+
+pub const I_Tag = enum(u8) {
+  ThereAgain,
+  SomethingElse,
+};
+
+pub const ThereAgain_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const I = extern struct {
+  tag: I_Tag,
+  body: extern union {
+    there_again: ThereAgain_Body,
+  },
+};
+
+pub extern fn root(a: A, b: B, c: C, d: D, f: F, h: H, i: I) void;
@@ -0,0 +1,270 @@
+#define NOINLINE __attribute__((noinline))
+#define NODISCARD [[nodiscard]]
+
+
+import ctypes
+import enum
+
+class OwnedSlice_u32(ctypes.Structure):
+  pass
+
+class Polygon_u32(ctypes.Structure):
+  pass
+
+class OwnedSlice_i32(ctypes.Structure):
+  pass
+
+class Foo_u32(ctypes.Structure):
+  pass
+
+class Polygon_i32(ctypes.Structure):
+  pass
+
+class Baz_i32(ctypes.Union):
+  pass
+
+class Taz(ctypes.Union):
+  pass
+
+class Tazz(ctypes.Union):
+  pass
+
+class Tazzz(ctypes.Union):
+  pass
+
+class Tazzzz(ctypes.Union):
+  pass
+
+class Qux(ctypes.Union):
+  pass
+
+class FillRule(enum.IntEnum):
+  A = 0
+  B = enum.auto()
+
+# This will have a destructor manually implemented via variant_body, and
+# similarly a Drop impl in Rust.
+OwnedSlice_u32._fields_ = [
+  ("len", ctypes.c_size_t),
+  ("ptr", ctypes.POINTER(ctypes.c_uint32)),
+]
+
+Polygon_u32._fields_ = [
+  ("fill", ctypes.c_uint8),
+  ("coordinates", OwnedSlice_u32),
+]
+
+# This will have a destructor manually implemented via variant_body, and
+# similarly a Drop impl in Rust.
+OwnedSlice_i32._fields_ = [
+  ("len", ctypes.c_size_t),
+  ("ptr", ctypes.POINTER(ctypes.c_int32)),
+]
+
+class Foo_u32_Tag(enum.IntEnum):
+  Bar_u32 = 0
+  Polygon1_u32 = enum.auto()
+  Slice1_u32 = enum.auto()
+  Slice2_u32 = enum.auto()
+  Slice3_u32 = enum.auto()
+  Slice4_u32 = enum.auto()
+
+class Slice3_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_u32),
+  ]
+
+class Slice4_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_i32),
+  ]
+
+class _Foo_u32_Body(ctypes.Union):
+  _fields_ = [
+    ("polygon1", Polygon_u32),
+    ("slice1", OwnedSlice_u32),
+    ("slice2", OwnedSlice_i32),
+    ("slice3", Slice3_Body_u32),
+    ("slice4", Slice4_Body_u32),
+  ]
+
+Foo_u32._anonymous_ = ["_body"]
+Foo_u32._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _Foo_u32_Body),
+]
+
+Polygon_i32._fields_ = [
+  ("fill", ctypes.c_uint8),
+  ("coordinates", OwnedSlice_i32),
+]
+
+class Baz_i32_Tag(enum.IntEnum):
+  Bar2_i32 = 0
+  Polygon21_i32 = enum.auto()
+  Slice21_i32 = enum.auto()
+  Slice22_i32 = enum.auto()
+  Slice23_i32 = enum.auto()
+  Slice24_i32 = enum.auto()
+
+class _Baz_i32_polygon21(ctypes.Structure):
+  _fields_ = [
+    ("polygon21_tag", ctypes.c_uint8),
+    ("polygon21", Polygon_i32),
+  ]
+
+class _Baz_i32_slice21(ctypes.Structure):
+  _fields_ = [
+    ("slice21_tag", ctypes.c_uint8),
+    ("slice21", OwnedSlice_i32),
+  ]
+
+class _Baz_i32_slice22(ctypes.Structure):
+  _fields_ = [
+    ("slice22_tag", ctypes.c_uint8),
+    ("slice22", OwnedSlice_i32),
+  ]
+
+class Slice23_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_i32),
+  ]
+
+class Slice24_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("fill", ctypes.c_uint8),
+    ("coords", OwnedSlice_i32),
+  ]
+
+Baz_i32._anonymous_ = ["_polygon21", "_slice21", "_slice22"]
+Baz_i32._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_polygon21", _Baz_i32_polygon21),
+  ("_slice21", _Baz_i32_slice21),
+  ("_slice22", _Baz_i32_slice22),
+  ("slice23", Slice23_Body_i32),
+  ("slice24", Slice24_Body_i32),
+]
+
+class Taz_Tag(enum.IntEnum):
+  Bar3 = 0
+  Taz1 = enum.auto()
+  Taz3 = enum.auto()
+
+class _Taz_taz1(ctypes.Structure):
+  _fields_ = [
+    ("taz1_tag", ctypes.c_uint8),
+    ("taz1", ctypes.c_int32),
+  ]
+
+class _Taz_taz3(ctypes.Structure):
+  _fields_ = [
+    ("taz3_tag", ctypes.c_uint8),
+    ("taz3", OwnedSlice_i32),
+  ]
+
+Taz._anonymous_ = ["_taz1", "_taz3"]
+Taz._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_taz1", _Taz_taz1),
+  ("_taz3", _Taz_taz3),
+]
+
+class Tazz_Tag(enum.IntEnum):
+  Bar4 = 0
+  Taz2 = enum.auto()
+
+class _Tazz_taz2(ctypes.Structure):
+  _fields_ = [
+    ("taz2_tag", ctypes.c_uint8),
+    ("taz2", ctypes.c_int32),
+  ]
+
+Tazz._anonymous_ = ["_taz2"]
+Tazz._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_taz2", _Tazz_taz2),
+]
+
+class Tazzz_Tag(enum.IntEnum):
+  Bar5 = 0
+  Taz5 = enum.auto()
+
+class _Tazzz_taz5(ctypes.Structure):
+  _fields_ = [
+    ("taz5_tag", ctypes.c_uint8),
+    ("taz5", ctypes.c_int32),
+  ]
+
+Tazzz._anonymous_ = ["_taz5"]
+Tazzz._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_taz5", _Tazzz_taz5),
+]
+
+class Tazzzz_Tag(enum.IntEnum):
+  Taz6 = 0
+  Taz7 = enum.auto()
+
+class _Tazzzz_taz6(ctypes.Structure):
+  _fields_ = [
+    ("taz6_tag", ctypes.c_uint8),
+    ("taz6", ctypes.c_int32),
+  ]
+
+class _Tazzzz_taz7(ctypes.Structure):
+  _fields_ = [
+    ("taz7_tag", ctypes.c_uint8),
+    ("taz7", ctypes.c_uint32),
+  ]
+
+Tazzzz._anonymous_ = ["_taz6", "_taz7"]
+Tazzzz._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_taz6", _Tazzzz_taz6),
+  ("_taz7", _Tazzzz_taz7),
+]
+
+class Qux_Tag(enum.IntEnum):
+  Qux1 = 0
+  Qux2 = enum.auto()
+
+class _Qux_qux1(ctypes.Structure):
+  _fields_ = [
+    ("qux1_tag", ctypes.c_uint8),
+    ("qux1", ctypes.c_int32),
+  ]
+
+class _Qux_qux2(ctypes.Structure):
+  _fields_ = [
+    ("qux2_tag", ctypes.c_uint8),
+    ("qux2", ctypes.c_uint32),
+  ]
+
+Qux._anonymous_ = ["_qux1", "_qux2"]
+Qux._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_qux1", _Qux_qux1),
+  ("_qux2", _Qux_qux2),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Foo_u32),
+    ctypes.POINTER(Baz_i32),
+    ctypes.POINTER(Taz),
+    Tazz,
+    ctypes.POINTER(Tazzz),
+    ctypes.POINTER(Tazzzz),
+    ctypes.POINTER(Qux),
+  ]
+  lib.root.restype = None
+
+  return lib
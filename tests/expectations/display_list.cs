@@ -0,0 +1,53 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Rect {
+    public float x;
+    public float y;
+    public float w;
+    public float h;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Color {
+    public byte r;
+    public byte g;
+    public byte b;
+    public byte a;
+  }
+
+  public enum DisplayItem_Tag : byte {
+    Fill,
+    Image,
+    ClearScreen,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Fill_Body {
+    public DisplayItem_Tag tag;
+    public Rect _0;
+    public Color _1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Image_Body {
+    public DisplayItem_Tag tag;
+    public uint id;
+    public Rect bounds;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct DisplayItem {
+    [FieldOffset(0)] public DisplayItem_Tag tag;
+    [FieldOffset(0)] public Fill_Body fill;
+    [FieldOffset(0)] public Image_Body image;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  [return: MarshalAs(UnmanagedType.U1)]
+  public static extern bool push_item(DisplayItem item);
+}
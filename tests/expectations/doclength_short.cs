@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  /// <summary>
+  /// The root of all evil.
+  /// </summary>
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+
+  /// <summary>
+  /// A little above the root, and a lot more visible, with a run-on sentence
+  /// </summary>
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void trunk();
+}
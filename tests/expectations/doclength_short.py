@@ -0,0 +1,15 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # The root of all evil.
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  # A little above the root, and a lot more visible, with a run-on sentence
+  lib.trunk.argtypes = []
+  lib.trunk.restype = None
+
+  return lib
@@ -0,0 +1,7 @@
+const std = @import("std");
+
+/// The root of all evil.
+pub extern fn root() void;
+
+/// A little above the root, and a lot more visible, with a run-on sentence
+pub extern fn trunk() void;
@@ -0,0 +1,11 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  # The root of all evil.
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,300 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum A : ulong {
+    a1 = 0,
+    a2 = 2,
+    a3,
+    a4 = 5,
+  }
+
+  public enum B : uint {
+    b1 = 0,
+    b2 = 2,
+    b3,
+    b4 = 5,
+  }
+
+  public enum C : ushort {
+    c1 = 0,
+    c2 = 2,
+    c3,
+    c4 = 5,
+  }
+
+  public enum D : byte {
+    d1 = 0,
+    d2 = 2,
+    d3,
+    d4 = 5,
+  }
+
+  public enum E : ulong {
+    e1 = 0,
+    e2 = 2,
+    e3,
+    e4 = 5,
+  }
+
+  public enum F : long {
+    f1 = 0,
+    f2 = 2,
+    f3,
+    f4 = 5,
+  }
+
+  public enum L {
+    l1,
+    l2,
+    l3,
+    l4,
+  }
+
+  public enum M : sbyte {
+    m1 = -1,
+    m2 = 0,
+    m3 = 1,
+  }
+
+  public enum N {
+    n1,
+    n2,
+    n3,
+    n4,
+  }
+
+  public enum O : sbyte {
+    o1,
+    o2,
+    o3,
+    o4,
+  }
+
+  public struct J { }
+
+  public struct K { }
+
+  public struct Opaque { }
+
+  public enum G_Tag : byte {
+    Foo,
+    Bar,
+    Baz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Body {
+    public G_Tag tag;
+    public short _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar_Body {
+    public G_Tag tag;
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct G {
+    [FieldOffset(0)] public G_Tag tag;
+    [FieldOffset(0)] public Foo_Body foo;
+    [FieldOffset(0)] public Bar_Body bar;
+  }
+
+  public enum H_Tag {
+    H_Foo,
+    H_Bar,
+    H_Baz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct H_Foo_Body {
+    public short _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct H_Bar_Body {
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct H {
+    public H_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public H_Foo_Body foo;
+      [FieldOffset(0)] public H_Bar_Body bar;
+    }
+  }
+
+  public enum ExI_Tag : byte {
+    ExI_Foo,
+    ExI_Bar,
+    ExI_Baz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExI_Foo_Body {
+    public short _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExI_Bar_Body {
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExI {
+    public ExI_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public ExI_Foo_Body foo;
+      [FieldOffset(0)] public ExI_Bar_Body bar;
+    }
+  }
+
+  public enum P_Tag : byte {
+    P0,
+    P1,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct P0_Body {
+    public byte _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct P1_Body {
+    public byte _0;
+    public byte _1;
+    public byte _2;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct P {
+    public P_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public P0_Body p0;
+      [FieldOffset(0)] public P1_Body p1;
+    }
+  }
+
+  public enum Q_Tag {
+    Ok,
+    Err,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Ok_Body {
+    public IntPtr _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Err_Body {
+    public uint _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Q {
+    public Q_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public Ok_Body ok;
+      [FieldOffset(0)] public Err_Body err;
+    }
+  }
+
+  public enum R_Tag {
+    IRFoo,
+    IRBar,
+    IRBaz,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct IRFoo_Body {
+    public short _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct IRBar_Body {
+    public byte x;
+    public short y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct R {
+    public R_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public IRFoo_Body IRFoo;
+      [FieldOffset(0)] public IRBar_Body IRBar;
+    }
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr opaque,
+                                 A a,
+                                 B b,
+                                 C c,
+                                 D d,
+                                 E e,
+                                 F f,
+                                 G g,
+                                 H h,
+                                 ExI i,
+                                 J j,
+                                 K k,
+                                 L l,
+                                 M m,
+                                 N n,
+                                 O o,
+                                 P p,
+                                 Q q,
+                                 R r);
+}
+
+#if 0
+''' '
+#endif
+
+#include <stddef.h>
+#include "testing-helpers.h"
+static_assert(offsetof(CBINDGEN_STRUCT(P), tag) == 0, "unexpected offset for tag");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p0");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p1");
+static_assert(sizeof(CBINDGEN_STRUCT(P)) == 4, "unexpected size for P");
+
+#if 0
+' '''
+#endif
@@ -0,0 +1,278 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class G(ctypes.Union):
+  pass
+
+class H(ctypes.Structure):
+  pass
+
+class ExI(ctypes.Structure):
+  pass
+
+class P(ctypes.Structure):
+  pass
+
+class Q(ctypes.Structure):
+  pass
+
+class R(ctypes.Structure):
+  pass
+
+class A(enum.IntEnum):
+  a1 = 0
+  a2 = 2
+  a3 = enum.auto()
+  a4 = 5
+
+class B(enum.IntEnum):
+  b1 = 0
+  b2 = 2
+  b3 = enum.auto()
+  b4 = 5
+
+class C(enum.IntEnum):
+  c1 = 0
+  c2 = 2
+  c3 = enum.auto()
+  c4 = 5
+
+class D(enum.IntEnum):
+  d1 = 0
+  d2 = 2
+  d3 = enum.auto()
+  d4 = 5
+
+class E(enum.IntEnum):
+  e1 = 0
+  e2 = 2
+  e3 = enum.auto()
+  e4 = 5
+
+class F(enum.IntEnum):
+  f1 = 0
+  f2 = 2
+  f3 = enum.auto()
+  f4 = 5
+
+class L(enum.IntEnum):
+  l1 = 0
+  l2 = enum.auto()
+  l3 = enum.auto()
+  l4 = enum.auto()
+
+class M(enum.IntEnum):
+  m1 = -1
+  m2 = 0
+  m3 = 1
+
+class N(enum.IntEnum):
+  n1 = 0
+  n2 = enum.auto()
+  n3 = enum.auto()
+  n4 = enum.auto()
+
+class O(enum.IntEnum):
+  o1 = 0
+  o2 = enum.auto()
+  o3 = enum.auto()
+  o4 = enum.auto()
+
+class J(ctypes.Structure):
+  pass
+
+class K(ctypes.Structure):
+  pass
+
+class Opaque(ctypes.Structure):
+  pass
+
+class G_Tag(enum.IntEnum):
+  Foo = 0
+  Bar = enum.auto()
+  Baz = enum.auto()
+
+class _G_foo(ctypes.Structure):
+  _fields_ = [
+    ("foo_tag", ctypes.c_uint8),
+    ("foo", ctypes.c_int16),
+  ]
+
+class Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+G._anonymous_ = ["_foo"]
+G._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_foo", _G_foo),
+  ("bar", Bar_Body),
+]
+
+class H_Tag(enum.IntEnum):
+  H_Foo = 0
+  H_Bar = enum.auto()
+  H_Baz = enum.auto()
+
+class H_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _H_Body(ctypes.Union):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+    ("bar", H_Bar_Body),
+  ]
+
+H._anonymous_ = ["_body"]
+H._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _H_Body),
+]
+
+class ExI_Tag(enum.IntEnum):
+  ExI_Foo = 0
+  ExI_Bar = enum.auto()
+  ExI_Baz = enum.auto()
+
+class ExI_Bar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _ExI_Body(ctypes.Union):
+  _fields_ = [
+    ("foo", ctypes.c_int16),
+    ("bar", ExI_Bar_Body),
+  ]
+
+ExI._anonymous_ = ["_body"]
+ExI._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _ExI_Body),
+]
+
+class P_Tag(enum.IntEnum):
+  P0 = 0
+  P1 = enum.auto()
+
+class P1_Body(ctypes.Structure):
+  _fields_ = [
+    ("_0", ctypes.c_uint8),
+    ("_1", ctypes.c_uint8),
+    ("_2", ctypes.c_uint8),
+  ]
+
+class _P_Body(ctypes.Union):
+  _fields_ = [
+    ("p0", ctypes.c_uint8),
+    ("p1", P1_Body),
+  ]
+
+P._anonymous_ = ["_body"]
+P._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _P_Body),
+]
+
+class Q_Tag(enum.IntEnum):
+  Ok = 0
+  Err = enum.auto()
+
+class _Q_Body(ctypes.Union):
+  _fields_ = [
+    ("ok", ctypes.POINTER(ctypes.c_uint32)),
+    ("err", ctypes.c_uint32),
+  ]
+
+Q._anonymous_ = ["_body"]
+Q._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _Q_Body),
+]
+
+class R_Tag(enum.IntEnum):
+  IRFoo = 0
+  IRBar = enum.auto()
+  IRBaz = enum.auto()
+
+class IRBar_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_int16),
+  ]
+
+class _R_Body(ctypes.Union):
+  _fields_ = [
+    ("IRFoo", ctypes.c_int16),
+    ("IRBar", IRBar_Body),
+  ]
+
+R._anonymous_ = ["_body"]
+R._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _R_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Opaque),
+    ctypes.c_uint64,
+    ctypes.c_uint32,
+    ctypes.c_uint16,
+    ctypes.c_uint8,
+    ctypes.c_size_t,
+    ctypes.c_ssize_t,
+    G,
+    H,
+    ExI,
+    J,
+    K,
+    ctypes.c_int,
+    ctypes.c_int8,
+    ctypes.c_int,
+    ctypes.c_int8,
+    P,
+    Q,
+    R,
+  ]
+  lib.root.restype = None
+
+  return lib
+
+#if 0
+''' '
+#endif
+
+#include <stddef.h>
+#include "testing-helpers.h"
+static_assert(offsetof(CBINDGEN_STRUCT(P), tag) == 0, "unexpected offset for tag");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p0");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p1");
+static_assert(sizeof(CBINDGEN_STRUCT(P)) == 4, "unexpected size for P");
+
+#if 0
+' '''
+#endif
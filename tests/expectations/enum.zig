@@ -0,0 +1,237 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const A = enum(u64) {
+  a1 = 0,
+  a2 = 2,
+  a3,
+  a4 = 5,
+};
+
+pub const B = enum(u32) {
+  b1 = 0,
+  b2 = 2,
+  b3,
+  b4 = 5,
+};
+
+pub const C = enum(u16) {
+  c1 = 0,
+  c2 = 2,
+  c3,
+  c4 = 5,
+};
+
+pub const D = enum(u8) {
+  d1 = 0,
+  d2 = 2,
+  d3,
+  d4 = 5,
+};
+
+pub const E = enum(usize) {
+  e1 = 0,
+  e2 = 2,
+  e3,
+  e4 = 5,
+};
+
+pub const F = enum(isize) {
+  f1 = 0,
+  f2 = 2,
+  f3,
+  f4 = 5,
+};
+
+pub const L = enum(c_int) {
+  l1,
+  l2,
+  l3,
+  l4,
+};
+
+pub const M = enum(i8) {
+  m1 = -1,
+  m2 = 0,
+  m3 = 1,
+};
+
+pub const N = enum(c_int) {
+  n1,
+  n2,
+  n3,
+  n4,
+};
+
+pub const O = enum(i8) {
+  o1,
+  o2,
+  o3,
+  o4,
+};
+
+pub const J = opaque {};
+
+pub const K = opaque {};
+
+pub const Opaque = opaque {};
+
+pub const G_Tag = enum(u8) {
+  Foo,
+  Bar,
+  Baz,
+};
+
+pub const Bar_Body = extern struct {
+  tag: G_Tag,
+  x: u8,
+  y: i16,
+};
+
+pub const G = extern union {
+  tag: G_Tag,
+  foo: extern struct {
+    foo_tag: G_Tag,
+    foo: i16,
+  },
+  bar: Bar_Body,
+};
+
+pub const H_Tag = enum(c_int) {
+  H_Foo,
+  H_Bar,
+  H_Baz,
+};
+
+pub const H_Bar_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const H = extern struct {
+  tag: H_Tag,
+  body: extern union {
+    foo: i16,
+    bar: H_Bar_Body,
+  },
+};
+
+pub const ExI_Tag = enum(u8) {
+  ExI_Foo,
+  ExI_Bar,
+  ExI_Baz,
+};
+
+pub const ExI_Bar_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const ExI = extern struct {
+  tag: ExI_Tag,
+  body: extern union {
+    foo: i16,
+    bar: ExI_Bar_Body,
+  },
+};
+
+pub const P_Tag = enum(u8) {
+  P0,
+  P1,
+};
+
+pub const P1_Body = extern struct {
+  _0: u8,
+  _1: u8,
+  _2: u8,
+};
+
+pub const P = extern struct {
+  tag: P_Tag,
+  body: extern union {
+    p0: u8,
+    p1: P1_Body,
+  },
+};
+
+pub const Q_Tag = enum(c_int) {
+  Ok,
+  Err,
+};
+
+pub const Q = extern struct {
+  tag: Q_Tag,
+  body: extern union {
+    ok: *u32,
+    err: u32,
+  },
+};
+
+pub const R_Tag = enum(c_int) {
+  IRFoo,
+  IRBar,
+  IRBaz,
+};
+
+pub const IRBar_Body = extern struct {
+  x: u8,
+  y: i16,
+};
+
+pub const R = extern struct {
+  tag: R_Tag,
+  body: extern union {
+    IRFoo: i16,
+    IRBar: IRBar_Body,
+  },
+};
+
+pub extern fn root(
+  opaque_: ?*Opaque,
+  a: A,
+  b: B,
+  c: C,
+  d: D,
+  e: E,
+  f: F,
+  g: G,
+  h: H,
+  i: ExI,
+  j: J,
+  k: K,
+  l: L,
+  m: M,
+  n: N,
+  o: O,
+  p: P,
+  q: Q,
+  r: R,
+) void;
+
+#if 0
+''' '
+#endif
+
+#include <stddef.h>
+#include "testing-helpers.h"
+static_assert(offsetof(CBINDGEN_STRUCT(P), tag) == 0, "unexpected offset for tag");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p0");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p1");
+static_assert(sizeof(CBINDGEN_STRUCT(P)) == 4, "unexpected size for P");
+
+#if 0
+' '''
+#endif
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public const sbyte FOURTY_FOUR = 4;
+
+  public enum E : sbyte {
+    A = 1,
+    B = -1,
+    C = (1 + 2),
+    D = FOURTY_FOUR,
+    F = 5,
+    G = unchecked((sbyte)54),
+    H = unchecked((sbyte)false),
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr arg0);
+}
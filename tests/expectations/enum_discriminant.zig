@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const FOURTY_FOUR: i8 = 4;
+
+pub const E = enum(i8) {
+  A = 1,
+  B = -1,
+  C = (1 + 2),
+  D = FOURTY_FOUR,
+  F = 5,
+  G = @as(i8, 54),
+  H = @as(i8, false),
+};
+
+pub extern fn root(_: *const E) void;
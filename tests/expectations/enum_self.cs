@@ -0,0 +1,39 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Bar {
+    public IntPtr something;
+  }
+
+  public enum Bar_Tag : byte {
+    Min,
+    Max,
+    Other,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Min_Body {
+    public Bar_Tag tag;
+    public Foo_Bar _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Max_Body {
+    public Bar_Tag tag;
+    public Foo_Bar _0;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Bar {
+    [FieldOffset(0)] public Bar_Tag tag;
+    [FieldOffset(0)] public Min_Body min;
+    [FieldOffset(0)] public Max_Body max;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Bar b);
+}
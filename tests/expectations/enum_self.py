@@ -0,0 +1,44 @@
+import ctypes
+import enum
+
+class Foo_Bar(ctypes.Structure):
+  pass
+
+class Bar(ctypes.Union):
+  pass
+
+Foo_Bar._fields_ = [
+  ("something", ctypes.POINTER(ctypes.c_int32)),
+]
+
+class Bar_Tag(enum.IntEnum):
+  Min = 0
+  Max = enum.auto()
+  Other = enum.auto()
+
+class _Bar_min(ctypes.Structure):
+  _fields_ = [
+    ("min_tag", ctypes.c_uint8),
+    ("min", Foo_Bar),
+  ]
+
+class _Bar_max(ctypes.Structure):
+  _fields_ = [
+    ("max_tag", ctypes.c_uint8),
+    ("max", Foo_Bar),
+  ]
+
+Bar._anonymous_ = ["_min", "_max"]
+Bar._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_min", _Bar_min),
+  ("_max", _Bar_max),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Bar]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,35 @@
+#include <stdint.h>
+
+#if 0
+''' '
+#endif
+
+typedef uint64_t Option_Foo;
+
+#if 0
+' '''
+#endif
+
+#if 0
+from libc.stdint cimport uint64_t
+ctypedef uint64_t Option_Foo
+#endif
+
+
+import ctypes
+import enum
+
+class Bar(ctypes.Structure):
+  pass
+
+Bar._fields_ = [
+  ("foo", Option_Foo),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Bar]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,15 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = []
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,18 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+Foo._fields_ = []
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.extra_debug_fn.argtypes = []
+  lib.extra_debug_fn.restype = None
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,18 @@
+import ctypes
+import enum
+
+class dep_struct(ctypes.Structure):
+  pass
+
+dep_struct._fields_ = [
+  ("x", ctypes.c_uint32),
+  ("y", ctypes.c_double),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.get_x.argtypes = [ctypes.POINTER(dep_struct)]
+  lib.get_x.restype = ctypes.c_uint32
+
+  return lib
@@ -0,0 +1,15 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "expand_dep_2";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct dep_struct {
+    public uint x;
+    public double y;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern uint get_x(IntPtr dep_struct);
+}
@@ -0,0 +1,14 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "expand_no_default_features";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo a);
+}
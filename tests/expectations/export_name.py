@@ -0,0 +1,10 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.do_the_thing_with_export_name.argtypes = []
+  lib.do_the_thing_with_export_name.restype = None
+
+  return lib
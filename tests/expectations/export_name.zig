@@ -0,0 +1,3 @@
+const std = @import("std");
+
+pub extern fn do_the_thing_with_export_name() void;
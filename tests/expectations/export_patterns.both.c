@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct mylib_Point {
+  float x;
+  float y;
+} mylib_Point;
+
+typedef struct mylib_Rect {
+  struct mylib_Point origin;
+  struct mylib_Point size;
+} mylib_Rect;
+
+typedef struct PalEntry {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+} PalEntry;
+
+typedef struct PalHeader {
+  uint32_t count;
+} PalHeader;
+
+float ffi_area(struct mylib_Rect rect);
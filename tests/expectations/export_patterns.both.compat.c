@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct mylib_Point {
+  float x;
+  float y;
+} mylib_Point;
+
+typedef struct mylib_Rect {
+  struct mylib_Point origin;
+  struct mylib_Point size;
+} mylib_Rect;
+
+typedef struct PalEntry {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+} PalEntry;
+
+typedef struct PalHeader {
+  uint32_t count;
+} PalHeader;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float ffi_area(struct mylib_Rect rect);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} mylib_Point;
+
+typedef struct {
+  mylib_Point origin;
+  mylib_Point size;
+} mylib_Rect;
+
+typedef struct {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+} PalEntry;
+
+typedef struct {
+  uint32_t count;
+} PalHeader;
+
+float ffi_area(mylib_Rect rect);
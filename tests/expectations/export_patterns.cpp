@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct mylib_Point {
+  float x;
+  float y;
+};
+
+struct mylib_Rect {
+  mylib_Point origin;
+  mylib_Point size;
+};
+
+struct PalEntry {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+};
+
+struct PalHeader {
+  uint32_t count;
+};
+
+extern "C" {
+
+float ffi_area(mylib_Rect rect);
+
+} // extern "C"
@@ -0,0 +1,33 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct mylib_Point {
+    public float x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct mylib_Rect {
+    public mylib_Point origin;
+    public mylib_Point size;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PalEntry {
+    public byte r;
+    public byte g;
+    public byte b;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PalHeader {
+    public uint count;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern float ffi_area(mylib_Rect rect);
+}
@@ -0,0 +1,42 @@
+import ctypes
+import enum
+
+class mylib_Point(ctypes.Structure):
+  pass
+
+class mylib_Rect(ctypes.Structure):
+  pass
+
+class PalEntry(ctypes.Structure):
+  pass
+
+class PalHeader(ctypes.Structure):
+  pass
+
+mylib_Point._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+mylib_Rect._fields_ = [
+  ("origin", mylib_Point),
+  ("size", mylib_Point),
+]
+
+PalEntry._fields_ = [
+  ("r", ctypes.c_uint8),
+  ("g", ctypes.c_uint8),
+  ("b", ctypes.c_uint8),
+]
+
+PalHeader._fields_ = [
+  ("count", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.ffi_area.argtypes = [mylib_Rect]
+  lib.ffi_area.restype = ctypes.c_float
+
+  return lib
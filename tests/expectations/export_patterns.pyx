@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct mylib_Point:
+    float x;
+    float y;
+
+  ctypedef struct mylib_Rect:
+    mylib_Point origin;
+    mylib_Point size;
+
+  ctypedef struct PalEntry:
+    uint8_t r;
+    uint8_t g;
+    uint8_t b;
+
+  ctypedef struct PalHeader:
+    uint32_t count;
+
+  float ffi_area(mylib_Rect rect);
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct mylib_Point {
+  float x;
+  float y;
+};
+
+struct mylib_Rect {
+  struct mylib_Point origin;
+  struct mylib_Point size;
+};
+
+struct PalEntry {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+};
+
+struct PalHeader {
+  uint32_t count;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float ffi_area(struct mylib_Rect rect);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
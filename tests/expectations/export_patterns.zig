@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const mylib_Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const mylib_Rect = extern struct {
+  origin: mylib_Point,
+  size: mylib_Point,
+};
+
+pub const PalEntry = extern struct {
+  r: u8,
+  g: u8,
+  b: u8,
+};
+
+pub const PalHeader = extern struct {
+  count: u32,
+};
+
+pub extern fn ffi_area(rect: mylib_Rect) f32;
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define SMALL 42
+
+#define HALF 0.5
+
+typedef struct {
+  __int128 signed_;
+  unsigned __int128 unsigned_;
+  _Float16 half;
+  __float128 quad;
+} Wide;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+unsigned __int128 widen(uint64_t a, uint64_t b);
+
+_Float16 narrow(__float128 x);
+
+void root(Wide w);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
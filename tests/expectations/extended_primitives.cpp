@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const unsigned __int128 SMALL = 42;
+
+constexpr static const _Float16 HALF = 0.5;
+
+struct Wide {
+  __int128 signed_;
+  unsigned __int128 unsigned_;
+  _Float16 half;
+  __float128 quad;
+};
+
+extern "C" {
+
+unsigned __int128 widen(uint64_t a, uint64_t b);
+
+_Float16 narrow(__float128 x);
+
+void root(Wide w);
+
+} // extern "C"
@@ -0,0 +1,13 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public static readonly UInt128 SMALL = 42;
+
+  public static readonly Half HALF = (Half)0.5;
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern UInt128 widen(ulong a, ulong b);
+}
@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+  ctypedef float f16 "_Float16"
+  ctypedef long double f128 "__float128"
+  ctypedef unsigned long long u128 "unsigned __int128"
+  ctypedef long long i128 "__int128"
+
+cdef extern from *:
+
+  const u128 SMALL # = 42
+
+  const f16 HALF # = 0.5
+
+  ctypedef struct Wide:
+    i128 signed_;
+    u128 unsigned_;
+    f16 half;
+    f128 quad;
+
+  u128 widen(uint64_t a, uint64_t b);
+
+  f16 narrow(f128 x);
+
+  void root(Wide w);
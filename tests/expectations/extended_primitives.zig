@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const SMALL: u128 = 42;
+
+pub const HALF: f16 = 0.5;
+
+pub const Wide = extern struct {
+  signed: i128,
+  unsigned: u128,
+  half: f16,
+  quad: f128,
+};
+
+pub extern fn widen(a: u64, b: u64) u128;
+
+pub extern fn narrow(x: f128) f16;
+
+pub extern fn root(w: Wide) void;
@@ -0,0 +1,27 @@
+typedef __int128 my_int128_t;
+typedef unsigned __int128 my_uint128_t;
+typedef _Float16 my_float16_t;
+typedef __float128 my_float128_t;
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Wide {
+  my_int128_t signed_;
+  my_uint128_t unsigned_;
+  my_float16_t half;
+  my_float128_t quad;
+} Wide;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Wide w);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
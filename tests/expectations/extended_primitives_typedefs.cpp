@@ -0,0 +1,24 @@
+typedef __int128 my_int128_t;
+typedef unsigned __int128 my_uint128_t;
+typedef _Float16 my_float16_t;
+typedef __float128 my_float128_t;
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Wide {
+  my_int128_t signed_;
+  my_uint128_t unsigned_;
+  my_float16_t half;
+  my_float128_t quad;
+};
+
+extern "C" {
+
+void root(Wide w);
+
+} // extern "C"
@@ -0,0 +1,25 @@
+typedef __int128 my_int128_t;
+typedef unsigned __int128 my_uint128_t;
+typedef _Float16 my_float16_t;
+typedef __float128 my_float128_t;
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+  ctypedef float f16 "my_float16_t"
+  ctypedef long double f128 "my_float128_t"
+  ctypedef unsigned long long u128 "my_uint128_t"
+  ctypedef long long i128 "my_int128_t"
+
+cdef extern from *:
+
+  cdef struct Wide:
+    i128 signed_;
+    u128 unsigned_;
+    f16 half;
+    f128 quad;
+
+  void root(Wide w);
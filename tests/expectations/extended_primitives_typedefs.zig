@@ -0,0 +1,16 @@
+typedef __int128 my_int128_t;
+typedef unsigned __int128 my_uint128_t;
+typedef _Float16 my_float16_t;
+typedef __float128 my_float128_t;
+
+
+const std = @import("std");
+
+pub const Wide = extern struct {
+  signed: i128,
+  unsigned: u128,
+  half: f16,
+  quad: f128,
+};
+
+pub extern fn root(w: Wide) void;
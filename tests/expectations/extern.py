@@ -0,0 +1,21 @@
+import ctypes
+import enum
+
+class Normal(ctypes.Structure):
+  pass
+
+Normal._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = []
+  lib.foo.restype = ctypes.c_int32
+
+  lib.bar.argtypes = [Normal]
+  lib.bar.restype = None
+
+  return lib
@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const Normal = extern struct {
+  x: i32,
+  y: f32,
+};
+
+pub extern fn foo() i32;
+
+pub extern fn bar(a: Normal) void;
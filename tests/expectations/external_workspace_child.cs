@@ -0,0 +1,14 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "child";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExtType {
+    public uint data;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void consume_ext(ExtType _ext);
+}
@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Message {
+  uint32_t len;
+  uint16_t kind;
+  uint8_t data[];
+} Message;
+
+typedef struct Matrix {
+  uintptr_t rows;
+  float data[][4];
+} Matrix;
+
+typedef struct Legacy {
+  uint32_t len;
+  uint8_t data[0];
+} Legacy;
+
+typedef struct Misplaced {
+  uint64_t marker[0];
+  uint32_t len;
+} Misplaced;
+
+typedef struct Envelope {
+  uint32_t id;
+  struct Message message;
+} Envelope;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(const struct Message *m,
+          const struct Matrix *x,
+          const struct Legacy *l,
+          struct Misplaced p,
+          const struct Envelope *e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
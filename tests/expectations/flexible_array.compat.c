@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t len;
+  uint16_t kind;
+  uint8_t data[];
+} Message;
+
+typedef struct {
+  uintptr_t rows;
+  float data[][4];
+} Matrix;
+
+typedef struct {
+  uint32_t len;
+  uint8_t data[0];
+} Legacy;
+
+typedef struct {
+  uint64_t marker[0];
+  uint32_t len;
+} Misplaced;
+
+typedef struct {
+  uint32_t id;
+  Message message;
+} Envelope;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(const Message *m, const Matrix *x, const Legacy *l, Misplaced p, const Envelope *e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,49 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Message {
+  uint32_t len;
+  uint16_t kind;
+  uint8_t data[0];
+
+  Message(uint32_t const& len,
+          uint16_t const& kind)
+    : len(len),
+      kind(kind)
+  {}
+
+};
+
+struct Matrix {
+  uintptr_t rows;
+  float data[0][4];
+
+  Matrix(uintptr_t const& rows)
+    : rows(rows)
+  {}
+
+};
+
+struct Legacy {
+  uint32_t len;
+  uint8_t data[0];
+};
+
+struct Misplaced {
+  uint64_t marker[0];
+  uint32_t len;
+};
+
+struct Envelope {
+  uint32_t id;
+  Message message;
+};
+
+extern "C" {
+
+void root(const Message *m, const Matrix *x, const Legacy *l, Misplaced p, const Envelope *e);
+
+} // extern "C"
@@ -0,0 +1,40 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Message {
+    public uint len;
+    public ushort kind;
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 0)] public byte[] data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Matrix {
+    public nuint rows;
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 0 * 4)] public float[] data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Legacy {
+    public uint len;
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 0)] public byte[] data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Misplaced {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 0)] public ulong[] marker;
+    public uint len;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Envelope {
+    public uint id;
+    public Message message;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr m, IntPtr x, IntPtr l, Misplaced p, IntPtr e);
+}
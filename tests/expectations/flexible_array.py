@@ -0,0 +1,57 @@
+import ctypes
+import enum
+
+class Message(ctypes.Structure):
+  pass
+
+class Matrix(ctypes.Structure):
+  pass
+
+class Legacy(ctypes.Structure):
+  pass
+
+class Misplaced(ctypes.Structure):
+  pass
+
+class Envelope(ctypes.Structure):
+  pass
+
+Message._fields_ = [
+  ("len", ctypes.c_uint32),
+  ("kind", ctypes.c_uint16),
+  ("data", ctypes.c_uint8 * 0),
+]
+
+Matrix._fields_ = [
+  ("rows", ctypes.c_size_t),
+  ("data", (ctypes.c_float * 4) * 0),
+]
+
+Legacy._fields_ = [
+  ("len", ctypes.c_uint32),
+  ("data", ctypes.c_uint8 * 0),
+]
+
+Misplaced._fields_ = [
+  ("marker", ctypes.c_uint64 * 0),
+  ("len", ctypes.c_uint32),
+]
+
+Envelope._fields_ = [
+  ("id", ctypes.c_uint32),
+  ("message", Message),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Message),
+    ctypes.POINTER(Matrix),
+    ctypes.POINTER(Legacy),
+    Misplaced,
+    ctypes.POINTER(Envelope),
+  ]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Message {
+  uint32_t len;
+  uint16_t kind;
+  uint8_t data[];
+};
+
+struct Matrix {
+  uintptr_t rows;
+  float data[][4];
+};
+
+struct Legacy {
+  uint32_t len;
+  uint8_t data[0];
+};
+
+struct Misplaced {
+  uint64_t marker[0];
+  uint32_t len;
+};
+
+struct Envelope {
+  uint32_t id;
+  struct Message message;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(const struct Message *m,
+          const struct Matrix *x,
+          const struct Legacy *l,
+          struct Misplaced p,
+          const struct Envelope *e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
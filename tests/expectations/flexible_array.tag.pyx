@@ -0,0 +1,30 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Message:
+    uint32_t len;
+    uint16_t kind;
+    uint8_t data[];
+
+  cdef struct Matrix:
+    uintptr_t rows;
+    float data[][4];
+
+  cdef struct Legacy:
+    uint32_t len;
+    uint8_t data[0];
+
+  cdef struct Misplaced:
+    uint64_t marker[0];
+    uint32_t len;
+
+  cdef struct Envelope:
+    uint32_t id;
+    Message message;
+
+  void root(const Message *m, const Matrix *x, const Legacy *l, Misplaced p, const Envelope *e);
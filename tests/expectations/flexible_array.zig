@@ -0,0 +1,35 @@
+const std = @import("std");
+
+pub const Message = extern struct {
+  len: u32,
+  kind: u16,
+  data: [0]u8,
+};
+
+pub const Matrix = extern struct {
+  rows: usize,
+  data: [0][4]f32,
+};
+
+pub const Legacy = extern struct {
+  len: u32,
+  data: [0]u8,
+};
+
+pub const Misplaced = extern struct {
+  marker: [0]u64,
+  len: u32,
+};
+
+pub const Envelope = extern struct {
+  id: u32,
+  message: Message,
+};
+
+pub extern fn root(
+  m: ?*const Message,
+  x: ?*const Matrix,
+  l: ?*const Legacy,
+  p: Misplaced,
+  e: ?*const Envelope,
+) void;
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Fns {
+    public IntPtr noArgs;
+    public IntPtr anonymousArg;
+    public IntPtr returnsNumber;
+    public IntPtr namedArgs;
+    public IntPtr namedArgsWildcards;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Fns _fns);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void no_return();
+}
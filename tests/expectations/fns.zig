@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const Fns = extern struct {
+  noArgs: *const fn () callconv(.C) void,
+  anonymousArg: *const fn (i32) callconv(.C) void,
+  returnsNumber: *const fn () callconv(.C) i32,
+  namedArgs: *const fn (i32, i16) callconv(.C) i8,
+  namedArgsWildcards: *const fn (i32, i16, i64) callconv(.C) i8,
+};
+
+pub extern fn root(_fns: Fns) void;
+
+pub extern fn no_return() noreturn;
@@ -0,0 +1,62 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StructInfo {
+    public IntPtr fields;
+    public nuint num_fields;
+  }
+
+  public enum TypeData_Tag {
+    Primitive,
+    Struct,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Struct_Body {
+    public StructInfo _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TypeData {
+    public TypeData_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public Struct_Body @struct;
+    }
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TypeInfo {
+    public TypeData data;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(TypeInfo x);
+}
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
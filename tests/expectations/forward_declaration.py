@@ -0,0 +1,64 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class StructInfo(ctypes.Structure):
+  pass
+
+class TypeData(ctypes.Structure):
+  pass
+
+class TypeInfo(ctypes.Structure):
+  pass
+
+StructInfo._fields_ = [
+  ("fields", ctypes.POINTER(ctypes.POINTER(TypeInfo))),
+  ("num_fields", ctypes.c_size_t),
+]
+
+class TypeData_Tag(enum.IntEnum):
+  Primitive = 0
+  Struct = enum.auto()
+
+class _TypeData_Body(ctypes.Union):
+  _fields_ = [
+    ("struct", StructInfo),
+  ]
+
+TypeData._anonymous_ = ["_body"]
+TypeData._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _TypeData_Body),
+]
+
+TypeInfo._fields_ = [
+  ("data", TypeData),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [TypeInfo]
+  lib.root.restype = None
+
+  return lib
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
@@ -0,0 +1,16 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.unnamed.argtypes = [ctypes.POINTER(ctypes.c_uint64)]
+  lib.unnamed.restype = None
+
+  lib.pointer_test.argtypes = [ctypes.POINTER(ctypes.c_uint64)]
+  lib.pointer_test.restype = None
+
+  lib.print_from_rust.argtypes = []
+  lib.print_from_rust.restype = None
+
+  return lib
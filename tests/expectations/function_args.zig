@@ -0,0 +1,7 @@
+const std = @import("std");
+
+pub extern fn unnamed(_: ?*const u64) void;
+
+pub extern fn pointer_test(a: ?*const u64) void;
+
+pub extern fn print_from_rust() void;
@@ -0,0 +1,28 @@
+import ctypes
+import enum
+#ifndef NO_RETURN_ATTR
+  #ifdef __GNUC__
+    #define NO_RETURN_ATTR __attribute__ ((noreturn))
+  #else // __GNUC__
+    #define NO_RETURN_ATTR
+  #endif // __GNUC__
+#endif // NO_RETURN_ATTR
+
+
+class Example(ctypes.Structure):
+  pass
+
+Example._fields_ = [
+  ("f", ctypes.CFUNCTYPE(None, ctypes.c_size_t, ctypes.c_size_t)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.loop_forever.argtypes = []
+  lib.loop_forever.restype = None
+
+  lib.normal_return.argtypes = [Example, ctypes.CFUNCTYPE(None, ctypes.c_uint8)]
+  lib.normal_return.restype = ctypes.c_uint8
+
+  return lib
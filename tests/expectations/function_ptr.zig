@@ -0,0 +1,7 @@
+const std = @import("std");
+
+pub const MyCallback = ?*const fn (usize, usize) callconv(.C) void;
+
+pub const MyOtherCallback = ?*const fn (usize, usize, usize, usize, usize) callconv(.C) void;
+
+pub extern fn my_function(a: MyCallback, b: MyOtherCallback) void;
@@ -0,0 +1,19 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.A.argtypes = []
+  lib.A.restype = None
+
+  lib.B.argtypes = []
+  lib.B.restype = None
+
+  lib.C.argtypes = []
+  lib.C.restype = None
+
+  lib.D.argtypes = []
+  lib.D.restype = None
+
+  return lib
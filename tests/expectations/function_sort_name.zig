@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub extern fn A() void;
+
+pub extern fn B() void;
+
+pub extern fn C() void;
+
+pub extern fn D() void;
@@ -0,0 +1,19 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.C.argtypes = []
+  lib.C.restype = None
+
+  lib.B.argtypes = []
+  lib.B.restype = None
+
+  lib.D.argtypes = []
+  lib.D.restype = None
+
+  lib.A.argtypes = []
+  lib.A.restype = None
+
+  return lib
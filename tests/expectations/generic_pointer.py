@@ -0,0 +1,19 @@
+import ctypes
+import enum
+
+class Foo_____u8(ctypes.Structure):
+  pass
+
+Foo_____u8._fields_ = [
+  ("a", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Boo = Foo_____u8
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Boo]
+  lib.root.restype = None
+
+  return lib
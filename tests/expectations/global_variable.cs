@@ -0,0 +1,12 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof(NativeMethods).Assembly, null), name);
+
+  public static IntPtr MUT_GLOBAL_ARRAY => __GetExport("MUT_GLOBAL_ARRAY");
+
+  public static IntPtr CONST_GLOBAL_ARRAY => __GetExport("CONST_GLOBAL_ARRAY");
+}
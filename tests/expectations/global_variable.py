@@ -0,0 +1,11 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.MUT_GLOBAL_ARRAY = (ctypes.c_char * 128).in_dll(lib, "MUT_GLOBAL_ARRAY")
+
+  lib.CONST_GLOBAL_ARRAY = (ctypes.c_char * 128).in_dll(lib, "CONST_GLOBAL_ARRAY")
+
+  return lib
@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub extern var MUT_GLOBAL_ARRAY: [128]c_char;
+
+pub extern const CONST_GLOBAL_ARRAY: [128]c_char;
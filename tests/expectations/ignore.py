@@ -0,0 +1,10 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.no_ignore_root.argtypes = []
+  lib.no_ignore_root.restype = None
+
+  return lib
@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B {
+    public A data;
+  }
+}
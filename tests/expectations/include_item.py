@@ -0,0 +1,17 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  pass
+
+class B(ctypes.Structure):
+  pass
+
+A._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+B._fields_ = [
+  ("data", A),
+]
@@ -0,0 +1,3 @@
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+}
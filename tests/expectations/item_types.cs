@@ -0,0 +1,11 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum OnlyThisShouldBeGenerated : byte {
+    Foo,
+    Bar,
+  }
+}
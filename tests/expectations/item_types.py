@@ -0,0 +1,6 @@
+import ctypes
+import enum
+
+class OnlyThisShouldBeGenerated(enum.IntEnum):
+  Foo = 0
+  Bar = enum.auto()
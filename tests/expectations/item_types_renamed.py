@@ -0,0 +1,6 @@
+import ctypes
+import enum
+
+class StyleOnlyThisShouldBeGenerated(enum.IntEnum):
+  Foo = 0
+  Bar = enum.auto()
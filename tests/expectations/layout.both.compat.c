@@ -17,10 +17,6 @@ typedef struct RustPackedUnion RustPackedUnion;
 
 typedef struct UnsupportedAlign4Enum UnsupportedAlign4Enum;
 
-typedef struct UnsupportedPacked4Struct UnsupportedPacked4Struct;
-
-typedef struct UnsupportedPacked4Union UnsupportedPacked4Union;
-
 typedef struct CBINDGEN_ALIGNED(1) Align1Struct {
   uintptr_t arg1;
   uint8_t *arg2;
@@ -70,3 +66,17 @@ typedef union CBINDGEN_PACKED PackedUnion {
   uintptr_t variant1;
   uint8_t *variant2;
 } PackedUnion;
+
+#pragma pack(push, 4)
+typedef struct Packed4Struct {
+  uintptr_t arg1;
+  uint8_t *arg2;
+} Packed4Struct;
+#pragma pack(pop)
+
+#pragma pack(push, 4)
+typedef union Packed4Union {
+  uintptr_t variant1;
+  uint8_t *variant2;
+} Packed4Union;
+#pragma pack(pop)
@@ -18,10 +18,6 @@ struct RustPackedUnion;
 
 struct UnsupportedAlign4Enum;
 
-struct UnsupportedPacked4Struct;
-
-struct UnsupportedPacked4Union;
-
 struct CBINDGEN_ALIGNED(1) Align1Struct {
   uintptr_t arg1;
   uint8_t *arg2;
@@ -71,3 +67,17 @@ union CBINDGEN_PACKED PackedUnion {
   uintptr_t variant1;
   uint8_t *variant2;
 };
+
+#pragma pack(push, 4)
+struct Packed4Struct {
+  uintptr_t arg1;
+  uint8_t *arg2;
+};
+#pragma pack(pop)
+
+#pragma pack(push, 4)
+union Packed4Union {
+  uintptr_t variant1;
+  uint8_t *variant2;
+};
+#pragma pack(pop)
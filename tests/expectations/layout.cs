@@ -0,0 +1,92 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct RustAlign4Struct { }
+
+  public struct RustAlign4Union { }
+
+  public struct RustPackedStruct { }
+
+  public struct RustPackedUnion { }
+
+  public struct UnsupportedAlign4Enum { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align1Struct {
+    public nuint arg1;
+    public IntPtr arg2;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align2Struct {
+    public nuint arg1;
+    public IntPtr arg2;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align4Struct {
+    public nuint arg1;
+    public IntPtr arg2;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align8Struct {
+    public nuint arg1;
+    public IntPtr arg2;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Align32Struct {
+    public nuint arg1;
+    public IntPtr arg2;
+  }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 1)]
+  public struct PackedStruct {
+    public nuint arg1;
+    public IntPtr arg2;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Align1Union {
+    [FieldOffset(0)] public nuint variant1;
+    [FieldOffset(0)] public IntPtr variant2;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Align4Union {
+    [FieldOffset(0)] public nuint variant1;
+    [FieldOffset(0)] public IntPtr variant2;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Align16Union {
+    [FieldOffset(0)] public nuint variant1;
+    [FieldOffset(0)] public IntPtr variant2;
+  }
+
+  [StructLayout(LayoutKind.Explicit, Pack = 1)]
+  public struct PackedUnion {
+    [FieldOffset(0)] public nuint variant1;
+    [FieldOffset(0)] public IntPtr variant2;
+  }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 4)]
+  public struct Packed4Struct {
+    public nuint arg1;
+    public IntPtr arg2;
+  }
+
+  [StructLayout(LayoutKind.Explicit, Pack = 4)]
+  public struct Packed4Union {
+    [FieldOffset(0)] public nuint variant1;
+    [FieldOffset(0)] public IntPtr variant2;
+  }
+}
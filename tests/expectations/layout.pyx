@@ -25,12 +25,6 @@ cdef extern from *:
   ctypedef struct UnsupportedAlign4Enum:
     pass
 
-  ctypedef struct UnsupportedPacked4Struct:
-    pass
-
-  ctypedef struct UnsupportedPacked4Union:
-    pass
-
   ctypedef struct Align1Struct:
     uintptr_t arg1;
     uint8_t *arg2;
@@ -70,3 +64,11 @@ cdef extern from *:
   ctypedef union PackedUnion:
     uintptr_t variant1;
     uint8_t *variant2;
+
+  ctypedef struct Packed4Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
+
+  ctypedef union Packed4Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
@@ -25,12 +25,6 @@ cdef extern from *:
   cdef struct UnsupportedAlign4Enum:
     pass
 
-  cdef struct UnsupportedPacked4Struct:
-    pass
-
-  cdef struct UnsupportedPacked4Union:
-    pass
-
   cdef struct Align1Struct:
     uintptr_t arg1;
     uint8_t *arg2;
@@ -70,3 +64,11 @@ cdef extern from *:
   cdef union PackedUnion:
     uintptr_t variant1;
     uint8_t *variant2;
+
+  cdef struct Packed4Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
+
+  cdef union Packed4Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
@@ -0,0 +1,48 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+import ctypes
+import enum
+
+class PackedStruct(ctypes.Structure):
+  pass
+
+class PackedUnion(ctypes.Union):
+  pass
+
+class OpaqueAlign16Union(ctypes.Structure):
+  pass
+
+class OpaqueAlign1Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign1Union(ctypes.Structure):
+  pass
+
+class OpaqueAlign2Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign32Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign4Struct(ctypes.Structure):
+  pass
+
+class OpaqueAlign4Union(ctypes.Structure):
+  pass
+
+class OpaqueAlign8Struct(ctypes.Structure):
+  pass
+
+PackedStruct._pack_ = 1
+PackedStruct._fields_ = [
+  ("arg1", ctypes.c_size_t),
+  ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+PackedUnion._pack_ = 1
+PackedUnion._fields_ = [
+  ("variant1", ctypes.c_size_t),
+  ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+]
@@ -0,0 +1,77 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#pragma pack(push, 2)
+typedef struct {
+  uint16_t tag;
+  uint32_t length;
+  uint16_t checksum;
+} Packed2;
+#pragma pack(pop)
+static_assert(sizeof(Packed2) == 8, "unexpected size of Packed2");
+static_assert(alignof(Packed2) == 2, "unexpected alignment of Packed2");
+static_assert(offsetof(Packed2, tag) == 0, "unexpected offset of Packed2::tag");
+static_assert(offsetof(Packed2, length) == 2, "unexpected offset of Packed2::length");
+static_assert(offsetof(Packed2, checksum) == 6, "unexpected offset of Packed2::checksum");
+
+#pragma pack(push, 4)
+typedef struct {
+  Packed2 header;
+  uint64_t sequence;
+  uint32_t flags;
+} Packed4;
+#pragma pack(pop)
+static_assert(sizeof(Packed4) == 20, "unexpected size of Packed4");
+static_assert(alignof(Packed4) == 4, "unexpected alignment of Packed4");
+static_assert(offsetof(Packed4, header) == 0, "unexpected offset of Packed4::header");
+static_assert(offsetof(Packed4, sequence) == 8, "unexpected offset of Packed4::sequence");
+static_assert(offsetof(Packed4, flags) == 16, "unexpected offset of Packed4::flags");
+
+#pragma pack(push, 4)
+typedef union {
+  uint32_t small;
+  uint64_t large;
+} Packed4Union;
+#pragma pack(pop)
+static_assert(sizeof(Packed4Union) == 8, "unexpected size of Packed4Union");
+static_assert(alignof(Packed4Union) == 4, "unexpected alignment of Packed4Union");
+static_assert(offsetof(Packed4Union, small) == 0, "unexpected offset of Packed4Union::small");
+static_assert(offsetof(Packed4Union, large) == 0, "unexpected offset of Packed4Union::large");
+
+typedef struct CBINDGEN_PACKED {
+  uint8_t a;
+  uint32_t b;
+} Packed1;
+static_assert(sizeof(Packed1) == 5, "unexpected size of Packed1");
+static_assert(alignof(Packed1) == 1, "unexpected alignment of Packed1");
+static_assert(offsetof(Packed1, a) == 0, "unexpected offset of Packed1::a");
+static_assert(offsetof(Packed1, b) == 1, "unexpected offset of Packed1::b");
+
+#pragma pack(push, 2)
+typedef struct {
+  uint8_t a;
+  uint32_t b;
+} P2;
+#pragma pack(pop)
+static_assert(sizeof(P2) == 6, "unexpected size of P2");
+static_assert(alignof(P2) == 2, "unexpected alignment of P2");
+static_assert(offsetof(P2, a) == 0, "unexpected offset of P2::a");
+static_assert(offsetof(P2, b) == 2, "unexpected offset of P2::b");
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Packed4 a, Packed4Union b, Packed1 c, P2 d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,44 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential, Pack = 2)]
+  public struct Packed2 {
+    public ushort tag;
+    public uint length;
+    public ushort checksum;
+  }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 4)]
+  public struct Packed4 {
+    public Packed2 header;
+    public ulong sequence;
+    public uint flags;
+  }
+
+  [StructLayout(LayoutKind.Explicit, Pack = 4)]
+  public struct Packed4Union {
+    [FieldOffset(0)] public uint small;
+    [FieldOffset(0)] public ulong large;
+  }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 1)]
+  public struct Packed1 {
+    public byte a;
+    public uint b;
+  }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 2)]
+  public struct P2 {
+    public byte a;
+    public uint b;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Packed4 a, Packed4Union b, Packed1 c, P2 d);
+}
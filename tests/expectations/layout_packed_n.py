@@ -0,0 +1,60 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+
+
+import ctypes
+import enum
+
+class Packed2(ctypes.Structure):
+  pass
+
+class Packed4(ctypes.Structure):
+  pass
+
+class Packed4Union(ctypes.Union):
+  pass
+
+class Packed1(ctypes.Structure):
+  pass
+
+class P2(ctypes.Structure):
+  pass
+
+Packed2._pack_ = 2
+Packed2._fields_ = [
+  ("tag", ctypes.c_uint16),
+  ("length", ctypes.c_uint32),
+  ("checksum", ctypes.c_uint16),
+]
+
+Packed4._pack_ = 4
+Packed4._fields_ = [
+  ("header", Packed2),
+  ("sequence", ctypes.c_uint64),
+  ("flags", ctypes.c_uint32),
+]
+
+Packed4Union._pack_ = 4
+Packed4Union._fields_ = [
+  ("small", ctypes.c_uint32),
+  ("large", ctypes.c_uint64),
+]
+
+Packed1._pack_ = 1
+Packed1._fields_ = [
+  ("a", ctypes.c_uint8),
+  ("b", ctypes.c_uint32),
+]
+
+P2._pack_ = 2
+P2._fields_ = [
+  ("a", ctypes.c_uint8),
+  ("b", ctypes.c_uint32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Packed4, Packed4Union, Packed1, P2]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,77 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#pragma pack(push, 2)
+struct Packed2 {
+  uint16_t tag;
+  uint32_t length;
+  uint16_t checksum;
+};
+#pragma pack(pop)
+static_assert(sizeof(struct Packed2) == 8, "unexpected size of Packed2");
+static_assert(alignof(struct Packed2) == 2, "unexpected alignment of Packed2");
+static_assert(offsetof(struct Packed2, tag) == 0, "unexpected offset of Packed2::tag");
+static_assert(offsetof(struct Packed2, length) == 2, "unexpected offset of Packed2::length");
+static_assert(offsetof(struct Packed2, checksum) == 6, "unexpected offset of Packed2::checksum");
+
+#pragma pack(push, 4)
+struct Packed4 {
+  struct Packed2 header;
+  uint64_t sequence;
+  uint32_t flags;
+};
+#pragma pack(pop)
+static_assert(sizeof(struct Packed4) == 20, "unexpected size of Packed4");
+static_assert(alignof(struct Packed4) == 4, "unexpected alignment of Packed4");
+static_assert(offsetof(struct Packed4, header) == 0, "unexpected offset of Packed4::header");
+static_assert(offsetof(struct Packed4, sequence) == 8, "unexpected offset of Packed4::sequence");
+static_assert(offsetof(struct Packed4, flags) == 16, "unexpected offset of Packed4::flags");
+
+#pragma pack(push, 4)
+union Packed4Union {
+  uint32_t small;
+  uint64_t large;
+};
+#pragma pack(pop)
+static_assert(sizeof(union Packed4Union) == 8, "unexpected size of Packed4Union");
+static_assert(alignof(union Packed4Union) == 4, "unexpected alignment of Packed4Union");
+static_assert(offsetof(union Packed4Union, small) == 0, "unexpected offset of Packed4Union::small");
+static_assert(offsetof(union Packed4Union, large) == 0, "unexpected offset of Packed4Union::large");
+
+struct CBINDGEN_PACKED Packed1 {
+  uint8_t a;
+  uint32_t b;
+};
+static_assert(sizeof(struct Packed1) == 5, "unexpected size of Packed1");
+static_assert(alignof(struct Packed1) == 1, "unexpected alignment of Packed1");
+static_assert(offsetof(struct Packed1, a) == 0, "unexpected offset of Packed1::a");
+static_assert(offsetof(struct Packed1, b) == 1, "unexpected offset of Packed1::b");
+
+#pragma pack(push, 2)
+struct P2 {
+  uint8_t a;
+  uint32_t b;
+};
+#pragma pack(pop)
+static_assert(sizeof(struct P2) == 6, "unexpected size of P2");
+static_assert(alignof(struct P2) == 2, "unexpected alignment of P2");
+static_assert(offsetof(struct P2, a) == 0, "unexpected offset of P2::a");
+static_assert(offsetof(struct P2, b) == 2, "unexpected offset of P2::b");
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Packed4 a, union Packed4Union b, struct Packed1 c, struct P2 d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
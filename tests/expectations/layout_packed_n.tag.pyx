@@ -0,0 +1,34 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Packed2:
+    uint16_t tag;
+    uint32_t length;
+    uint16_t checksum;
+
+  cdef struct Packed4:
+    Packed2 header;
+    uint64_t sequence;
+    uint32_t flags;
+
+  cdef union Packed4Union:
+    uint32_t small;
+    uint64_t large;
+
+  cdef packed struct Packed1:
+    uint8_t a;
+    uint32_t b;
+
+  cdef struct P2:
+    uint8_t a;
+    uint32_t b;
+
+  void root(Packed4 a, Packed4Union b, Packed1 c, P2 d);
@@ -0,0 +1,33 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+
+
+const std = @import("std");
+
+pub const Packed2 = extern struct {
+  tag: u16 align(@min(2, @alignOf(u16))),
+  length: u32 align(@min(2, @alignOf(u32))),
+  checksum: u16 align(@min(2, @alignOf(u16))),
+};
+
+pub const Packed4 = extern struct {
+  header: Packed2 align(@min(4, @alignOf(Packed2))),
+  sequence: u64 align(@min(4, @alignOf(u64))),
+  flags: u32 align(@min(4, @alignOf(u32))),
+};
+
+pub const Packed4Union = extern union {
+  small: u32 align(@min(4, @alignOf(u32))),
+  large: u64 align(@min(4, @alignOf(u64))),
+};
+
+pub const Packed1 = extern struct {
+  a: u8 align(1),
+  b: u32 align(1),
+};
+
+pub const P2 = extern struct {
+  a: u8 align(@min(2, @alignOf(u8))),
+  b: u32 align(@min(2, @alignOf(u32))),
+};
+
+pub extern fn root(a: Packed4, b: Packed4Union, c: Packed1, d: P2) void;
@@ -0,0 +1,40 @@
+#define CBINDGEN_PACKED_N(n)   __attribute__ ((packed, aligned(n)))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct CBINDGEN_PACKED_N(2) {
+  uint16_t tag;
+  uint32_t length;
+  uint16_t checksum;
+} Packed2;
+static_assert(sizeof(Packed2) == 8, "unexpected size of Packed2");
+static_assert(alignof(Packed2) == 2, "unexpected alignment of Packed2");
+static_assert(offsetof(Packed2, tag) == 0, "unexpected offset of Packed2::tag");
+static_assert(offsetof(Packed2, length) == 2, "unexpected offset of Packed2::length");
+static_assert(offsetof(Packed2, checksum) == 6, "unexpected offset of Packed2::checksum");
+
+typedef union CBINDGEN_PACKED_N(4) {
+  uint32_t small;
+  uint64_t large;
+} Packed4Union;
+static_assert(sizeof(Packed4Union) == 8, "unexpected size of Packed4Union");
+static_assert(alignof(Packed4Union) == 4, "unexpected alignment of Packed4Union");
+static_assert(offsetof(Packed4Union, small) == 0, "unexpected offset of Packed4Union::small");
+static_assert(offsetof(Packed4Union, large) == 0, "unexpected offset of Packed4Union::large");
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Packed2 a, Packed4Union b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,25 @@
+#define CBINDGEN_PACKED_N(n)   __attribute__ ((packed, aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential, Pack = 2)]
+  public struct Packed2 {
+    public ushort tag;
+    public uint length;
+    public ushort checksum;
+  }
+
+  [StructLayout(LayoutKind.Explicit, Pack = 4)]
+  public struct Packed4Union {
+    [FieldOffset(0)] public uint small;
+    [FieldOffset(0)] public ulong large;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Packed2 a, Packed4Union b);
+}
@@ -0,0 +1,32 @@
+#define CBINDGEN_PACKED_N(n)   __attribute__ ((packed, aligned(n)))
+
+
+import ctypes
+import enum
+
+class Packed2(ctypes.Structure):
+  pass
+
+class Packed4Union(ctypes.Union):
+  pass
+
+Packed2._pack_ = 2
+Packed2._fields_ = [
+  ("tag", ctypes.c_uint16),
+  ("length", ctypes.c_uint32),
+  ("checksum", ctypes.c_uint16),
+]
+
+Packed4Union._pack_ = 4
+Packed4Union._fields_ = [
+  ("small", ctypes.c_uint32),
+  ("large", ctypes.c_uint64),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Packed2, Packed4Union]
+  lib.root.restype = None
+
+  return lib
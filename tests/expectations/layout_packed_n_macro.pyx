@@ -0,0 +1,21 @@
+#define CBINDGEN_PACKED_N(n)   __attribute__ ((packed, aligned(n)))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Packed2:
+    uint16_t tag;
+    uint32_t length;
+    uint16_t checksum;
+
+  ctypedef union Packed4Union:
+    uint32_t small;
+    uint64_t large;
+
+  void root(Packed2 a, Packed4Union b);
@@ -0,0 +1,32 @@
+#define CBINDGEN_PACKED_N(n)   __attribute__ ((packed, aligned(n)))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct CBINDGEN_PACKED_N(2) Packed2 {
+  uint16_t tag;
+  uint32_t length;
+  uint16_t checksum;
+};
+static_assert(sizeof(struct Packed2) == 8, "unexpected size of Packed2");
+static_assert(alignof(struct Packed2) == 2, "unexpected alignment of Packed2");
+static_assert(offsetof(struct Packed2, tag) == 0, "unexpected offset of Packed2::tag");
+static_assert(offsetof(struct Packed2, length) == 2, "unexpected offset of Packed2::length");
+static_assert(offsetof(struct Packed2, checksum) == 6, "unexpected offset of Packed2::checksum");
+
+union CBINDGEN_PACKED_N(4) Packed4Union {
+  uint32_t small;
+  uint64_t large;
+};
+static_assert(sizeof(union Packed4Union) == 8, "unexpected size of Packed4Union");
+static_assert(alignof(union Packed4Union) == 4, "unexpected alignment of Packed4Union");
+static_assert(offsetof(union Packed4Union, small) == 0, "unexpected offset of Packed4Union::small");
+static_assert(offsetof(union Packed4Union, large) == 0, "unexpected offset of Packed4Union::large");
+
+void root(struct Packed2 a, union Packed4Union b);
@@ -0,0 +1,17 @@
+#define CBINDGEN_PACKED_N(n)   __attribute__ ((packed, aligned(n)))
+
+
+const std = @import("std");
+
+pub const Packed2 = extern struct {
+  tag: u16 align(@min(2, @alignOf(u16))),
+  length: u32 align(@min(2, @alignOf(u32))),
+  checksum: u16 align(@min(2, @alignOf(u16))),
+};
+
+pub const Packed4Union = extern union {
+  small: u32 align(@min(4, @alignOf(u32))),
+  large: u64 align(@min(4, @alignOf(u64))),
+};
+
+pub extern fn root(a: Packed2, b: Packed4Union) void;
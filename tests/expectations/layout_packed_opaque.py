@@ -0,0 +1,84 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+import ctypes
+import enum
+
+class Align1Union(ctypes.Union):
+  pass
+
+class Align4Union(ctypes.Union):
+  pass
+
+class Align16Union(ctypes.Union):
+  pass
+
+class Align1Struct(ctypes.Structure):
+  pass
+
+class Align2Struct(ctypes.Structure):
+  pass
+
+class Align4Struct(ctypes.Structure):
+  pass
+
+class Align8Struct(ctypes.Structure):
+  pass
+
+class Align32Struct(ctypes.Structure):
+  pass
+
+class OpaquePackedStruct(ctypes.Structure):
+  pass
+
+class OpaquePackedUnion(ctypes.Structure):
+  pass
+
+Align1Union._align_ = 1
+Align1Union._fields_ = [
+  ("variant1", ctypes.c_size_t),
+  ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Align4Union._align_ = 4
+Align4Union._fields_ = [
+  ("variant1", ctypes.c_size_t),
+  ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Align16Union._align_ = 16
+Align16Union._fields_ = [
+  ("variant1", ctypes.c_size_t),
+  ("variant2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Align1Struct._align_ = 1
+Align1Struct._fields_ = [
+  ("arg1", ctypes.c_size_t),
+  ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Align2Struct._align_ = 2
+Align2Struct._fields_ = [
+  ("arg1", ctypes.c_size_t),
+  ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Align4Struct._align_ = 4
+Align4Struct._fields_ = [
+  ("arg1", ctypes.c_size_t),
+  ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Align8Struct._align_ = 8
+Align8Struct._fields_ = [
+  ("arg1", ctypes.c_size_t),
+  ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+]
+
+Align32Struct._align_ = 32
+Align32Struct._fields_ = [
+  ("arg1", ctypes.c_size_t),
+  ("arg2", ctypes.POINTER(ctypes.c_uint8)),
+]
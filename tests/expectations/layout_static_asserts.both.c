@@ -0,0 +1,161 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum CTag {
+  CTagA,
+  CTagB,
+} CTag;
+static_assert(sizeof(CTag) == 4, "unexpected size of CTag");
+static_assert(alignof(CTag) == 4, "unexpected alignment of CTag");
+
+enum Plain {
+  PlainA,
+  PlainB,
+};
+typedef uint8_t Plain;
+static_assert(sizeof(Plain) == 1, "unexpected size of Plain");
+static_assert(alignof(Plain) == 1, "unexpected alignment of Plain");
+
+typedef struct Mixed {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+  const uint8_t *d;
+  uint16_t e[3];
+  int32_t (*f)(int32_t);
+} Mixed;
+static_assert(sizeof(Mixed) == 40, "unexpected size of Mixed");
+static_assert(alignof(Mixed) == 8, "unexpected alignment of Mixed");
+static_assert(offsetof(Mixed, a) == 0, "unexpected offset of Mixed::a");
+static_assert(offsetof(Mixed, b) == 4, "unexpected offset of Mixed::b");
+static_assert(offsetof(Mixed, c) == 8, "unexpected offset of Mixed::c");
+static_assert(offsetof(Mixed, d) == 16, "unexpected offset of Mixed::d");
+static_assert(offsetof(Mixed, e) == 24, "unexpected offset of Mixed::e");
+static_assert(offsetof(Mixed, f) == 32, "unexpected offset of Mixed::f");
+
+typedef struct CBINDGEN_PACKED Packed {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+} Packed;
+static_assert(sizeof(Packed) == 7, "unexpected size of Packed");
+static_assert(alignof(Packed) == 1, "unexpected alignment of Packed");
+static_assert(offsetof(Packed, a) == 0, "unexpected offset of Packed::a");
+static_assert(offsetof(Packed, b) == 1, "unexpected offset of Packed::b");
+static_assert(offsetof(Packed, c) == 5, "unexpected offset of Packed::c");
+
+typedef struct CBINDGEN_ALIGNED(16) Aligned {
+  uint8_t a;
+  struct Packed b;
+} Aligned;
+static_assert(sizeof(Aligned) == 16, "unexpected size of Aligned");
+static_assert(alignof(Aligned) == 16, "unexpected alignment of Aligned");
+static_assert(offsetof(Aligned, a) == 0, "unexpected offset of Aligned::a");
+static_assert(offsetof(Aligned, b) == 1, "unexpected offset of Aligned::b");
+
+typedef struct Mixed Alias;
+
+typedef union Either {
+  uint8_t a;
+  uint64_t b;
+  uint8_t c[12];
+} Either;
+static_assert(sizeof(Either) == 16, "unexpected size of Either");
+static_assert(alignof(Either) == 8, "unexpected alignment of Either");
+static_assert(offsetof(Either, a) == 0, "unexpected offset of Either::a");
+static_assert(offsetof(Either, b) == 0, "unexpected offset of Either::b");
+static_assert(offsetof(Either, c) == 0, "unexpected offset of Either::c");
+
+typedef struct Nested {
+  Alias a;
+  union Either b;
+  bool c;
+} Nested;
+static_assert(sizeof(Nested) == 64, "unexpected size of Nested");
+static_assert(alignof(Nested) == 8, "unexpected alignment of Nested");
+static_assert(offsetof(Nested, a) == 0, "unexpected offset of Nested::a");
+static_assert(offsetof(Nested, b) == 40, "unexpected offset of Nested::b");
+static_assert(offsetof(Nested, c) == 56, "unexpected offset of Nested::c");
+
+enum Tagged_Tag {
+  TaggedA,
+  TaggedB,
+  TaggedC,
+};
+typedef uint8_t Tagged_Tag;
+
+typedef struct TaggedB_Body {
+  uint8_t x;
+  uint16_t y;
+} TaggedB_Body;
+static_assert(sizeof(TaggedB_Body) == 4, "unexpected size of TaggedB_Body");
+static_assert(alignof(TaggedB_Body) == 2, "unexpected alignment of TaggedB_Body");
+static_assert(offsetof(TaggedB_Body, x) == 0, "unexpected offset of TaggedB_Body::x");
+static_assert(offsetof(TaggedB_Body, y) == 2, "unexpected offset of TaggedB_Body::y");
+
+typedef struct Tagged {
+  Tagged_Tag tag;
+  union {
+    struct {
+      uint64_t tagged_a;
+    };
+    TaggedB_Body tagged_b;
+  };
+} Tagged;
+static_assert(sizeof(Tagged) == 16, "unexpected size of Tagged");
+static_assert(alignof(Tagged) == 8, "unexpected alignment of Tagged");
+
+enum Inline_Tag {
+  InlineA,
+  InlineB,
+  InlineC,
+};
+typedef uint8_t Inline_Tag;
+
+typedef struct InlineB_Body {
+  Inline_Tag tag;
+  uint8_t x;
+  uint16_t y;
+} InlineB_Body;
+static_assert(sizeof(InlineB_Body) == 4, "unexpected size of InlineB_Body");
+static_assert(alignof(InlineB_Body) == 2, "unexpected alignment of InlineB_Body");
+static_assert(offsetof(InlineB_Body, tag) == 0, "unexpected offset of InlineB_Body::tag");
+static_assert(offsetof(InlineB_Body, x) == 1, "unexpected offset of InlineB_Body::x");
+static_assert(offsetof(InlineB_Body, y) == 2, "unexpected offset of InlineB_Body::y");
+
+typedef union Inline {
+  Inline_Tag tag;
+  struct {
+    Inline_Tag inline_a_tag;
+    uint64_t inline_a;
+  };
+  InlineB_Body inline_b;
+} Inline;
+static_assert(sizeof(Inline) == 16, "unexpected size of Inline");
+static_assert(alignof(Inline) == 8, "unexpected alignment of Inline");
+
+typedef struct Conditional {
+  uint8_t a;
+#if defined(DEFINE_EXTRA)
+  uint32_t b
+#endif
+  ;
+} Conditional;
+
+void root(struct Mixed a,
+          struct Aligned b,
+          struct Nested c,
+          Plain d,
+          enum CTag e,
+          struct Tagged f,
+          union Inline g,
+          struct Conditional i);
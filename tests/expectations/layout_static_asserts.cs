@@ -0,0 +1,130 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum CTag {
+    CTagA,
+    CTagB,
+  }
+
+  public enum Plain : byte {
+    PlainA,
+    PlainB,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Mixed {
+    public byte a;
+    public uint b;
+    public ushort c;
+    public IntPtr d;
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 3)] public ushort[] e;
+    public IntPtr f;
+  }
+
+  [StructLayout(LayoutKind.Sequential, Pack = 1)]
+  public struct Packed {
+    public byte a;
+    public uint b;
+    public ushort c;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Aligned {
+    public byte a;
+    public Packed b;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Either {
+    [FieldOffset(0)] public byte a;
+    [FieldOffset(0)] public ulong b;
+    [FieldOffset(0)] [MarshalAs(UnmanagedType.ByValArray, SizeConst = 12)] public byte[] c;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Nested {
+    public Mixed a;
+    public Either b;
+    [MarshalAs(UnmanagedType.U1)] public bool c;
+  }
+
+  public enum Tagged_Tag : byte {
+    TaggedA,
+    TaggedB,
+    TaggedC,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TaggedA_Body {
+    public ulong _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TaggedB_Body {
+    public byte x;
+    public ushort y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Tagged {
+    public Tagged_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public TaggedA_Body tagged_a;
+      [FieldOffset(0)] public TaggedB_Body tagged_b;
+    }
+  }
+
+  public enum Inline_Tag : byte {
+    InlineA,
+    InlineB,
+    InlineC,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct InlineA_Body {
+    public Inline_Tag tag;
+    public ulong _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct InlineB_Body {
+    public Inline_Tag tag;
+    public byte x;
+    public ushort y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Inline {
+    [FieldOffset(0)] public Inline_Tag tag;
+    [FieldOffset(0)] public InlineA_Body inline_a;
+    [FieldOffset(0)] public InlineB_Body inline_b;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Conditional {
+    public byte a;
+#if DEFINE_EXTRA
+    public uint b;
+#endif
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Mixed a,
+                                 Aligned b,
+                                 Nested c,
+                                 Plain d,
+                                 CTag e,
+                                 Tagged f,
+                                 Inline g,
+                                 Conditional i);
+}
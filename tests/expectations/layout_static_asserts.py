@@ -0,0 +1,119 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+import ctypes
+import enum
+
+class Mixed(ctypes.Structure):
+  pass
+
+class Packed(ctypes.Structure):
+  pass
+
+class Aligned(ctypes.Structure):
+  pass
+
+class Either(ctypes.Union):
+  pass
+
+class Nested(ctypes.Structure):
+  pass
+
+class Tagged(ctypes.Structure):
+  pass
+
+class Inline(ctypes.Union):
+  pass
+
+class CTag(enum.IntEnum):
+  CTagA = 0
+  CTagB = enum.auto()
+
+class Plain(enum.IntEnum):
+  PlainA = 0
+  PlainB = enum.auto()
+
+Mixed._fields_ = [
+  ("a", ctypes.c_uint8),
+  ("b", ctypes.c_uint32),
+  ("c", ctypes.c_uint16),
+  ("d", ctypes.POINTER(ctypes.c_uint8)),
+  ("e", ctypes.c_uint16 * 3),
+  ("f", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32)),
+]
+
+Packed._pack_ = 1
+Packed._fields_ = [
+  ("a", ctypes.c_uint8),
+  ("b", ctypes.c_uint32),
+  ("c", ctypes.c_uint16),
+]
+
+Aligned._align_ = 16
+Aligned._fields_ = [
+  ("a", ctypes.c_uint8),
+  ("b", Packed),
+]
+
+Alias = Mixed
+
+Either._fields_ = [
+  ("a", ctypes.c_uint8),
+  ("b", ctypes.c_uint64),
+  ("c", ctypes.c_uint8 * 12),
+]
+
+Nested._fields_ = [
+  ("a", Alias),
+  ("b", Either),
+  ("c", ctypes.c_bool),
+]
+
+class Tagged_Tag(enum.IntEnum):
+  TaggedA = 0
+  TaggedB = enum.auto()
+  TaggedC = enum.auto()
+
+class TaggedB_Body(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_uint16),
+  ]
+
+class _Tagged_Body(ctypes.Union):
+  _fields_ = [
+    ("tagged_a", ctypes.c_uint64),
+    ("tagged_b", TaggedB_Body),
+  ]
+
+Tagged._anonymous_ = ["_body"]
+Tagged._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _Tagged_Body),
+]
+
+class Inline_Tag(enum.IntEnum):
+  InlineA = 0
+  InlineB = enum.auto()
+  InlineC = enum.auto()
+
+class _Inline_inline_a(ctypes.Structure):
+  _fields_ = [
+    ("inline_a_tag", ctypes.c_uint8),
+    ("inline_a", ctypes.c_uint64),
+  ]
+
+class InlineB_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_uint8),
+    ("y", ctypes.c_uint16),
+  ]
+
+Inline._anonymous_ = ["_inline_a"]
+Inline._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_inline_a", _Inline_inline_a),
+  ("inline_b", InlineB_Body),
+]
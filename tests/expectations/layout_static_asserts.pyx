@@ -0,0 +1,86 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef enum CTag:
+    CTagA,
+    CTagB,
+
+  cdef enum:
+    PlainA,
+    PlainB,
+  ctypedef uint8_t Plain;
+
+  ctypedef struct Mixed:
+    uint8_t a;
+    uint32_t b;
+    uint16_t c;
+    const uint8_t *d;
+    uint16_t e[3];
+    int32_t (*f)(int32_t);
+
+  ctypedef packed struct Packed:
+    uint8_t a;
+    uint32_t b;
+    uint16_t c;
+
+  ctypedef struct Aligned:
+    uint8_t a;
+    Packed b;
+
+  ctypedef Mixed Alias;
+
+  ctypedef union Either:
+    uint8_t a;
+    uint64_t b;
+    uint8_t c[12];
+
+  ctypedef struct Nested:
+    Alias a;
+    Either b;
+    bool c;
+
+  cdef enum:
+    TaggedA,
+    TaggedB,
+    TaggedC,
+  ctypedef uint8_t Tagged_Tag;
+
+  ctypedef struct TaggedB_Body:
+    uint8_t x;
+    uint16_t y;
+
+  ctypedef struct Tagged:
+    Tagged_Tag tag;
+    uint64_t tagged_a;
+    TaggedB_Body tagged_b;
+
+  cdef enum:
+    InlineA,
+    InlineB,
+    InlineC,
+  ctypedef uint8_t Inline_Tag;
+
+  ctypedef struct InlineB_Body:
+    Inline_Tag tag;
+    uint8_t x;
+    uint16_t y;
+
+  ctypedef union Inline:
+    Inline_Tag tag;
+    uint64_t inline_a;
+    InlineB_Body inline_b;
+
+  ctypedef struct Conditional:
+    uint8_t a;
+    uint32_t b;
+
+  void root(Mixed a, Aligned b, Nested c, Plain d, CTag e, Tagged f, Inline g, Conditional i);
@@ -0,0 +1,187 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum CTag {
+  CTagA,
+  CTagB,
+};
+static_assert(sizeof(enum CTag) == 4, "unexpected size of CTag");
+static_assert(alignof(enum CTag) == 4, "unexpected alignment of CTag");
+
+enum Plain
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  PlainA,
+  PlainB,
+};
+#ifndef __cplusplus
+typedef uint8_t Plain;
+#endif // __cplusplus
+static_assert(sizeof(Plain) == 1, "unexpected size of Plain");
+static_assert(alignof(Plain) == 1, "unexpected alignment of Plain");
+
+struct Mixed {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+  const uint8_t *d;
+  uint16_t e[3];
+  int32_t (*f)(int32_t);
+};
+static_assert(sizeof(struct Mixed) == 40, "unexpected size of Mixed");
+static_assert(alignof(struct Mixed) == 8, "unexpected alignment of Mixed");
+static_assert(offsetof(struct Mixed, a) == 0, "unexpected offset of Mixed::a");
+static_assert(offsetof(struct Mixed, b) == 4, "unexpected offset of Mixed::b");
+static_assert(offsetof(struct Mixed, c) == 8, "unexpected offset of Mixed::c");
+static_assert(offsetof(struct Mixed, d) == 16, "unexpected offset of Mixed::d");
+static_assert(offsetof(struct Mixed, e) == 24, "unexpected offset of Mixed::e");
+static_assert(offsetof(struct Mixed, f) == 32, "unexpected offset of Mixed::f");
+
+struct CBINDGEN_PACKED Packed {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+};
+static_assert(sizeof(struct Packed) == 7, "unexpected size of Packed");
+static_assert(alignof(struct Packed) == 1, "unexpected alignment of Packed");
+static_assert(offsetof(struct Packed, a) == 0, "unexpected offset of Packed::a");
+static_assert(offsetof(struct Packed, b) == 1, "unexpected offset of Packed::b");
+static_assert(offsetof(struct Packed, c) == 5, "unexpected offset of Packed::c");
+
+struct CBINDGEN_ALIGNED(16) Aligned {
+  uint8_t a;
+  struct Packed b;
+};
+static_assert(sizeof(struct Aligned) == 16, "unexpected size of Aligned");
+static_assert(alignof(struct Aligned) == 16, "unexpected alignment of Aligned");
+static_assert(offsetof(struct Aligned, a) == 0, "unexpected offset of Aligned::a");
+static_assert(offsetof(struct Aligned, b) == 1, "unexpected offset of Aligned::b");
+
+typedef struct Mixed Alias;
+
+union Either {
+  uint8_t a;
+  uint64_t b;
+  uint8_t c[12];
+};
+static_assert(sizeof(union Either) == 16, "unexpected size of Either");
+static_assert(alignof(union Either) == 8, "unexpected alignment of Either");
+static_assert(offsetof(union Either, a) == 0, "unexpected offset of Either::a");
+static_assert(offsetof(union Either, b) == 0, "unexpected offset of Either::b");
+static_assert(offsetof(union Either, c) == 0, "unexpected offset of Either::c");
+
+struct Nested {
+  Alias a;
+  union Either b;
+  bool c;
+};
+static_assert(sizeof(struct Nested) == 64, "unexpected size of Nested");
+static_assert(alignof(struct Nested) == 8, "unexpected alignment of Nested");
+static_assert(offsetof(struct Nested, a) == 0, "unexpected offset of Nested::a");
+static_assert(offsetof(struct Nested, b) == 40, "unexpected offset of Nested::b");
+static_assert(offsetof(struct Nested, c) == 56, "unexpected offset of Nested::c");
+
+enum Tagged_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  TaggedA,
+  TaggedB,
+  TaggedC,
+};
+#ifndef __cplusplus
+typedef uint8_t Tagged_Tag;
+#endif // __cplusplus
+
+struct TaggedB_Body {
+  uint8_t x;
+  uint16_t y;
+};
+static_assert(sizeof(struct TaggedB_Body) == 4, "unexpected size of TaggedB_Body");
+static_assert(alignof(struct TaggedB_Body) == 2, "unexpected alignment of TaggedB_Body");
+static_assert(offsetof(struct TaggedB_Body, x) == 0, "unexpected offset of TaggedB_Body::x");
+static_assert(offsetof(struct TaggedB_Body, y) == 2, "unexpected offset of TaggedB_Body::y");
+
+struct Tagged {
+  Tagged_Tag tag;
+  union {
+    struct {
+      uint64_t tagged_a;
+    };
+    struct TaggedB_Body tagged_b;
+  };
+};
+static_assert(sizeof(struct Tagged) == 16, "unexpected size of Tagged");
+static_assert(alignof(struct Tagged) == 8, "unexpected alignment of Tagged");
+
+enum Inline_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  InlineA,
+  InlineB,
+  InlineC,
+};
+#ifndef __cplusplus
+typedef uint8_t Inline_Tag;
+#endif // __cplusplus
+
+struct InlineB_Body {
+  Inline_Tag tag;
+  uint8_t x;
+  uint16_t y;
+};
+static_assert(sizeof(struct InlineB_Body) == 4, "unexpected size of InlineB_Body");
+static_assert(alignof(struct InlineB_Body) == 2, "unexpected alignment of InlineB_Body");
+static_assert(offsetof(struct InlineB_Body, tag) == 0, "unexpected offset of InlineB_Body::tag");
+static_assert(offsetof(struct InlineB_Body, x) == 1, "unexpected offset of InlineB_Body::x");
+static_assert(offsetof(struct InlineB_Body, y) == 2, "unexpected offset of InlineB_Body::y");
+
+union Inline {
+  Inline_Tag tag;
+  struct {
+    Inline_Tag inline_a_tag;
+    uint64_t inline_a;
+  };
+  struct InlineB_Body inline_b;
+};
+static_assert(sizeof(union Inline) == 16, "unexpected size of Inline");
+static_assert(alignof(union Inline) == 8, "unexpected alignment of Inline");
+
+struct Conditional {
+  uint8_t a;
+#if defined(DEFINE_EXTRA)
+  uint32_t b
+#endif
+  ;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Mixed a,
+          struct Aligned b,
+          struct Nested c,
+          Plain d,
+          enum CTag e,
+          struct Tagged f,
+          union Inline g,
+          struct Conditional i);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,89 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+const std = @import("std");
+
+pub const CTag = enum(c_int) {
+  CTagA,
+  CTagB,
+};
+
+pub const Plain = enum(u8) {
+  PlainA,
+  PlainB,
+};
+
+pub const Mixed = extern struct {
+  a: u8,
+  b: u32,
+  c: u16,
+  d: ?*const u8,
+  e: [3]u16,
+  f: ?*const fn (i32) callconv(.C) i32,
+};
+
+pub const Packed = extern struct {
+  a: u8 align(1),
+  b: u32 align(1),
+  c: u16 align(1),
+};
+
+pub const Aligned = extern struct {
+  a: u8 align(@max(16, @alignOf(u8))),
+  b: Packed,
+};
+
+pub const Alias = Mixed;
+
+pub const Either = extern union {
+  a: u8,
+  b: u64,
+  c: [12]u8,
+};
+
+pub const Nested = extern struct {
+  a: Alias,
+  b: Either,
+  c: bool,
+};
+
+pub const Tagged_Tag = enum(u8) {
+  TaggedA,
+  TaggedB,
+  TaggedC,
+};
+
+pub const TaggedB_Body = extern struct {
+  x: u8,
+  y: u16,
+};
+
+pub const Tagged = extern struct {
+  tag: Tagged_Tag,
+  body: extern union {
+    tagged_a: u64,
+    tagged_b: TaggedB_Body,
+  },
+};
+
+pub const Inline_Tag = enum(u8) {
+  InlineA,
+  InlineB,
+  InlineC,
+};
+
+pub const InlineB_Body = extern struct {
+  tag: Inline_Tag,
+  x: u8,
+  y: u16,
+};
+
+pub const Inline = extern union {
+  tag: Inline_Tag,
+  inline_a: extern struct {
+    inline_a_tag: Inline_Tag,
+    inline_a: u64,
+  },
+  inline_b: InlineB_Body,
+};
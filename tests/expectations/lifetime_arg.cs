@@ -0,0 +1,35 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A {
+    public IntPtr data;
+  }
+
+  public enum E_Tag {
+    V,
+    U,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct U_Body {
+    public IntPtr _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct E {
+    public E_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public U_Body u;
+    }
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A _a, E _e);
+}
@@ -0,0 +1,35 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  pass
+
+class E(ctypes.Structure):
+  pass
+
+A._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_int32)),
+]
+
+class E_Tag(enum.IntEnum):
+  V = 0
+  U = enum.auto()
+
+class _E_Body(ctypes.Union):
+  _fields_ = [
+    ("u", ctypes.POINTER(ctypes.c_uint8)),
+  ]
+
+E._anonymous_ = ["_body"]
+E._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _E_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, E]
+  lib.root.restype = None
+
+  return lib
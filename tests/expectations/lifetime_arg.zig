@@ -0,0 +1,19 @@
+const std = @import("std");
+
+pub const A = extern struct {
+  data: *const i32,
+};
+
+pub const E_Tag = enum(c_int) {
+  V,
+  U,
+};
+
+pub const E = extern struct {
+  tag: E_Tag,
+  body: extern union {
+    u: *const u8,
+  },
+};
+
+pub extern fn root(_a: A, _e: E) void;
@@ -0,0 +1 @@
+using System;using System.Runtime.InteropServices;public static partial class NativeMethods {  const string __DllName = "__Internal";  [StructLayout(LayoutKind.Sequential)]  public struct Dummy {    public int x;    public float y;  }  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]  public static extern void root(Dummy d);}
\ No newline at end of file
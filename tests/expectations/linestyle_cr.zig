@@ -0,0 +1 @@
+const std = @import("std");pub const Dummy = extern struct {  x: i32,  y: f32,};pub extern fn root(d: Dummy) void;
\ No newline at end of file
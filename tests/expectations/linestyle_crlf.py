@@ -0,0 +1,18 @@
+import ctypes
+import enum
+
+class Dummy(ctypes.Structure):
+  pass
+
+Dummy._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Dummy]
+  lib.root.restype = None
+
+  return lib
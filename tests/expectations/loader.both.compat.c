@@ -0,0 +1,76 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+#endif
+#define LOADER_API /* nothing */
+#define LOADER_COLD __attribute__((cold))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <dlfcn.h>
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+LOADER_API struct Point point_new(int32_t x, int32_t y);
+
+LOADER_API double point_length(const struct Point *point);
+
+#if defined(PLATFORM_UNIX)
+LOADER_API void unix_only(int32_t (*callback)(int32_t));
+#endif
+
+LOADER_API void rarely_called(void) LOADER_COLD;
+
+extern int32_t imported(int32_t x);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+typedef struct PluginFunctions {
+  LOADER_API struct Point (*point_new)(int32_t x, int32_t y);
+  LOADER_API double (*point_length)(const struct Point *point);
+#if defined(PLATFORM_UNIX)
+  LOADER_API void (*unix_only)(int32_t (*callback)(int32_t));
+#endif
+  LOADER_API void (*rarely_called)(void) LOADER_COLD;
+} PluginFunctions;
+
+static inline void *plugin_load(const char *path, PluginFunctions *table) {
+  void *handle = dlopen(path, RTLD_NOW | RTLD_LOCAL);
+  if (!handle) {
+    return NULL;
+  }
+  table->point_new = (struct Point(*)(int32_t, int32_t))dlsym(handle, "point_new");
+  if (!table->point_new) {
+    dlclose(handle);
+    return NULL;
+  }
+  table->point_length = (double(*)(const struct Point*))dlsym(handle, "point_length");
+  if (!table->point_length) {
+    dlclose(handle);
+    return NULL;
+  }
+#if defined(PLATFORM_UNIX)
+  table->unix_only = (void(*)(int32_t(*)(int32_t)))dlsym(handle, "unix_only");
+  if (!table->unix_only) {
+    dlclose(handle);
+    return NULL;
+  }
+#endif
+  table->rarely_called = (void(*)(void))dlsym(handle, "rarely_called");
+  if (!table->rarely_called) {
+    dlclose(handle);
+    return NULL;
+  }
+  return handle;
+}
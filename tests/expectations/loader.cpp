@@ -0,0 +1,73 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+#endif
+#define LOADER_API /* nothing */
+#define LOADER_COLD __attribute__((cold))
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <dlfcn.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+LOADER_API Point point_new(int32_t x, int32_t y);
+
+LOADER_API double point_length(const Point *point);
+
+#if defined(PLATFORM_UNIX)
+LOADER_API void unix_only(int32_t (*callback)(int32_t));
+#endif
+
+LOADER_API void rarely_called() LOADER_COLD;
+
+extern int32_t imported(int32_t x);
+
+} // extern "C"
+
+struct PluginFunctions {
+  LOADER_API Point (*point_new)(int32_t x, int32_t y);
+  LOADER_API double (*point_length)(const Point *point);
+#if defined(PLATFORM_UNIX)
+  LOADER_API void (*unix_only)(int32_t (*callback)(int32_t));
+#endif
+  LOADER_API void (*rarely_called)() LOADER_COLD;
+};
+
+static inline void *plugin_load(const char *path, PluginFunctions *table) {
+  void *handle = dlopen(path, RTLD_NOW | RTLD_LOCAL);
+  if (!handle) {
+    return nullptr;
+  }
+  table->point_new = (Point(*)(int32_t, int32_t))dlsym(handle, "point_new");
+  if (!table->point_new) {
+    dlclose(handle);
+    return nullptr;
+  }
+  table->point_length = (double(*)(const Point*))dlsym(handle, "point_length");
+  if (!table->point_length) {
+    dlclose(handle);
+    return nullptr;
+  }
+#if defined(PLATFORM_UNIX)
+  table->unix_only = (void(*)(int32_t(*)(int32_t)))dlsym(handle, "unix_only");
+  if (!table->unix_only) {
+    dlclose(handle);
+    return nullptr;
+  }
+#endif
+  table->rarely_called = (void(*)())dlsym(handle, "rarely_called");
+  if (!table->rarely_called) {
+    dlclose(handle);
+    return nullptr;
+  }
+  return handle;
+}
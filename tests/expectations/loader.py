@@ -0,0 +1,34 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+#endif
+#define LOADER_API /* nothing */
+#define LOADER_COLD __attribute__((cold))
+
+
+import ctypes
+import enum
+
+class Point(ctypes.Structure):
+  pass
+
+Point._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.point_new.argtypes = [ctypes.c_int32, ctypes.c_int32]
+  lib.point_new.restype = Point
+
+  lib.point_length.argtypes = [ctypes.POINTER(Point)]
+  lib.point_length.restype = ctypes.c_double
+
+  lib.rarely_called.argtypes = []
+  lib.rarely_called.restype = None
+
+  lib.imported.argtypes = [ctypes.c_int32]
+  lib.imported.restype = ctypes.c_int32
+
+  return lib
@@ -0,0 +1,29 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+#endif
+#define LOADER_API /* nothing */
+#define LOADER_COLD __attribute__((cold))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Point:
+    int32_t x;
+    int32_t y;
+
+  LOADER_API Point point_new(int32_t x, int32_t y);
+
+  LOADER_API double point_length(const Point *point);
+
+  IF PLATFORM_UNIX:
+    LOADER_API void unix_only(int32_t (*callback)(int32_t));
+
+  LOADER_API void rarely_called() LOADER_COLD;
+
+  extern int32_t imported(int32_t x);
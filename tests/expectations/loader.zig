@@ -0,0 +1,21 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+#endif
+#define LOADER_API /* nothing */
+#define LOADER_COLD __attribute__((cold))
+
+
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub extern fn point_new(x: i32, y: i32) Point;
+
+pub extern fn point_length(point: ?*const Point) f64;
+
+pub extern fn rarely_called() void;
+
+pub extern fn imported(x: i32) i32;
@@ -0,0 +1,19 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum Bar {
+    BarSome,
+    BarThing,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct FooU8 {
+    public byte a;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FooU8 x, Bar y);
+}
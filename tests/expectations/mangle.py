@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+class FooU8(ctypes.Structure):
+  pass
+
+class Bar(enum.IntEnum):
+  BarSome = 0
+  BarThing = enum.auto()
+
+FooU8._fields_ = [
+  ("a", ctypes.c_uint8),
+]
+
+Boo = FooU8
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Boo, ctypes.c_int]
+  lib.root.restype = None
+
+  return lib
@@ -26,16 +26,16 @@ struct Point {
   int32_t y;
 };
 
-using Foo = NotReprC<ManuallyDrop<Point>>;
+using Foo = NotReprC<Point>;
 
 struct MyStruct {
-  ManuallyDrop<Point> point;
+  Point point;
 };
 
 extern "C" {
 
 void root(const Foo *a, const MyStruct *with_manual_drop);
 
-void take(ManuallyDrop<Point> with_manual_drop);
+void take(Point with_manual_drop);
 
 } // extern "C"
@@ -0,0 +1,47 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using ManuallyDrop = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class Point(ctypes.Structure):
+  pass
+
+class MyStruct(ctypes.Structure):
+  pass
+
+class NotReprC_Point(ctypes.Structure):
+  pass
+
+Foo = NotReprC_Point
+
+Point._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+MyStruct._fields_ = [
+  ("point", Point),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(MyStruct)]
+  lib.root.restype = None
+
+  lib.take.argtypes = [Point]
+  lib.take.restype = None
+
+  return lib
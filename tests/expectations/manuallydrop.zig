@@ -0,0 +1,32 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using ManuallyDrop = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const NotReprC_Point = opaque {};
+
+pub const Foo = NotReprC_Point;
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const MyStruct = extern struct {
+  point: Point,
+};
+
+pub extern fn root(a: *const Foo, with_manual_drop: *const MyStruct) void;
+
+pub extern fn take(with_manual_drop: Point) void;
@@ -21,10 +21,10 @@ using MaybeUninit = T;
 template<typename T = void>
 struct NotReprC;
 
-using Foo = NotReprC<MaybeUninit<const int32_t*>>;
+using Foo = NotReprC<const int32_t*>;
 
 struct MyStruct {
-  MaybeUninit<const int32_t*> number;
+  const int32_t *number;
 };
 
 extern "C" {
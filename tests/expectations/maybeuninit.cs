@@ -0,0 +1,30 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using MaybeUninit = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct NotReprC______i32 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MyStruct {
+    public IntPtr number;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, IntPtr with_maybe_uninit);
+}
@@ -0,0 +1,36 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using MaybeUninit = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class MyStruct(ctypes.Structure):
+  pass
+
+class NotReprC______i32(ctypes.Structure):
+  pass
+
+Foo = NotReprC______i32
+
+MyStruct._fields_ = [
+  ("number", ctypes.POINTER(ctypes.c_int32)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(MyStruct)]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+EXPORT_ME_TOO = 42
+
+class ExportMe(ctypes.Structure):
+  pass
+
+ExportMe._fields_ = [
+  ("val", ctypes.c_uint64),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.export_me.argtypes = [ctypes.POINTER(ExportMe)]
+  lib.export_me.restype = None
+
+  lib.from_really_nested_mod.argtypes = []
+  lib.from_really_nested_mod.restype = None
+
+  return lib
@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "mod_2018";
+
+  public const byte EXPORT_ME_TOO = 42;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExportMe {
+    public ulong val;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExportMe2 {
+    public ulong val;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void export_me(IntPtr val);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void export_me_2(IntPtr arg0);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void from_really_nested_mod();
+}
@@ -0,0 +1,32 @@
+import ctypes
+import enum
+
+EXPORT_ME_TOO = 42
+
+class ExportMe(ctypes.Structure):
+  pass
+
+class ExportMe2(ctypes.Structure):
+  pass
+
+ExportMe._fields_ = [
+  ("val", ctypes.c_uint64),
+]
+
+ExportMe2._fields_ = [
+  ("val", ctypes.c_uint64),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.export_me.argtypes = [ctypes.POINTER(ExportMe)]
+  lib.export_me.restype = None
+
+  lib.export_me_2.argtypes = [ctypes.POINTER(ExportMe2)]
+  lib.export_me_2.restype = None
+
+  lib.from_really_nested_mod.argtypes = []
+  lib.from_really_nested_mod.restype = None
+
+  return lib
@@ -0,0 +1,44 @@
+#if 0
+DEF FOO = 0
+DEF BAR = 0
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "mod_attr";
+
+#if FOO
+  public const int FOO = 1;
+#endif
+
+#if BAR
+  public const int BAR = 2;
+#endif
+
+#if FOO
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+
+  }
+#endif
+
+#if BAR
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar {
+
+  }
+#endif
+
+#if FOO
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo(IntPtr foo);
+#endif
+
+#if BAR
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void bar(IntPtr bar);
+#endif
+}
@@ -0,0 +1,8 @@
+#if 0
+DEF FOO = 0
+DEF BAR = 0
+#endif
+
+
+import ctypes
+import enum
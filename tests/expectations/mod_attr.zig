@@ -0,0 +1,7 @@
+#if 0
+DEF FOO = 0
+DEF BAR = 0
+#endif
+
+
+const std = @import("std");
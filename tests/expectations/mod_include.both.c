@@ -0,0 +1,42 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define GENERATED_VERSION 3
+
+typedef struct Generated {
+  uint32_t id;
+} Generated;
+
+#if defined(DEFINED)
+typedef struct Handle {
+  int32_t fd;
+} Handle;
+#endif
+
+#if defined(NOT_DEFINED)
+typedef struct Handle {
+  uint8_t *handle;
+} Handle;
+#endif
+
+typedef struct Shared {
+  uint64_t value;
+} Shared;
+
+typedef struct Extra {
+  bool flag;
+} Extra;
+
+typedef struct Nested {
+  uint8_t depth;
+} Nested;
+
+void root(struct Generated a, struct Handle b, struct Shared c, struct Extra d, struct Nested e);
@@ -0,0 +1,42 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define GENERATED_VERSION 3
+
+typedef struct {
+  uint32_t id;
+} Generated;
+
+#if defined(DEFINED)
+typedef struct {
+  int32_t fd;
+} Handle;
+#endif
+
+#if defined(NOT_DEFINED)
+typedef struct {
+  uint8_t *handle;
+} Handle;
+#endif
+
+typedef struct {
+  uint64_t value;
+} Shared;
+
+typedef struct {
+  bool flag;
+} Extra;
+
+typedef struct {
+  uint8_t depth;
+} Nested;
+
+void root(Generated a, Handle b, Shared c, Extra d, Nested e);
@@ -0,0 +1,47 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t GENERATED_VERSION = 3;
+
+struct Generated {
+  uint32_t id;
+};
+
+#if defined(DEFINED)
+struct Handle {
+  int32_t fd;
+};
+#endif
+
+#if defined(NOT_DEFINED)
+struct Handle {
+  uint8_t *handle;
+};
+#endif
+
+struct Shared {
+  uint64_t value;
+};
+
+struct Extra {
+  bool flag;
+};
+
+struct Nested {
+  uint8_t depth;
+};
+
+extern "C" {
+
+void root(Generated a, Handle b, Shared c, Extra d, Nested e);
+
+} // extern "C"
@@ -0,0 +1,51 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "mod_include";
+
+  public const uint GENERATED_VERSION = 3;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Generated {
+    public uint id;
+  }
+
+#if DEFINED
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Handle {
+    public int fd;
+  }
+#endif
+
+#if NOT_DEFINED
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Handle {
+    public IntPtr handle;
+  }
+#endif
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Shared {
+    public ulong value;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Extra {
+    [MarshalAs(UnmanagedType.U1)] public bool flag;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Nested {
+    public byte depth;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Generated a, Handle b, Shared c, Extra d, Nested e);
+}
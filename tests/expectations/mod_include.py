@@ -0,0 +1,38 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+import ctypes
+import enum
+
+GENERATED_VERSION = 3
+
+class Generated(ctypes.Structure):
+  pass
+
+class Shared(ctypes.Structure):
+  pass
+
+class Extra(ctypes.Structure):
+  pass
+
+class Nested(ctypes.Structure):
+  pass
+
+Generated._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+Shared._fields_ = [
+  ("value", ctypes.c_uint64),
+]
+
+Extra._fields_ = [
+  ("flag", ctypes.c_bool),
+]
+
+Nested._fields_ = [
+  ("depth", ctypes.c_uint8),
+]
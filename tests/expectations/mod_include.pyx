@@ -0,0 +1,37 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t GENERATED_VERSION # = 3
+
+  ctypedef struct Generated:
+    uint32_t id;
+
+  IF DEFINED:
+    ctypedef struct Handle:
+      int32_t fd;
+
+  IF NOT_DEFINED:
+    ctypedef struct Handle:
+      uint8_t *handle;
+
+  ctypedef struct Shared:
+    uint64_t value;
+
+  ctypedef struct Extra:
+    bool flag;
+
+  ctypedef struct Nested:
+    uint8_t depth;
+
+  void root(Generated a, Handle b, Shared c, Extra d, Nested e);
@@ -0,0 +1,42 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define GENERATED_VERSION 3
+
+struct Generated {
+  uint32_t id;
+};
+
+#if defined(DEFINED)
+struct Handle {
+  int32_t fd;
+};
+#endif
+
+#if defined(NOT_DEFINED)
+struct Handle {
+  uint8_t *handle;
+};
+#endif
+
+struct Shared {
+  uint64_t value;
+};
+
+struct Extra {
+  bool flag;
+};
+
+struct Nested {
+  uint8_t depth;
+};
+
+void root(struct Generated a, struct Handle b, struct Shared c, struct Extra d, struct Nested e);
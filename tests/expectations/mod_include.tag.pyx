@@ -0,0 +1,37 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t GENERATED_VERSION # = 3
+
+  cdef struct Generated:
+    uint32_t id;
+
+  IF DEFINED:
+    cdef struct Handle:
+      int32_t fd;
+
+  IF NOT_DEFINED:
+    cdef struct Handle:
+      uint8_t *handle;
+
+  cdef struct Shared:
+    uint64_t value;
+
+  cdef struct Extra:
+    bool flag;
+
+  cdef struct Nested:
+    uint8_t depth;
+
+  void root(Generated a, Handle b, Shared c, Extra d, Nested e);
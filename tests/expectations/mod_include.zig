@@ -0,0 +1,25 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+const std = @import("std");
+
+pub const GENERATED_VERSION: u32 = 3;
+
+pub const Generated = extern struct {
+  id: u32,
+};
+
+pub const Shared = extern struct {
+  value: u64,
+};
+
+pub const Extra = extern struct {
+  flag: bool,
+};
+
+pub const Nested = extern struct {
+  depth: u8,
+};
@@ -0,0 +1,49 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Bar_Bar_f32 { }
+
+  public struct Bar_Foo_f32 { }
+
+  public struct Bar_f32 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_i32 {
+    public IntPtr data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_f32 {
+    public IntPtr data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Bar_f32 {
+    public IntPtr data;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Tuple_Foo_f32_____f32 {
+    public IntPtr a;
+    public IntPtr b;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Tuple_f32__f32 {
+    public IntPtr a;
+    public IntPtr b;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo_i32 a,
+                                 Foo_f32 b,
+                                 Bar_f32 c,
+                                 Foo_Bar_f32 d,
+                                 Bar_Foo_f32 e,
+                                 Bar_Bar_f32 f,
+                                 Tuple_Foo_f32_____f32 g,
+                                 Tuple_f32__f32 h);
+}
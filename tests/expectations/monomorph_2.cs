@@ -0,0 +1,28 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct A { }
+
+  public struct B { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct List_A {
+    public IntPtr members;
+    public nuint count;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct List_B {
+    public IntPtr members;
+    public nuint count;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo(List_A a);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void bar(List_B b);
+}
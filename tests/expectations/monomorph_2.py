@@ -0,0 +1,35 @@
+import ctypes
+import enum
+
+class List_A(ctypes.Structure):
+  pass
+
+class List_B(ctypes.Structure):
+  pass
+
+class A(ctypes.Structure):
+  pass
+
+class B(ctypes.Structure):
+  pass
+
+List_A._fields_ = [
+  ("members", ctypes.POINTER(A)),
+  ("count", ctypes.c_size_t),
+]
+
+List_B._fields_ = [
+  ("members", ctypes.POINTER(B)),
+  ("count", ctypes.c_size_t),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [List_A]
+  lib.foo.restype = None
+
+  lib.bar.argtypes = [List_B]
+  lib.bar.restype = None
+
+  return lib
@@ -0,0 +1,67 @@
+import ctypes
+import enum
+
+class Foo_i32(ctypes.Union):
+  pass
+
+class Foo_f32(ctypes.Union):
+  pass
+
+class Foo_Bar_f32(ctypes.Union):
+  pass
+
+class Tuple_Foo_f32_____f32(ctypes.Union):
+  pass
+
+class Tuple_f32__f32(ctypes.Union):
+  pass
+
+class Bar_Bar_f32(ctypes.Structure):
+  pass
+
+class Bar_Foo_f32(ctypes.Structure):
+  pass
+
+class Bar_f32(ctypes.Structure):
+  pass
+
+Foo_i32._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_int32)),
+]
+
+Foo_f32._fields_ = [
+  ("data", ctypes.POINTER(ctypes.c_float)),
+]
+
+Foo_Bar_f32._fields_ = [
+  ("data", ctypes.POINTER(Bar_f32)),
+]
+
+Tuple_Foo_f32_____f32._fields_ = [
+  ("a", ctypes.POINTER(Foo_f32)),
+  ("b", ctypes.POINTER(ctypes.c_float)),
+]
+
+Tuple_f32__f32._fields_ = [
+  ("a", ctypes.POINTER(ctypes.c_float)),
+  ("b", ctypes.POINTER(ctypes.c_float)),
+]
+
+Indirection_f32 = Tuple_f32__f32
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    Foo_i32,
+    Foo_f32,
+    Bar_f32,
+    Foo_Bar_f32,
+    Bar_Foo_f32,
+    Bar_Bar_f32,
+    Tuple_Foo_f32_____f32,
+    Indirection_f32,
+  ]
+  lib.root.restype = None
+
+  return lib
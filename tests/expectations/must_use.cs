@@ -0,0 +1,40 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum MaybeOwnedPtr_i32_Tag : byte {
+    Owned_i32,
+    None_i32,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Owned_Body_i32 {
+    public IntPtr _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct MaybeOwnedPtr_i32 {
+    public MaybeOwnedPtr_i32_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public Owned_Body_i32 owned;
+    }
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct OwnedPtr_i32 {
+    public IntPtr ptr;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern MaybeOwnedPtr_i32 maybe_consume(OwnedPtr_i32 input);
+}
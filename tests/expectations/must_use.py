@@ -0,0 +1,40 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+import ctypes
+import enum
+
+class MaybeOwnedPtr_i32(ctypes.Structure):
+  pass
+
+class OwnedPtr_i32(ctypes.Structure):
+  pass
+
+class MaybeOwnedPtr_i32_Tag(enum.IntEnum):
+  Owned_i32 = 0
+  None_i32 = enum.auto()
+
+class _MaybeOwnedPtr_i32_Body(ctypes.Union):
+  _fields_ = [
+    ("owned", ctypes.POINTER(ctypes.c_int32)),
+  ]
+
+MaybeOwnedPtr_i32._anonymous_ = ["_body"]
+MaybeOwnedPtr_i32._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _MaybeOwnedPtr_i32_Body),
+]
+
+OwnedPtr_i32._fields_ = [
+  ("ptr", ctypes.POINTER(ctypes.c_int32)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.maybe_consume.argtypes = [OwnedPtr_i32]
+  lib.maybe_consume.restype = MaybeOwnedPtr_i32
+
+  return lib
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace constants.test {
+  public static partial class NativeMethods {
+    const string __DllName = "__Internal";
+
+    public const int FOO = 10;
+
+    public const float ZOM = (float)3.14;
+
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Foo {
+      [MarshalAs(UnmanagedType.ByValArray, SizeConst = FOO)] public int[] x;
+    }
+
+    [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+    public static extern void root(Foo x);
+  }
+}
@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_u64 {
+    public IntPtr a;
+    public IntPtr b;
+    public IntPtr c;
+    public IntPtr d;
+    public IntPtr e;
+    public IntPtr f;
+    public IntPtr g;
+    public IntPtr h;
+    public IntPtr i;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr arg, IntPtr foo, IntPtr d);
+}
@@ -0,0 +1,32 @@
+import ctypes
+import enum
+
+class Foo_u64(ctypes.Structure):
+  pass
+
+class Opaque(ctypes.Structure):
+  pass
+
+Foo_u64._fields_ = [
+  ("a", ctypes.POINTER(ctypes.c_float)),
+  ("b", ctypes.POINTER(ctypes.c_uint64)),
+  ("c", ctypes.POINTER(Opaque)),
+  ("d", ctypes.POINTER(ctypes.POINTER(ctypes.c_uint64))),
+  ("e", ctypes.POINTER(ctypes.POINTER(ctypes.c_float))),
+  ("f", ctypes.POINTER(ctypes.POINTER(Opaque))),
+  ("g", ctypes.POINTER(ctypes.c_uint64)),
+  ("h", ctypes.POINTER(ctypes.c_int32)),
+  ("i", ctypes.POINTER(ctypes.POINTER(ctypes.c_int32))),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(ctypes.c_int32),
+    ctypes.POINTER(Foo_u64),
+    ctypes.POINTER(ctypes.POINTER(Opaque)),
+  ]
+  lib.root.restype = None
+
+  return lib
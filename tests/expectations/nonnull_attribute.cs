@@ -0,0 +1,62 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct References {
+    public IntPtr a;
+    public IntPtr b;
+    public IntPtr c;
+    public IntPtr d;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Pointers_u64 {
+    public IntPtr a;
+    public IntPtr b;
+    public IntPtr c;
+    public IntPtr d;
+    public IntPtr e;
+    public IntPtr f;
+    public IntPtr g;
+    public IntPtr h;
+    public IntPtr i;
+    public IntPtr j;
+    public IntPtr k;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void value_arg(References arg);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void mutltiple_args(IntPtr arg, IntPtr foo, IntPtr d);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ref_arg(IntPtr arg);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void mut_ref_arg(IntPtr arg);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void optional_ref_arg(IntPtr arg);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void optional_mut_ref_arg(IntPtr arg);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void nullable_const_ptr(IntPtr arg);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void nullable_mut_ptr(IntPtr arg);
+}
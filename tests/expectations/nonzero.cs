@@ -0,0 +1,48 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+struct NonZeroI64;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Option_i64 { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct NonZeroTest {
+    public byte a;
+    public ushort b;
+    public uint c;
+    public ulong d;
+    public sbyte e;
+    public short f;
+    public int g;
+    public long h;
+    public long i;
+    public IntPtr j;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(NonZeroTest test,
+                                 byte a,
+                                 ushort b,
+                                 uint c,
+                                 ulong d,
+                                 sbyte e,
+                                 short f,
+                                 int g,
+                                 long h,
+                                 long i,
+                                 IntPtr j);
+}
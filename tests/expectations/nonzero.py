@@ -0,0 +1,54 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+struct NonZeroI64;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class NonZeroTest(ctypes.Structure):
+  pass
+
+class Option_i64(ctypes.Structure):
+  pass
+
+NonZeroTest._fields_ = [
+  ("a", ctypes.c_uint8),
+  ("b", ctypes.c_uint16),
+  ("c", ctypes.c_uint32),
+  ("d", ctypes.c_uint64),
+  ("e", ctypes.c_int8),
+  ("f", ctypes.c_int16),
+  ("g", ctypes.c_int32),
+  ("h", ctypes.c_int64),
+  ("i", ctypes.c_int64),
+  ("j", ctypes.POINTER(Option_i64)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    NonZeroTest,
+    ctypes.c_uint8,
+    ctypes.c_uint16,
+    ctypes.c_uint32,
+    ctypes.c_uint64,
+    ctypes.c_int8,
+    ctypes.c_int16,
+    ctypes.c_int32,
+    ctypes.c_int64,
+    ctypes.c_int64,
+    ctypes.POINTER(Option_i64),
+  ]
+  lib.root.restype = None
+
+  return lib
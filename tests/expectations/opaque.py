@@ -0,0 +1,40 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+// These could be added as opaque types I guess.
+template <typename T>
+struct BuildHasherDefault;
+
+struct DefaultHasher;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class HashMap_i32__i32__BuildHasherDefault_DefaultHasher(ctypes.Structure):
+  pass
+
+class Result_Foo(ctypes.Structure):
+  pass
+
+# Fast hash map used internally.
+FastHashMap_i32__i32 = HashMap_i32__i32__BuildHasherDefault_DefaultHasher
+
+Foo = FastHashMap_i32__i32
+
+Bar = Result_Foo
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Foo), ctypes.POINTER(Bar)]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,31 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+// These could be added as opaque types I guess.
+template <typename T>
+struct BuildHasherDefault;
+
+struct DefaultHasher;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const HashMap_i32__i32__BuildHasherDefault_DefaultHasher = opaque {};
+
+pub const Result_Foo = opaque {};
+
+/// Fast hash map used internally.
+pub const FastHashMap_i32__i32 = HashMap_i32__i32__BuildHasherDefault_DefaultHasher;
+
+pub const Foo = FastHashMap_i32__i32;
+
+pub const Bar = Result_Foo;
+
+pub extern fn root(a: *const Foo, b: *const Bar) void;
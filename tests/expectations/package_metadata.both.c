@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum PmDirection {
+  PM_DIRECTION_NORTH,
+  PM_DIRECTION_SOUTH,
+} PmDirection;
+
+typedef struct PmPoint {
+  int32_t x;
+  int32_t y;
+} PmPoint;
+
+struct PmPoint walk(struct PmPoint from, enum PmDirection direction);
@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  PM_DIRECTION_NORTH,
+  PM_DIRECTION_SOUTH,
+} PmDirection;
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} PmPoint;
+
+PmPoint walk(PmPoint from, PmDirection direction);
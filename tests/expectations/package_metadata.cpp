@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class PmDirection {
+  PM_DIRECTION_NORTH,
+  PM_DIRECTION_SOUTH,
+};
+
+struct PmPoint {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+PmPoint walk(PmPoint from, PmDirection direction);
+
+} // extern "C"
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "package_metadata";
+
+  public enum PmDirection {
+    PM_DIRECTION_NORTH,
+    PM_DIRECTION_SOUTH,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PmPoint {
+    public int x;
+    public int y;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern PmPoint walk(PmPoint from, PmDirection direction);
+}
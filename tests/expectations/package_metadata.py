@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+class PmPoint(ctypes.Structure):
+  pass
+
+class PmDirection(enum.IntEnum):
+  PM_DIRECTION_NORTH = 0
+  PM_DIRECTION_SOUTH = enum.auto()
+
+PmPoint._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.walk.argtypes = [PmPoint, ctypes.c_int]
+  lib.walk.restype = PmPoint
+
+  return lib
@@ -0,0 +1,17 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef enum PmDirection:
+    PM_DIRECTION_NORTH,
+    PM_DIRECTION_SOUTH,
+
+  ctypedef struct PmPoint:
+    int32_t x;
+    int32_t y;
+
+  PmPoint walk(PmPoint from, PmDirection direction);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum PmDirection {
+  PM_DIRECTION_NORTH,
+  PM_DIRECTION_SOUTH,
+};
+
+struct PmPoint {
+  int32_t x;
+  int32_t y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct PmPoint walk(struct PmPoint from, enum PmDirection direction);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
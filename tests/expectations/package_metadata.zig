@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const PmDirection = enum(c_int) {
+  PM_DIRECTION_NORTH,
+  PM_DIRECTION_SOUTH,
+};
+
+pub const PmPoint = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub extern fn walk(from: PmPoint, direction: PmDirection) PmPoint;
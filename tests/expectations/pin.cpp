@@ -21,12 +21,12 @@ using Box = T*;
 #include <new>
 
 struct PinTest {
-  Pin<Box<int32_t>> pinned_box;
-  Pin<int32_t*> pinned_ref;
+  Box<int32_t> pinned_box;
+  int32_t *pinned_ref;
 };
 
 extern "C" {
 
-void root(Pin<int32_t*> s, PinTest p);
+void root(int32_t *s, PinTest p);
 
 } // extern "C"
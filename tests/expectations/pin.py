@@ -0,0 +1,34 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Pin = T;
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import ctypes
+import enum
+
+class PinTest(ctypes.Structure):
+  pass
+
+PinTest._fields_ = [
+  ("pinned_box", ctypes.POINTER(ctypes.c_int32)),
+  ("pinned_ref", ctypes.POINTER(ctypes.c_int32)),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(ctypes.c_int32), PinTest]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,33 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public const int PREFIX_LEN = 22;
+
+  public const long PREFIX_X = (22 << 22);
+
+  public const long PREFIX_Y = (PREFIX_X + PREFIX_X);
+
+  public enum PREFIX_AbsoluteFontWeight_Tag : byte {
+    Weight,
+    Normal,
+    Bold,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PREFIX_Weight_Body {
+    public PREFIX_AbsoluteFontWeight_Tag tag;
+    public float _0;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct PREFIX_AbsoluteFontWeight {
+    [FieldOffset(0)] public PREFIX_AbsoluteFontWeight_Tag tag;
+    [FieldOffset(0)] public PREFIX_Weight_Body weight;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr x, IntPtr y, PREFIX_AbsoluteFontWeight z);
+}
@@ -0,0 +1,40 @@
+import ctypes
+import enum
+
+PREFIX_LEN = 22
+
+PREFIX_X = (22 << 22)
+
+PREFIX_Y = (PREFIX_X + PREFIX_X)
+
+class PREFIX_AbsoluteFontWeight(ctypes.Union):
+  pass
+
+PREFIX_NamedLenArray = ctypes.c_int32 * PREFIX_LEN
+
+PREFIX_ValuedLenArray = ctypes.c_int32 * 22
+
+class PREFIX_AbsoluteFontWeight_Tag(enum.IntEnum):
+  Weight = 0
+  Normal = enum.auto()
+  Bold = enum.auto()
+
+class _PREFIX_AbsoluteFontWeight_weight(ctypes.Structure):
+  _fields_ = [
+    ("weight_tag", ctypes.c_uint8),
+    ("weight", ctypes.c_float),
+  ]
+
+PREFIX_AbsoluteFontWeight._anonymous_ = ["_weight"]
+PREFIX_AbsoluteFontWeight._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_weight", _PREFIX_AbsoluteFontWeight_weight),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIX_NamedLenArray, PREFIX_ValuedLenArray, PREFIX_AbsoluteFontWeight]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,27 @@
+const std = @import("std");
+
+pub const PREFIX_LEN: i32 = 22;
+
+pub const PREFIX_X: i64 = (22 << 22);
+
+pub const PREFIX_Y: i64 = (PREFIX_X + PREFIX_X);
+
+pub const PREFIX_NamedLenArray = [PREFIX_LEN]i32;
+
+pub const PREFIX_ValuedLenArray = [22]i32;
+
+pub const PREFIX_AbsoluteFontWeight_Tag = enum(u8) {
+  Weight,
+  Normal,
+  Bold,
+};
+
+pub const PREFIX_AbsoluteFontWeight = extern union {
+  tag: PREFIX_AbsoluteFontWeight_Tag,
+  weight: extern struct {
+    weight_tag: PREFIX_AbsoluteFontWeight_Tag,
+    weight: f32,
+  },
+};
+
+pub extern fn root(x: PREFIX_NamedLenArray, y: PREFIX_ValuedLenArray, z: PREFIX_AbsoluteFontWeight) void;
@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PREFIXFoo {
+    public int a;
+    public uint b;
+  }
+  public static readonly PREFIXFoo PREFIXFoo_FOO = new PREFIXFoo { a = 42, b = 47 };
+
+  public static readonly PREFIXFoo PREFIXBAR = new PREFIXFoo { a = 42, b = 1337 };
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(PREFIXFoo x);
+}
@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const PREFIXFoo = extern struct {
+  a: i32,
+  b: u32,
+};
+pub const PREFIXFoo_FOO: PREFIXFoo = PREFIXFoo{ .a = 42, .b = 47 };
+
+pub const PREFIXBAR: PREFIXFoo = PREFIXFoo{ .a = 42, .b = 1337 };
+
+pub extern fn root(x: PREFIXFoo) void;
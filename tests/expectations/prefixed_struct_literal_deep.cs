@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PREFIXBar {
+    public int a;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PREFIXFoo {
+    public int a;
+    public uint b;
+    public PREFIXBar bar;
+  }
+
+  public static readonly PREFIXFoo PREFIXVAL = new PREFIXFoo { a = 42, b = 1337, bar = new PREFIXBar { a = 323 } };
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(PREFIXFoo x);
+}
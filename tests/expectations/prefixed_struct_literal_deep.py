@@ -0,0 +1,28 @@
+import ctypes
+import enum
+
+class PREFIXBar(ctypes.Structure):
+  pass
+
+class PREFIXFoo(ctypes.Structure):
+  pass
+
+PREFIXBar._fields_ = [
+  ("a", ctypes.c_int32),
+]
+
+PREFIXFoo._fields_ = [
+  ("a", ctypes.c_int32),
+  ("b", ctypes.c_uint32),
+  ("bar", PREFIXBar),
+]
+
+PREFIXVAL = PREFIXFoo(a=42, b=1337, bar=PREFIXBar(a=323))
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [PREFIXFoo]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array(uint n, IntPtr arg, IntPtr v);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array1(uint n, IntPtr arg, IntPtr v);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array2(uint n, IntPtr arg, IntPtr v);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array_wrong_syntax(IntPtr arg, IntPtr v, IntPtr arg2);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array_unnamed(IntPtr arg0, IntPtr arg1);
+}
@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub extern fn ptr_as_array(n: u32, arg: ?*const [3]u32, v: ?*const u64) void;
+
+pub extern fn ptr_as_array1(n: u32, arg: ?*const [3]u32, v: ?*[4]u64) void;
+
+pub extern fn ptr_as_array2(n: u32, arg: ?[*]u32, v: ?[*]const u64) void;
+
+pub extern fn ptr_as_array_wrong_syntax(arg: ?*u32, v: ?*const u32, _: ?*const u32) void;
+
+pub extern fn ptr_as_array_unnamed(_: ?*u32, _: ?*const u32) void;
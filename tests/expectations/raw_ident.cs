@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof(NativeMethods).Assembly, null), name);
+
+  public enum Enum : byte {
+    a,
+    b,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Struct {
+    public Enum field;
+  }
+
+  public static IntPtr STATIC => __GetExport("STATIC");
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void fn(Struct arg);
+}
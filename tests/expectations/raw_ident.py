@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+class Struct(ctypes.Structure):
+  pass
+
+class Enum(enum.IntEnum):
+  a = 0
+  b = enum.auto()
+
+Struct._fields_ = [
+  ("field", ctypes.c_uint8),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.STATIC = ctypes.c_uint8.in_dll(lib, "STATIC")
+
+  lib.fn.argtypes = [Struct]
+  lib.fn.restype = None
+
+  return lib
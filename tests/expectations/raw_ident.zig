@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub const Enum = enum(u8) {
+  a,
+  b,
+};
+
+pub const Struct = extern struct {
+  field: Enum,
+};
+
+pub extern const STATIC: Enum;
+
+pub extern fn fn(arg: Struct) void;
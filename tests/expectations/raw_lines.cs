@@ -0,0 +1,10 @@
+using System;
+using System.Runtime.InteropServices;
+#define VERSION 1
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+}
@@ -0,0 +1,11 @@
+import ctypes
+import enum
+#define VERSION 1
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,38 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof(NativeMethods).Assembly, null), name);
+
+  public const int C_H = 10;
+
+  public enum C_E : byte {
+    x = 0,
+    y = 1,
+  }
+
+  public struct C_A { }
+
+  public struct C_C { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct C_AwesomeB {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct C_D {
+    [FieldOffset(0)] public int x;
+    [FieldOffset(0)] public float y;
+  }
+
+  public const nint C_I = unchecked((nint)unchecked((IntPtr)10));
+
+  public static IntPtr G => __GetExport("G");
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, C_AwesomeB b, C_C c, C_D d, C_E e, C_A f);
+}
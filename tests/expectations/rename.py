@@ -0,0 +1,44 @@
+import ctypes
+import enum
+
+C_H = 10
+
+class C_AwesomeB(ctypes.Structure):
+  pass
+
+class C_D(ctypes.Union):
+  pass
+
+class C_E(enum.IntEnum):
+  x = 0
+  y = 1
+
+class C_A(ctypes.Structure):
+  pass
+
+class C_C(ctypes.Structure):
+  pass
+
+C_AwesomeB._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+C_D._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+C_F = C_A
+
+C_I = ctypes.c_ssize_t(int(ctypes.c_void_p(10).value)).value
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.G = ctypes.c_int32.in_dll(lib, "G")
+
+  lib.root.argtypes = [ctypes.POINTER(C_A), C_AwesomeB, C_C, C_D, ctypes.c_uint8, C_F]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.test_camel_case.argtypes = [ctypes.c_int32]
+  lib.test_camel_case.restype = None
+
+  lib.test_pascal_case.argtypes = [ctypes.c_int32]
+  lib.test_pascal_case.restype = None
+
+  lib.test_snake_case.argtypes = [ctypes.c_int32]
+  lib.test_snake_case.restype = None
+
+  lib.test_screaming_snake_case.argtypes = [ctypes.c_int32]
+  lib.test_screaming_snake_case.restype = None
+
+  lib.test_gecko_case.argtypes = [ctypes.c_int32]
+  lib.test_gecko_case.restype = None
+
+  return lib
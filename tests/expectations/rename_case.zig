@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub extern fn test_camel_case(fooBar: i32) void;
+
+pub extern fn test_pascal_case(FooBar: i32) void;
+
+pub extern fn test_snake_case(foo_bar: i32) void;
+
+pub extern fn test_screaming_snake_case(FOO_BAR: i32) void;
+
+pub extern fn test_gecko_case(aFooBar: i32) void;
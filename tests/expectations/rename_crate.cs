@@ -0,0 +1,51 @@
+#if 0
+DEF DEFINE_FREEBSD = 0
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "rename_crate";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+    public int x;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct RenamedTy {
+    public ulong y;
+  }
+
+#if !DEFINE_FREEBSD
+  [StructLayout(LayoutKind.Sequential)]
+  public struct NoExternTy {
+    public byte field;
+  }
+#endif
+
+#if !DEFINE_FREEBSD
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ContainsNoExternTy {
+    public NoExternTy field;
+  }
+#endif
+
+#if DEFINE_FREEBSD
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ContainsNoExternTy {
+    public ulong field;
+  }
+#endif
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo a);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void renamed_func(RenamedTy a);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void no_extern_func(ContainsNoExternTy a);
+}
@@ -0,0 +1,32 @@
+#if 0
+DEF DEFINE_FREEBSD = 0
+#endif
+
+
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+class RenamedTy(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.c_int32),
+]
+
+RenamedTy._fields_ = [
+  ("y", ctypes.c_uint64),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Foo]
+  lib.root.restype = None
+
+  lib.renamed_func.argtypes = [RenamedTy]
+  lib.renamed_func.restype = None
+
+  return lib
@@ -0,0 +1,18 @@
+#if 0
+DEF DEFINE_FREEBSD = 0
+#endif
+
+
+const std = @import("std");
+
+pub const Foo = extern struct {
+  x: i32,
+};
+
+pub const RenamedTy = extern struct {
+  y: u64,
+};
+
+pub extern fn root(a: Foo) void;
+
+pub extern fn renamed_func(a: RenamedTy) void;
@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct StyleA { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B {
+    public int x;
+    public float y;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, B b);
+}
@@ -0,0 +1,21 @@
+import ctypes
+import enum
+
+class B(ctypes.Structure):
+  pass
+
+class StyleA(ctypes.Structure):
+  pass
+
+B._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(StyleA), B]
+  lib.root.restype = None
+
+  return lib
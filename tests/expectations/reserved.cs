@@ -0,0 +1,96 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct A {
+    public int @namespace;
+    public float @float;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct B {
+    public int @namespace;
+    public float @float;
+  }
+
+  public enum C_Tag : byte {
+    D,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct D_Body {
+    public int @namespace;
+    public float @float;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct C {
+    public C_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public D_Body d;
+    }
+  }
+
+  public enum E_Tag : byte {
+    Double,
+    Float,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Double_Body {
+    public double _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Float_Body {
+    public float _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct E {
+    public E_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public Double_Body @double;
+      [FieldOffset(0)] public Float_Body @float;
+    }
+  }
+
+  public enum F_Tag : byte {
+    @double,
+    @float,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct double_Body {
+    public double _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct float_Body {
+    public float _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct F {
+    public F_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public double_Body @double;
+      [FieldOffset(0)] public float_Body @float;
+    }
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A a, B b, C c, E e, F f, int @namespace, float @float);
+}
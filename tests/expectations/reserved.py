@@ -0,0 +1,87 @@
+import ctypes
+import enum
+
+class A(ctypes.Structure):
+  pass
+
+class B(ctypes.Structure):
+  pass
+
+class C(ctypes.Structure):
+  pass
+
+class E(ctypes.Structure):
+  pass
+
+class F(ctypes.Structure):
+  pass
+
+A._fields_ = [
+  ("namespace", ctypes.c_int32),
+  ("float", ctypes.c_float),
+]
+
+B._fields_ = [
+  ("namespace", ctypes.c_int32),
+  ("float", ctypes.c_float),
+]
+
+class C_Tag(enum.IntEnum):
+  D = 0
+
+class D_Body(ctypes.Structure):
+  _fields_ = [
+    ("namespace", ctypes.c_int32),
+    ("float", ctypes.c_float),
+  ]
+
+class _C_Body(ctypes.Union):
+  _fields_ = [
+    ("d", D_Body),
+  ]
+
+C._anonymous_ = ["_body"]
+C._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _C_Body),
+]
+
+class E_Tag(enum.IntEnum):
+  Double = 0
+  Float = enum.auto()
+
+class _E_Body(ctypes.Union):
+  _fields_ = [
+    ("double", ctypes.c_double),
+    ("float", ctypes.c_float),
+  ]
+
+E._anonymous_ = ["_body"]
+E._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _E_Body),
+]
+
+class F_Tag(enum.IntEnum):
+  double = 0
+  float = enum.auto()
+
+class _F_Body(ctypes.Union):
+  _fields_ = [
+    ("double", ctypes.c_double),
+    ("float", ctypes.c_float),
+  ]
+
+F._anonymous_ = ["_body"]
+F._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _F_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [A, B, C, E, F, ctypes.c_int32, ctypes.c_float]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,55 @@
+const std = @import("std");
+
+pub const A = extern struct {
+  namespace: i32,
+  float: f32,
+};
+
+pub const B = extern struct {
+  namespace: i32,
+  float: f32,
+};
+
+pub const C_Tag = enum(u8) {
+  D,
+};
+
+pub const D_Body = extern struct {
+  namespace: i32,
+  float: f32,
+};
+
+pub const C = extern struct {
+  tag: C_Tag,
+  body: extern union {
+    d: D_Body,
+  },
+};
+
+pub const E_Tag = enum(u8) {
+  Double,
+  Float,
+};
+
+pub const E = extern struct {
+  tag: E_Tag,
+  body: extern union {
+    double: f64,
+    float: f32,
+  },
+};
+
+pub const F_Tag = enum(u8) {
+  double,
+  float,
+};
+
+pub const F = extern struct {
+  tag: F_Tag,
+  body: extern union {
+    double: f64,
+    float: f32,
+  },
+};
+
+pub extern fn root(a: A, b: B, c: C, e: E, f: F, namespace: i32, float: f32) void;
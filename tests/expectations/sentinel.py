@@ -0,0 +1,52 @@
+import ctypes
+import enum
+
+class C(ctypes.Union):
+  pass
+
+class A(enum.IntEnum):
+  A_A1 = 0
+  A_A2 = enum.auto()
+  A_A3 = enum.auto()
+  # Must be last for serialization purposes
+  A_Sentinel = enum.auto()
+
+class B(enum.IntEnum):
+  B_B1 = 0
+  B_B2 = enum.auto()
+  B_B3 = enum.auto()
+  # Must be last for serialization purposes
+  B_Sentinel = enum.auto()
+
+class C_Tag(enum.IntEnum):
+  C_C1 = 0
+  C_C2 = enum.auto()
+  C_C3 = enum.auto()
+  # Must be last for serialization purposes
+  C_Sentinel = enum.auto()
+
+class C_C1_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("a", ctypes.c_uint32),
+  ]
+
+class C_C2_Body(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("b", ctypes.c_uint32),
+  ]
+
+C._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("c1", C_C1_Body),
+  ("c2", C_C2_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.c_uint8, ctypes.c_uint8, C]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,43 @@
+const std = @import("std");
+
+pub const A = enum(u8) {
+  A_A1,
+  A_A2,
+  A_A3,
+  /// Must be last for serialization purposes
+  A_Sentinel,
+};
+
+pub const B = enum(u8) {
+  B_B1,
+  B_B2,
+  B_B3,
+  /// Must be last for serialization purposes
+  B_Sentinel,
+};
+
+pub const C_Tag = enum(u8) {
+  C_C1,
+  C_C2,
+  C_C3,
+  /// Must be last for serialization purposes
+  C_Sentinel,
+};
+
+pub const C_C1_Body = extern struct {
+  tag: C_Tag,
+  a: u32,
+};
+
+pub const C_C2_Body = extern struct {
+  tag: C_Tag,
+  b: u32,
+};
+
+pub const C = extern union {
+  tag: C_Tag,
+  c1: C_C1_Body,
+  c2: C_C2_Body,
+};
+
+pub extern fn root(a: A, b: B, c: C) void;
@@ -0,0 +1,29 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Opaque { }
+
+  public struct Option_____Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+    public IntPtr x;
+    public IntPtr y;
+    public IntPtr z;
+    public IntPtr zz;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Bar {
+    [FieldOffset(0)] public IntPtr x;
+    [FieldOffset(0)] public IntPtr y;
+    [FieldOffset(0)] public IntPtr z;
+    [FieldOffset(0)] public IntPtr zz;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, IntPtr b, Foo c, Bar d, IntPtr e, IntPtr f);
+}
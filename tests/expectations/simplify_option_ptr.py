@@ -0,0 +1,43 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+class Bar(ctypes.Union):
+  pass
+
+class Opaque(ctypes.Structure):
+  pass
+
+class Option_____Opaque(ctypes.Structure):
+  pass
+
+Foo._fields_ = [
+  ("x", ctypes.POINTER(Opaque)),
+  ("y", ctypes.POINTER(Opaque)),
+  ("z", ctypes.CFUNCTYPE(None)),
+  ("zz", ctypes.POINTER(ctypes.CFUNCTYPE(None))),
+]
+
+Bar._fields_ = [
+  ("x", ctypes.POINTER(Opaque)),
+  ("y", ctypes.POINTER(Opaque)),
+  ("z", ctypes.CFUNCTYPE(None)),
+  ("zz", ctypes.POINTER(ctypes.CFUNCTYPE(None))),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Opaque),
+    ctypes.POINTER(Opaque),
+    Foo,
+    Bar,
+    ctypes.POINTER(Option_____Opaque),
+    ctypes.CFUNCTYPE(None, ctypes.POINTER(Opaque)),
+  ]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const Opaque = opaque {};
+
+pub const Option_____Opaque = opaque {};
+
+pub const Foo = extern struct {
+  x: ?*const Opaque,
+  y: ?*Opaque,
+  z: ?*const fn () callconv(.C) void,
+  zz: ?*?*const fn () callconv(.C) void,
+};
+
+pub const Bar = extern union {
+  x: ?*const Opaque,
+  y: ?*Opaque,
+  z: ?*const fn () callconv(.C) void,
+  zz: ?*?*const fn () callconv(.C) void,
+};
+
+pub extern fn root(
+  a: ?*const Opaque,
+  b: ?*Opaque,
+  c: Foo,
+  d: Bar,
+  e: ?*Option_____Opaque,
+  f: *const fn (?*const Opaque) callconv(.C) void,
+) void;
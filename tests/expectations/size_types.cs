@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public enum IE : long {
+    IV,
+  }
+
+  public enum UE : ulong {
+    UV,
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(nuint arg0, nint arg1, UE arg2, IE arg3);
+}
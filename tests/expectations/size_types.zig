@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const IE = enum(isize) {
+  IV,
+};
+
+pub const UE = enum(usize) {
+  UV,
+};
+
+pub const Usize = usize;
+
+pub const Isize = isize;
+
+pub extern fn root(_: Usize, _: Isize, _: UE, _: IE) void;
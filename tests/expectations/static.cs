@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof(NativeMethods).Assembly, null), name);
+
+  public struct Bar { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo {
+
+  }
+
+  public static IntPtr NUMBER => __GetExport("NUMBER");
+
+  public static IntPtr FOO => __GetExport("FOO");
+
+  public static IntPtr BAR => __GetExport("BAR");
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+}
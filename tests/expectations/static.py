@@ -0,0 +1,24 @@
+import ctypes
+import enum
+
+class Foo(ctypes.Structure):
+  pass
+
+class Bar(ctypes.Structure):
+  pass
+
+Foo._fields_ = []
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.NUMBER = ctypes.c_int32.in_dll(lib, "NUMBER")
+
+  lib.FOO = Foo.in_dll(lib, "FOO")
+
+  lib.BAR = Bar.in_dll(lib, "BAR")
+
+  lib.root.argtypes = []
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,23 @@
+import ctypes
+import enum
+
+class Option_i32(ctypes.Structure):
+  pass
+
+class Result_i32__String(ctypes.Structure):
+  pass
+
+class Vec_String(ctypes.Structure):
+  pass
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    ctypes.POINTER(Vec_String),
+    ctypes.POINTER(Option_i32),
+    ctypes.POINTER(Result_i32__String),
+  ]
+  lib.root.restype = None
+
+  return lib
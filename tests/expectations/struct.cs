@@ -0,0 +1,35 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Normal {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct NormalWithZST {
+    public int x;
+    public float y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TupleRenamed {
+    public int m0;
+    public float m1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct TupleNamed {
+    public int x;
+    public float y;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, Normal b, NormalWithZST c, TupleRenamed d, TupleNamed e);
+}
@@ -0,0 +1,45 @@
+import ctypes
+import enum
+
+class Normal(ctypes.Structure):
+  pass
+
+class NormalWithZST(ctypes.Structure):
+  pass
+
+class TupleRenamed(ctypes.Structure):
+  pass
+
+class TupleNamed(ctypes.Structure):
+  pass
+
+class Opaque(ctypes.Structure):
+  pass
+
+Normal._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+NormalWithZST._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+TupleRenamed._fields_ = [
+  ("m0", ctypes.c_int32),
+  ("m1", ctypes.c_float),
+]
+
+TupleNamed._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), Normal, NormalWithZST, TupleRenamed, TupleNamed]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,29 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ABC {
+    public float a;
+    public uint b;
+    public uint c;
+  }
+  public static readonly ABC ABC_abc = new ABC { a = 1.0, b = 2, c = 3 };
+  public static readonly ABC ABC_bac = new ABC { a = 1.0, b = 2, c = 3 };
+  public static readonly ABC ABC_cba = new ABC { a = 1.0, b = 2, c = 3 };
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct BAC {
+    public uint b;
+    public float a;
+    public int c;
+  }
+  public static readonly BAC BAC_abc = new BAC { b = 1, a = 2.0, c = 3 };
+  public static readonly BAC BAC_bac = new BAC { b = 1, a = 2.0, c = 3 };
+  public static readonly BAC BAC_cba = new BAC { b = 1, a = 2.0, c = 3 };
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(ABC a1, BAC a2);
+}
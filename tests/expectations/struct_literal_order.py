@@ -0,0 +1,34 @@
+import ctypes
+import enum
+
+class ABC(ctypes.Structure):
+  pass
+
+class BAC(ctypes.Structure):
+  pass
+
+ABC._fields_ = [
+  ("a", ctypes.c_float),
+  ("b", ctypes.c_uint32),
+  ("c", ctypes.c_uint32),
+]
+ABC_abc = ABC(a=1.0, b=2, c=3)
+ABC_bac = ABC(a=1.0, b=2, c=3)
+ABC_cba = ABC(a=1.0, b=2, c=3)
+
+BAC._fields_ = [
+  ("b", ctypes.c_uint32),
+  ("a", ctypes.c_float),
+  ("c", ctypes.c_int32),
+]
+BAC_abc = BAC(b=1, a=2.0, c=3)
+BAC_bac = BAC(b=1, a=2.0, c=3)
+BAC_cba = BAC(b=1, a=2.0, c=3)
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ABC, BAC]
+  lib.root.restype = None
+
+  return lib
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Foo_Bar {
+    public IntPtr something;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Bar {
+    public int something;
+    public Foo_Bar subexpressions;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Bar b);
+}
@@ -0,0 +1,25 @@
+import ctypes
+import enum
+
+class Foo_Bar(ctypes.Structure):
+  pass
+
+class Bar(ctypes.Structure):
+  pass
+
+Foo_Bar._fields_ = [
+  ("something", ctypes.POINTER(ctypes.c_int32)),
+]
+
+Bar._fields_ = [
+  ("something", ctypes.c_int32),
+  ("subexpressions", Foo_Bar),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [Bar]
+  lib.root.restype = None
+
+  return lib
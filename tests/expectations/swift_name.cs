@@ -0,0 +1,77 @@
+#define CF_SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct SelfTypeTestStruct {
+    public byte times;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct PointerToOpaque {
+    public IntPtr ptr;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void rust_print_hello_world();
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_ref(IntPtr self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_ref_mut(IntPtr self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_not_exist_box(IntPtr self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern IntPtr SelfTypeTestStruct_should_not_exist_return_box();
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_self(SelfTypeTestStruct self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_mut_self(SelfTypeTestStruct self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_by_name(SelfTypeTestStruct self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_annotated_mut_by_name(SelfTypeTestStruct self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_unannotated(SelfTypeTestStruct self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void SelfTypeTestStruct_should_exist_mut_unannotated(SelfTypeTestStruct self);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_ref(IntPtr test_struct);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_ref_mut(IntPtr test_struct);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void unnamed_argument(IntPtr arg0);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_not_exist_box(IntPtr boxed);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_annotated_by_name(SelfTypeTestStruct test_struct);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void free_function_should_exist_annotated_mut_by_name(SelfTypeTestStruct test_struct);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern PointerToOpaque PointerToOpaque_create(byte times);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void PointerToOpaque_sayHello(PointerToOpaque self);
+}
@@ -0,0 +1,191 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StylePoint_i32 {
+    public int x;
+    public int y;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StylePoint_f32 {
+    public float x;
+    public float y;
+  }
+
+  public enum StyleFoo_i32_Tag : byte {
+    Foo_i32,
+    Bar_i32,
+    Baz_i32,
+    Bazz_i32,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleFoo_Body_i32 {
+    public StyleFoo_i32_Tag tag;
+    public int x;
+    public StylePoint_i32 y;
+    public StylePoint_f32 z;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar_Body_i32 {
+    public StyleFoo_i32_Tag tag;
+    public int _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBaz_Body_i32 {
+    public StyleFoo_i32_Tag tag;
+    public StylePoint_i32 _0;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct StyleFoo_i32 {
+    [FieldOffset(0)] public StyleFoo_i32_Tag tag;
+    [FieldOffset(0)] public StyleFoo_Body_i32 foo;
+    [FieldOffset(0)] public StyleBar_Body_i32 bar;
+    [FieldOffset(0)] public StyleBaz_Body_i32 baz;
+  }
+
+  public enum StyleBar_i32_Tag {
+    Bar1_i32,
+    Bar2_i32,
+    Bar3_i32,
+    Bar4_i32,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar1_Body_i32 {
+    public int x;
+    public StylePoint_i32 y;
+    public StylePoint_f32 z;
+    public IntPtr u;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar2_Body_i32 {
+    public int _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar3_Body_i32 {
+    public StylePoint_i32 _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar_i32 {
+    public StyleBar_i32_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public StyleBar1_Body_i32 bar1;
+      [FieldOffset(0)] public StyleBar2_Body_i32 bar2;
+      [FieldOffset(0)] public StyleBar3_Body_i32 bar3;
+    }
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StylePoint_u32 {
+    public uint x;
+    public uint y;
+  }
+
+  public enum StyleBar_u32_Tag {
+    Bar1_u32,
+    Bar2_u32,
+    Bar3_u32,
+    Bar4_u32,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar1_Body_u32 {
+    public int x;
+    public StylePoint_u32 y;
+    public StylePoint_f32 z;
+    public IntPtr u;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar2_Body_u32 {
+    public uint _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar3_Body_u32 {
+    public StylePoint_u32 _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBar_u32 {
+    public StyleBar_u32_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public StyleBar1_Body_u32 bar1;
+      [FieldOffset(0)] public StyleBar2_Body_u32 bar2;
+      [FieldOffset(0)] public StyleBar3_Body_u32 bar3;
+    }
+  }
+
+  public enum StyleBaz_Tag : byte {
+    Baz1,
+    Baz2,
+    Baz3,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBaz1_Body {
+    public StyleBaz_Tag tag;
+    public StyleBar_u32 _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleBaz2_Body {
+    public StyleBaz_Tag tag;
+    public StylePoint_i32 _0;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct StyleBaz {
+    [FieldOffset(0)] public StyleBaz_Tag tag;
+    [FieldOffset(0)] public StyleBaz1_Body baz1;
+    [FieldOffset(0)] public StyleBaz2_Body baz2;
+  }
+
+  public enum StyleTaz_Tag : byte {
+    Taz1,
+    Taz2,
+    Taz3,
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleTaz1_Body {
+    public StyleBar_u32 _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleTaz2_Body {
+    public StyleBaz _0;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct StyleTaz {
+    public StyleTaz_Tag tag;
+    public Body body;
+
+    [StructLayout(LayoutKind.Explicit)]
+    public struct Body {
+      [FieldOffset(0)] public StyleTaz1_Body taz1;
+      [FieldOffset(0)] public StyleTaz2_Body taz2;
+    }
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo(IntPtr foo, IntPtr bar, IntPtr baz, IntPtr taz);
+}
@@ -0,0 +1,183 @@
+import ctypes
+import enum
+
+class StylePoint_i32(ctypes.Structure):
+  pass
+
+class StylePoint_f32(ctypes.Structure):
+  pass
+
+class StyleFoo_i32(ctypes.Union):
+  pass
+
+class StyleBar_i32(ctypes.Structure):
+  pass
+
+class StylePoint_u32(ctypes.Structure):
+  pass
+
+class StyleBar_u32(ctypes.Structure):
+  pass
+
+class StyleBaz(ctypes.Union):
+  pass
+
+class StyleTaz(ctypes.Structure):
+  pass
+
+StylePoint_i32._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_int32),
+]
+
+StylePoint_f32._fields_ = [
+  ("x", ctypes.c_float),
+  ("y", ctypes.c_float),
+]
+
+class StyleFoo_i32_Tag(enum.IntEnum):
+  Foo_i32 = 0
+  Bar_i32 = enum.auto()
+  Baz_i32 = enum.auto()
+  Bazz_i32 = enum.auto()
+
+class StyleFoo_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("tag", ctypes.c_uint8),
+    ("x", ctypes.c_int32),
+    ("y", StylePoint_i32),
+    ("z", StylePoint_f32),
+  ]
+
+class _StyleFoo_i32_bar(ctypes.Structure):
+  _fields_ = [
+    ("bar_tag", ctypes.c_uint8),
+    ("bar", ctypes.c_int32),
+  ]
+
+class _StyleFoo_i32_baz(ctypes.Structure):
+  _fields_ = [
+    ("baz_tag", ctypes.c_uint8),
+    ("baz", StylePoint_i32),
+  ]
+
+StyleFoo_i32._anonymous_ = ["_bar", "_baz"]
+StyleFoo_i32._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("foo", StyleFoo_Body_i32),
+  ("_bar", _StyleFoo_i32_bar),
+  ("_baz", _StyleFoo_i32_baz),
+]
+
+class StyleBar_i32_Tag(enum.IntEnum):
+  Bar1_i32 = 0
+  Bar2_i32 = enum.auto()
+  Bar3_i32 = enum.auto()
+  Bar4_i32 = enum.auto()
+
+class StyleBar1_Body_i32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", StylePoint_i32),
+    ("z", StylePoint_f32),
+    ("u", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32)),
+  ]
+
+class _StyleBar_i32_Body(ctypes.Union):
+  _fields_ = [
+    ("bar1", StyleBar1_Body_i32),
+    ("bar2", ctypes.c_int32),
+    ("bar3", StylePoint_i32),
+  ]
+
+StyleBar_i32._anonymous_ = ["_body"]
+StyleBar_i32._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _StyleBar_i32_Body),
+]
+
+StylePoint_u32._fields_ = [
+  ("x", ctypes.c_uint32),
+  ("y", ctypes.c_uint32),
+]
+
+class StyleBar_u32_Tag(enum.IntEnum):
+  Bar1_u32 = 0
+  Bar2_u32 = enum.auto()
+  Bar3_u32 = enum.auto()
+  Bar4_u32 = enum.auto()
+
+class StyleBar1_Body_u32(ctypes.Structure):
+  _fields_ = [
+    ("x", ctypes.c_int32),
+    ("y", StylePoint_u32),
+    ("z", StylePoint_f32),
+    ("u", ctypes.CFUNCTYPE(ctypes.c_int32, ctypes.c_int32)),
+  ]
+
+class _StyleBar_u32_Body(ctypes.Union):
+  _fields_ = [
+    ("bar1", StyleBar1_Body_u32),
+    ("bar2", ctypes.c_uint32),
+    ("bar3", StylePoint_u32),
+  ]
+
+StyleBar_u32._anonymous_ = ["_body"]
+StyleBar_u32._fields_ = [
+  ("tag", ctypes.c_int),
+  ("_body", _StyleBar_u32_Body),
+]
+
+class StyleBaz_Tag(enum.IntEnum):
+  Baz1 = 0
+  Baz2 = enum.auto()
+  Baz3 = enum.auto()
+
+class _StyleBaz_baz1(ctypes.Structure):
+  _fields_ = [
+    ("baz1_tag", ctypes.c_uint8),
+    ("baz1", StyleBar_u32),
+  ]
+
+class _StyleBaz_baz2(ctypes.Structure):
+  _fields_ = [
+    ("baz2_tag", ctypes.c_uint8),
+    ("baz2", StylePoint_i32),
+  ]
+
+StyleBaz._anonymous_ = ["_baz1", "_baz2"]
+StyleBaz._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_baz1", _StyleBaz_baz1),
+  ("_baz2", _StyleBaz_baz2),
+]
+
+class StyleTaz_Tag(enum.IntEnum):
+  Taz1 = 0
+  Taz2 = enum.auto()
+  Taz3 = enum.auto()
+
+class _StyleTaz_Body(ctypes.Union):
+  _fields_ = [
+    ("taz1", StyleBar_u32),
+    ("taz2", StyleBaz),
+  ]
+
+StyleTaz._anonymous_ = ["_body"]
+StyleTaz._fields_ = [
+  ("tag", ctypes.c_uint8),
+  ("_body", _StyleTaz_Body),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.foo.argtypes = [
+    ctypes.POINTER(StyleFoo_i32),
+    ctypes.POINTER(StyleBar_i32),
+    ctypes.POINTER(StyleBaz),
+    ctypes.POINTER(StyleTaz),
+  ]
+  lib.foo.restype = None
+
+  return lib
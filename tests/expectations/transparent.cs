@@ -0,0 +1,25 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct DummyStruct { }
+
+  public struct EnumWithAssociatedConstantInImpl { }
+
+  public const uint TransparentPrimitiveWithAssociatedConstants_ZERO = 0;
+  public const uint TransparentPrimitiveWithAssociatedConstants_ONE = 1;
+
+  public const uint EnumWithAssociatedConstantInImpl_TEN = 10;
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(DummyStruct a,
+                                 uint b,
+                                 DummyStruct c,
+                                 uint d,
+                                 DummyStruct e,
+                                 uint f,
+                                 uint g,
+                                 EnumWithAssociatedConstantInImpl h);
+}
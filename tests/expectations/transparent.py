@@ -0,0 +1,43 @@
+import ctypes
+import enum
+
+class DummyStruct(ctypes.Structure):
+  pass
+
+class EnumWithAssociatedConstantInImpl(ctypes.Structure):
+  pass
+
+TransparentComplexWrappingStructTuple = DummyStruct
+
+TransparentPrimitiveWrappingStructTuple = ctypes.c_uint32
+
+TransparentComplexWrappingStructure = DummyStruct
+
+TransparentPrimitiveWrappingStructure = ctypes.c_uint32
+
+TransparentComplexWrapper_i32 = DummyStruct
+
+TransparentPrimitiveWrapper_i32 = ctypes.c_uint32
+
+TransparentPrimitiveWithAssociatedConstants = ctypes.c_uint32
+TransparentPrimitiveWithAssociatedConstants_ZERO = 0
+TransparentPrimitiveWithAssociatedConstants_ONE = 1
+
+EnumWithAssociatedConstantInImpl_TEN = 10
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [
+    TransparentComplexWrappingStructTuple,
+    TransparentPrimitiveWrappingStructTuple,
+    TransparentComplexWrappingStructure,
+    TransparentPrimitiveWrappingStructure,
+    TransparentComplexWrapper_i32,
+    TransparentPrimitiveWrapper_i32,
+    TransparentPrimitiveWithAssociatedConstants,
+    EnumWithAssociatedConstantInImpl,
+  ]
+  lib.root.restype = None
+
+  return lib
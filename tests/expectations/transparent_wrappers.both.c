@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counters {
+  uint32_t hits;
+  uint64_t misses;
+  int32_t shared;
+  uint8_t maybe;
+  uint16_t external;
+} Counters;
+
+void counters_reset(struct Counters *counters, uint32_t seed);
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counters {
+  uint32_t hits;
+  uint64_t misses;
+  int32_t shared;
+  uint8_t maybe;
+  uint16_t external;
+} Counters;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void counters_reset(struct Counters *counters, uint32_t seed);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
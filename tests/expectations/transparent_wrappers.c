@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t hits;
+  uint64_t misses;
+  int32_t shared;
+  uint8_t maybe;
+  uint16_t external;
+} Counters;
+
+void counters_reset(Counters *counters, uint32_t seed);
@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Counters {
+  uint32_t hits;
+  uint64_t misses;
+  int32_t shared;
+  uint8_t maybe;
+  uint16_t external;
+};
+
+extern "C" {
+
+void counters_reset(Counters *counters, uint32_t seed);
+
+} // extern "C"
@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Counters {
+    public uint hits;
+    public ulong misses;
+    public int shared;
+    public byte maybe;
+    public ushort external;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void counters_reset(IntPtr counters, uint seed);
+}
@@ -0,0 +1,21 @@
+import ctypes
+import enum
+
+class Counters(ctypes.Structure):
+  pass
+
+Counters._fields_ = [
+  ("hits", ctypes.c_uint32),
+  ("misses", ctypes.c_uint64),
+  ("shared", ctypes.c_int32),
+  ("maybe", ctypes.c_uint8),
+  ("external", ctypes.c_uint16),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.counters_reset.argtypes = [ctypes.POINTER(Counters), ctypes.c_uint32]
+  lib.counters_reset.restype = None
+
+  return lib
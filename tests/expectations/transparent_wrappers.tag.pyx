@@ -0,0 +1,16 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Counters:
+    uint32_t hits;
+    uint64_t misses;
+    int32_t shared;
+    uint8_t maybe;
+    uint16_t external;
+
+  void counters_reset(Counters *counters, uint32_t seed);
@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const Counters = extern struct {
+  hits: u32,
+  misses: u64,
+  shared: i32,
+  maybe: u8,
+  external: u16,
+};
+
+pub extern fn counters_reset(counters: *Counters, seed: u32) void;
@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const Foo_i32__i32 = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const IntFoo_i32 = Foo_i32__i32;
+
+pub extern fn root(a: IntFoo_i32) void;
@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  public struct Opaque { }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct Normal {
+    [FieldOffset(0)] public int x;
+    [FieldOffset(0)] public float y;
+  }
+
+  [StructLayout(LayoutKind.Explicit)]
+  public struct NormalWithZST {
+    [FieldOffset(0)] public int x;
+    [FieldOffset(0)] public float y;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(IntPtr a, Normal b, NormalWithZST c);
+}
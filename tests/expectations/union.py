@@ -0,0 +1,29 @@
+import ctypes
+import enum
+
+class Normal(ctypes.Union):
+  pass
+
+class NormalWithZST(ctypes.Union):
+  pass
+
+class Opaque(ctypes.Structure):
+  pass
+
+Normal._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+NormalWithZST._fields_ = [
+  ("x", ctypes.c_int32),
+  ("y", ctypes.c_float),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.POINTER(Opaque), Normal, NormalWithZST]
+  lib.root.restype = None
+
+  return lib
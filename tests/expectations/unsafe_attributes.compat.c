@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t NUMBER;
+
+extern const int32_t RENAMED_STATIC;
+
+extern int64_t OLD_STYLE_RENAMED_STATIC;
+
+uint32_t root(uint32_t x);
+
+void renamed_fn(void);
+
+void foo_method(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+extern const uint32_t NUMBER;
+
+extern const int32_t RENAMED_STATIC;
+
+extern int64_t OLD_STYLE_RENAMED_STATIC;
+
+uint32_t root(uint32_t x);
+
+void renamed_fn();
+
+void foo_method();
+
+} // extern "C"
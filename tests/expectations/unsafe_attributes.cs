@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  static IntPtr __GetExport(string name) => NativeLibrary.GetExport(NativeLibrary.Load(__DllName, typeof(NativeMethods).Assembly, null), name);
+
+  public static IntPtr NUMBER => __GetExport("NUMBER");
+
+  public static IntPtr RENAMED_STATIC => __GetExport("RENAMED_STATIC");
+
+  public static IntPtr OLD_STYLE_RENAMED_STATIC => __GetExport("OLD_STYLE_RENAMED_STATIC");
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern uint root(uint x);
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void renamed_fn();
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo_method();
+}
@@ -0,0 +1,22 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.NUMBER = ctypes.c_uint32.in_dll(lib, "NUMBER")
+
+  lib.RENAMED_STATIC = ctypes.c_int32.in_dll(lib, "RENAMED_STATIC")
+
+  lib.OLD_STYLE_RENAMED_STATIC = ctypes.c_int64.in_dll(lib, "OLD_STYLE_RENAMED_STATIC")
+
+  lib.root.argtypes = [ctypes.c_uint32]
+  lib.root.restype = ctypes.c_uint32
+
+  lib.renamed_fn.argtypes = []
+  lib.renamed_fn.restype = None
+
+  lib.foo_method.argtypes = []
+  lib.foo_method.restype = None
+
+  return lib
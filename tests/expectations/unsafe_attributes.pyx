@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  extern const uint32_t NUMBER;
+
+  extern const int32_t RENAMED_STATIC;
+
+  extern int64_t OLD_STYLE_RENAMED_STATIC;
+
+  uint32_t root(uint32_t x);
+
+  void renamed_fn();
+
+  void foo_method();
@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub extern const NUMBER: u32;
+
+pub extern const RENAMED_STATIC: i32;
+
+pub extern var OLD_STYLE_RENAMED_STATIC: i64;
+
+pub extern fn root(x: u32) u32;
+
+pub extern fn renamed_fn() void;
+
+pub extern fn foo_method() void;
@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t id;
+} Handle;
+
+/**
+ * The handle of the facade.
+ */
+typedef Handle MyHandle;
+
+typedef struct {
+  float first;
+  float second;
+} Pair_f32;
+
+typedef Pair_f32 MyPair_f32;
+
+typedef struct {
+  uint8_t data[4];
+} Buffer_u8__4;
+
+typedef Buffer_u8__4 MyBuffer_u8__4;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(MyHandle a, MyPair_f32 b, const MyBuffer_u8__4 *c);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
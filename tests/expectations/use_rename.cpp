@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Handle {
+  uint32_t id;
+};
+
+/// The handle of the facade.
+using MyHandle = Handle;
+
+template<typename T>
+struct Pair {
+  T first;
+  T second;
+};
+
+template<typename T>
+using MyPair = Pair<T>;
+
+template<typename T, uintptr_t N>
+struct Buffer {
+  T data[N];
+};
+
+template<typename T, uintptr_t N>
+using MyBuffer = Buffer<T, N>;
+
+extern "C" {
+
+void root(MyHandle a, MyPair<float> b, const MyBuffer<uint8_t, 4> *c);
+
+} // extern "C"
@@ -0,0 +1,25 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "__Internal";
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Handle {
+    public uint id;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Pair_f32 {
+    public float first;
+    public float second;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct Buffer_u8__4 {
+    [MarshalAs(UnmanagedType.ByValArray, SizeConst = 4)] public byte[] data;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Handle a, Pair_f32 b, IntPtr c);
+}
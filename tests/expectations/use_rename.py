@@ -0,0 +1,39 @@
+import ctypes
+import enum
+
+class Handle(ctypes.Structure):
+  pass
+
+class Pair_f32(ctypes.Structure):
+  pass
+
+class Buffer_u8__4(ctypes.Structure):
+  pass
+
+Handle._fields_ = [
+  ("id", ctypes.c_uint32),
+]
+
+# The handle of the facade.
+MyHandle = Handle
+
+Pair_f32._fields_ = [
+  ("first", ctypes.c_float),
+  ("second", ctypes.c_float),
+]
+
+MyPair_f32 = Pair_f32
+
+Buffer_u8__4._fields_ = [
+  ("data", ctypes.c_uint8 * 4),
+]
+
+MyBuffer_u8__4 = Buffer_u8__4
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [MyHandle, MyPair_f32, ctypes.POINTER(MyBuffer_u8__4)]
+  lib.root.restype = None
+
+  return lib
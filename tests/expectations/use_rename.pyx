@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Handle:
+    uint32_t id;
+
+  # The handle of the facade.
+  ctypedef Handle MyHandle;
+
+  ctypedef struct Pair_f32:
+    float first;
+    float second;
+
+  ctypedef Pair_f32 MyPair_f32;
+
+  ctypedef struct Buffer_u8__4:
+    uint8_t data[4];
+
+  ctypedef Buffer_u8__4 MyBuffer_u8__4;
+
+  void root(MyHandle a, MyPair_f32 b, const MyBuffer_u8__4 *c);
@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle {
+  uint32_t id;
+};
+
+/**
+ * The handle of the facade.
+ */
+typedef struct Handle MyHandle;
+
+struct Pair_f32 {
+  float first;
+  float second;
+};
+
+typedef struct Pair_f32 MyPair_f32;
+
+struct Buffer_u8__4 {
+  uint8_t data[4];
+};
+
+typedef struct Buffer_u8__4 MyBuffer_u8__4;
+
+void root(MyHandle a, MyPair_f32 b, const MyBuffer_u8__4 *c);
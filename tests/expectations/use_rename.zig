@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const Handle = extern struct {
+  id: u32,
+};
+
+/// The handle of the facade.
+pub const MyHandle = Handle;
+
+pub const Pair_f32 = extern struct {
+  first: f32,
+  second: f32,
+};
+
+pub const MyPair_f32 = Pair_f32;
+
+pub const Buffer_u8__4 = extern struct {
+  data: [4]u8,
+};
+
+pub const MyBuffer_u8__4 = Buffer_u8__4;
+
+pub extern fn root(a: MyHandle, b: MyPair_f32, c: ?*const MyBuffer_u8__4) void;
@@ -0,0 +1,11 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace root {
+  public static partial class NativeMethods {
+    const string __DllName = "__Internal";
+
+    [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+    public static extern void root();
+  }
+}
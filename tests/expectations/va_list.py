@@ -0,0 +1,13 @@
+import ctypes
+import enum
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.va_list_test.argtypes = [ctypes.c_void_p]
+  lib.va_list_test.restype = ctypes.c_int32
+
+  lib.va_list_test2.argtypes = [ctypes.c_void_p]
+  lib.va_list_test2.restype = ctypes.c_int32
+
+  return lib
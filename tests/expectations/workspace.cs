@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static partial class NativeMethods {
+  const string __DllName = "workspace";
+
+  public const int EXT_CONST = 0;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public struct ExtType {
+    public uint data;
+  }
+
+  [DllImport(__DllName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void consume_ext(ExtType _ext);
+}
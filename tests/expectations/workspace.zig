@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const EXT_CONST: i32 = 0;
+
+pub const ExtType = extern struct {
+  data: u32,
+};
+
+pub extern fn consume_ext(_ext: ExtType) void;
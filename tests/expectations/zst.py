@@ -0,0 +1,18 @@
+import ctypes
+import enum
+
+class TraitObject(ctypes.Structure):
+  pass
+
+TraitObject._fields_ = [
+  ("data", ctypes.c_void_p),
+  ("vtable", ctypes.c_void_p),
+]
+
+def load(path):
+  lib = ctypes.CDLL(path)
+
+  lib.root.argtypes = [ctypes.c_void_p, TraitObject]
+  lib.root.restype = ctypes.c_void_p
+
+  return lib
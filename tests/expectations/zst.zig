@@ -0,0 +1,8 @@
+const std = @import("std");
+
+pub const TraitObject = extern struct {
+  data: ?*anyopaque,
+  vtable: ?*anyopaque,
+};
+
+pub extern fn root(ptr: ?*const anyopaque, t: TraitObject) ?*anyopaque;
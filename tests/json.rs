@@ -0,0 +1,90 @@
+use cbindgen::*;
+
+use serde_json::Value;
+use std::path::PathBuf;
+
+fn generate_json() -> Value {
+    let test_dir = {
+        let mut this_file = PathBuf::from(file!());
+        this_file.pop();
+        this_file.push("json");
+        this_file
+    };
+
+    let bindings = Builder::new()
+        .with_config(Config::from_file(test_dir.join("cbindgen.toml")).unwrap())
+        .with_src(test_dir.join("items.rs"))
+        .generate()
+        .expect("build should succeed");
+
+    let mut output = Vec::new();
+    bindings.write_json(&mut output);
+    serde_json::from_slice(&output).expect("output should be valid JSON")
+}
+
+fn find<'a>(list: &'a Value, name: &str) -> &'a Value {
+    list.as_array()
+        .unwrap()
+        .iter()
+        .find(|x| x["name"] == name)
+        .unwrap_or_else(|| panic!("{} should be in the output", name))
+}
+
+#[test]
+fn json_version() {
+    let json = generate_json();
+    assert_eq!(json["version"], 2);
+    assert_eq!(json["cbindgen_version"], VERSION);
+}
+
+#[test]
+fn json_items() {
+    let json = generate_json();
+    let items = &json["items"];
+
+    let point = find(items, "Point");
+    assert_eq!(point["kind"], "struct");
+    assert_eq!(point["documentation"][0], " A point.");
+    assert_eq!(point["annotations"]["derive-eq"], true);
+    assert_eq!(point["fields"][1]["name"], "y");
+    assert_eq!(
+        point["fields"][1]["type"],
+        serde_json::json!({ "kind": "primitive", "name": "f32" })
+    );
+
+    let shape = find(items, "Shape");
+    assert_eq!(shape["kind"], "enum");
+    assert_eq!(shape["repr"]["type"], "u8");
+    let variants = &shape["variants"];
+    assert_eq!(find(variants, "Circle")["body"]["name"], "circle");
+    assert_eq!(find(variants, "Empty")["body"], Value::Null);
+    assert_eq!(find(variants, "Empty")["discriminant"]["value"], "5");
+
+    let extra = find(items, "Extra");
+    assert_eq!(extra["cfg"]["kind"], "named");
+    assert_eq!(extra["cfg"]["value"], "extra");
+    assert_eq!(extra["fields"][0]["type"]["kind"], "array");
+    assert_eq!(extra["fields"][0]["type"]["length"], "4");
+
+    let callback = find(items, "Callback");
+    assert_eq!(callback["kind"], "typedef");
+    assert_eq!(callback["aliased"]["kind"], "function_pointer");
+    assert_eq!(callback["aliased"]["args"][0]["type"]["is_const"], true);
+}
+
+#[test]
+fn json_constants_globals_functions() {
+    let json = generate_json();
+
+    let origin = find(&json["constants"], "ORIGIN_X");
+    assert_eq!(origin["value"]["kind"], "unary_op");
+    assert_eq!(origin["value"]["value"]["op"], "<<");
+
+    let counter = find(&json["globals"], "COUNTER");
+    assert_eq!(counter["mutable"], true);
+
+    let draw = find(&json["functions"], "draw");
+    assert_eq!(draw["return"]["name"], "c_void");
+    assert_eq!(draw["args"][2]["type"]["kind"], "pointer");
+    assert_eq!(draw["args"][2]["type"]["pointee"]["export_name"], "Extra");
+}
@@ -0,0 +1,30 @@
+/// A point.
+/// cbindgen:derive-eq=true
+#[repr(C)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    Circle { radius: f32 },
+    Square(Point),
+    Empty = 5,
+}
+
+#[cfg(feature = "extra")]
+#[repr(C)]
+pub struct Extra {
+    pub values: [u32; 4],
+}
+
+pub type Callback = extern "C" fn(point: *const Point) -> bool;
+
+pub const ORIGIN_X: i32 = -(1 << 4);
+
+#[no_mangle]
+pub static mut COUNTER: u64 = 0;
+
+#[no_mangle]
+pub extern "C" fn draw(shape: Shape, callback: Callback, extra: *mut Extra) {}
@@ -0,0 +1,119 @@
+use cbindgen::language_backend::{
+    CLikeLanguageBackend, Condition, Constant, Documentation, Enum, Field, Function,
+    LanguageBackend, Literal, OpaqueItem, Static, Struct, Type, Typedef, Union,
+};
+use cbindgen::*;
+use std::path::PathBuf;
+
+/// Writes C, with line comments for the documentation.
+#[derive(Debug)]
+struct LineCommentBackend(CLikeLanguageBackend);
+
+impl LanguageBackend for LineCommentBackend {
+    fn write_headers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.0.write_headers(bindings, out)
+    }
+    fn open_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.0.open_namespaces(bindings, out)
+    }
+    fn close_namespaces(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.0.close_namespaces(bindings, out)
+    }
+    fn write_footers(&self, bindings: &Bindings, out: &mut SourceWriter) {
+        self.0.write_footers(bindings, out)
+    }
+    fn write_enum(&self, config: &Config, out: &mut SourceWriter, e: &Enum) {
+        self.0.write_enum(config, out, e)
+    }
+    fn write_struct(&self, config: &Config, out: &mut SourceWriter, s: &Struct) {
+        self.0.write_struct(config, out, s)
+    }
+    fn write_union(&self, config: &Config, out: &mut SourceWriter, u: &Union) {
+        self.0.write_union(config, out, u)
+    }
+    fn write_opaque_item(&self, config: &Config, out: &mut SourceWriter, o: &OpaqueItem) {
+        self.0.write_opaque_item(config, out, o)
+    }
+    fn write_type_def(&self, config: &Config, out: &mut SourceWriter, t: &Typedef) {
+        self.0.write_type_def(config, out, t)
+    }
+    fn write_static(&self, config: &Config, out: &mut SourceWriter, s: &Static) {
+        self.0.write_static(config, out, s)
+    }
+    fn write_function(&self, config: &Config, out: &mut SourceWriter, f: &Function) {
+        self.0.write_function(config, out, f)
+    }
+    fn write_constant(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter,
+        c: &Constant,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        self.0.write_constant(config, out, c, associated_to_struct)
+    }
+    fn write_field(&self, config: &Config, out: &mut SourceWriter, f: &Field) {
+        self.0.write_field(config, out, f)
+    }
+    fn write_type(&self, config: &Config, out: &mut SourceWriter, t: &Type) {
+        self.0.write_type(config, out, t)
+    }
+    fn write_literal(&self, config: &Config, out: &mut SourceWriter, l: &Literal) {
+        self.0.write_literal(config, out, l)
+    }
+    fn write_documentation(&self, _config: &Config, out: &mut SourceWriter, d: &Documentation) {
+        for line in &d.doc_comment {
+            write!(out, "//{}", line);
+            out.new_line();
+        }
+    }
+    fn write_condition_before(&self, config: &Config, out: &mut SourceWriter, c: &Condition) {
+        self.0.write_condition_before(config, out, c)
+    }
+    fn write_condition_after(&self, config: &Config, out: &mut SourceWriter, c: &Condition) {
+        self.0.write_condition_after(config, out, c)
+    }
+}
+
+fn generate(builder: Builder) -> String {
+    let src = {
+        let mut this_file = PathBuf::from(file!());
+        this_file.pop();
+        this_file.push("language_backend");
+        this_file.push("items.rs");
+        this_file
+    };
+
+    let bindings = builder
+        .with_language(Language::C)
+        .with_no_includes()
+        .with_src(src)
+        .generate()
+        .expect("build should succeed");
+
+    let mut output = Vec::new();
+    bindings.write(&mut output);
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn custom_language_backend() {
+    let default = generate(Builder::new());
+    assert!(default.starts_with("/**\n * A point.\n */\n"));
+
+    let custom =
+        generate(Builder::new().with_language_backend(LineCommentBackend(CLikeLanguageBackend)));
+    assert_eq!(
+        custom,
+        "// A point.
+typedef struct Point {
+  // The horizontal coordinate.
+  float x;
+  float y;
+} Point;
+
+// Moves `p` by `dx`.
+struct Point translate(struct Point p, float dx);
+"
+    );
+}
@@ -0,0 +1,13 @@
+/// A point.
+#[repr(C)]
+pub struct Point {
+    /// The horizontal coordinate.
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Moves `p` by `dx`.
+#[no_mangle]
+pub extern "C" fn translate(p: Point, dx: f32) -> Point {
+    Point { x: p.x + dx, y: p.y }
+}
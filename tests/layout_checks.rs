@@ -0,0 +1,141 @@
+use cbindgen::*;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn test_dir() -> PathBuf {
+    let mut this_file = PathBuf::from(file!());
+    this_file.pop();
+    this_file.push("layout_checks");
+    this_file
+}
+
+fn generate(language: Language) -> Bindings {
+    let mut config = Config::default();
+    config.language = language;
+    generate_with_config(config)
+}
+
+fn generate_with_config(mut config: Config) -> Bindings {
+    config.style = Style::Tag;
+    Builder::new()
+        .with_config(config)
+        .with_src(test_dir().join("items.rs"))
+        .generate()
+        .expect("build should succeed")
+}
+
+fn rust_checks(bindings: &Bindings) -> String {
+    let mut output = Vec::new();
+    bindings.write_rust_layout_checks(&mut output);
+    String::from_utf8(output).unwrap()
+}
+
+fn run(command: &mut Command) -> String {
+    let output = command.output().expect("command should run");
+    assert!(
+        output.status.success(),
+        "{:?} failed: {}",
+        command,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn layout_checks_rust() {
+    let checks = rust_checks(&generate(Language::C));
+
+    assert!(checks.contains("const _: () = assert!(core::mem::size_of::<Mixed>() == 32);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::offset_of!(Mixed, e) == 24);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::offset_of!(Tuple, 1) == 8);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::size_of::<Alias>() == 32);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::align_of::<Tagged>() == 8);"));
+    assert!(checks
+        .contains("    println!(\"offset struct Tuple._1 {}\", core::mem::offset_of!(Tuple, 1));"));
+    // Instantiations of generic types can't be named in Rust.
+    assert!(!checks.contains("Generic"));
+}
+
+#[test]
+fn layout_checks_rust_32_bit_x86() {
+    let mut config = Config::default();
+    config.layout.pointer_width = 32;
+    config.layout.align_64 = 4;
+    let checks = rust_checks(&generate_with_config(config));
+
+    assert!(checks.contains("const _: () = assert!(core::mem::size_of::<Mixed>() == 24);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::offset_of!(Mixed, e) == 16);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::offset_of!(Tuple, 1) == 4);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::size_of::<Tuple>() == 12);"));
+    assert!(checks.contains("const _: () = assert!(core::mem::align_of::<Tagged>() == 4);"));
+}
+
+#[test]
+fn layout_config_rejects_invalid_widths() {
+    let error = toml::from_str::<Config>("[layout]\npointer_width = 4\n").unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("invalid width 4, expected 16, 32 or 64"));
+    assert!(toml::from_str::<Config>("[layout]\nalign_64 = 3\n").is_err());
+}
+
+#[test]
+fn layout_checks_compile() {
+    if env::var_os("CBINDGEN_TEST_NO_COMPILE").is_some() {
+        return;
+    }
+
+    for language in [Language::C, Language::Cxx] {
+        let bindings = generate(language);
+        let tmp_dir = tempfile::Builder::new()
+            .prefix("cbindgen-layout-checks")
+            .tempdir()
+            .unwrap();
+        let tmp_dir = tmp_dir.path();
+
+        bindings.write_to_file(tmp_dir.join("bindings.h"));
+        bindings.write_c_layout_checks_to_file(tmp_dir.join("checks.c"), "bindings.h");
+        bindings.write_rust_layout_checks_to_file(tmp_dir.join("checks.rs"));
+        fs::write(
+            tmp_dir.join("main.rs"),
+            format!(
+                "#![allow(dead_code)]\ninclude!({:?});\ninclude!(\"checks.rs\");\nfn main() {{ print_layouts(); }}\n",
+                fs::canonicalize(test_dir().join("items.rs")).unwrap()
+            ),
+        )
+        .unwrap();
+
+        let compiler = match language {
+            Language::Cxx => env::var("CXX").unwrap_or_else(|_| "g++".to_owned()),
+            _ => env::var("CC").unwrap_or_else(|_| "gcc".to_owned()),
+        };
+        let mut c_command = Command::new(compiler);
+        if language == Language::Cxx {
+            c_command.arg("-x").arg("c++").arg("-std=c++17");
+        }
+        run(c_command
+            .arg(tmp_dir.join("checks.c"))
+            .arg("-o")
+            .arg(tmp_dir.join("checks_c")));
+        run(
+            Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned()))
+                .arg("--edition=2018")
+                .arg(tmp_dir.join("main.rs"))
+                .arg("-o")
+                .arg(tmp_dir.join("checks_rust")),
+        );
+
+        let c_output = run(&mut Command::new(tmp_dir.join("checks_c")));
+        let rust_output = run(&mut Command::new(tmp_dir.join("checks_rust")));
+        let size = if language == Language::Cxx {
+            "size Mixed 32\n"
+        } else {
+            "size struct Mixed 32\n"
+        };
+        assert!(c_output.contains(size));
+        assert_eq!(c_output, rust_output);
+    }
+}
@@ -0,0 +1,34 @@
+#[repr(C)]
+pub struct Mixed {
+    pub a: u8,
+    pub b: u32,
+    pub c: u16,
+    pub d: *const u8,
+    pub e: [u16; 3],
+}
+
+#[repr(C)]
+pub struct Tuple(pub u8, pub u64);
+
+#[repr(C)]
+pub union Either {
+    pub a: u8,
+    pub b: u64,
+}
+
+pub type Alias = Mixed;
+
+#[repr(C, u8)]
+pub enum Tagged {
+    A(u64),
+    B { x: u8, y: u16 },
+    C,
+}
+
+#[repr(C)]
+pub struct Generic<T> {
+    pub a: T,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Alias, b: Tuple, c: Either, d: Tagged, e: Generic<u16>) {}
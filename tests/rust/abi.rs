@@ -0,0 +1,35 @@
+pub type StdcallCallback = Option<unsafe extern "stdcall" fn(a: i32) -> i32>;
+pub type SystemCallback = unsafe extern "system" fn();
+pub type PlainCallback = unsafe extern "C" fn(a: i32);
+
+#[repr(C)]
+pub struct Callbacks {
+    fastcall: extern "fastcall" fn(a: u8, b: u8),
+    c_unwind: Option<extern "C-unwind" fn()>,
+}
+
+#[no_mangle]
+pub extern "stdcall" fn stdcall_fn(callback: StdcallCallback) {}
+
+#[no_mangle]
+pub extern "system" fn system_fn(callback: SystemCallback) -> i32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C-unwind" fn c_unwind_fn(callbacks: Callbacks) {}
+
+#[no_mangle]
+pub extern "efiapi" fn efiapi_fn(callback: PlainCallback) {}
+
+#[no_mangle]
+pub extern "sysv64" fn sysv64_fn() -> extern "fastcall" fn(a: u8, b: u8) {
+    loop {}
+}
+
+extern "system" {
+    fn imported(a: i32);
+}
+
+#[no_mangle]
+pub extern "Rust" fn rust_fn() {}
@@ -0,0 +1,23 @@
+#[repr(C)]
+pub struct Point(f32, f32);
+
+#[repr(C)]
+pub struct Flags {
+    bits: u8,
+    mode: u32,
+}
+
+#[repr(u8)]
+pub enum Mode {
+    Fast,
+    Safe,
+}
+
+/// cbindgen:derive-eq
+#[repr(C)]
+pub struct Overridden {
+    x: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(p: Point, f: Flags, m: Mode, o: Overridden) {}
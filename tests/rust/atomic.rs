@@ -0,0 +1,17 @@
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicPtr, AtomicU32, AtomicUsize};
+
+#[repr(C)]
+pub struct RingBuffer {
+    head: AtomicUsize,
+    tail: AtomicUsize,
+    closed: AtomicBool,
+    sequence: AtomicI64,
+    data: AtomicPtr<u8>,
+    slots: *const AtomicU32,
+}
+
+#[no_mangle]
+pub extern "C" fn ring_buffer_push(buffer: &RingBuffer, counter: &AtomicU32) {}
+
+#[no_mangle]
+pub extern "C" fn ring_buffer_wait(closed: *const AtomicBool, data: &AtomicPtr<u8>) {}
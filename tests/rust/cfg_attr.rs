@@ -0,0 +1,51 @@
+#[cfg_attr(feature = "ffi", repr(C))]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "ffi", derive(Debug))]
+#[repr(C)]
+pub struct Always {
+    value: u32,
+}
+
+#[cfg_attr(feature = "ffi", no_mangle)]
+pub extern "C" fn make_point(x: f32, y: f32) -> Point {
+    Point { x, y }
+}
+
+#[cfg_attr(feature = "ffi", export_name = "renamed_always")]
+pub extern "C" fn always(a: Always) {}
+
+#[cfg_attr(feature = "ffi", derive(Debug))]
+#[no_mangle]
+pub extern "C" fn unconditional(a: Always) {}
+
+#[cfg_attr(unix, cfg(feature = "ffi"))]
+#[no_mangle]
+pub extern "C" fn ffi_on_unix() {}
+
+#[cfg_attr(feature = "ffi", cfg_attr(unix, unsafe(no_mangle)))]
+pub extern "C" fn unix_ffi_value() -> u32 {
+    1
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "simd", repr(align(16)))]
+pub struct Vector {
+    x: f32,
+    y: f32,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+pub struct Header {
+    kind: u8,
+    length: u32,
+}
+
+#[cfg_attr(feature = "ffi", no_mangle)]
+#[no_mangle]
+pub extern "C" fn take_vector(v: Vector, h: Header) {}
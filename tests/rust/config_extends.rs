@@ -0,0 +1,27 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Rect {
+    origin: Point,
+    size: Point,
+}
+
+#[repr(C)]
+pub struct Hidden {
+    secret: u32,
+}
+
+#[repr(C)]
+pub enum Corner {
+    TopLeft,
+    BottomRight,
+}
+
+#[no_mangle]
+pub extern "C" fn corner(rect: Rect, corner: Corner) -> Point {
+    rect.origin
+}
@@ -0,0 +1,33 @@
+pub type Callback = extern "C" fn(value: bool, len: usize) -> bool;
+pub type StdcallCallback = extern "stdcall" fn(value: u8);
+pub type SystemCallback = extern "system" fn();
+
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Buffer {
+    data: [u8; 16],
+    points: [[Point; 2]; 2],
+    next: *mut Point,
+    callback: Callback,
+    on_fill: StdcallCallback,
+    on_done: SystemCallback,
+}
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn fill(buffer: *mut Buffer, value: u8, callback: Callback) -> bool {
+    true
+}
+
+#[no_mangle]
+pub extern "fastcall" fn clear(buffer: *mut Buffer) {}
+
+#[no_mangle]
+pub extern "system" fn done(buffer: *mut Buffer) {}
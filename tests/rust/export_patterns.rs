@@ -0,0 +1,41 @@
+#[repr(C)]
+pub struct FfiPoint {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct FfiRect {
+    origin: FfiPoint,
+    size: FfiPoint,
+}
+
+#[repr(C)]
+pub struct CacheInternal {
+    hits: u32,
+}
+
+#[repr(C)]
+pub struct DebugInfo {
+    line: u32,
+}
+
+#[repr(C)]
+pub struct PaletteEntry {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[repr(C)]
+pub struct PaletteHeader {
+    count: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn ffi_area(rect: FfiRect) -> f32 {
+    rect.size.x * rect.size.y
+}
+
+#[no_mangle]
+pub extern "C" fn debug_dump(info: *const DebugInfo, cache: *const CacheInternal) {}
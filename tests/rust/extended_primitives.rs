@@ -0,0 +1,27 @@
+#[repr(C)]
+pub struct Wide {
+    signed: i128,
+    unsigned: u128,
+    half: f16,
+    quad: f128,
+}
+
+#[no_mangle]
+pub extern "C" fn widen(a: u64, b: u64) -> u128 {
+    ((a as u128) << 64) | b as u128
+}
+
+#[no_mangle]
+pub extern "C" fn narrow(x: f128) -> f16 {
+    x as f16
+}
+
+#[no_mangle]
+pub extern "C" fn root(w: Wide) {}
+
+pub const SMALL: u128 = 42;
+
+pub const HALF: f16 = 0.5;
+
+/// Doesn't fit in a C literal.
+pub const BIG: u128 = 0xFFFF_FFFF_FFFF_FFFF_FFFF;
@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Wide {
+    signed: i128,
+    unsigned: u128,
+    half: f16,
+    quad: f128,
+}
+
+#[no_mangle]
+pub extern "C" fn root(w: Wide) {}
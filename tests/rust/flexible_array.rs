@@ -0,0 +1,44 @@
+/// cbindgen:derive-constructor
+#[repr(C)]
+pub struct Message {
+    len: u32,
+    kind: u16,
+    data: [u8; 0],
+}
+
+/// cbindgen:derive-constructor
+#[repr(C)]
+pub struct Matrix {
+    rows: usize,
+    data: [[f32; 4]; 0],
+}
+
+/// cbindgen:flexible-array=false
+#[repr(C)]
+pub struct Legacy {
+    len: u32,
+    data: [u8; 0],
+}
+
+#[repr(C)]
+pub struct Misplaced {
+    marker: [u64; 0],
+    len: u32,
+}
+
+// Warns, as C doesn't allow `Message` there.
+#[repr(C)]
+pub struct Envelope {
+    id: u32,
+    message: Message,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    m: *const Message,
+    x: *const Matrix,
+    l: *const Legacy,
+    p: Misplaced,
+    e: *const Envelope,
+) {
+}
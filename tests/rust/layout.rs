@@ -58,16 +58,16 @@ pub union PackedUnion {
     pub variant2: *mut u8,
 }
 
-// #[repr(packed(n), C)] structs are currently unsupported.
+// #[repr(packed(n), C)] structs are written inside `#pragma pack(n)`.
 #[repr(packed(4), C)]
-pub struct UnsupportedPacked4Struct {
+pub struct Packed4Struct {
     pub arg1: usize,
     pub arg2: *mut u8,
 }
 
-// #[repr(packed(n), C)] unions are currently unsupported.
+// #[repr(packed(n), C)] unions are written inside `#pragma pack(n)`.
 #[repr(packed(4), C)]
-pub union UnsupportedPacked4Union {
+pub union Packed4Union {
     pub variant1: usize,
     pub variant2: *mut u8,
 }
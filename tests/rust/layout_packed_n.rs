@@ -0,0 +1,36 @@
+#[repr(C, packed(2))]
+pub struct Packed2 {
+    tag: u16,
+    length: u32,
+    checksum: u16,
+}
+
+// `b` is at offset 2, not 1 as with `__attribute__((packed, aligned(2)))`.
+#[repr(C, packed(2))]
+pub struct P2 {
+    a: u8,
+    b: u32,
+}
+
+#[repr(C, packed(4))]
+pub struct Packed4 {
+    header: Packed2,
+    sequence: u64,
+    flags: u32,
+}
+
+#[repr(C, packed(4))]
+pub union Packed4Union {
+    small: u32,
+    large: u64,
+}
+
+// Same as #[repr(packed)].
+#[repr(C, packed(1))]
+pub struct Packed1 {
+    a: u8,
+    b: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Packed4, b: Packed4Union, c: Packed1, d: P2) {}
@@ -0,0 +1,16 @@
+// With `packed_n`, the macro is used instead of `#pragma pack(n)`.
+#[repr(C, packed(2))]
+pub struct Packed2 {
+    tag: u16,
+    length: u32,
+    checksum: u16,
+}
+
+#[repr(C, packed(4))]
+pub union Packed4Union {
+    small: u32,
+    large: u64,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Packed2, b: Packed4Union) {}
@@ -0,0 +1,84 @@
+#[repr(C)]
+pub struct Mixed {
+    a: u8,
+    b: u32,
+    c: u16,
+    d: *const u8,
+    e: [u16; 3],
+    f: Option<extern "C" fn(i32) -> i32>,
+}
+
+#[repr(C, packed)]
+pub struct Packed {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+#[repr(C, align(16))]
+pub struct Aligned {
+    a: u8,
+    b: Packed,
+}
+
+#[repr(C)]
+pub union Either {
+    a: u8,
+    b: u64,
+    c: [u8; 12],
+}
+
+pub type Alias = Mixed;
+
+#[repr(C)]
+pub struct Nested {
+    a: Alias,
+    b: Either,
+    c: bool,
+}
+
+#[repr(u8)]
+pub enum Plain {
+    PlainA,
+    PlainB,
+}
+
+#[repr(C)]
+pub enum CTag {
+    CTagA,
+    CTagB,
+}
+
+#[repr(C, u8)]
+pub enum Tagged {
+    TaggedA(u64),
+    TaggedB { x: u8, y: u16 },
+    TaggedC,
+}
+
+#[repr(u8)]
+pub enum Inline {
+    InlineA(u64),
+    InlineB { x: u8, y: u16 },
+    InlineC,
+}
+
+#[repr(C)]
+pub struct Conditional {
+    a: u8,
+    #[cfg(feature = "extra")]
+    b: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: Mixed,
+    b: Aligned,
+    c: Nested,
+    d: Plain,
+    e: CTag,
+    f: Tagged,
+    g: Inline,
+    i: Conditional,
+) {
+}
@@ -0,0 +1,27 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn point_new(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+#[no_mangle]
+pub extern "C" fn point_length(point: *const Point) -> f64 {
+    0.0
+}
+
+#[cfg(unix)]
+#[no_mangle]
+pub extern "C" fn unix_only(callback: extern "C" fn(i32) -> i32) {}
+
+/// cbindgen:postfix=LOADER_COLD
+#[no_mangle]
+pub extern "C" fn rarely_called() {}
+
+extern "C" {
+    fn imported(x: i32) -> i32;
+}
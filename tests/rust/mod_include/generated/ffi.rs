@@ -0,0 +1,6 @@
+#[repr(C)]
+pub struct Generated {
+    id: u32,
+}
+
+include!("ffi_consts.rs");
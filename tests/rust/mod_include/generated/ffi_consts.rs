@@ -0,0 +1 @@
+pub const GENERATED_VERSION: u32 = 3;
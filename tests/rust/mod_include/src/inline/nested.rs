@@ -0,0 +1,4 @@
+#[repr(C)]
+pub struct Nested {
+    depth: u8,
+}
@@ -0,0 +1,19 @@
+include!(concat!(env!("OUT_DIR"), "/ffi.rs"));
+
+#[cfg_attr(unix, path = "platform/unix.rs")]
+#[cfg_attr(windows, path = "platform/windows.rs")]
+mod platform;
+
+#[path = "shared/types.rs"]
+mod types;
+
+mod inline {
+    #[path = "nested.rs"]
+    mod nested;
+}
+
+pub use platform::*;
+pub use types::*;
+
+#[no_mangle]
+pub extern "C" fn root(a: Generated, b: Handle, c: Shared, d: Extra, e: Nested) {}
@@ -0,0 +1,4 @@
+#[repr(C)]
+pub struct Handle {
+    fd: i32,
+}
@@ -0,0 +1,4 @@
+#[repr(C)]
+pub struct Handle {
+    handle: *mut u8,
+}
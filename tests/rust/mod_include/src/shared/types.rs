@@ -0,0 +1,9 @@
+// Files loaded through `#[path]` find their submodules next to them.
+mod extra;
+
+pub use extra::*;
+
+#[repr(C)]
+pub struct Shared {
+    value: u64,
+}
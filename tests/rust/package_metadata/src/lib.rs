@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+pub enum Direction {
+    North,
+    South,
+}
+
+#[no_mangle]
+pub extern "C" fn walk(from: Point, direction: Direction) -> Point {
+    from
+}
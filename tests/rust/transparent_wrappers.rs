@@ -0,0 +1,14 @@
+use std::cell::UnsafeCell;
+use std::num::{Saturating, Wrapping};
+
+#[repr(C)]
+pub struct Counters {
+    hits: Wrapping<u32>,
+    misses: Saturating<u64>,
+    shared: UnsafeCell<i32>,
+    maybe: std::mem::MaybeUninit<u8>,
+    external: external_crate::Volatile<u16>,
+}
+
+#[no_mangle]
+pub extern "C" fn counters_reset(counters: &mut Counters, seed: external_crate::Volatile<u32>) {}
@@ -0,0 +1,23 @@
+#[unsafe(no_mangle)]
+pub extern "C" fn root(x: u32) -> u32 {
+    x
+}
+
+#[unsafe(export_name = "renamed_fn")]
+pub extern "C" fn original_fn() {}
+
+#[unsafe(no_mangle)]
+pub static NUMBER: u32 = 10;
+
+#[unsafe(export_name = "RENAMED_STATIC")]
+pub static ORIGINAL_STATIC: i32 = 20;
+
+#[export_name = "OLD_STYLE_RENAMED_STATIC"]
+pub static mut OLD_STYLE_STATIC: i64 = 30;
+
+pub struct Foo;
+
+impl Foo {
+    #[unsafe(no_mangle)]
+    pub extern "C" fn foo_method() {}
+}
@@ -0,0 +1,25 @@
+mod inner {
+    #[repr(C)]
+    pub struct Handle {
+        id: u32,
+    }
+
+    #[repr(C)]
+    pub struct Pair<T> {
+        first: T,
+        second: T,
+    }
+
+    #[repr(C)]
+    pub struct Buffer<T, const N: usize> {
+        data: [T; N],
+    }
+}
+
+/// The handle of the facade.
+pub use inner::Handle as MyHandle;
+pub use inner::{Buffer as MyBuffer, Pair as MyPair};
+pub use self::inner::Handle as _;
+
+#[no_mangle]
+pub extern "C" fn root(a: MyHandle, b: MyPair<f32>, c: *const MyBuffer<u8, 4>) {}
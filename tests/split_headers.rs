@@ -0,0 +1,156 @@
+use cbindgen::*;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn test_dir() -> PathBuf {
+    let mut this_file = PathBuf::from(file!());
+    this_file.pop();
+    this_file.push("split_headers");
+    this_file
+}
+
+fn split_header(name: &str, items: &[&str]) -> SplitHeader {
+    SplitHeader {
+        name: name.to_owned(),
+        items: items.iter().map(|item| item.to_string()).collect(),
+    }
+}
+
+fn generate(language: Language, headers: Vec<SplitHeader>) -> Result<Bindings, Error> {
+    let mut config = Config::default();
+    config.language = language;
+    config.include_guard = Some("BINDINGS_H".to_owned());
+    config.split.headers = headers;
+    config.split.umbrella = Some("all.h".to_owned());
+    config.loader.table = Some("Functions".to_owned());
+    Builder::new()
+        .with_config(config)
+        .with_src(test_dir().join("items.rs"))
+        .generate()
+}
+
+fn default_headers() -> Vec<SplitHeader> {
+    vec![
+        split_header("geometry.h", &["items::geometry", "Colo?"]),
+        split_header("shapes.h", &["items::shapes"]),
+    ]
+}
+
+#[test]
+fn split_headers_by_module() {
+    let bindings = generate(Language::C, default_headers()).expect("build should succeed");
+    let tmp_dir = tempfile::Builder::new()
+        .prefix("cbindgen-split-headers")
+        .tempdir()
+        .unwrap();
+    let tmp_dir = tmp_dir.path();
+
+    assert!(bindings.write_to_file(tmp_dir.join("bindings.h")));
+    let read = |name: &str| fs::read_to_string(tmp_dir.join(name)).unwrap();
+
+    let geometry = read("geometry.h");
+    assert!(geometry.contains("#ifndef GEOMETRY_H"));
+    assert!(geometry.contains("} Point;"));
+    assert!(geometry.contains("} Rect;"));
+    assert!(geometry.contains("} Color;"));
+    assert!(geometry.contains("float rect_area(const struct Rect *rect);"));
+    assert!(!geometry.contains("#include \""));
+
+    let shapes = read("shapes.h");
+    assert!(shapes.contains("#ifndef SHAPES_H"));
+    assert!(shapes.contains("#include \"geometry.h\""));
+    assert!(shapes.contains("} Shape;"));
+    assert!(shapes.contains("ShapeKind shape_kind(const struct Shape *shape);"));
+    assert!(!shapes.contains("} Rect;"));
+    // `Canvas` is only pointed to, so it's forward-declared rather than
+    // included from the main header, which points to `Shape` in turn.
+    assert!(shapes.contains("typedef struct Canvas Canvas;"));
+    assert!(!shapes.contains("#include \"bindings.h\""));
+
+    let main = read("bindings.h");
+    assert!(main.contains("#ifndef BINDINGS_H"));
+    assert!(main.contains("typedef struct Shape Shape;"));
+    assert!(!main.contains("#include \"shapes.h\""));
+    assert!(main.contains("#define MAX_SHAPES 64"));
+    assert!(main.contains("} Canvas;"));
+    assert!(main.contains("void canvas_draw(struct Canvas *canvas);"));
+    assert!(!main.contains("} Shape;"));
+    assert!(!main.contains("Functions"));
+
+    let umbrella = read("all.h");
+    assert!(umbrella.contains("#ifndef ALL_H"));
+    for header in ["bindings.h", "geometry.h", "shapes.h"] {
+        assert!(umbrella.contains(&format!("#include \"{}\"", header)));
+    }
+    // The table of the loader covers the functions of all the headers.
+    assert!(umbrella.contains("typedef struct Functions {"));
+    for function in ["rect_area", "shape_kind", "shape_canvas", "canvas_draw"] {
+        assert!(umbrella.contains(&format!("table->{} = ", function)));
+    }
+
+    // Writing the same bindings again changes nothing.
+    assert!(!bindings.write_to_file(tmp_dir.join("bindings.h")));
+
+    // Only the main header is written to a stream.
+    let mut output = Vec::new();
+    bindings.write(&mut output);
+    assert_eq!(String::from_utf8(output).unwrap(), main);
+}
+
+#[test]
+fn split_headers_compile() {
+    if env::var_os("CBINDGEN_TEST_NO_COMPILE").is_some() {
+        return;
+    }
+
+    for (language, compiler, source) in [
+        (Language::C, "gcc", "main.c"),
+        (Language::Cxx, "g++", "main.cpp"),
+    ] {
+        let bindings = generate(language, default_headers()).expect("build should succeed");
+        let tmp_dir = tempfile::Builder::new()
+            .prefix("cbindgen-split-headers")
+            .tempdir()
+            .unwrap();
+        let tmp_dir = tmp_dir.path();
+
+        bindings.write_to_file(tmp_dir.join("bindings.h"));
+        for header in ["all.h", "bindings.h", "geometry.h", "shapes.h"] {
+            fs::write(
+                tmp_dir.join(source),
+                format!("#include \"{}\"\n#include \"all.h\"\n", header),
+            )
+            .unwrap();
+            let output = Command::new(compiler)
+                .arg("-fsyntax-only")
+                .arg(tmp_dir.join(source))
+                .output()
+                .expect("compiler should run");
+            assert!(
+                output.status.success(),
+                "{} failed on {}: {}",
+                compiler,
+                header,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
+
+#[test]
+fn split_headers_cycle() {
+    // `Rect` needs `Point` from the main header, which needs `Rect` for `Shape`.
+    let headers = vec![split_header("rect.h", &["Rect"])];
+    match generate(Language::C, headers) {
+        Err(Error::SplitHeaderCycle(cycle)) => {
+            assert_eq!(cycle.len(), 3);
+            assert_eq!(cycle[0], cycle[2]);
+            assert!(cycle.contains(&"`rect.h`".to_owned()));
+        }
+        Err(error) => panic!("unexpected error: {}", error),
+        Ok(_) => panic!("headers including each other should be an error"),
+    }
+}
@@ -0,0 +1,64 @@
+pub mod geometry {
+    #[repr(C)]
+    pub struct Point {
+        pub x: f32,
+        pub y: f32,
+    }
+
+    #[repr(C)]
+    pub struct Rect {
+        pub origin: Point,
+        pub size: Point,
+    }
+
+    #[no_mangle]
+    pub extern "C" fn rect_area(rect: &Rect) -> f32 {
+        rect.size.x * rect.size.y
+    }
+}
+
+pub mod shapes {
+    use super::geometry::Rect;
+    use super::Color;
+
+    #[repr(u8)]
+    pub enum ShapeKind {
+        Circle,
+        Square,
+    }
+
+    #[repr(C)]
+    pub struct Shape {
+        pub bounds: Rect,
+        pub kind: ShapeKind,
+        pub fill: Color,
+    }
+
+    #[no_mangle]
+    pub extern "C" fn shape_kind(shape: &Shape) -> ShapeKind {
+        shape.kind
+    }
+
+    #[no_mangle]
+    pub extern "C" fn shape_canvas(shape: &Shape) -> *mut super::Canvas {
+        std::ptr::null_mut()
+    }
+}
+
+#[repr(C)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+#[repr(C)]
+pub struct Canvas {
+    pub shapes: *mut shapes::Shape,
+    pub len: usize,
+}
+
+pub const MAX_SHAPES: u32 = 64;
+
+#[no_mangle]
+pub extern "C" fn canvas_draw(canvas: *mut Canvas) {}
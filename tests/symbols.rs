@@ -0,0 +1,165 @@
+use cbindgen::*;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn generate(symbols: SymbolsConfig) -> Bindings {
+    let test_dir = {
+        let mut this_file = PathBuf::from(file!());
+        this_file.pop();
+        this_file.push("symbols");
+        this_file
+    };
+
+    let mut config = Config::default();
+    config.symbols = symbols;
+    Builder::new()
+        .with_config(config)
+        .with_src(test_dir.join("items.rs"))
+        .generate()
+        .expect("build should succeed")
+}
+
+fn write(bindings: &Bindings, write: impl Fn(&Bindings, &mut Vec<u8>)) -> String {
+    let mut output = Vec::new();
+    write(bindings, &mut output);
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn symbols_version_script() {
+    let bindings = generate(SymbolsConfig {
+        version_node: Some("PLUGIN_2.0".to_owned()),
+        ..Default::default()
+    });
+    assert_eq!(
+        write(&bindings, |b, out| b.write_version_script(out)),
+        "PLUGIN_2.0 {
+  global:
+    plugin_init;
+    plugin_run_v2;
+    /* cfg(feature = \"extra\") */
+    plugin_extra;
+    PLUGIN_VERSION;
+  local:
+    *;
+};
+"
+    );
+
+    let bindings = generate(SymbolsConfig::default());
+    assert!(write(&bindings, |b, out| b.write_version_script(out)).starts_with("{\n  global:\n"));
+}
+
+#[test]
+fn symbols_def_file() {
+    let bindings = generate(SymbolsConfig {
+        library: Some("plugin".to_owned()),
+        ..Default::default()
+    });
+    assert_eq!(
+        write(&bindings, |b, out| b.write_def_file(out)),
+        "LIBRARY plugin
+EXPORTS
+    plugin_init
+    plugin_run_v2
+    ; cfg(feature = \"extra\")
+    plugin_extra
+    PLUGIN_VERSION DATA
+"
+    );
+}
+
+#[test]
+fn symbols_list() {
+    let bindings = generate(SymbolsConfig::default());
+    assert_eq!(
+        write(&bindings, |b, out| b.write_symbol_list(out)),
+        "plugin_init\nplugin_run_v2\nplugin_extra\nPLUGIN_VERSION\n"
+    );
+}
+
+#[test]
+fn symbols_cfgs() {
+    let bindings = generate(SymbolsConfig {
+        cfgs: Some(vec![]),
+        ..Default::default()
+    });
+    assert_eq!(
+        write(&bindings, |b, out| b.write_symbol_list(out)),
+        "plugin_init\nplugin_run_v2\nPLUGIN_VERSION\n"
+    );
+    assert!(!write(&bindings, |b, out| b.write_def_file(out)).contains("plugin_extra"));
+
+    let bindings = generate(SymbolsConfig {
+        cfgs: Some(vec!["feature = extra".to_owned()]),
+        ..Default::default()
+    });
+    assert_eq!(
+        write(&bindings, |b, out| b.write_symbol_list(out)),
+        "plugin_init\nplugin_run_v2\nplugin_extra\nPLUGIN_VERSION\n"
+    );
+}
+
+#[test]
+fn symbols_version_script_links() {
+    if env::var_os("CBINDGEN_TEST_NO_COMPILE").is_some() {
+        return;
+    }
+
+    let tmp_dir = tempfile::Builder::new()
+        .prefix("cbindgen-symbols")
+        .tempdir()
+        .unwrap();
+    let tmp_dir = tmp_dir.path();
+
+    let bindings = generate(SymbolsConfig {
+        version_node: Some("PLUGIN_2.0".to_owned()),
+        // `plugin_extra` isn't built, which some linkers reject in a version script.
+        cfgs: Some(vec![]),
+        ..Default::default()
+    });
+    bindings.write_version_script_to_file(tmp_dir.join("plugin.map"));
+    fs::write(
+        tmp_dir.join("plugin.c"),
+        "int plugin_init(void) { return 0; }\n\
+         void plugin_run_v2(unsigned steps) { (void)steps; }\n\
+         const unsigned PLUGIN_VERSION = 2;\n\
+         void plugin_internal(void) {}\n",
+    )
+    .unwrap();
+
+    let output = Command::new("gcc")
+        .current_dir(tmp_dir)
+        .args(["-shared", "-fPIC", "-o", "libplugin.so", "plugin.c"])
+        .arg("-Wl,--version-script=plugin.map")
+        .output()
+        .expect("gcc should run");
+    assert!(
+        output.status.success(),
+        "linking failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new("nm")
+        .current_dir(tmp_dir)
+        .args(["-D", "--defined-only", "libplugin.so"])
+        .output()
+        .expect("nm should run");
+    let symbols = String::from_utf8(output.stdout).unwrap();
+    for symbol in [
+        "plugin_init@@PLUGIN_2.0",
+        "plugin_run_v2@@PLUGIN_2.0",
+        "PLUGIN_VERSION@@PLUGIN_2.0",
+    ] {
+        assert!(
+            symbols.contains(symbol),
+            "{} should be exported: {}",
+            symbol,
+            symbols
+        );
+    }
+    assert!(!symbols.contains("plugin_internal"));
+}
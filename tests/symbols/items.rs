@@ -0,0 +1,18 @@
+#[no_mangle]
+pub extern "C" fn plugin_init() -> i32 {
+    0
+}
+
+#[export_name = "plugin_run_v2"]
+pub extern "C" fn plugin_run(steps: u32) {}
+
+#[cfg(feature = "extra")]
+#[no_mangle]
+pub extern "C" fn plugin_extra() {}
+
+#[no_mangle]
+pub static PLUGIN_VERSION: u32 = 2;
+
+extern "C" {
+    fn host_log(message: *const u8);
+}
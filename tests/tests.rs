@@ -56,6 +56,15 @@ fn run_cbindgen(
         Language::Cython => {
             command.arg("--lang").arg("cython");
         }
+        Language::Zig => {
+            command.arg("--lang").arg("zig");
+        }
+        Language::Python => {
+            command.arg("--lang").arg("python");
+        }
+        Language::CSharp => {
+            command.arg("--lang").arg("csharp");
+        }
     }
 
     if let Some(style) = style {
@@ -116,6 +125,9 @@ fn compile(
         Language::Cxx => env::var("CXX").unwrap_or_else(|_| "g++".to_owned()),
         Language::C => env::var("CC").unwrap_or_else(|_| "gcc".to_owned()),
         Language::Cython => env::var("CYTHON").unwrap_or_else(|_| "cython".to_owned()),
+        Language::Zig => env::var("ZIG").unwrap_or_else(|_| "zig".to_owned()),
+        Language::Python => env::var("PYTHON").unwrap_or_else(|_| "python3".to_owned()),
+        Language::CSharp => env::var("CSC").unwrap_or_else(|_| "csc".to_owned()),
     };
 
     let file_name = cbindgen_output
@@ -173,6 +185,25 @@ fn compile(
             command.arg("-o").arg(&object);
             command.arg(cbindgen_output);
         }
+        Language::Zig => {
+            command.arg("ast-check");
+            command.arg(cbindgen_output);
+        }
+        Language::Python => {
+            // Running the module is enough to have ctypes check the layouts.
+            command.arg(cbindgen_output);
+        }
+        Language::CSharp => {
+            command.arg("-nologo");
+            command.arg("-target:library");
+            command.arg("-unsafe");
+            command.arg("-define:DEFINED");
+            if !skip_warning_as_error {
+                command.arg("-warnaserror");
+            }
+            command.arg(format!("-out:{}", object.display()));
+            command.arg(cbindgen_output);
+        }
     }
 
     println!("Running: {:?}", command);
@@ -216,6 +247,9 @@ fn run_compile_test(
         // is extension-sensitive and won't work on them, so we use implementation files (`.pyx`)
         // in the test suite.
         Language::Cython => ".pyx",
+        Language::Zig => ".zig",
+        Language::Python => ".py",
+        Language::CSharp => ".cs",
     };
 
     let skip_warning_as_error = name.rfind(SKIP_WARNING_AS_ERROR_SUFFIX).is_some();
@@ -245,7 +279,7 @@ fn run_compile_test(
     );
     if generate_depfile {
         let depfile = depfile_contents.expect("No depfile generated");
-        assert!(depfile.len() > 0);
+        assert!(!depfile.is_empty());
         let mut rules = depfile.split(':');
         let target = rules.next().expect("No target found");
         assert_eq!(target, generated_file.as_os_str().to_str().unwrap());
@@ -281,6 +315,19 @@ fn run_compile_test(
             return;
         }
 
+        // Most of the raw snippets in the test configs (`header`, `trailer`, `[export.body]`,
+        // ...) are written to be valid C, C++ and Cython at the same time, but not Zig,
+        // Python or C#, so checking their output is opt-in.
+        if language == Language::Zig && env::var_os("ZIG").is_none() {
+            return;
+        }
+        if language == Language::Python && env::var_os("PYTHON").is_none() {
+            return;
+        }
+        if language == Language::CSharp && env::var_os("CSC").is_none() {
+            return;
+        }
+
         compile(
             &generated_file,
             &tests_path,
@@ -350,6 +397,36 @@ fn test_file(name: &'static str, filename: &'static str) {
             &mut cbindgen_outputs,
         );
     }
+
+    run_compile_test(
+        name,
+        test,
+        tmp_dir,
+        Language::Zig,
+        /* cpp_compat = */ false,
+        None,
+        &mut HashSet::new(),
+    );
+
+    run_compile_test(
+        name,
+        test,
+        tmp_dir,
+        Language::Python,
+        /* cpp_compat = */ false,
+        None,
+        &mut HashSet::new(),
+    );
+
+    run_compile_test(
+        name,
+        test,
+        tmp_dir,
+        Language::CSharp,
+        /* cpp_compat = */ false,
+        None,
+        &mut HashSet::new(),
+    );
 }
 
 macro_rules! test_file {